@@ -1,4 +1,5 @@
 use crate::ethereum::EthConfiguration;
+use crate::stake_history::StakeHistoryConfiguration;
 use sc_cli::RunCmd;
 
 #[derive(Debug, clap::Parser)]
@@ -15,6 +16,9 @@ pub struct Cli {
 
     #[command(flatten)]
     pub eth: EthConfiguration,
+
+    #[command(flatten)]
+    pub stake_history: StakeHistoryConfiguration,
 }
 
 #[allow(clippy::large_enum_variant)]
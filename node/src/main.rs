@@ -11,6 +11,7 @@ mod client;
 mod command;
 mod ethereum;
 mod rpc;
+mod stake_history;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
@@ -28,6 +28,7 @@ use sp_runtime::traits::Block as BlockT;
 use crate::{
     client::{FullBackend, FullClient},
     ethereum::create_eth,
+    stake_history::{StakeHistory, StakeHistoryApiServer},
 };
 
 /// Extra dependencies for Ethereum compatibility.
@@ -93,6 +94,8 @@ pub struct FullDeps<P, A: ChainApi, CT, CIDP> {
     pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
     /// Ethereum-compatibility specific dependencies.
     pub eth: EthDeps<P, A, CT, CIDP>,
+    /// Stake history index, if enabled.
+    pub stake_history: Option<StakeHistory>,
 }
 
 /// Instantiate all full RPC extensions.
@@ -114,7 +117,9 @@ where
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
-    use subtensor_custom_rpc::{SubtensorCustom, SubtensorCustomApiServer};
+    use subtensor_custom_rpc::{
+        SubtensorCustom, SubtensorCustomApiServer, SubtensorJson, SubtensorJsonApiServer,
+    };
 
     let mut module = RpcModule::new(());
     let FullDeps {
@@ -122,14 +127,22 @@ where
         pool,
         command_sink,
         eth,
+        stake_history,
     } = deps;
 
     // Custom RPC methods for Paratensor
     module.merge(SubtensorCustom::new(client.clone()).into_rpc())?;
+    module.merge(
+        SubtensorJson::<_, _, node_subtensor_runtime::Runtime>::new(client.clone()).into_rpc(),
+    )?;
 
     module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
     module.merge(TransactionPayment::new(client).into_rpc())?;
 
+    if let Some(stake_history) = stake_history {
+        module.merge(stake_history.into_rpc())?;
+    }
+
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
     // to call into the runtime.
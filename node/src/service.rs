@@ -25,6 +25,9 @@ use crate::ethereum::{
     FrontierBackend, FrontierBlockImport, FrontierPartialComponents, StorageOverride,
     StorageOverrideHandler,
 };
+use crate::stake_history::{
+    spawn_stake_history_task, StakeHistory, StakeHistoryBackend, StakeHistoryConfiguration,
+};
 
 /// The minimum period of blocks on which justifications will be
 /// imported and generated.
@@ -237,6 +240,7 @@ pub fn build_manual_seal_import_queue(
 pub async fn new_full<NB>(
     mut config: Configuration,
     eth_config: EthConfiguration,
+    stake_history_config: StakeHistoryConfiguration,
     sealing: Option<Sealing>,
 ) -> Result<TaskManager, ServiceError>
 where
@@ -266,6 +270,16 @@ where
         fee_history_cache_limit,
     } = new_frontier_partial(&eth_config)?;
 
+    let stake_history_backend = if stake_history_config.enable_stake_history {
+        let db_path = db_config_dir(&config).join("stake_history");
+        let backend = StakeHistoryBackend::open(&db_path).map_err(|e| {
+            ServiceError::Other(format!("failed opening stake history database: {:?}", e))
+        })?;
+        Some(Arc::new(backend))
+    } else {
+        None
+    };
+
     let maybe_registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
     let mut net_config = sc_network::config::FullNetworkConfiguration::<_, _, NB>::new(
         &config.network,
@@ -376,6 +390,9 @@ where
         let pubsub_notification_sinks = pubsub_notification_sinks.clone();
         let storage_override = storage_override.clone();
         let fee_history_cache = fee_history_cache.clone();
+        let stake_history = stake_history_backend.clone().map(|backend| {
+            StakeHistory::new(backend, stake_history_config.stake_history_max_block_range)
+        });
         let block_data_cache = Arc::new(fc_rpc::EthBlockDataCacheTask::new(
             task_manager.spawn_handle(),
             storage_override.clone(),
@@ -434,6 +451,7 @@ where
                     None
                 },
                 eth: eth_deps,
+                stake_history: stake_history.clone(),
             };
             crate::rpc::create_full(
                 deps,
@@ -473,6 +491,15 @@ where
     )
     .await;
 
+    if let Some(stake_history_backend) = stake_history_backend {
+        spawn_stake_history_task(
+            &task_manager,
+            client.clone(),
+            stake_history_backend,
+            stake_history_config.stake_history_start_block,
+        );
+    }
+
     if role.is_authority() {
         // manual-seal authorship
         if let Some(sealing) = sealing {
@@ -595,14 +622,27 @@ where
 pub async fn build_full(
     config: Configuration,
     eth_config: EthConfiguration,
+    stake_history_config: StakeHistoryConfiguration,
     sealing: Option<Sealing>,
 ) -> Result<TaskManager, ServiceError> {
     match config.network.network_backend {
         sc_network::config::NetworkBackendType::Libp2p => {
-            new_full::<sc_network::NetworkWorker<_, _>>(config, eth_config, sealing).await
+            new_full::<sc_network::NetworkWorker<_, _>>(
+                config,
+                eth_config,
+                stake_history_config,
+                sealing,
+            )
+            .await
         }
         sc_network::config::NetworkBackendType::Litep2p => {
-            new_full::<sc_network::NetworkWorker<_, _>>(config, eth_config, sealing).await
+            new_full::<sc_network::NetworkWorker<_, _>>(
+                config,
+                eth_config,
+                stake_history_config,
+                sealing,
+            )
+            .await
         }
     }
 }
@@ -0,0 +1,602 @@
+//! Optional node-side index of stake changes.
+//!
+//! When enabled, every finalized block is scanned for `ColdkeyStakeAdded`, `ColdkeyStakeRemoved`,
+//! `StakeMoved`, `StakeRebalanced`, `UnbondCancelled`, `HotkeyEmissionDrained` and `Slashed`
+//! events. The resulting per-coldkey stake deltas are written to a local database next to the
+//! frontier database and served by the `stakeHistory_getForColdkey` RPC.
+//!
+//! `HotkeyEmissionDrained` only carries the emission staked for the hotkey owner, so the rewards
+//! of the nominators are read from their reward ledgers in the state of the block.
+//!
+//! Indexing starts at `--stake-history-start-block`, or the finalized head, the first time the
+//! node runs with the index enabled and resumes from the last indexed block afterwards. A block
+//! which cannot be indexed stops the indexer, which retries it at the next finalized block.
+//!
+//! Events are decoded with the types of the runtime the node was built with. Blocks of runtimes
+//! older than [`FIRST_INDEXED_SPEC_VERSION`] are skipped, and blocks of runtimes newer than the
+//! native one stop the indexer until the node is upgraded. An index written with another
+//! [`INDEX_VERSION`] is dropped and rebuilt.
+
+use futures::StreamExt;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{ErrorObject, ErrorObjectOwned},
+};
+use kvdb::{DBTransaction, KeyValueDB};
+use node_subtensor_runtime::{AccountId, BlockNumber, Hash, RuntimeEvent};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sc_service::TaskManager;
+use sc_telemetry::log;
+use scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{blake2_128, storage::StorageKey, twox_128};
+use sp_runtime::traits::Header as HeaderT;
+use std::{collections::VecDeque, io, path::Path, sync::Arc};
+
+use crate::client::FullClient;
+
+const LOG_TARGET: &str = "stake-history";
+
+/// Column holding the history entries, keyed by `coldkey ++ block_number ++ index`.
+const COLUMN_ENTRIES: u32 = 0;
+/// Column holding the indexer metadata.
+const COLUMN_META: u32 = 1;
+const NUM_COLUMNS: u32 = 2;
+
+const META_LAST_INDEXED_BLOCK: &[u8] = b"last_indexed_block";
+const META_INDEX_VERSION: &[u8] = b"index_version";
+
+/// Version of the decoding of block events into history entries.
+pub const INDEX_VERSION: u32 = 1;
+
+/// The first runtime spec version depositing the stake events decoded by the indexer.
+pub const FIRST_INDEXED_SPEC_VERSION: u32 = 211;
+
+/// The stake history configuration used to run a node.
+#[derive(Clone, Debug, clap::Parser)]
+pub struct StakeHistoryConfiguration {
+    /// Index the stake changes of finalized blocks and serve them over the
+    /// `stakeHistory_getForColdkey` RPC method.
+    #[arg(long)]
+    pub enable_stake_history: bool,
+
+    /// Maximum number of blocks a single stake history query may span.
+    #[arg(long, default_value = "100000")]
+    pub stake_history_max_block_range: u32,
+
+    /// Block to start indexing from while the index is empty, to backfill the history of earlier
+    /// blocks. Defaults to the finalized head. Indexing reads the state of each block, so
+    /// backfilling requires the state of those blocks to be kept, e.g. by an archive node.
+    #[arg(long)]
+    pub stake_history_start_block: Option<BlockNumber>,
+}
+
+/// The kind of stake change recorded in the history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StakeChangeKind {
+    /// Stake added by the coldkey.
+    Added,
+    /// Stake removed by the coldkey.
+    Removed,
+    /// Stake credited to the coldkey when the hotkey emission was drained.
+    EmissionDrain,
+    /// Stake slashed by governance, including stake still unbonding from the hotkey.
+    Slashed,
+}
+
+/// A single stake change of a coldkey.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeHistoryEntry {
+    pub block_number: BlockNumber,
+    pub block_hash: Hash,
+    pub hotkey: AccountId,
+    pub kind: StakeChangeKind,
+    /// The amount of stake added or removed in rao.
+    pub amount: u64,
+}
+
+/// Local database holding the stake history.
+pub struct StakeHistoryBackend {
+    db: Arc<dyn KeyValueDB>,
+}
+
+impl StakeHistoryBackend {
+    /// Opens or creates the stake history database at `path`.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let config = kvdb_rocksdb::DatabaseConfig::with_columns(NUM_COLUMNS);
+        let db = kvdb_rocksdb::Database::open(&config, path)?;
+        let backend = Self { db: Arc::new(db) };
+        backend.ensure_index_version()?;
+        Ok(backend)
+    }
+
+    /// Drops an index written with another [`INDEX_VERSION`], so that it is rebuilt.
+    fn ensure_index_version(&self) -> io::Result<()> {
+        let version = self
+            .db
+            .get(COLUMN_META, META_INDEX_VERSION)?
+            .map(|value| {
+                u32::decode(&mut &value[..])
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .transpose()?;
+        if version == Some(INDEX_VERSION) {
+            return Ok(());
+        }
+        if self.last_indexed_block()?.is_some() {
+            log::info!(
+                target: LOG_TARGET,
+                "Rebuilding the stake history index written with version {:?}",
+                version
+            );
+        }
+
+        let mut transaction = DBTransaction::new();
+        transaction.delete_prefix(COLUMN_ENTRIES, &[]);
+        transaction.delete_prefix(COLUMN_META, &[]);
+        transaction.put_vec(COLUMN_META, META_INDEX_VERSION, INDEX_VERSION.encode());
+        self.db.write(transaction)
+    }
+
+    /// Returns the last block written to the index, if any.
+    pub fn last_indexed_block(&self) -> io::Result<Option<BlockNumber>> {
+        self.db
+            .get(COLUMN_META, META_LAST_INDEXED_BLOCK)?
+            .map(|value| {
+                BlockNumber::decode(&mut &value[..])
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .transpose()
+    }
+
+    /// Writes the stake changes of a block and marks it as indexed.
+    pub fn insert_block(
+        &self,
+        block_number: BlockNumber,
+        changes: Vec<(AccountId, StakeHistoryEntry)>,
+    ) -> io::Result<()> {
+        let mut transaction = DBTransaction::new();
+        for (index, (coldkey, entry)) in (0u32..).zip(changes) {
+            transaction.put_vec(
+                COLUMN_ENTRIES,
+                &entry_key(&coldkey, block_number, index),
+                entry.encode(),
+            );
+        }
+        transaction.put_vec(COLUMN_META, META_LAST_INDEXED_BLOCK, block_number.encode());
+        self.db.write(transaction)
+    }
+
+    /// Returns the stake changes of `coldkey` between `from_block` and `to_block`, inclusive.
+    pub fn get_for_coldkey(
+        &self,
+        coldkey: &AccountId,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> io::Result<Vec<StakeHistoryEntry>> {
+        let prefix: &[u8] = coldkey.as_ref();
+        let mut entries = Vec::new();
+        for item in self.db.iter_with_prefix(COLUMN_ENTRIES, prefix) {
+            let (key, value) = item?;
+            let block_number = key
+                .get(prefix.len()..prefix.len().saturating_add(4))
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(BlockNumber::from_be_bytes)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid key"))?;
+            if block_number < from_block {
+                continue;
+            }
+            if block_number > to_block {
+                break;
+            }
+            entries.push(
+                StakeHistoryEntry::decode(&mut &value[..])
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            );
+        }
+        Ok(entries)
+    }
+}
+
+/// Entries are keyed by coldkey first so that a prefix scan returns them in block order.
+fn entry_key(coldkey: &AccountId, block_number: BlockNumber, index: u32) -> Vec<u8> {
+    let mut key: Vec<u8> = coldkey.as_ref().to_vec();
+    key.extend_from_slice(&block_number.to_be_bytes());
+    key.extend_from_slice(&index.to_be_bytes());
+    key
+}
+
+type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// Returns the storage key prefix of a storage item.
+fn storage_prefix(pallet: &[u8], item: &[u8]) -> Vec<u8> {
+    [twox_128(pallet), twox_128(item)].concat()
+}
+
+/// Appends a `Blake2_128Concat` hashed storage map key to `key`.
+fn push_blake2_128_concat(key: &mut Vec<u8>, map_key: &AccountId) {
+    key.extend_from_slice(&blake2_128(map_key.as_ref()));
+    key.extend_from_slice(map_key.as_ref());
+}
+
+/// Reads and decodes the value stored at `key` in the block with `hash`.
+fn read_storage<V: Decode>(
+    client: &FullClient,
+    hash: Hash,
+    key: Vec<u8>,
+) -> Result<Option<V>, String> {
+    match client.storage(hash, &StorageKey(key)) {
+        Ok(Some(data)) => V::decode(&mut &data.0[..])
+            .map(Some)
+            .map_err(|e| format!("failed to decode storage: {:?}", e)),
+        Ok(None) => Ok(None),
+        Err(e) => Err(format!("failed to read storage: {:?}", e)),
+    }
+}
+
+/// Reads the events deposited in the block with `hash`.
+///
+/// Blocks of runtimes older than [`FIRST_INDEXED_SPEC_VERSION`] hold no events to index. Blocks of
+/// runtimes newer than the native runtime are rejected, as their events may not decode correctly.
+fn read_events(client: &FullClient, hash: Hash) -> Result<Vec<EventRecord>, String> {
+    let spec_version = client
+        .runtime_version_at(hash)
+        .map_err(|e| format!("failed to read the runtime version: {:?}", e))?
+        .spec_version;
+    if spec_version < FIRST_INDEXED_SPEC_VERSION {
+        return Ok(Vec::new());
+    }
+    if spec_version > node_subtensor_runtime::VERSION.spec_version {
+        return Err(format!(
+            "events of runtime spec version {} cannot be decoded by this node, which supports up to {}",
+            spec_version,
+            node_subtensor_runtime::VERSION.spec_version
+        ));
+    }
+
+    Ok(
+        read_storage::<Vec<EventRecord>>(client, hash, storage_prefix(b"System", b"Events"))?
+            .unwrap_or_default(),
+    )
+}
+
+/// Returns the stake credited by the emission drain of `hotkey` in the block `block_hash`, as
+/// `(coldkey, staked_on_hotkey, amount)`.
+///
+/// The drain runs when the block is initialized, so the nominators are the coldkeys staked on
+/// the hotkey at the parent block. The reward of each nominator is read from its reward ledger
+/// and credited to the hotkey its reward destination stakes on, or to `hotkey` if the reward was
+/// restaked as a fallback. `owner_emission` is credited to the owner of the hotkey.
+fn emission_drain_changes(
+    client: &FullClient,
+    block_number: BlockNumber,
+    block_hash: Hash,
+    parent_hash: Hash,
+    hotkey: &AccountId,
+    owner_emission: u64,
+    restaked: &[(AccountId, AccountId)],
+) -> Result<Vec<(AccountId, AccountId, u64)>, String> {
+    let mut stake_prefix = storage_prefix(b"SubtensorModule", b"Stake");
+    push_blake2_128_concat(&mut stake_prefix, hotkey);
+    let stake_prefix = StorageKey(stake_prefix);
+    let nominators = client
+        .storage_keys(parent_hash, Some(&stake_prefix), None)
+        .map_err(|e| format!("failed to read the nominators: {:?}", e))?
+        .map(|key| {
+            key.0
+                .get(stake_prefix.0.len()..)
+                .and_then(|mut coldkey| AccountId::decode(&mut coldkey).ok())
+                .ok_or_else(|| "invalid stake key".to_string())
+        })
+        .collect::<Result<Vec<AccountId>, String>>()?;
+
+    let mut changes = Vec::new();
+    for nominator in nominators {
+        let mut ledger_key = storage_prefix(b"SubtensorModule", b"NominatorRewards");
+        push_blake2_128_concat(&mut ledger_key, &nominator);
+        let reward: u64 = read_storage::<
+            VecDeque<pallet_subtensor::NominatorRewardEntry<AccountId>>,
+        >(client, block_hash, ledger_key)?
+        .unwrap_or_default()
+        .iter()
+        .filter(|entry| entry.block == u64::from(block_number) && entry.hotkey == *hotkey)
+        .fold(0, |total: u64, entry| total.saturating_add(entry.reward));
+        if reward == 0 {
+            continue;
+        }
+
+        let staked_on = if restaked.contains(&(nominator.clone(), hotkey.clone())) {
+            Some(hotkey.clone())
+        } else {
+            let mut destination_key = storage_prefix(b"SubtensorModule", b"RewardDestinations");
+            push_blake2_128_concat(&mut destination_key, &nominator);
+            push_blake2_128_concat(&mut destination_key, hotkey);
+            match read_storage(client, parent_hash, destination_key)?.unwrap_or_default() {
+                pallet_subtensor::RewardDestination::Restake => Some(hotkey.clone()),
+                pallet_subtensor::RewardDestination::Hotkey(destination) => Some(destination),
+                pallet_subtensor::RewardDestination::Free => None,
+            }
+        };
+        if let Some(staked_on) = staked_on {
+            changes.push((nominator, staked_on, reward));
+        }
+    }
+
+    if owner_emission > 0 {
+        let mut owner_key = storage_prefix(b"SubtensorModule", b"Owner");
+        push_blake2_128_concat(&mut owner_key, hotkey);
+        let owner: AccountId = read_storage(client, block_hash, owner_key)?
+            .ok_or_else(|| format!("owner of hotkey {} not found", hotkey))?;
+        changes.push((owner, hotkey.clone(), owner_emission));
+    }
+    Ok(changes)
+}
+
+/// Extracts the per-coldkey stake changes from the events of a block.
+fn stake_changes(
+    client: &FullClient,
+    block_number: BlockNumber,
+    block_hash: Hash,
+    parent_hash: Hash,
+    events: Vec<EventRecord>,
+) -> Result<Vec<(AccountId, StakeHistoryEntry)>, String> {
+    let entry = |hotkey: AccountId, kind: StakeChangeKind, amount: u64| StakeHistoryEntry {
+        block_number,
+        block_hash,
+        hotkey,
+        kind,
+        amount,
+    };
+
+    // Rewards whose destination could not receive them were restaked on the drained hotkey.
+    let restaked: Vec<(AccountId, AccountId)> =
+        events
+            .iter()
+            .filter_map(|record| match &record.event {
+                RuntimeEvent::SubtensorModule(
+                    pallet_subtensor::Event::NominatorRewardRestaked(coldkey, hotkey, _),
+                ) => Some((coldkey.clone(), hotkey.clone())),
+                _ => None,
+            })
+            .collect();
+
+    let mut changes = Vec::new();
+    for record in events {
+        match record.event {
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::ColdkeyStakeAdded(
+                coldkey,
+                hotkey,
+                amount,
+            )) => changes.push((coldkey, entry(hotkey, StakeChangeKind::Added, amount))),
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::ColdkeyStakeRemoved(
+                coldkey,
+                hotkey,
+                amount,
+            )) => changes.push((coldkey, entry(hotkey, StakeChangeKind::Removed, amount))),
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::UnbondCancelled(
+                coldkey,
+                hotkey,
+                amount,
+            )) => changes.push((coldkey, entry(hotkey, StakeChangeKind::Added, amount))),
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::HotkeyEmissionDrained(
+                hotkey,
+                _,
+                owner_emission,
+                _,
+            )) => {
+                for (coldkey, staked_on, amount) in emission_drain_changes(
+                    client,
+                    block_number,
+                    block_hash,
+                    parent_hash,
+                    &hotkey,
+                    owner_emission,
+                    &restaked,
+                )? {
+                    changes.push((
+                        coldkey,
+                        entry(staked_on, StakeChangeKind::EmissionDrain, amount),
+                    ));
+                }
+            }
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::Slashed(hotkey, slashed, _)) => {
+                for (coldkey, amount) in slashed {
+                    changes.push((
+                        coldkey,
+                        entry(hotkey.clone(), StakeChangeKind::Slashed, amount),
+                    ));
+                }
+            }
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::StakeMoved(
+                coldkey,
+                from_hotkey,
+                to_hotkey,
+                amount,
+            )) => {
+                changes.push((
+                    coldkey.clone(),
+                    entry(from_hotkey, StakeChangeKind::Removed, amount),
+                ));
+                changes.push((coldkey, entry(to_hotkey, StakeChangeKind::Added, amount)));
+            }
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::StakeRebalanced(
+                coldkey,
+                stake_changes,
+            )) => {
+                for (hotkey, old_stake, new_stake) in stake_changes {
+                    let change = if new_stake > old_stake {
+                        entry(
+                            hotkey,
+                            StakeChangeKind::Added,
+                            new_stake.saturating_sub(old_stake),
+                        )
+                    } else {
+                        entry(
+                            hotkey,
+                            StakeChangeKind::Removed,
+                            old_stake.saturating_sub(new_stake),
+                        )
+                    };
+                    changes.push((coldkey.clone(), change));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(changes)
+}
+
+/// Indexes every block after the last indexed one up to and including `finalized`.
+///
+/// Stops at the first block which cannot be indexed, leaving it to be retried by the next call.
+fn index_up_to(
+    client: &FullClient,
+    backend: &StakeHistoryBackend,
+    start_block: Option<BlockNumber>,
+    finalized: BlockNumber,
+) {
+    let start = match backend.last_indexed_block() {
+        Ok(Some(last)) => last.saturating_add(1),
+        Ok(None) => start_block.unwrap_or(finalized),
+        Err(e) => {
+            log::error!(target: LOG_TARGET, "Failed to read the stake history: {:?}", e);
+            return;
+        }
+    };
+
+    for block_number in start..=finalized {
+        let (block_hash, parent_hash) = match client.hash(block_number) {
+            Ok(Some(hash)) => match client.header(hash) {
+                Ok(Some(header)) => (hash, *header.parent_hash()),
+                _ => {
+                    log::warn!(target: LOG_TARGET, "Header of block #{} not found", block_number);
+                    return;
+                }
+            },
+            _ => {
+                log::warn!(target: LOG_TARGET, "Block #{} not found", block_number);
+                return;
+            }
+        };
+        let changes = match read_events(client, block_hash)
+            .and_then(|events| stake_changes(client, block_number, block_hash, parent_hash, events))
+        {
+            Ok(changes) => changes,
+            Err(e) => {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Stopped indexing at block #{} ({:?}), retrying at the next finalized block: {}",
+                    block_number,
+                    block_hash,
+                    e
+                );
+                return;
+            }
+        };
+        if let Err(e) = backend.insert_block(block_number, changes) {
+            log::error!(target: LOG_TARGET, "Failed to write the stake history: {:?}", e);
+            return;
+        }
+    }
+}
+
+/// Spawns the task indexing the stake changes of finalized blocks.
+///
+/// Indexing reads block events and writes to the database synchronously, so the task runs on a
+/// dedicated blocking thread instead of the async executor. While the index is empty, indexing
+/// starts at `start_block`, or the finalized head if it is `None`.
+pub fn spawn_stake_history_task(
+    task_manager: &TaskManager,
+    client: Arc<FullClient>,
+    backend: Arc<StakeHistoryBackend>,
+    start_block: Option<BlockNumber>,
+) {
+    task_manager.spawn_handle().spawn_blocking(
+        "stake-history-indexer",
+        Some("stake-history"),
+        async move {
+            let mut finality_notifications = client.finality_notification_stream();
+            index_up_to(
+                &client,
+                &backend,
+                start_block,
+                client.info().finalized_number,
+            );
+            while let Some(notification) = finality_notifications.next().await {
+                index_up_to(
+                    &client,
+                    &backend,
+                    start_block,
+                    *notification.header.number(),
+                );
+            }
+        },
+    );
+}
+
+/// Stake history RPC methods.
+#[rpc(server)]
+pub trait StakeHistoryApi {
+    /// Returns the stake changes of `coldkey` between `from_block` and `to_block`, inclusive.
+    #[method(name = "stakeHistory_getForColdkey")]
+    fn get_for_coldkey(
+        &self,
+        coldkey: AccountId,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> RpcResult<Vec<StakeHistoryEntry>>;
+}
+
+/// Implements the [`StakeHistoryApiServer`] RPC trait over the local stake history database.
+#[derive(Clone)]
+pub struct StakeHistory {
+    backend: Arc<StakeHistoryBackend>,
+    max_block_range: u32,
+}
+
+impl StakeHistory {
+    /// Creates a new instance of the stake history RPC handler.
+    pub fn new(backend: Arc<StakeHistoryBackend>, max_block_range: u32) -> Self {
+        Self {
+            backend,
+            max_block_range,
+        }
+    }
+}
+
+impl StakeHistoryApiServer for StakeHistory {
+    fn get_for_coldkey(
+        &self,
+        coldkey: AccountId,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> RpcResult<Vec<StakeHistoryEntry>> {
+        if from_block > to_block {
+            return Err(invalid_params("from_block must not exceed to_block"));
+        }
+        if to_block.saturating_sub(from_block) >= self.max_block_range {
+            return Err(invalid_params(&format!(
+                "block range must span fewer than {} blocks",
+                self.max_block_range
+            )));
+        }
+
+        self.backend
+            .get_for_coldkey(&coldkey, from_block, to_block)
+            .map_err(|e| {
+                ErrorObject::owned(
+                    1,
+                    format!("Unable to read stake history: {:?}", e),
+                    None::<()>,
+                )
+            })
+    }
+}
+
+fn invalid_params(message: &str) -> ErrorObjectOwned {
+    ErrorObject::owned(3, message, None::<()>)
+}
@@ -17,6 +17,7 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_support::traits::tokens::Balance;
     use frame_system::pallet_prelude::*;
+    use pallet_subtensor::utils::rate_limiting::TransactionType;
     use sp_runtime::BoundedVec;
 
     /// The main data structure of the module.
@@ -62,6 +63,20 @@ pub mod pallet {
         MaxValidatorsLargerThanMaxUIds,
         /// The maximum number of subnet validators must be more than the current number of UIDs already in the subnet.
         MaxAllowedUIdsLessThanCurrentUIds,
+        /// The maximum number of unrevealed commits must be between one and the allowed upper bound.
+        MaxUnrevealedCommitsOutOfRange,
+        /// The transaction type is not a rate limited transaction type.
+        InvalidTransactionType,
+        /// The number of epochs of the lowest emission pruning policy must be between one and the allowed upper bound.
+        PruningEmissionEpochsOutOfRange,
+        /// The number of uids protected from pruning exceeds the allowed upper bound.
+        TooManyPruningProtectedUids,
+        /// A uid protected from pruning is not below the maximum number of allowed UIDs, or is listed twice.
+        InvalidPruningProtectedUid,
+        /// The number of identity registrars exceeds the allowed upper bound.
+        TooManyIdentityRegistrars,
+        /// An identity registrar is listed more than once.
+        DuplicateIdentityRegistrar,
     }
 
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -1209,6 +1224,431 @@ pub mod pallet {
             );
             Ok(())
         }
+
+        /// Sets the unbonding period for a specific subnet.
+        ///
+        /// This extrinsic allows the root account to set the number of blocks stake removed from hotkeys registered on the subnet waits in the unbonding queue before it is released.
+        /// A period of zero disables unbonding and releases removed stake immediately.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `netuid` - The unique identifier of the subnet for which the period is being set.
+        /// * `unbonding_period` - The unbonding period in blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(58)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1))
+                .saturating_add(T::DbWeight::get().reads(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_unbonding_period(
+            origin: OriginFor<T>,
+            netuid: u16,
+            unbonding_period: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_unbonding_period(netuid, unbonding_period);
+            log::debug!(
+                "UnbondingPeriodSet( netuid: {:?}, unbonding_period: {:?} ) ",
+                netuid,
+                unbonding_period
+            );
+            Ok(())
+        }
+
+        /// Sets the maximum number of unrevealed weight commits a hotkey can have on a specific subnet.
+        ///
+        /// This extrinsic allows the subnet owner or root account to set how many weight commits a hotkey can have pending before further commits are rejected.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or the root account.
+        /// * `netuid` - The unique identifier of the subnet for which the limit is being set.
+        /// * `max_unrevealed_commits` - The maximum number of unrevealed commits per hotkey.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither the subnet owner nor the root account.
+        /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
+        /// * `MaxUnrevealedCommitsOutOfRange` - If the limit is zero or above the allowed upper bound.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(59)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1))
+                .saturating_add(T::DbWeight::get().reads(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_max_unrevealed_commits(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_unrevealed_commits: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                max_unrevealed_commits > 0
+                    && max_unrevealed_commits <= pallet_subtensor::MAX_UNREVEALED_COMMITS_LIMIT,
+                Error::<T>::MaxUnrevealedCommitsOutOfRange
+            );
+
+            pallet_subtensor::Pallet::<T>::set_max_unrevealed_commits(
+                netuid,
+                max_unrevealed_commits,
+            );
+            log::debug!(
+                "MaxUnrevealedCommitsSet( netuid: {:?}, max_unrevealed_commits: {:?} ) ",
+                netuid,
+                max_unrevealed_commits
+            );
+            Ok(())
+        }
+
+        /// Sets the rate limit of a transaction type on a specific subnet or on all subnets.
+        ///
+        /// This extrinsic allows the root account to set the number of blocks an account has to wait between two
+        /// transactions of the given type. A limit set on a subnet takes precedence over the global limit.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `tx_type` - The transaction type, as numbered by `TransactionType`.
+        /// * `netuid` - The subnet the limit is set on, or `GLOBAL_RATE_LIMIT_NETUID` for all subnets.
+        /// * `rate_limit` - The rate limit in blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `InvalidTransactionType` - If the transaction type is unknown.
+        /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(60)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1))
+                .saturating_add(T::DbWeight::get().reads(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_rate_limit(
+            origin: OriginFor<T>,
+            tx_type: u16,
+            netuid: u16,
+            rate_limit: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let transaction_type = TransactionType::from(tx_type);
+            ensure!(
+                !matches!(transaction_type, TransactionType::Unknown),
+                Error::<T>::InvalidTransactionType
+            );
+            ensure!(
+                netuid == pallet_subtensor::GLOBAL_RATE_LIMIT_NETUID
+                    || pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_rate_limit(&transaction_type, netuid, rate_limit);
+            log::debug!(
+                "RateLimitSet( tx_type: {:?}, netuid: {:?}, rate_limit: {:?} ) ",
+                tx_type,
+                netuid,
+                rate_limit
+            );
+            Ok(())
+        }
+
+        /// Sets the policy choosing the neuron pruned when a new neuron registers on a full subnet.
+        ///
+        /// This extrinsic allows the subnet owner or root account to choose between pruning the neuron with the lowest pruning score, incentive, emission over the last epochs or stake, or the oldest neuron.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or the root account.
+        /// * `netuid` - The unique identifier of the subnet for which the policy is being set.
+        /// * `policy` - The pruning policy.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither the subnet owner nor the root account.
+        /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
+        /// * `PruningEmissionEpochsOutOfRange` - If the lowest emission policy considers zero or too many epochs.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(61)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(2))
+                .saturating_add(T::DbWeight::get().reads(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_pruning_policy(
+            origin: OriginFor<T>,
+            netuid: u16,
+            policy: pallet_subtensor::PruningPolicy,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            if let pallet_subtensor::PruningPolicy::LowestEmission(epochs) = policy {
+                ensure!(
+                    epochs > 0 && epochs <= pallet_subtensor::MAX_PRUNING_EMISSION_EPOCHS,
+                    Error::<T>::PruningEmissionEpochsOutOfRange
+                );
+            }
+
+            pallet_subtensor::Pallet::<T>::set_pruning_policy(netuid, policy);
+            log::debug!(
+                "PruningPolicySet( netuid: {:?}, policy: {:?} ) ",
+                netuid,
+                policy
+            );
+            Ok(())
+        }
+
+        /// Sets the uids of a subnet which are only pruned when all neurons of the subnet are protected.
+        ///
+        /// This extrinsic allows the subnet owner or root account to protect uids from pruning under any pruning policy.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or the root account.
+        /// * `netuid` - The unique identifier of the subnet for which the uids are being protected.
+        /// * `uids` - The protected uids, replacing the previously protected ones.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither the subnet owner nor the root account.
+        /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
+        /// * `TooManyPruningProtectedUids` - If more uids than the allowed upper bound are protected.
+        /// * `InvalidPruningProtectedUid` - If a uid is not below the maximum number of allowed UIDs or is duplicated.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(62)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1))
+                .saturating_add(T::DbWeight::get().reads(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_pruning_protected_uids(
+            origin: OriginFor<T>,
+            netuid: u16,
+            uids: Vec<u16>,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                uids.len() <= pallet_subtensor::MAX_PRUNING_PROTECTED_UIDS,
+                Error::<T>::TooManyPruningProtectedUids
+            );
+            let max_allowed_uids: u16 = pallet_subtensor::Pallet::<T>::get_max_allowed_uids(netuid);
+            for (i, uid) in uids.iter().enumerate() {
+                ensure!(
+                    *uid < max_allowed_uids && !uids.iter().take(i).any(|other| other == uid),
+                    Error::<T>::InvalidPruningProtectedUid
+                );
+            }
+
+            pallet_subtensor::Pallet::<T>::set_pruning_protected_uids(netuid, uids.clone());
+            log::debug!(
+                "PruningProtectedUidsSet( netuid: {:?}, uids: {:?} ) ",
+                netuid,
+                uids
+            );
+            Ok(())
+        }
+
+        /// Enables or disables the registration auction of a subnet.
+        ///
+        /// This extrinsic allows the subnet owner or root account to register neurons through an auction settled at the
+        /// end of each adjustment interval instead of first-come burn registration. Disabling the auction refunds its open bids.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or the root account.
+        /// * `netuid` - The unique identifier of the subnet for which the auction is being toggled.
+        /// * `enabled` - Whether neurons register through the auction.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither the subnet owner nor the root account.
+        /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(63)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1))
+                .saturating_add(T::DbWeight::get().reads(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_registration_auction_enabled(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_registration_auction_enabled(netuid, enabled);
+            log::debug!(
+                "RegistrationAuctionEnabledSet( netuid: {:?}, enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Sets the controller adjusting the burn and difficulty of a subnet.
+        ///
+        /// This extrinsic allows the subnet owner or root account to choose between the EMA controller weighted by the
+        /// adjustment alpha, a PID controller and a controller decaying the burn and difficulty toward their minimum when idle.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or the root account.
+        /// * `netuid` - The unique identifier of the subnet for which the controller is being set.
+        /// * `controller` - The adjustment controller, which restarts the accumulated registration error.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither the subnet owner nor the root account.
+        /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(64)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(2))
+                .saturating_add(T::DbWeight::get().reads(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_adjustment_controller(
+            origin: OriginFor<T>,
+            netuid: u16,
+            controller: pallet_subtensor::AdjustmentController,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_adjustment_controller(netuid, controller);
+            log::debug!(
+                "AdjustmentControllerSet( netuid: {:?}, controller: {:?} ) ",
+                netuid,
+                controller
+            );
+            Ok(())
+        }
+
+        /// Sets the registrars attesting the typed fields of coldkey and subnet identities.
+        ///
+        /// This extrinsic allows the root account to replace the identity registrars. The attestations of removed
+        /// registrars are no longer reported as verified.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `registrars` - The identity registrars, replacing the previous ones.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `TooManyIdentityRegistrars` - If more registrars than the allowed upper bound are set.
+        /// * `DuplicateIdentityRegistrar` - If a registrar is listed more than once.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(65)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_identity_registrars(
+            origin: OriginFor<T>,
+            registrars: Vec<T::AccountId>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                registrars.len() <= pallet_subtensor::MAX_IDENTITY_REGISTRARS,
+                Error::<T>::TooManyIdentityRegistrars
+            );
+            for (i, registrar) in registrars.iter().enumerate() {
+                ensure!(
+                    !registrars.iter().take(i).any(|other| other == registrar),
+                    Error::<T>::DuplicateIdentityRegistrar
+                );
+            }
+
+            pallet_subtensor::Pallet::<T>::set_identity_registrars(registrars.clone());
+            log::debug!("IdentityRegistrarsSet( registrars: {:?} ) ", registrars);
+            Ok(())
+        }
+
+        /// Sets the number of blocks a senate member serves before sitting out as many blocks.
+        ///
+        /// This extrinsic allows the root account to limit the terms of senate members. Members which have served the
+        /// term limit leave the senate at the next root epoch. A term limit of zero lets members serve without limit.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `term_limit` - The term limit in blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(66)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_senate_term_limit(origin: OriginFor<T>, term_limit: u64) -> DispatchResult {
+            ensure_root(origin)?;
+
+            pallet_subtensor::Pallet::<T>::set_senate_term_limit(term_limit);
+            log::debug!("SenateTermLimitSet( term_limit: {:?} ) ", term_limit);
+            Ok(())
+        }
     }
 }
 
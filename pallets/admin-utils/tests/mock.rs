@@ -190,6 +190,8 @@ impl pallet_subtensor::Config for Test {
     type Preimages = ();
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type TimelockKeySource = ();
+    type NestedCalls = ();
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
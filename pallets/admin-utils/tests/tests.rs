@@ -5,6 +5,7 @@ use frame_support::{
 };
 use frame_system::Config;
 use pallet_admin_utils::Error;
+use pallet_subtensor::utils::rate_limiting::TransactionType;
 use pallet_subtensor::Error as SubtensorError;
 use pallet_subtensor::{migrations, Event};
 use sp_core::U256;
@@ -569,6 +570,399 @@ fn test_sudo_set_activity_cutoff() {
     });
 }
 
+#[test]
+fn test_sudo_set_unbonding_period() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u64 = 7200;
+        add_network(netuid, 10);
+        let init_value: u64 = SubtensorModule::get_unbonding_period(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_unbonding_period(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_unbonding_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_unbonding_period(netuid), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_unrevealed_commits() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        let to_be_set: u16 = 20;
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let init_value: u16 = SubtensorModule::get_max_unrevealed_commits(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_max_unrevealed_commits(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_unrevealed_commits(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_unrevealed_commits(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                0
+            ),
+            Err(Error::<Test>::MaxUnrevealedCommitsOutOfRange.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_unrevealed_commits(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                pallet_subtensor::MAX_UNREVEALED_COMMITS_LIMIT + 1
+            ),
+            Err(Error::<Test>::MaxUnrevealedCommitsOutOfRange.into())
+        );
+        assert_eq!(
+            SubtensorModule::get_max_unrevealed_commits(netuid),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_max_unrevealed_commits(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_unrevealed_commits(netuid),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+        let tx_type = TransactionType::ServeAxon;
+        add_network(netuid, 10);
+        add_network(other_netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                tx_type.into(),
+                netuid,
+                10
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                TransactionType::Unknown.into(),
+                netuid,
+                10
+            ),
+            Err(Error::<Test>::InvalidTransactionType.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                tx_type.into(),
+                other_netuid + 1,
+                10
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+
+        // The global limit applies to all subnets without a limit of their own.
+        assert_ok!(AdminUtils::sudo_set_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            tx_type.into(),
+            pallet_subtensor::GLOBAL_RATE_LIMIT_NETUID,
+            20
+        ));
+        assert_eq!(SubtensorModule::get_serving_rate_limit(netuid), 20);
+        assert_eq!(SubtensorModule::get_serving_rate_limit(other_netuid), 20);
+
+        assert_ok!(AdminUtils::sudo_set_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            tx_type.into(),
+            netuid,
+            10
+        ));
+        assert_eq!(SubtensorModule::get_serving_rate_limit(netuid), 10);
+        assert_eq!(SubtensorModule::get_serving_rate_limit(other_netuid), 20);
+    });
+}
+
+#[test]
+fn test_sudo_set_pruning_policy() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        let to_be_set = pallet_subtensor::PruningPolicy::LowestEmission(5);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let init_value = SubtensorModule::get_pruning_policy(netuid);
+        assert_eq!(
+            init_value,
+            pallet_subtensor::PruningPolicy::LowestPruningScore
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_pruning_policy(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_pruning_policy(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        for epochs in [0, pallet_subtensor::MAX_PRUNING_EMISSION_EPOCHS + 1] {
+            assert_eq!(
+                AdminUtils::sudo_set_pruning_policy(
+                    <<Test as Config>::RuntimeOrigin>::signed(owner),
+                    netuid,
+                    pallet_subtensor::PruningPolicy::LowestEmission(epochs)
+                ),
+                Err(Error::<Test>::PruningEmissionEpochsOutOfRange.into())
+            );
+        }
+        assert_eq!(SubtensorModule::get_pruning_policy(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_pruning_policy(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_pruning_policy(netuid), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_pruning_protected_uids() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        let to_be_set: Vec<u16> = vec![0, 3];
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let max_allowed_uids: u16 = SubtensorModule::get_max_allowed_uids(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_pruning_protected_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set.clone()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_pruning_protected_uids(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set.clone()
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_pruning_protected_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                vec![0; pallet_subtensor::MAX_PRUNING_PROTECTED_UIDS + 1]
+            ),
+            Err(Error::<Test>::TooManyPruningProtectedUids.into())
+        );
+        for uids in [vec![max_allowed_uids], vec![1, 1]] {
+            assert_eq!(
+                AdminUtils::sudo_set_pruning_protected_uids(
+                    <<Test as Config>::RuntimeOrigin>::signed(owner),
+                    netuid,
+                    uids
+                ),
+                Err(Error::<Test>::InvalidPruningProtectedUid.into())
+            );
+        }
+        assert!(SubtensorModule::get_pruning_protected_uids(netuid).is_empty());
+        assert_ok!(AdminUtils::sudo_set_pruning_protected_uids(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            to_be_set.clone()
+        ));
+        assert_eq!(
+            SubtensorModule::get_pruning_protected_uids(netuid),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_registration_auction_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_eq!(
+            AdminUtils::sudo_set_registration_auction_enabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_registration_auction_enabled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert!(!SubtensorModule::get_registration_auction_enabled(netuid));
+        assert_ok!(AdminUtils::sudo_set_registration_auction_enabled(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_registration_auction_enabled(netuid));
+        assert_ok!(AdminUtils::sudo_set_registration_auction_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            false
+        ));
+        assert!(!SubtensorModule::get_registration_auction_enabled(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_adjustment_controller() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        let to_be_set = pallet_subtensor::AdjustmentController::Pid {
+            proportional_gain: u16::MAX / 2,
+            integral_gain: u16::MAX / 8,
+        };
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_eq!(
+            SubtensorModule::get_adjustment_controller(netuid),
+            pallet_subtensor::AdjustmentController::Ema
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_adjustment_controller(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_adjustment_controller(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        pallet_subtensor::AdjustmentIntegral::<Test>::insert(netuid, 5);
+        assert_ok!(AdminUtils::sudo_set_adjustment_controller(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_adjustment_controller(netuid),
+            to_be_set
+        );
+        // The accumulated registration error restarts with the controller.
+        assert_eq!(SubtensorModule::get_adjustment_integral(netuid), 0);
+    });
+}
+
+#[test]
+fn test_sudo_set_identity_registrars() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: Vec<U256> = vec![U256::from(3), U256::from(4)];
+        assert!(SubtensorModule::get_identity_registrars().is_empty());
+        assert_eq!(
+            AdminUtils::sudo_set_identity_registrars(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set.clone()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_identity_registrars(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                vec![U256::from(3), U256::from(3)]
+            ),
+            Err(Error::<Test>::DuplicateIdentityRegistrar.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_identity_registrars(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                (0..=pallet_subtensor::MAX_IDENTITY_REGISTRARS as u64)
+                    .map(U256::from)
+                    .collect()
+            ),
+            Err(Error::<Test>::TooManyIdentityRegistrars.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_identity_registrars(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set.clone()
+        ));
+        assert_eq!(SubtensorModule::get_identity_registrars(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_senate_term_limit() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 50_400;
+        assert_eq!(SubtensorModule::get_senate_term_limit(), 0);
+        assert_eq!(
+            AdminUtils::sudo_set_senate_term_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_senate_term_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_senate_term_limit(), to_be_set);
+    });
+}
+
 #[test]
 fn test_sudo_set_target_registrations_per_interval() {
     new_test_ext().execute_with(|| {
@@ -0,0 +1,712 @@
+//! Human readable JSON representations of the SCALE encoded runtime API responses.
+//!
+//! Accounts are rendered as SS58 strings and balances as decimal TAO strings so that
+//! clients can consume them without knowing the SCALE layout of the runtime types.
+
+use codec::Compact;
+use pallet_subtensor::rpc_info::{
+    delegate_info::DelegateInfo,
+    neuron_info::{NeuronInfo, NeuronInfoLite},
+    stake_info::StakeInfo,
+    subnet_info::{SubnetHyperparams, SubnetInfo, SubnetInfov2},
+};
+use pallet_subtensor::{
+    AdjustmentController, AxonInfo, ChainIdentity, IdentityField, PrometheusInfo, PruningPolicy,
+    RewardDestination, SubnetIdentity,
+};
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::Ss58Codec;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Number of rao in one TAO.
+const RAO_PER_TAO: u64 = 1_000_000_000;
+
+/// Renders a rao amount as a decimal TAO string with nine fractional digits.
+pub fn rao_to_tao_string(rao: u64) -> String {
+    format!(
+        "{}.{:09}",
+        rao.checked_div(RAO_PER_TAO).unwrap_or_default(),
+        rao.checked_rem(RAO_PER_TAO).unwrap_or_default()
+    )
+}
+
+/// Renders an encoded axon or prometheus ip address in its textual form.
+fn ip_to_string(ip: u128, ip_type: u8) -> String {
+    match ip_type {
+        4 => Ipv4Addr::from(ip as u32).to_string(),
+        6 => Ipv6Addr::from(ip).to_string(),
+        _ => ip.to_string(),
+    }
+}
+
+fn ss58<A: Ss58Codec>(account: &A) -> String {
+    account.to_ss58check()
+}
+
+fn tao(amount: &Compact<u64>) -> String {
+    rao_to_tao_string(amount.0)
+}
+
+fn stake_map<A: Ss58Codec>(stake: &[(A, Compact<u64>)]) -> Vec<(String, String)> {
+    stake
+        .iter()
+        .map(|(account, amount)| (ss58(account), tao(amount)))
+        .collect()
+}
+
+fn uid_pairs(pairs: &[(Compact<u16>, Compact<u16>)]) -> Vec<(u16, u16)> {
+    pairs.iter().map(|(uid, value)| (uid.0, value.0)).collect()
+}
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+fn verified_fields(fields: &[IdentityField]) -> Vec<IdentityFieldJson> {
+    fields.iter().copied().map(Into::into).collect()
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DelegateInfoJson {
+    pub delegate_ss58: String,
+    pub take: u16,
+    /// Pairs of nominator ss58 and staked TAO.
+    pub nominators: Vec<(String, String)>,
+    pub owner_ss58: String,
+    pub registrations: Vec<u16>,
+    pub validator_permits: Vec<u16>,
+    pub return_per_1000: String,
+    pub total_daily_return: String,
+    /// Identity of the owner coldkey.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<ChainIdentityJson>,
+    /// Fields of the owner identity attested by a current registrar.
+    pub verified_identity_fields: Vec<IdentityFieldJson>,
+}
+
+impl<T> From<DelegateInfo<T>> for DelegateInfoJson
+where
+    T: pallet_subtensor::Config,
+    T::AccountId: Ss58Codec,
+{
+    fn from(info: DelegateInfo<T>) -> Self {
+        Self {
+            delegate_ss58: ss58(&info.delegate_ss58),
+            take: info.take.0,
+            nominators: stake_map(&info.nominators),
+            owner_ss58: ss58(&info.owner_ss58),
+            registrations: info.registrations.iter().map(|n| n.0).collect(),
+            validator_permits: info.validator_permits.iter().map(|n| n.0).collect(),
+            return_per_1000: tao(&info.return_per_1000),
+            total_daily_return: tao(&info.total_daily_return),
+            identity: info.identity.map(Into::into),
+            verified_identity_fields: verified_fields(&info.verified_identity_fields),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DelegatedInfoJson {
+    pub delegate: DelegateInfoJson,
+    /// TAO staked by the queried coldkey on this delegate.
+    pub stake: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AxonInfoJson {
+    pub block: u64,
+    pub version: u32,
+    pub ip: String,
+    pub port: u16,
+    pub ip_type: u8,
+    pub protocol: u8,
+}
+
+impl From<AxonInfo> for AxonInfoJson {
+    fn from(axon: AxonInfo) -> Self {
+        Self {
+            block: axon.block,
+            version: axon.version,
+            ip: ip_to_string(axon.ip, axon.ip_type),
+            port: axon.port,
+            ip_type: axon.ip_type,
+            protocol: axon.protocol,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PrometheusInfoJson {
+    pub block: u64,
+    pub version: u32,
+    pub ip: String,
+    pub port: u16,
+    pub ip_type: u8,
+}
+
+impl From<PrometheusInfo> for PrometheusInfoJson {
+    fn from(prometheus: PrometheusInfo) -> Self {
+        Self {
+            block: prometheus.block,
+            version: prometheus.version,
+            ip: ip_to_string(prometheus.ip, prometheus.ip_type),
+            port: prometheus.port,
+            ip_type: prometheus.ip_type,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NeuronInfoJson {
+    pub hotkey: String,
+    pub coldkey: String,
+    pub uid: u16,
+    pub netuid: u16,
+    pub active: bool,
+    pub axon_info: AxonInfoJson,
+    pub prometheus_info: PrometheusInfoJson,
+    /// Pairs of coldkey ss58 and staked TAO.
+    pub stake: Vec<(String, String)>,
+    pub rank: u16,
+    pub emission: String,
+    pub incentive: u16,
+    pub consensus: u16,
+    pub trust: u16,
+    pub validator_trust: u16,
+    pub dividends: u16,
+    pub last_update: u64,
+    pub validator_permit: bool,
+    /// Pairs of uid and weight, omitted for the lite variant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weights: Option<Vec<(u16, u16)>>,
+    /// Pairs of uid and bond, omitted for the lite variant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bonds: Option<Vec<(u16, u16)>>,
+    pub pruning_score: u16,
+}
+
+impl<T> From<NeuronInfo<T>> for NeuronInfoJson
+where
+    T: pallet_subtensor::Config,
+    T::AccountId: Ss58Codec,
+{
+    fn from(neuron: NeuronInfo<T>) -> Self {
+        Self {
+            hotkey: ss58(&neuron.hotkey),
+            coldkey: ss58(&neuron.coldkey),
+            uid: neuron.uid.0,
+            netuid: neuron.netuid.0,
+            active: neuron.active,
+            axon_info: neuron.axon_info.into(),
+            prometheus_info: neuron.prometheus_info.into(),
+            stake: stake_map(&neuron.stake),
+            rank: neuron.rank.0,
+            emission: tao(&neuron.emission),
+            incentive: neuron.incentive.0,
+            consensus: neuron.consensus.0,
+            trust: neuron.trust.0,
+            validator_trust: neuron.validator_trust.0,
+            dividends: neuron.dividends.0,
+            last_update: neuron.last_update.0,
+            validator_permit: neuron.validator_permit,
+            weights: Some(uid_pairs(&neuron.weights)),
+            bonds: Some(uid_pairs(&neuron.bonds)),
+            pruning_score: neuron.pruning_score.0,
+        }
+    }
+}
+
+impl<T> From<NeuronInfoLite<T>> for NeuronInfoJson
+where
+    T: pallet_subtensor::Config,
+    T::AccountId: Ss58Codec,
+{
+    fn from(neuron: NeuronInfoLite<T>) -> Self {
+        Self {
+            hotkey: ss58(&neuron.hotkey),
+            coldkey: ss58(&neuron.coldkey),
+            uid: neuron.uid.0,
+            netuid: neuron.netuid.0,
+            active: neuron.active,
+            axon_info: neuron.axon_info.into(),
+            prometheus_info: neuron.prometheus_info.into(),
+            stake: stake_map(&neuron.stake),
+            rank: neuron.rank.0,
+            emission: tao(&neuron.emission),
+            incentive: neuron.incentive.0,
+            consensus: neuron.consensus.0,
+            trust: neuron.trust.0,
+            validator_trust: neuron.validator_trust.0,
+            dividends: neuron.dividends.0,
+            last_update: neuron.last_update.0,
+            validator_permit: neuron.validator_permit,
+            weights: None,
+            bonds: None,
+            pruning_score: neuron.pruning_score.0,
+        }
+    }
+}
+
+/// The identity of a coldkey, the logo hash as 0x-prefixed hex string.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ChainIdentityJson {
+    pub name: String,
+    pub url: String,
+    pub image: String,
+    pub discord: String,
+    pub description: String,
+    pub additional: String,
+    pub github_repo: String,
+    pub contact: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_hash: Option<String>,
+}
+
+impl From<ChainIdentity> for ChainIdentityJson {
+    fn from(identity: ChainIdentity) -> Self {
+        Self {
+            name: text(&identity.name),
+            url: text(&identity.url),
+            image: text(&identity.image),
+            discord: text(&identity.discord),
+            description: text(&identity.description),
+            additional: text(&identity.additional),
+            github_repo: text(&identity.github_repo),
+            contact: text(&identity.contact),
+            logo_hash: identity.logo_hash.map(|hash| format!("{:?}", hash)),
+        }
+    }
+}
+
+/// The identity of a subnet, the logo hash as 0x-prefixed hex string.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SubnetIdentityJson {
+    pub subnet_name: String,
+    pub github_repo: String,
+    pub subnet_contact: String,
+    pub subnet_url: String,
+    pub discord: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_hash: Option<String>,
+}
+
+impl From<SubnetIdentity> for SubnetIdentityJson {
+    fn from(identity: SubnetIdentity) -> Self {
+        Self {
+            subnet_name: text(&identity.subnet_name),
+            github_repo: text(&identity.github_repo),
+            subnet_contact: text(&identity.subnet_contact),
+            subnet_url: text(&identity.subnet_url),
+            discord: text(&identity.discord),
+            logo_hash: identity.logo_hash.map(|hash| format!("{:?}", hash)),
+        }
+    }
+}
+
+/// A typed identity field which registrars attest.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum IdentityFieldJson {
+    Url,
+    GithubRepo,
+    Discord,
+    Contact,
+    LogoHash,
+}
+
+impl From<IdentityField> for IdentityFieldJson {
+    fn from(field: IdentityField) -> Self {
+        match field {
+            IdentityField::Url => Self::Url,
+            IdentityField::GithubRepo => Self::GithubRepo,
+            IdentityField::Discord => Self::Discord,
+            IdentityField::Contact => Self::Contact,
+            IdentityField::LogoHash => Self::LogoHash,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SubnetInfoJson {
+    pub netuid: u16,
+    pub rho: u16,
+    pub kappa: u16,
+    pub difficulty: u64,
+    pub immunity_period: u16,
+    pub max_allowed_validators: u16,
+    pub min_allowed_weights: u16,
+    pub max_weights_limit: u16,
+    pub scaling_law_power: u16,
+    pub subnetwork_n: u16,
+    pub max_allowed_uids: u16,
+    pub blocks_since_last_step: u64,
+    pub tempo: u16,
+    pub network_modality: u16,
+    pub network_connect: Vec<[u16; 2]>,
+    pub emission_values: String,
+    pub burn: String,
+    pub owner_ss58: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<SubnetIdentityJson>,
+    /// Fields of the identity attested by a current registrar.
+    pub verified_identity_fields: Vec<IdentityFieldJson>,
+}
+
+impl<T> From<SubnetInfo<T>> for SubnetInfoJson
+where
+    T: pallet_subtensor::Config,
+    T::AccountId: Ss58Codec,
+{
+    fn from(info: SubnetInfo<T>) -> Self {
+        Self {
+            netuid: info.netuid.0,
+            rho: info.rho.0,
+            kappa: info.kappa.0,
+            difficulty: info.difficulty.0,
+            immunity_period: info.immunity_period.0,
+            max_allowed_validators: info.max_allowed_validators.0,
+            min_allowed_weights: info.min_allowed_weights.0,
+            max_weights_limit: info.max_weights_limit.0,
+            scaling_law_power: info.scaling_law_power.0,
+            subnetwork_n: info.subnetwork_n.0,
+            max_allowed_uids: info.max_allowed_uids.0,
+            blocks_since_last_step: info.blocks_since_last_step.0,
+            tempo: info.tempo.0,
+            network_modality: info.network_modality.0,
+            network_connect: info.network_connect,
+            emission_values: tao(&info.emission_values),
+            burn: tao(&info.burn),
+            owner_ss58: ss58(&info.owner),
+            identity: None,
+            verified_identity_fields: Vec::new(),
+        }
+    }
+}
+
+impl<T> From<SubnetInfov2<T>> for SubnetInfoJson
+where
+    T: pallet_subtensor::Config,
+    T::AccountId: Ss58Codec,
+{
+    fn from(info: SubnetInfov2<T>) -> Self {
+        Self {
+            netuid: info.netuid.0,
+            rho: info.rho.0,
+            kappa: info.kappa.0,
+            difficulty: info.difficulty.0,
+            immunity_period: info.immunity_period.0,
+            max_allowed_validators: info.max_allowed_validators.0,
+            min_allowed_weights: info.min_allowed_weights.0,
+            max_weights_limit: info.max_weights_limit.0,
+            scaling_law_power: info.scaling_law_power.0,
+            subnetwork_n: info.subnetwork_n.0,
+            max_allowed_uids: info.max_allowed_uids.0,
+            blocks_since_last_step: info.blocks_since_last_step.0,
+            tempo: info.tempo.0,
+            network_modality: info.network_modality.0,
+            network_connect: info.network_connect,
+            emission_values: tao(&info.emission_values),
+            burn: tao(&info.burn),
+            owner_ss58: ss58(&info.owner),
+            identity: info.identity.map(Into::into),
+            verified_identity_fields: verified_fields(&info.verified_identity_fields),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SubnetHyperparamsJson {
+    pub rho: u16,
+    pub kappa: u16,
+    pub immunity_period: u16,
+    pub min_allowed_weights: u16,
+    pub max_weights_limit: u16,
+    pub tempo: u16,
+    pub min_difficulty: u64,
+    pub max_difficulty: u64,
+    pub weights_version: u64,
+    pub weights_rate_limit: u64,
+    pub adjustment_interval: u16,
+    pub activity_cutoff: u16,
+    pub registration_allowed: bool,
+    pub target_regs_per_interval: u16,
+    pub min_burn: String,
+    pub max_burn: String,
+    pub bonds_moving_avg: u64,
+    pub max_regs_per_block: u16,
+    pub serving_rate_limit: u64,
+    pub max_validators: u16,
+    pub adjustment_alpha: u64,
+    pub difficulty: u64,
+    pub commit_reveal_weights_interval: u64,
+    pub commit_reveal_weights_enabled: bool,
+    pub alpha_high: u16,
+    pub alpha_low: u16,
+    pub liquid_alpha_enabled: bool,
+    pub max_unrevealed_commits: u16,
+    pub pruning_policy: PruningPolicyJson,
+    pub pruning_protected_uids: Vec<u16>,
+    pub registration_auction_enabled: bool,
+    pub adjustment_controller: AdjustmentControllerJson,
+}
+
+impl From<SubnetHyperparams> for SubnetHyperparamsJson {
+    fn from(params: SubnetHyperparams) -> Self {
+        Self {
+            rho: params.rho.0,
+            kappa: params.kappa.0,
+            immunity_period: params.immunity_period.0,
+            min_allowed_weights: params.min_allowed_weights.0,
+            max_weights_limit: params.max_weights_limit.0,
+            tempo: params.tempo.0,
+            min_difficulty: params.min_difficulty.0,
+            max_difficulty: params.max_difficulty.0,
+            weights_version: params.weights_version.0,
+            weights_rate_limit: params.weights_rate_limit.0,
+            adjustment_interval: params.adjustment_interval.0,
+            activity_cutoff: params.activity_cutoff.0,
+            registration_allowed: params.registration_allowed,
+            target_regs_per_interval: params.target_regs_per_interval.0,
+            min_burn: tao(&params.min_burn),
+            max_burn: tao(&params.max_burn),
+            bonds_moving_avg: params.bonds_moving_avg.0,
+            max_regs_per_block: params.max_regs_per_block.0,
+            serving_rate_limit: params.serving_rate_limit.0,
+            max_validators: params.max_validators.0,
+            adjustment_alpha: params.adjustment_alpha.0,
+            difficulty: params.difficulty.0,
+            commit_reveal_weights_interval: params.commit_reveal_weights_interval.0,
+            commit_reveal_weights_enabled: params.commit_reveal_weights_enabled,
+            alpha_high: params.alpha_high.0,
+            alpha_low: params.alpha_low.0,
+            liquid_alpha_enabled: params.liquid_alpha_enabled,
+            max_unrevealed_commits: params.max_unrevealed_commits.0,
+            pruning_policy: params.pruning_policy.into(),
+            pruning_protected_uids: params
+                .pruning_protected_uids
+                .iter()
+                .map(|uid| uid.0)
+                .collect(),
+            registration_auction_enabled: params.registration_auction_enabled,
+            adjustment_controller: params.adjustment_controller.into(),
+        }
+    }
+}
+
+/// The policy choosing the neuron replaced when a subnet is full.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum PruningPolicyJson {
+    LowestPruningScore,
+    LowestIncentive,
+    LowestEmission { epochs: u16 },
+    LowestStake,
+    OldestFirst,
+}
+
+impl From<PruningPolicy> for PruningPolicyJson {
+    fn from(policy: PruningPolicy) -> Self {
+        match policy {
+            PruningPolicy::LowestPruningScore => Self::LowestPruningScore,
+            PruningPolicy::LowestIncentive => Self::LowestIncentive,
+            PruningPolicy::LowestEmission(epochs) => Self::LowestEmission { epochs },
+            PruningPolicy::LowestStake => Self::LowestStake,
+            PruningPolicy::OldestFirst => Self::OldestFirst,
+        }
+    }
+}
+
+/// The controller adjusting the burn and difficulty of a subnet, gains and decay normalized by u16::MAX.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AdjustmentControllerJson {
+    Ema,
+    Pid {
+        proportional_gain: u16,
+        integral_gain: u16,
+    },
+    IdleDecay {
+        decay: u16,
+    },
+}
+
+impl From<AdjustmentController> for AdjustmentControllerJson {
+    fn from(controller: AdjustmentController) -> Self {
+        match controller {
+            AdjustmentController::Ema => Self::Ema,
+            AdjustmentController::Pid {
+                proportional_gain,
+                integral_gain,
+            } => Self::Pid {
+                proportional_gain,
+                integral_gain,
+            },
+            AdjustmentController::IdleDecay(decay) => Self::IdleDecay { decay },
+        }
+    }
+}
+
+/// Where the rewards of a nomination are paid, the destination hotkey as SS58 string.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum RewardDestinationJson {
+    Restake,
+    Free,
+    Hotkey(String),
+}
+
+impl<A: Ss58Codec> From<RewardDestination<A>> for RewardDestinationJson {
+    fn from(destination: RewardDestination<A>) -> Self {
+        match destination {
+            RewardDestination::Restake => Self::Restake,
+            RewardDestination::Free => Self::Free,
+            RewardDestination::Hotkey(hotkey) => Self::Hotkey(ss58(&hotkey)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeInfoJson {
+    pub hotkey: String,
+    pub coldkey: String,
+    pub stake: String,
+    pub reward_destination: RewardDestinationJson,
+}
+
+impl<T> From<StakeInfo<T>> for StakeInfoJson
+where
+    T: pallet_subtensor::Config,
+    T::AccountId: Ss58Codec,
+{
+    fn from(info: StakeInfo<T>) -> Self {
+        Self {
+            hotkey: ss58(&info.hotkey),
+            coldkey: ss58(&info.coldkey),
+            stake: tao(&info.stake),
+            reward_destination: info.reward_destination.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use sp_core::{crypto::AccountId32, H256};
+
+    #[test]
+    fn rao_to_tao_string_keeps_every_rao() {
+        assert_eq!(rao_to_tao_string(0), "0.000000000");
+        assert_eq!(rao_to_tao_string(1), "0.000000001");
+        assert_eq!(rao_to_tao_string(999_999_999), "0.999999999");
+        assert_eq!(rao_to_tao_string(RAO_PER_TAO), "1.000000000");
+        assert_eq!(rao_to_tao_string(1_500_000_001), "1.500000001");
+        // No precision is lost above the 2^53 of a float
+        assert_eq!(rao_to_tao_string(u64::MAX), "18446744073.709551615");
+    }
+
+    #[test]
+    fn axon_and_prometheus_ips_are_rendered_by_type() {
+        let axon = AxonInfo {
+            block: 10,
+            version: 2,
+            ip: u128::from(u32::from(Ipv4Addr::new(127, 0, 0, 1))),
+            port: 8091,
+            ip_type: 4,
+            protocol: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            AxonInfoJson::from(axon),
+            AxonInfoJson {
+                block: 10,
+                version: 2,
+                ip: "127.0.0.1".to_string(),
+                port: 8091,
+                ip_type: 4,
+                protocol: 1,
+            }
+        );
+
+        let prometheus = PrometheusInfo {
+            ip: u128::from(Ipv6Addr::LOCALHOST),
+            ip_type: 6,
+            ..Default::default()
+        };
+        assert_eq!(PrometheusInfoJson::from(prometheus).ip, "::1");
+
+        // An unknown ip type is rendered as the encoded number
+        assert_eq!(ip_to_string(42, 0), "42");
+    }
+
+    #[test]
+    fn identities_are_rendered_as_text_and_hex() {
+        let identity = ChainIdentity {
+            name: b"opentensor".to_vec(),
+            url: vec![0xff],
+            logo_hash: Some(H256::repeat_byte(0xab)),
+            ..Default::default()
+        };
+        let json = ChainIdentityJson::from(identity);
+        assert_eq!(json.name, "opentensor");
+        // Invalid utf-8 is replaced rather than failing the response
+        assert_eq!(json.url, "\u{fffd}");
+        assert_eq!(json.logo_hash, Some(format!("0x{}", "ab".repeat(32))));
+
+        let identity = SubnetIdentity {
+            subnet_name: b"subnet".to_vec(),
+            logo_hash: None,
+            ..Default::default()
+        };
+        let json = SubnetIdentityJson::from(identity);
+        assert_eq!(json.subnet_name, "subnet");
+        assert_eq!(json.logo_hash, None);
+
+        assert_eq!(
+            verified_fields(&[IdentityField::Url, IdentityField::LogoHash]),
+            vec![IdentityFieldJson::Url, IdentityFieldJson::LogoHash]
+        );
+    }
+
+    #[test]
+    fn enums_keep_their_parameters() {
+        assert_eq!(
+            PruningPolicyJson::from(PruningPolicy::LowestEmission(3)),
+            PruningPolicyJson::LowestEmission { epochs: 3 }
+        );
+        assert_eq!(
+            AdjustmentControllerJson::from(AdjustmentController::Pid {
+                proportional_gain: 1,
+                integral_gain: 2,
+            }),
+            AdjustmentControllerJson::Pid {
+                proportional_gain: 1,
+                integral_gain: 2,
+            }
+        );
+        assert_eq!(
+            AdjustmentControllerJson::from(AdjustmentController::IdleDecay(7)),
+            AdjustmentControllerJson::IdleDecay { decay: 7 }
+        );
+    }
+
+    #[test]
+    fn accounts_are_rendered_as_ss58() {
+        let hotkey = AccountId32::new([1; 32]);
+        let RewardDestinationJson::Hotkey(hotkey_ss58) =
+            RewardDestinationJson::from(RewardDestination::Hotkey(hotkey.clone()))
+        else {
+            panic!("expected a hotkey destination");
+        };
+        assert_eq!(AccountId32::from_ss58check(&hotkey_ss58).unwrap(), hotkey);
+        assert_eq!(
+            RewardDestinationJson::from(RewardDestination::<AccountId32>::Restake),
+            RewardDestinationJson::Restake
+        );
+
+        assert_eq!(
+            stake_map(&[(hotkey.clone(), Compact(2_000_000_000))]),
+            vec![(hotkey_ss58, "2.000000000".to_string())]
+        );
+        assert_eq!(
+            uid_pairs(&[(Compact(1), Compact(u16::MAX))]),
+            vec![(1, u16::MAX)]
+        );
+    }
+}
@@ -1,19 +1,35 @@
 //! RPC interface for the custom Subtensor rpc methods
 
+pub mod json;
+
+use codec::{Compact, Decode, Encode};
 use jsonrpsee::{
     core::RpcResult,
     proc_macros::rpc,
     types::{error::ErrorObject, ErrorObjectOwned},
 };
 use sp_blockchain::HeaderBackend;
+use sp_core::crypto::Ss58Codec;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
 use sp_api::ProvideRuntimeApi;
 
 pub use subtensor_custom_rpc_runtime_api::{
-    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi,
-    SubnetRegistrationRuntimeApi,
+    DelegateInfoRuntimeApi, EmissionScheduleRuntimeApi, EpochInfoRuntimeApi, NeuronInfoRuntimeApi,
+    PreDispatchCheckRuntimeApi, RateLimitInfoRuntimeApi, RegistrationTermsRuntimeApi,
+    StakeGraphRuntimeApi, StakeInfoRuntimeApi, SubnetInfoRuntimeApi, SubnetRegistrationRuntimeApi,
+};
+
+use json::{
+    DelegateInfoJson, DelegatedInfoJson, NeuronInfoJson, StakeInfoJson, SubnetHyperparamsJson,
+    SubnetInfoJson,
+};
+use pallet_subtensor::rpc_info::{
+    delegate_info::DelegateInfo,
+    neuron_info::{NeuronInfo, NeuronInfoLite},
+    stake_info::StakeInfo,
+    subnet_info::{SubnetHyperparams, SubnetInfo, SubnetInfov2},
 };
 
 #[rpc(client, server)]
@@ -54,6 +70,61 @@ pub trait SubtensorCustomApi<BlockHash> {
 
     #[method(name = "subnetInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+
+    #[method(name = "stakeInfo_getNominatorRewards")]
+    fn get_nominator_rewards(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        from_block: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "epoch_simulate")]
+    fn epoch_simulate(
+        &self,
+        netuid: u16,
+        rao_emission: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "rateLimit_getBlocksUntilAllowed")]
+    fn get_blocks_until_allowed(
+        &self,
+        account_vec: Vec<u8>,
+        tx_type: u16,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u64>;
+
+    #[method(name = "emissionSchedule_getHotkeyDrainInfo")]
+    fn get_hotkey_drain_info(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "emissionSchedule_getSubnetsEpochSchedule")]
+    fn get_subnets_epoch_schedule(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "stakeGraph_getSubnetStakeGraph")]
+    fn get_subnet_stake_graph(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "registrationTerms_projectRegistrationTerms")]
+    fn project_registration_terms(
+        &self,
+        netuid: u16,
+        pow_registrations: u16,
+        burn_registrations: u16,
+        intervals: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "preDispatch_check")]
+    fn pre_dispatch_check(
+        &self,
+        call_vec: Vec<u8>,
+        signer_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 }
 
 pub struct SubtensorCustom<C, P> {
@@ -72,16 +143,116 @@ impl<C, P> SubtensorCustom<C, P> {
     }
 }
 
+/// Typed variants of the custom Subtensor rpc methods.
+///
+/// These mirror the `Vec<u8>` methods above but decode the runtime response and return JSON,
+/// with accounts given as SS58 strings and balances as decimal TAO strings.
+#[rpc(client, server)]
+pub trait SubtensorJsonApi<BlockHash> {
+    #[method(name = "delegateInfo_getDelegatesJson")]
+    fn get_delegates_json(&self, at: Option<BlockHash>) -> RpcResult<Vec<DelegateInfoJson>>;
+    #[method(name = "delegateInfo_getDelegateJson")]
+    fn get_delegate_json(
+        &self,
+        delegate_ss58: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<DelegateInfoJson>>;
+    #[method(name = "delegateInfo_getDelegatedJson")]
+    fn get_delegated_json(
+        &self,
+        delegatee_ss58: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<DelegatedInfoJson>>;
+
+    #[method(name = "neuronInfo_getNeuronsLiteJson")]
+    fn get_neurons_lite_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NeuronInfoJson>>;
+    #[method(name = "neuronInfo_getNeuronLiteJson")]
+    fn get_neuron_lite_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NeuronInfoJson>>;
+    #[method(name = "neuronInfo_getNeuronsJson")]
+    fn get_neurons_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NeuronInfoJson>>;
+    #[method(name = "neuronInfo_getNeuronJson")]
+    fn get_neuron_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NeuronInfoJson>>;
+
+    #[method(name = "subnetInfo_getSubnetInfoJson")]
+    fn get_subnet_info_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SubnetInfoJson>>;
+    #[method(name = "subnetInfo_getSubnetsInfoJson")]
+    fn get_subnets_info_json(&self, at: Option<BlockHash>) -> RpcResult<Vec<SubnetInfoJson>>;
+    #[method(name = "subnetInfo_getSubnetInfoJson_v2")]
+    fn get_subnet_info_v2_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SubnetInfoJson>>;
+    #[method(name = "subnetInfo_getSubnetHyperparamsJson")]
+    fn get_subnet_hyperparams_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SubnetHyperparamsJson>>;
+
+    #[method(name = "stakeInfo_getStakeInfoForColdkeyJson")]
+    fn get_stake_info_for_coldkey_json(
+        &self,
+        coldkey_ss58: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<StakeInfoJson>>;
+}
+
+/// Server for [`SubtensorJsonApi`], `R` is the runtime used to decode the responses.
+pub struct SubtensorJson<C, P, R> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<(P, R)>,
+}
+
+impl<C, P, R> SubtensorJson<C, P, R> {
+    /// Creates a new instance of the typed Subtensor Rpc helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
 /// Error type of this RPC api.
 pub enum Error {
     /// The call to runtime failed.
     RuntimeError(String),
+    /// The runtime response could not be decoded.
+    DecodeError(String),
+    /// A parameter passed to the rpc was invalid.
+    InvalidParams(String),
 }
 
 impl From<Error> for ErrorObjectOwned {
     fn from(e: Error) -> Self {
         match e {
             Error::RuntimeError(e) => ErrorObject::owned(1, e, None::<()>),
+            Error::DecodeError(e) => ErrorObject::owned(2, e, None::<()>),
+            Error::InvalidParams(e) => ErrorObject::owned(3, e, None::<()>),
         }
     }
 }
@@ -90,10 +261,29 @@ impl From<Error> for i32 {
     fn from(e: Error) -> i32 {
         match e {
             Error::RuntimeError(_) => 1,
+            Error::DecodeError(_) => 2,
+            Error::InvalidParams(_) => 3,
         }
     }
 }
 
+/// Decodes a SCALE encoded runtime response, an empty response is treated as `None`.
+fn decode_response<D: Decode>(bytes: Vec<u8>, what: &str) -> RpcResult<Option<D>> {
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    D::decode(&mut &bytes[..])
+        .map(Some)
+        .map_err(|e| Error::DecodeError(format!("Unable to decode {}: {:?}", what, e)).into())
+}
+
+/// Parses an SS58 address into the SCALE encoded account id expected by the runtime api.
+fn encode_ss58<A: Ss58Codec + Encode>(address: &str) -> RpcResult<Vec<u8>> {
+    A::from_ss58check(address)
+        .map(|account| account.encode())
+        .map_err(|e| Error::InvalidParams(format!("Invalid ss58 address: {:?}", e)).into())
+}
+
 impl<C, Block> SubtensorCustomApiServer<<Block as BlockT>::Hash> for SubtensorCustom<C, Block>
 where
     Block: BlockT,
@@ -102,6 +292,13 @@ where
     C::Api: NeuronInfoRuntimeApi<Block>,
     C::Api: SubnetInfoRuntimeApi<Block>,
     C::Api: SubnetRegistrationRuntimeApi<Block>,
+    C::Api: StakeInfoRuntimeApi<Block>,
+    C::Api: EpochInfoRuntimeApi<Block>,
+    C::Api: RateLimitInfoRuntimeApi<Block>,
+    C::Api: PreDispatchCheckRuntimeApi<Block>,
+    C::Api: EmissionScheduleRuntimeApi<Block>,
+    C::Api: StakeGraphRuntimeApi<Block>,
+    C::Api: RegistrationTermsRuntimeApi<Block>,
 {
     fn get_delegates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
@@ -246,4 +443,325 @@ where
             Error::RuntimeError(format!("Unable to get subnet lock cost: {:?}", e)).into()
         })
     }
+
+    fn get_nominator_rewards(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        from_block: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_nominator_rewards(at, coldkey_account_vec, from_block)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get nominator rewards: {:?}", e)).into()
+            })
+    }
+
+    fn epoch_simulate(
+        &self,
+        netuid: u16,
+        rao_emission: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.epoch_simulate(at, netuid, rao_emission)
+            .map_err(|e| Error::RuntimeError(format!("Unable to simulate epoch: {:?}", e)).into())
+    }
+
+    fn get_blocks_until_allowed(
+        &self,
+        account_vec: Vec<u8>,
+        tx_type: u16,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_blocks_until_allowed(at, account_vec, tx_type, netuid)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get blocks until allowed: {:?}", e)).into()
+            })
+    }
+
+    fn get_hotkey_drain_info(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_hotkey_drain_info(at, hotkey_account_vec)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get hotkey drain info: {:?}", e)).into()
+            })
+    }
+
+    fn get_subnets_epoch_schedule(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnets_epoch_schedule(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnets epoch schedule: {:?}", e)).into()
+        })
+    }
+
+    fn get_subnet_stake_graph(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnet_stake_graph(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnet stake graph: {:?}", e)).into()
+        })
+    }
+
+    fn project_registration_terms(
+        &self,
+        netuid: u16,
+        pow_registrations: u16,
+        burn_registrations: u16,
+        intervals: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.project_registration_terms(at, netuid, pow_registrations, burn_registrations, intervals)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to project registration terms: {:?}", e)).into()
+            })
+    }
+
+    fn pre_dispatch_check(
+        &self,
+        call_vec: Vec<u8>,
+        signer_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.pre_dispatch_check(at, call_vec, signer_account_vec)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to run pre dispatch check: {:?}", e)).into()
+            })
+    }
+}
+
+impl<C, Block, R> SubtensorJsonApiServer<<Block as BlockT>::Hash> for SubtensorJson<C, Block, R>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DelegateInfoRuntimeApi<Block>,
+    C::Api: NeuronInfoRuntimeApi<Block>,
+    C::Api: SubnetInfoRuntimeApi<Block>,
+    C::Api: StakeInfoRuntimeApi<Block>,
+    R: pallet_subtensor::Config + Send + Sync + 'static,
+    R::AccountId: Ss58Codec,
+{
+    fn get_delegates_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<DelegateInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_delegates(at)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)))?;
+        let delegates: Vec<DelegateInfo<R>> =
+            decode_response(bytes, "delegates info")?.unwrap_or_default();
+        Ok(delegates.into_iter().map(Into::into).collect())
+    }
+
+    fn get_delegate_json(
+        &self,
+        delegate_ss58: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<DelegateInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let delegate_account_vec = encode_ss58::<R::AccountId>(&delegate_ss58)?;
+        let bytes = api
+            .get_delegate(at, delegate_account_vec)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)))?;
+        let delegate: Option<DelegateInfo<R>> = decode_response(bytes, "delegate info")?;
+        Ok(delegate.map(Into::into))
+    }
+
+    fn get_delegated_json(
+        &self,
+        delegatee_ss58: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<DelegatedInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let delegatee_account_vec = encode_ss58::<R::AccountId>(&delegatee_ss58)?;
+        let bytes = api
+            .get_delegated(at, delegatee_account_vec)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)))?;
+        let delegated: Vec<(DelegateInfo<R>, Compact<u64>)> =
+            decode_response(bytes, "delegated info")?.unwrap_or_default();
+        Ok(delegated
+            .into_iter()
+            .map(|(delegate, stake)| DelegatedInfoJson {
+                delegate: delegate.into(),
+                stake: json::rao_to_tao_string(stake.0),
+            })
+            .collect())
+    }
+
+    fn get_neurons_lite_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NeuronInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api.get_neurons_lite(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e))
+        })?;
+        let neurons: Vec<NeuronInfoLite<R>> =
+            decode_response(bytes, "neurons lite info")?.unwrap_or_default();
+        Ok(neurons.into_iter().map(Into::into).collect())
+    }
+
+    fn get_neuron_lite_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<NeuronInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api.get_neuron_lite(at, netuid, uid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e))
+        })?;
+        let neuron: Option<NeuronInfoLite<R>> = decode_response(bytes, "neuron lite info")?;
+        Ok(neuron.map(Into::into))
+    }
+
+    fn get_neurons_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NeuronInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_neurons(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neurons info: {:?}", e)))?;
+        let neurons: Vec<NeuronInfo<R>> =
+            decode_response(bytes, "neurons info")?.unwrap_or_default();
+        Ok(neurons.into_iter().map(Into::into).collect())
+    }
+
+    fn get_neuron_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<NeuronInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_neuron(at, netuid, uid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)))?;
+        let neuron: Option<NeuronInfo<R>> = decode_response(bytes, "neuron info")?;
+        Ok(neuron.map(Into::into))
+    }
+
+    fn get_subnet_info_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<SubnetInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_subnet_info(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)))?;
+        let subnet: Option<SubnetInfo<R>> = decode_response(bytes, "subnet info")?;
+        Ok(subnet.map(Into::into))
+    }
+
+    fn get_subnets_info_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<SubnetInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_subnets_info(at)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnets info: {:?}", e)))?;
+        let subnets: Vec<Option<SubnetInfo<R>>> =
+            decode_response(bytes, "subnets info")?.unwrap_or_default();
+        Ok(subnets.into_iter().flatten().map(Into::into).collect())
+    }
+
+    fn get_subnet_info_v2_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<SubnetInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_subnet_info_v2(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)))?;
+        let subnet: Option<SubnetInfov2<R>> = decode_response(bytes, "subnet info")?;
+        Ok(subnet.map(Into::into))
+    }
+
+    fn get_subnet_hyperparams_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<SubnetHyperparamsJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_subnet_hyperparams(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)))?;
+        let params: Option<SubnetHyperparams> = decode_response(bytes, "subnet hyperparams")?;
+        Ok(params.map(Into::into))
+    }
+
+    fn get_stake_info_for_coldkey_json(
+        &self,
+        coldkey_ss58: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<StakeInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let coldkey_account_vec = encode_ss58::<R::AccountId>(&coldkey_ss58)?;
+        let bytes = api
+            .get_stake_info_for_coldkey(at, coldkey_account_vec)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get stake info: {:?}", e)))?;
+        let stake: Vec<StakeInfo<R>> = decode_response(bytes, "stake info")?.unwrap_or_default();
+        Ok(stake.into_iter().map(Into::into).collect())
+    }
 }
@@ -29,9 +29,35 @@ sp_api::decl_runtime_apis! {
     pub trait StakeInfoRuntimeApi {
         fn get_stake_info_for_coldkey( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_stake_info_for_coldkeys( coldkey_account_vecs: Vec<Vec<u8>> ) -> Vec<u8>;
+        fn get_nominator_rewards( coldkey_account_vec: Vec<u8>, from_block: u64 ) -> Vec<u8>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> u64;
     }
+
+    pub trait EpochInfoRuntimeApi {
+        fn epoch_simulate(netuid: u16, rao_emission: u64) -> Vec<u8>;
+    }
+
+    pub trait RateLimitInfoRuntimeApi {
+        fn get_blocks_until_allowed( account_vec: Vec<u8>, tx_type: u16, netuid: u16 ) -> u64;
+    }
+
+    pub trait PreDispatchCheckRuntimeApi {
+        fn pre_dispatch_check( call_vec: Vec<u8>, signer_account_vec: Vec<u8> ) -> Vec<u8>;
+    }
+
+    pub trait EmissionScheduleRuntimeApi {
+        fn get_hotkey_drain_info( hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_subnets_epoch_schedule() -> Vec<u8>;
+    }
+
+    pub trait StakeGraphRuntimeApi {
+        fn get_subnet_stake_graph(netuid: u16) -> Vec<u8>;
+    }
+
+    pub trait RegistrationTermsRuntimeApi {
+        fn project_registration_terms(netuid: u16, pow_registrations: u16, burn_registrations: u16, intervals: u16) -> Vec<u8>;
+    }
 }
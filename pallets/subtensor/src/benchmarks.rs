@@ -513,6 +513,9 @@ reveal_weights {
         discord: vec![],
         description: vec![],
         additional: vec![],
+        github_repo: vec![],
+        contact: vec![],
+        logo_hash: None,
     };
 
     Identities::<T>::insert(&old_coldkey, identity);
@@ -1,18 +1,20 @@
 use super::*;
 use frame_support::storage::IterableStorageMap;
+use frame_support::weights::Weight;
 use substrate_fixed::types::I110F18;
 
 impl<T: Config> Pallet<T> {
-    /// Executes the necessary operations for each block.
-    pub fn block_step() -> Result<(), &'static str> {
+    /// Executes the necessary operations for each block. Returns the weight of the operations
+    /// not covered by the fixed block step weight.
+    pub fn block_step() -> Result<Weight, &'static str> {
         let block_number: u64 = Self::get_current_block_as_u64();
         log::debug!("block_step for block: {:?} ", block_number);
         // --- 1. Adjust difficulties.
         Self::adjust_registration_terms_for_networks();
         // --- 2. Run emission through network.
-        Self::run_coinbase();
+        let coinbase_weight = Self::run_coinbase();
         // Return ok.
-        Ok(())
+        Ok(coinbase_weight)
     }
 
     /// Adjusts the network difficulties/burns of every active network. Resetting state parameters.
@@ -41,7 +43,7 @@ impl<T: Config> Pallet<T> {
                 // --- 4. Get the current counters for this network w.r.t burn and difficulty values.
                 let current_burn: u64 = Self::get_burn_as_u64(netuid);
                 let current_difficulty: u64 = Self::get_difficulty_as_u64(netuid);
-                let registrations_this_interval: u16 =
+                let mut registrations_this_interval: u16 =
                     Self::get_registrations_this_interval(netuid);
                 let pow_registrations_this_interval: u16 =
                     Self::get_pow_registrations_this_interval(netuid);
@@ -49,9 +51,26 @@ impl<T: Config> Pallet<T> {
                     Self::get_burn_registrations_this_interval(netuid);
                 let target_registrations_this_interval: u16 =
                     Self::get_target_registrations_per_interval(netuid);
+                let adjustment_integral: i64 = Self::get_adjustment_integral(netuid);
                 // --- 5. Adjust burn + pow
-                // There are six cases to consider. A, B, C, D, E, F
-                if registrations_this_interval > target_registrations_this_interval {
+                // Networks registering through an auction admit the highest bidders and price the
+                // burn from the demand at the clearing price, the bids being the registrations of
+                // the interval. Otherwise there are six cases to consider. A, B, C, D, E, F
+                if Self::get_registration_auction_enabled(netuid) {
+                    let (clearing_price, bids): (Option<u64>, u16) =
+                        Self::settle_registration_auction(netuid, current_block);
+                    registrations_this_interval = bids;
+                    Self::set_burn(
+                        netuid,
+                        Self::upgraded_burn(
+                            netuid,
+                            clearing_price.unwrap_or(current_burn),
+                            registrations_this_interval,
+                            target_registrations_this_interval,
+                            adjustment_integral,
+                        ),
+                    );
+                } else if registrations_this_interval > target_registrations_this_interval {
                     #[allow(clippy::comparison_chain)]
                     if pow_registrations_this_interval > burn_registrations_this_interval {
                         // A. There are too many registrations this interval and most of them are pow registrations
@@ -64,6 +83,7 @@ impl<T: Config> Pallet<T> {
                                 current_difficulty,
                                 registrations_this_interval,
                                 target_registrations_this_interval,
+                                adjustment_integral,
                             ),
                         );
                     } else if pow_registrations_this_interval < burn_registrations_this_interval {
@@ -77,6 +97,7 @@ impl<T: Config> Pallet<T> {
                                 current_burn,
                                 registrations_this_interval,
                                 target_registrations_this_interval,
+                                adjustment_integral,
                             ),
                         );
                     } else {
@@ -90,6 +111,7 @@ impl<T: Config> Pallet<T> {
                                 current_burn,
                                 registrations_this_interval,
                                 target_registrations_this_interval,
+                                adjustment_integral,
                             ),
                         );
                         // pow_difficulty ++
@@ -100,6 +122,7 @@ impl<T: Config> Pallet<T> {
                                 current_difficulty,
                                 registrations_this_interval,
                                 target_registrations_this_interval,
+                                adjustment_integral,
                             ),
                         );
                     }
@@ -117,6 +140,7 @@ impl<T: Config> Pallet<T> {
                                 current_burn,
                                 registrations_this_interval,
                                 target_registrations_this_interval,
+                                adjustment_integral,
                             ),
                         );
                     } else if pow_registrations_this_interval < burn_registrations_this_interval {
@@ -130,6 +154,7 @@ impl<T: Config> Pallet<T> {
                                 current_difficulty,
                                 registrations_this_interval,
                                 target_registrations_this_interval,
+                                adjustment_integral,
                             ),
                         );
                     } else {
@@ -143,6 +168,7 @@ impl<T: Config> Pallet<T> {
                                 current_burn,
                                 registrations_this_interval,
                                 target_registrations_this_interval,
+                                adjustment_integral,
                             ),
                         );
                         // pow_difficulty --
@@ -153,12 +179,20 @@ impl<T: Config> Pallet<T> {
                                 current_difficulty,
                                 registrations_this_interval,
                                 target_registrations_this_interval,
+                                adjustment_integral,
                             ),
                         );
                     }
                 }
 
-                // --- 6. Drain all counters for this network for this interval.
+                // --- 6. Accumulate the registration error of the interval for the PID controller.
+                Self::update_adjustment_integral(
+                    netuid,
+                    registrations_this_interval,
+                    target_registrations_this_interval,
+                );
+
+                // --- 7. Drain all counters for this network for this interval.
                 Self::set_last_adjustment_block(netuid, current_block);
                 Self::set_registrations_this_interval(netuid, 0);
                 Self::set_pow_registrations_this_interval(netuid, 0);
@@ -167,55 +201,144 @@ impl<T: Config> Pallet<T> {
                 log::debug!("interval not reached.");
             }
 
-            // --- 7. Drain block registrations for each network. Needed for registration rate limits.
+            // --- 8. Drain block registrations for each network. Needed for registration rate limits.
             Self::set_registrations_this_block(netuid, 0);
         }
     }
 
-    /// Calculates the upgraded difficulty by multiplying the current difficulty by the ratio ( reg_actual + reg_target / reg_target + reg_target )
-    /// We use I110F18 to avoid any overflows on u64. Also min_difficulty and max_difficulty bound the range.
+    /// Calculates the upgraded difficulty under the adjustment controller of the subnet.
+    /// The adjustment integral is the registration error accumulated by the previous intervals.
+    /// Also min_difficulty and max_difficulty bound the range.
     ///
     pub fn upgraded_difficulty(
         netuid: u16,
         current_difficulty: u64,
         registrations_this_interval: u16,
         target_registrations_per_interval: u16,
+        adjustment_integral: i64,
     ) -> u64 {
-        let updated_difficulty: I110F18 = I110F18::from_num(current_difficulty)
-            .saturating_mul(I110F18::from_num(
-                registrations_this_interval.saturating_add(target_registrations_per_interval),
-            ))
-            .saturating_div(I110F18::from_num(
-                target_registrations_per_interval.saturating_add(target_registrations_per_interval),
-            ));
-        let alpha: I110F18 = I110F18::from_num(Self::get_adjustment_alpha(netuid))
-            .saturating_div(I110F18::from_num(u64::MAX));
-        let next_value: I110F18 = alpha
-            .saturating_mul(I110F18::from_num(current_difficulty))
-            .saturating_add(
-                I110F18::from_num(1.0)
-                    .saturating_sub(alpha)
-                    .saturating_mul(updated_difficulty),
-            );
-        if next_value >= I110F18::from_num(Self::get_max_difficulty(netuid)) {
-            Self::get_max_difficulty(netuid)
-        } else if next_value <= I110F18::from_num(Self::get_min_difficulty(netuid)) {
-            return Self::get_min_difficulty(netuid);
-        } else {
-            return next_value.to_num::<u64>();
-        }
+        Self::upgraded_registration_term(
+            netuid,
+            current_difficulty,
+            registrations_this_interval,
+            target_registrations_per_interval,
+            adjustment_integral,
+            Self::get_min_difficulty(netuid),
+            Self::get_max_difficulty(netuid),
+        )
     }
 
-    /// Calculates the upgraded burn by multiplying the current burn by the ratio ( reg_actual + reg_target / reg_target + reg_target )
-    /// We use I110F18 to avoid any overflows on u64. Also min_burn and max_burn bound the range.
+    /// Calculates the upgraded burn under the adjustment controller of the subnet.
+    /// The adjustment integral is the registration error accumulated by the previous intervals.
+    /// Also min_burn and max_burn bound the range.
     ///
     pub fn upgraded_burn(
         netuid: u16,
         current_burn: u64,
         registrations_this_interval: u16,
         target_registrations_per_interval: u16,
+        adjustment_integral: i64,
     ) -> u64 {
-        let updated_burn: I110F18 = I110F18::from_num(current_burn)
+        Self::upgraded_registration_term(
+            netuid,
+            current_burn,
+            registrations_this_interval,
+            target_registrations_per_interval,
+            adjustment_integral,
+            Self::get_min_burn_as_u64(netuid),
+            Self::get_max_burn_as_u64(netuid),
+        )
+    }
+
+    /// Calculates the upgraded burn or difficulty under the adjustment controller of the subnet:
+    ///
+    /// * Ema: multiplies the current value by the ratio ( reg_actual + reg_target / reg_target + reg_target ),
+    ///   weighted against the current value by the adjustment alpha.
+    /// * Pid: multiplies the current value by 1 + kp * error + ki * accumulated error, where the error is
+    ///   ( reg_actual - reg_target ) / reg_target.
+    /// * IdleDecay: moves the current value toward min_value by the decay if there are no registrations,
+    ///   and follows the Ema controller otherwise.
+    ///
+    /// We use I110F18 to avoid any overflows on u64. Also min_value and max_value bound the range.
+    ///
+    pub fn upgraded_registration_term(
+        netuid: u16,
+        current_value: u64,
+        registrations_this_interval: u16,
+        target_registrations_per_interval: u16,
+        adjustment_integral: i64,
+        min_value: u64,
+        max_value: u64,
+    ) -> u64 {
+        let next_value: I110F18 = match Self::get_adjustment_controller(netuid) {
+            AdjustmentController::Ema => Self::ema_registration_term(
+                netuid,
+                current_value,
+                registrations_this_interval,
+                target_registrations_per_interval,
+            ),
+            AdjustmentController::Pid {
+                proportional_gain,
+                integral_gain,
+            } => {
+                let target: I110F18 = I110F18::from_num(target_registrations_per_interval.max(1));
+                let error: I110F18 = I110F18::from_num(registrations_this_interval)
+                    .saturating_sub(I110F18::from_num(target_registrations_per_interval))
+                    .saturating_div(target);
+                let accumulated_error: I110F18 =
+                    I110F18::from_num(Self::accumulated_registration_error(
+                        adjustment_integral,
+                        registrations_this_interval,
+                        target_registrations_per_interval,
+                    ))
+                    .saturating_div(target);
+                let kp: I110F18 = I110F18::from_num(proportional_gain)
+                    .saturating_div(I110F18::from_num(u16::MAX));
+                let ki: I110F18 =
+                    I110F18::from_num(integral_gain).saturating_div(I110F18::from_num(u16::MAX));
+                let factor: I110F18 = I110F18::from_num(1)
+                    .saturating_add(kp.saturating_mul(error))
+                    .saturating_add(ki.saturating_mul(accumulated_error))
+                    .max(I110F18::from_num(0));
+                I110F18::from_num(current_value).saturating_mul(factor)
+            }
+            AdjustmentController::IdleDecay(decay) => {
+                if registrations_this_interval == 0 {
+                    let decay: I110F18 =
+                        I110F18::from_num(decay).saturating_div(I110F18::from_num(u16::MAX));
+                    let excess: I110F18 =
+                        I110F18::from_num(current_value.saturating_sub(min_value));
+                    I110F18::from_num(current_value).saturating_sub(excess.saturating_mul(decay))
+                } else {
+                    Self::ema_registration_term(
+                        netuid,
+                        current_value,
+                        registrations_this_interval,
+                        target_registrations_per_interval,
+                    )
+                }
+            }
+        };
+        if next_value >= I110F18::from_num(max_value) {
+            max_value
+        } else if next_value <= I110F18::from_num(min_value) {
+            min_value
+        } else {
+            next_value.to_num::<u64>()
+        }
+    }
+
+    /// Calculates the burn or difficulty of the Ema controller, the current value multiplied by the ratio
+    /// ( reg_actual + reg_target / reg_target + reg_target ), weighted against the current value by the
+    /// adjustment alpha.
+    ///
+    pub fn ema_registration_term(
+        netuid: u16,
+        current_value: u64,
+        registrations_this_interval: u16,
+        target_registrations_per_interval: u16,
+    ) -> I110F18 {
+        let updated_value: I110F18 = I110F18::from_num(current_value)
             .saturating_mul(I110F18::from_num(
                 registrations_this_interval.saturating_add(target_registrations_per_interval),
             ))
@@ -224,19 +347,52 @@ impl<T: Config> Pallet<T> {
             ));
         let alpha: I110F18 = I110F18::from_num(Self::get_adjustment_alpha(netuid))
             .saturating_div(I110F18::from_num(u64::MAX));
-        let next_value: I110F18 = alpha
-            .saturating_mul(I110F18::from_num(current_burn))
+        alpha
+            .saturating_mul(I110F18::from_num(current_value))
             .saturating_add(
                 I110F18::from_num(1.0)
                     .saturating_sub(alpha)
-                    .saturating_mul(updated_burn),
-            );
-        if next_value >= I110F18::from_num(Self::get_max_burn_as_u64(netuid)) {
-            Self::get_max_burn_as_u64(netuid)
-        } else if next_value <= I110F18::from_num(Self::get_min_burn_as_u64(netuid)) {
-            return Self::get_min_burn_as_u64(netuid);
-        } else {
-            return next_value.to_num::<u64>();
+                    .saturating_mul(updated_value),
+            )
+    }
+
+    /// Returns the adjustment integral after the interval, the registrations above target
+    /// accumulated over the intervals, bounded by MAX_ADJUSTMENT_INTEGRAL_INTERVALS targets.
+    ///
+    pub fn accumulated_registration_error(
+        adjustment_integral: i64,
+        registrations_this_interval: u16,
+        target_registrations_per_interval: u16,
+    ) -> i64 {
+        let bound: i64 = i64::from(target_registrations_per_interval)
+            .saturating_mul(MAX_ADJUSTMENT_INTEGRAL_INTERVALS);
+        adjustment_integral
+            .saturating_add(
+                i64::from(registrations_this_interval)
+                    .saturating_sub(i64::from(target_registrations_per_interval)),
+            )
+            .clamp(bound.saturating_neg(), bound)
+    }
+
+    /// Accumulates the registration error of the interval if the subnet adjusts with the PID controller.
+    ///
+    pub fn update_adjustment_integral(
+        netuid: u16,
+        registrations_this_interval: u16,
+        target_registrations_per_interval: u16,
+    ) {
+        if !matches!(
+            Self::get_adjustment_controller(netuid),
+            AdjustmentController::Pid { .. }
+        ) {
+            return;
         }
+        AdjustmentIntegral::<T>::mutate(netuid, |integral| {
+            *integral = Self::accumulated_registration_error(
+                *integral,
+                registrations_this_interval,
+                target_registrations_per_interval,
+            )
+        });
     }
 }
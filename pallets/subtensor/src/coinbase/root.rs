@@ -295,7 +295,11 @@ impl<T: Config> Pallet<T> {
     /// Sets the network rate limit and emit the `NetworkRateLimitSet` event
     ///
     pub fn set_network_rate_limit(limit: u64) {
-        NetworkRateLimit::<T>::set(limit);
+        TxRateLimits::<T>::insert(
+            u16::from(TransactionType::RegisterNetwork),
+            GLOBAL_RATE_LIMIT_NETUID,
+            limit,
+        );
         Self::deposit_event(Event::NetworkRateLimitSet(limit));
     }
 
@@ -322,7 +326,10 @@ impl<T: Config> Pallet<T> {
     /// This function is responsible for calculating emission based on network weights, stake values,
     /// and registered hotkeys.
     ///
-    pub fn root_epoch(block_number: u64) -> Result<(), &'static str> {
+    /// The weight of the senate rebalance, which depends on the number of members, of the senate
+    /// terms and of the root validators, is added to `weight`.
+    ///
+    pub fn root_epoch(block_number: u64, weight: &mut Weight) -> Result<(), &'static str> {
         // --- 0. The unique ID associated with the root network.
         let root_netuid: u16 = Self::get_root_netuid();
 
@@ -335,6 +342,9 @@ impl<T: Config> Pallet<T> {
             return Err("");
         }
 
+        // --- 1.1 Rebalance the senate by stake at every root epoch.
+        weight.saturating_accrue(Self::rebalance_senate());
+
         // --- 2. Retrieves the number of root validators on subnets.
         let n: u16 = Self::get_num_root_validators();
         log::debug!("n:\n{:?}\n", n);
@@ -494,8 +504,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 2. Ensure that the number of registrations in this block doesn't exceed the allowed limit.
         ensure!(
-            Self::get_registrations_this_block(root_netuid)
-                < Self::get_max_registrations_per_block(root_netuid),
+            Self::passes_registration_rate_limit(root_netuid),
             Error::<T>::TooManyRegistrationsThisBlock
         );
 
@@ -637,6 +646,12 @@ impl<T: Config> Pallet<T> {
         // --- 3. Create a network account for the user if it doesn't exist.
         Self::create_account_if_non_existent(&coldkey, &hotkey);
 
+        // --- 3.1 Ensure the hotkey is not sitting out a term after reaching the term limit.
+        ensure!(
+            Self::is_eligible_for_senate(&hotkey),
+            Error::<T>::SenateTermLimitReached
+        );
+
         // --- 4. Join the Senate if eligible.
         // Returns the replaced member, if any.
         let replaced = Self::join_senate_if_eligible(&hotkey)?;
@@ -690,6 +705,11 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
+        // --- 2.1 Skip hotkeys sitting out a term after reaching the term limit.
+        if !Self::is_eligible_for_senate(hotkey) {
+            return Ok(None);
+        }
+
         // --- 3. Grab the hotkey's stake.
         let current_stake = Self::get_total_stake_for_hotkey(hotkey);
 
@@ -713,10 +733,13 @@ impl<T: Config> Pallet<T> {
                     // Swap the member with the lowest stake.
                     T::SenateMembers::swap_member(last, hotkey)
                         .map_err(|_| Error::<T>::CouldNotJoinSenate)?;
+                    SenateTermStart::<T>::remove(last);
+                    Self::start_senate_term(hotkey);
                 }
             }
         } else {
             T::SenateMembers::add_member(hotkey).map_err(|_| Error::<T>::CouldNotJoinSenate)?;
+            Self::start_senate_term(hotkey);
         }
 
         // Return the swapped out member, if any.
@@ -915,21 +938,12 @@ impl<T: Config> Pallet<T> {
         // --- 0. Ensure the caller is a signed user.
         let coldkey = ensure_signed(origin)?;
 
-        // --- 1. Rate limit for network registrations.
-        let current_block = Self::get_current_block_as_u64();
-        let last_lock_block = Self::get_network_last_lock_block();
-        ensure!(
-            current_block.saturating_sub(last_lock_block) >= NetworkRateLimit::<T>::get(),
-            Error::<T>::NetworkTxRateLimitExceeded
-        );
+        // --- 1. Rate limit for network registrations and the balance to lock.
+        Self::register_network_checks(&mut DispatchChecks, &coldkey)?;
 
         // --- 2. Calculate and lock the required tokens.
         let lock_amount: u64 = Self::get_network_lock_cost();
         log::debug!("network lock_amount: {:?}", lock_amount);
-        ensure!(
-            Self::can_remove_balance_from_coldkey_account(&coldkey, lock_amount),
-            Error::<T>::NotEnoughBalanceToStake
-        );
 
         // --- 4. Determine the netuid to register.
         let netuid_to_register: u16 = {
@@ -1002,6 +1016,29 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Checks `coldkey` registering a network before the lock is taken.
+    /// Shared by `user_add_network` and the pre-dispatch check, see `CallChecks`.
+    pub fn register_network_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+    ) -> Result<(), Error<T>> {
+        // --- 1. Rate limit for network registrations.
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_allowed(
+                &TransactionType::RegisterNetwork,
+                coldkey,
+                GLOBAL_RATE_LIMIT_NETUID,
+            ),
+            Error::<T>::NetworkTxRateLimitExceeded,
+        )?;
+
+        // --- 2. Ensure the coldkey can lock the required tokens.
+        checks.ensure(
+            Self::can_remove_balance_from_coldkey_account(coldkey, Self::get_network_lock_cost()),
+            Error::<T>::NotEnoughBalanceToStake,
+        )
+    }
+
     /// Facilitates the removal of a user's subnetwork.
     ///
     /// # Args:
@@ -1162,6 +1199,8 @@ impl<T: Config> Pallet<T> {
 
         // --- 8. Removes the weights for this subnet (do not remove).
         let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = TimelockedWeightCommits::<T>::clear_prefix((netuid,), u32::MAX, None);
+        TimelockedWeightCommitCount::<T>::remove(netuid);
 
         // --- 9. Iterate over stored weights and fill the matrix.
         for (uid_i, weights_i) in
@@ -1190,6 +1229,7 @@ impl<T: Config> Pallet<T> {
         Consensus::<T>::remove(netuid);
         Dividends::<T>::remove(netuid);
         PruningScores::<T>::remove(netuid);
+        PruningEmissionHistory::<T>::remove(netuid);
         LastUpdate::<T>::remove(netuid);
         ValidatorPermit::<T>::remove(netuid);
         ValidatorTrust::<T>::remove(netuid);
@@ -1207,6 +1247,12 @@ impl<T: Config> Pallet<T> {
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
+        SubnetPruningPolicy::<T>::remove(netuid);
+        PruningProtectedUids::<T>::remove(netuid);
+        SubnetAdjustmentController::<T>::remove(netuid);
+        AdjustmentIntegral::<T>::remove(netuid);
+        RegistrationAuctionEnabled::<T>::remove(netuid);
+        Self::refund_registration_bids(netuid);
 
         // --- 12. Add the balance back to the owner.
         Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
@@ -1218,6 +1264,7 @@ impl<T: Config> Pallet<T> {
             SubnetIdentities::<T>::remove(netuid);
             Self::deposit_event(Event::SubnetIdentityRemoved(netuid));
         }
+        let _ = SubnetIdentityAttestations::<T>::clear_prefix(netuid, u32::MAX, None);
     }
 
     #[allow(clippy::arithmetic_side_effects)]
@@ -1,4 +1,6 @@
 use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
 use substrate_fixed::types::I64F64;
 use substrate_fixed::types::I96F32;
 
@@ -27,9 +29,14 @@ impl<T: Config> Pallet<T> {
     // Step 4: Further distribute emissions from hotkeys to nominators.
     // Finally, the emissions received by hotkeys are further distributed to their nominators,
     // who are stakeholders that support the hotkeys.
-    pub fn run_coinbase() {
+    //
+    // Returns the weight of the senate rebalance, the timelocked weight reveals, the weight commit
+    // expiries and the hotkey emission drains, which depend on the senate terms, on the commits due
+    // and on the nominators of the drained hotkeys.
+    pub fn run_coinbase() -> Weight {
         // --- 0. Get current block.
         let current_block: u64 = Self::get_current_block_as_u64();
+        let mut weight: Weight = Weight::zero();
         log::debug!("Current block: {:?}", current_block);
 
         // --- 1. Get all netuids.
@@ -38,7 +45,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 2. Run the root epoch function which computes the block emission for each subnet.
         // coinbase --> root() --> subnet_block_emission
-        match Self::root_epoch(current_block) {
+        match Self::root_epoch(current_block, &mut weight) {
             Ok(_) => log::debug!("Root epoch run successfully for block: {:?}", current_block),
             Err(e) => {
                 log::trace!("Did not run epoch with: {:?}", e);
@@ -74,6 +81,8 @@ impl<T: Config> Pallet<T> {
         // --- 4. Drain the accumulated subnet emissions, pass them through the epoch().
         // Before accumulating on the hotkeys the function redistributes the emission towards hotkey parents.
         // subnet_emission --> epoch() --> hotkey_emission --> (hotkey + parent hotkeys)
+        // The subnets running their epoch share the budget of timelocked weight reveals of the block.
+        let mut timelocked_reveals_left: u32 = MAX_TIMELOCKED_REVEALS_PER_BLOCK;
         for netuid in subnets.clone().iter() {
             // --- 4.1 Check to see if the subnet should run its epoch.
             if Self::should_run_epoch(*netuid, current_block) {
@@ -118,9 +127,23 @@ impl<T: Config> Pallet<T> {
                     Self::coinbase(owner_cut.to_num::<u64>());
                 }
 
+                // --- 4.5 Apply the scheduled child key changes and expiries due this epoch.
+                Self::apply_scheduled_children(*netuid, current_block, &mut weight);
+
+                // --- 4.6 Decrypt and set the timelocked weight commits due this epoch.
+                weight.saturating_accrue(Self::reveal_timelocked_weight_commits(
+                    *netuid,
+                    current_block,
+                    &mut timelocked_reveals_left,
+                ));
+
+                // --- 4.7 Remove the weight commits which can no longer be revealed.
+                weight.saturating_accrue(Self::expire_weight_commits(*netuid));
+
                 // 4.3 Pass emission through epoch() --> hotkey emission.
                 let hotkey_emission: Vec<(T::AccountId, u64, u64)> =
                     Self::epoch(*netuid, subnet_emission);
+                Self::record_pruning_emission(*netuid);
                 log::debug!(
                     "Hotkey emission results for netuid {:?}: {:?}",
                     *netuid,
@@ -163,8 +186,12 @@ impl<T: Config> Pallet<T> {
             // --- 5.1 Check if we should drain the hotkey emission on this block.
             if Self::should_drain_hotkey(&hotkey, current_block, emission_tempo) {
                 // --- 5.2 Drain the hotkey emission and distribute it to nominators.
-                let total_new_tao: u64 =
-                    Self::drain_hotkey_emission(&hotkey, hotkey_emission, current_block);
+                let total_new_tao: u64 = Self::drain_hotkey_emission(
+                    &hotkey,
+                    hotkey_emission,
+                    current_block,
+                    &mut weight,
+                );
                 log::debug!(
                     "Drained hotkey emission for hotkey {:?} on block {:?}: {:?}",
                     hotkey,
@@ -177,6 +204,8 @@ impl<T: Config> Pallet<T> {
                 log::debug!("Increased total issuance by {:?}", total_new_tao);
             }
         }
+
+        weight
     }
 
     /// Accumulates the mining and validator emissions on a hotkey and distributes the validator emission among its parents.
@@ -279,11 +308,20 @@ impl<T: Config> Pallet<T> {
     /// 5. It then calculates the remaining emissions after the hotkey's take and distributes this remaining amount proportionally among the hotkey's nominators.
     /// 6. Each nominator's share of the emissions is added to their stake, but only if their stake was not manually increased since the last emission drain.
     /// 7. Finally, the hotkey's own take and any undistributed emissions are added to the hotkey's total stake.
+    /// 8. The nominator rewards are recorded in the nominator ledgers.
     ///
     /// This function ensures that emissions are fairly distributed according to stake proportions and delegation agreements, and it updates the necessary records to reflect these changes.
-    pub fn drain_hotkey_emission(hotkey: &T::AccountId, emission: u64, block_number: u64) -> u64 {
+    /// The weight of the drain, which depends on the number of nominators, is added to `weight`.
+    pub fn drain_hotkey_emission(
+        hotkey: &T::AccountId,
+        emission: u64,
+        block_number: u64,
+        weight: &mut Weight,
+    ) -> u64 {
         // --- 0. For accounting purposes record the total new added stake.
         let mut total_new_tao: u64 = 0;
+        let mut nominator_rewards: Vec<(T::AccountId, u64, u64)> = Vec::new();
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(6, 4));
 
         // Get the untouchable part of pending hotkey emission, so that we don't distribute this part of
         // PendingdHotkeyEmission to nominators
@@ -303,8 +341,9 @@ impl<T: Config> Pallet<T> {
         // --- 4 Calculate the emission take for the hotkey.
         // This is only the hotkey take. Childkey take was already deducted from validator emissions in
         // accumulate_hotkey_emission and now it is included in untouchable_emission.
-        let take_proportion: I64F64 = I64F64::from_num(Delegates::<T>::get(hotkey))
-            .saturating_div(I64F64::from_num(u16::MAX));
+        let take: u16 = Delegates::<T>::get(hotkey);
+        let take_proportion: I64F64 =
+            I64F64::from_num(take).saturating_div(I64F64::from_num(u16::MAX));
         let hotkey_take: u64 = (take_proportion
             .saturating_mul(I64F64::from_num(emission_to_distribute)))
         .to_num::<u64>();
@@ -318,6 +357,7 @@ impl<T: Config> Pallet<T> {
         // --- 7 Iterate over each nominator and get all viable stake.
         let mut total_viable_nominator_stake: u64 = total_hotkey_stake;
         for (nominator, _) in Stake::<T>::iter_prefix(hotkey) {
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
             let nonviable_nomintaor_stake = Self::get_nonviable_stake(hotkey, &nominator);
 
             total_viable_nominator_stake =
@@ -329,8 +369,9 @@ impl<T: Config> Pallet<T> {
             for (nominator, nominator_stake) in Stake::<T>::iter_prefix(hotkey) {
                 // --- 9 Skip emission for any stake the was added by the nominator since the last emission drain.
                 // This means the nominator will get emission on existing stake, but not on new stake, until the next emission drain.
+                let nonviable_nominator_stake = Self::get_nonviable_stake(hotkey, &nominator);
                 let viable_nominator_stake =
-                    nominator_stake.saturating_sub(Self::get_nonviable_stake(hotkey, &nominator));
+                    nominator_stake.saturating_sub(nonviable_nominator_stake);
 
                 // --- 10 Calculate this nominator's share of the emission.
                 let nominator_emission: I64F64 = I64F64::from_num(viable_nominator_stake)
@@ -338,14 +379,16 @@ impl<T: Config> Pallet<T> {
                     .unwrap_or(I64F64::from_num(0))
                     .saturating_mul(I64F64::from_num(emission_minus_take));
 
-                // --- 11 Increase the stake for the nominator.
-                Self::increase_stake_on_coldkey_hotkey_account(
-                    &nominator,
-                    hotkey,
-                    nominator_emission.to_num::<u64>(),
-                );
+                // --- 11 Pay the nominator's emission to its reward destination, by default its stake.
+                Self::pay_nominator_reward(&nominator, hotkey, nominator_emission.to_num::<u64>());
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 4));
 
-                // --- 12* Record event and Subtract the nominator's emission from the remainder.
+                // --- 12* Record the reward and Subtract the nominator's emission from the remainder.
+                nominator_rewards.push((
+                    nominator.clone(),
+                    nominator_emission.to_num::<u64>(),
+                    nonviable_nominator_stake,
+                ));
                 total_new_tao = total_new_tao.saturating_add(nominator_emission.to_num::<u64>());
                 remainder = remainder.saturating_sub(nominator_emission.to_num::<u64>());
             }
@@ -359,16 +402,79 @@ impl<T: Config> Pallet<T> {
         Self::increase_stake_on_hotkey_account(hotkey, hotkey_new_tao);
 
         // --- 14 Reset the stake delta for the hotkey.
-        let _ = StakeDeltaSinceLastEmissionDrain::<T>::clear_prefix(hotkey, u32::MAX, None);
+        let removed = StakeDeltaSinceLastEmissionDrain::<T>::clear_prefix(hotkey, u32::MAX, None);
+        weight.saturating_accrue(T::DbWeight::get().writes(u64::from(removed.unique)));
+
+        // --- 15 Record the nominator rewards in the nominator ledgers.
+        nominator_rewards
+            .retain(|(_, reward, nonviable_stake)| *reward > 0 || *nonviable_stake > 0);
+        let nominators_rewarded: u32 = nominator_rewards
+            .iter()
+            .filter(|(_, reward, _)| *reward > 0)
+            .count()
+            .try_into()
+            .unwrap_or(u32::MAX);
+        for (nominator, reward, nonviable_stake) in nominator_rewards {
+            Self::record_nominator_reward(
+                &nominator,
+                hotkey,
+                take,
+                reward,
+                nonviable_stake,
+                block_number,
+            );
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
 
-        // --- 15 Record new tao creation event and return the amount created.
+        // --- 16 Record new tao creation event and return the amount created.
         total_new_tao = total_new_tao.saturating_add(hotkey_new_tao);
+        Self::deposit_event(Event::HotkeyEmissionDrained(
+            hotkey.clone(),
+            total_new_tao,
+            hotkey_new_tao,
+            nominators_rewarded,
+        ));
         total_new_tao
     }
 
     ///////////////
     /// Helpers ///
     ///////////////
+    /// Appends the reward of a nominator from an emission drain of `hotkey` to the ledger of the
+    /// nominator, dropping the oldest entry once the ledger holds MAX_NOMINATOR_REWARD_ENTRIES.
+    /// Nominators without reward or non-viable stake are not recorded.
+    pub fn record_nominator_reward(
+        nominator: &T::AccountId,
+        hotkey: &T::AccountId,
+        take: u16,
+        reward: u64,
+        nonviable_stake: u64,
+        block_number: u64,
+    ) {
+        if reward == 0 && nonviable_stake == 0 {
+            return;
+        }
+        NominatorRewards::<T>::mutate(nominator, |ledger| {
+            if ledger.len() >= MAX_NOMINATOR_REWARD_ENTRIES {
+                ledger.pop_front();
+            }
+            ledger.push_back(NominatorRewardEntry {
+                block: block_number,
+                hotkey: hotkey.clone(),
+                take,
+                reward,
+                nonviable_stake,
+            });
+        });
+    }
+
+    /// Removes the reward ledger of `coldkey` once it no longer has any stake.
+    pub fn clear_nominator_rewards_if_unstaked(coldkey: &T::AccountId) {
+        if TotalColdkeyStake::<T>::get(coldkey) == 0 {
+            NominatorRewards::<T>::remove(coldkey);
+        }
+    }
+
     /// Determines whether the hotkey emission should be drained based on the current block and index.
     ///
     /// # Arguments
@@ -384,6 +490,37 @@ impl<T: Config> Pallet<T> {
             == hotkey_idx.rem_euclid(emit_tempo.saturating_add(1))
     }
 
+    /// Returns the first block after `block` on which the emission of the hotkey is drained.
+    ///
+    /// # Arguments
+    /// * `hotkey` - The hotkey identifier.
+    /// * `block` - The current block number.
+    /// * `emit_tempo` - The hotkey emission tempo.
+    ///
+    /// # Returns
+    /// * `u64` - The block of the next drain, a block for which `should_drain_hotkey` holds.
+    pub fn get_next_drain_block(hotkey: &T::AccountId, block: u64, emit_tempo: u64) -> u64 {
+        let period: u64 = emit_tempo.saturating_add(1);
+        let drain_slot: u64 = Self::hash_hotkey_to_u64(hotkey).rem_euclid(period);
+        let next_block: u64 = block.saturating_add(1);
+        let blocks_until_slot: u64 = drain_slot
+            .saturating_add(period)
+            .saturating_sub(next_block.rem_euclid(period))
+            .rem_euclid(period);
+        next_block.saturating_add(blocks_until_slot)
+    }
+
+    /// Returns the first block after `block` on which the epoch runs on the subnet, `None` if
+    /// the subnet never runs its epoch (tempo of zero).
+    pub fn get_next_epoch_block(netuid: u16, block: u64) -> Option<u64> {
+        let tempo: u16 = Self::get_tempo(netuid);
+        if tempo == 0 {
+            return None;
+        }
+        let next_block: u64 = block.saturating_add(1);
+        Some(next_block.saturating_add(Self::blocks_until_next_epoch(netuid, tempo, next_block)))
+    }
+
     /// Checks if the epoch should run for a given subnet based on the current block.
     ///
     /// # Arguments
@@ -0,0 +1,173 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+
+impl<T: Config> Pallet<T> {
+    /// Rebalances the senate at the root epoch.
+    ///
+    /// Members which have served the term limit leave the senate and sit out a term. The vacant
+    /// seats are then filled by the root validators with the most stake, which also replace the
+    /// members with less stake than themselves, ranked by `get_total_stake_for_hotkey`.
+    ///
+    /// Emits `SenateRebalanced` with the hotkeys which joined and left the senate, if any.
+    ///
+    /// Returns the weight of the rebalance, which depends on the number of members, of the senate
+    /// terms and of the root validators.
+    pub fn rebalance_senate() -> Weight {
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let term_limit: u64 = Self::get_senate_term_limit();
+        let mut members: Vec<T::AccountId> = T::SenateMembers::members();
+        let mut weight: Weight = T::DbWeight::get().reads(2);
+        let mut added: Vec<T::AccountId> = Vec::new();
+        let mut removed: Vec<T::AccountId> = Vec::new();
+
+        // --- 1. Start the term of the members which joined through governance, and forget the
+        // terms of former members and of the hotkeys which have sat out their term.
+        for member in members.iter() {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            if !SenateTermStart::<T>::contains_key(member) {
+                SenateTermStart::<T>::insert(member, current_block);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+        }
+        let former_members: Vec<T::AccountId> = SenateTermStart::<T>::iter_keys()
+            .inspect(|_| weight.saturating_accrue(T::DbWeight::get().reads(1)))
+            .filter(|hotkey| !members.contains(hotkey))
+            .collect();
+        for hotkey in former_members {
+            SenateTermStart::<T>::remove(&hotkey);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        // Each term end is read by the iteration and again by `is_eligible_for_senate`, along
+        // with the term limit.
+        let rested: Vec<T::AccountId> = SenateTermEnd::<T>::iter_keys()
+            .inspect(|_| weight.saturating_accrue(T::DbWeight::get().reads(3)))
+            .filter(|hotkey| Self::is_eligible_for_senate(hotkey))
+            .collect();
+        for hotkey in rested {
+            SenateTermEnd::<T>::remove(&hotkey);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+
+        // --- 2. Remove the members which have served the term limit.
+        if term_limit > 0 {
+            for member in members.iter() {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+                let term_start: u64 = SenateTermStart::<T>::get(member).unwrap_or(current_block);
+                if current_block.saturating_sub(term_start) >= term_limit
+                    && T::SenateMembers::remove_member(member).is_ok()
+                {
+                    SenateTermStart::<T>::remove(member);
+                    SenateTermEnd::<T>::insert(member, current_block);
+                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+                    removed.push(member.clone());
+                }
+            }
+            members.retain(|member| !removed.contains(member));
+        }
+
+        // --- 3. Rank the eligible root validators which are not members, most stake first, and
+        // the members, least stake first.
+        let root_netuid: u16 = Self::get_root_netuid();
+        let non_members: Vec<T::AccountId> = Keys::<T>::iter_prefix_values(root_netuid)
+            .inspect(|_| weight.saturating_accrue(T::DbWeight::get().reads(1)))
+            .filter(|hotkey| !members.contains(hotkey))
+            .collect();
+        weight.saturating_accrue(
+            T::DbWeight::get().reads((non_members.len() as u64).saturating_mul(2)),
+        );
+        let mut candidates: Vec<(u64, T::AccountId)> = non_members
+            .into_iter()
+            .filter(|hotkey| Self::is_eligible_for_senate(hotkey))
+            .map(|hotkey| (Self::get_total_stake_for_hotkey(&hotkey), hotkey))
+            .collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(candidates.len() as u64));
+        candidates.sort_by(|a, b| b.0.cmp(&a.0));
+        let mut ranked_members: Vec<(u64, T::AccountId)> = members
+            .iter()
+            .map(|member| (Self::get_total_stake_for_hotkey(member), member.clone()))
+            .collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(ranked_members.len() as u64));
+        ranked_members.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut ranked_members = ranked_members.into_iter();
+
+        // --- 4. Fill the vacant seats, then replace the members with less stake than the
+        // candidates.
+        let max_members: usize = T::SenateMembers::max_members() as usize;
+        let mut member_count: usize = members.len();
+        for (candidate_stake, candidate) in candidates {
+            if member_count < max_members {
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+                if T::SenateMembers::add_member(&candidate).is_ok() {
+                    SenateTermStart::<T>::insert(&candidate, current_block);
+                    weight.saturating_accrue(T::DbWeight::get().writes(1));
+                    member_count = member_count.saturating_add(1);
+                    added.push(candidate);
+                }
+                continue;
+            }
+
+            let Some((member_stake, member)) = ranked_members.next() else {
+                break;
+            };
+            if member_stake >= candidate_stake {
+                break;
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            if T::SenateMembers::swap_member(&member, &candidate).is_ok() {
+                SenateTermStart::<T>::remove(&member);
+                SenateTermStart::<T>::insert(&candidate, current_block);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+                removed.push(member);
+                added.push(candidate);
+            }
+        }
+
+        // --- 5. Announce the changes of membership.
+        if added.is_empty() && removed.is_empty() {
+            return weight;
+        }
+        log::debug!(
+            "SenateRebalanced( added:{:?} removed:{:?} )",
+            added,
+            removed
+        );
+        Self::deposit_event(Event::SenateRebalanced { added, removed });
+        weight
+    }
+
+    /// Returns whether the hotkey may join the senate, which it may not while sitting out a term
+    /// after reaching the term limit.
+    pub fn is_eligible_for_senate(hotkey: &T::AccountId) -> bool {
+        match SenateTermEnd::<T>::get(hotkey) {
+            Some(term_end) => {
+                Self::get_current_block_as_u64()
+                    >= term_end.saturating_add(Self::get_senate_term_limit())
+            }
+            None => true,
+        }
+    }
+
+    /// Starts the senate term of a hotkey which joined the senate.
+    pub fn start_senate_term(hotkey: &T::AccountId) {
+        SenateTermStart::<T>::insert(hotkey, Self::get_current_block_as_u64());
+    }
+
+    /// Moves the senate term of a swapped hotkey to the new hotkey.
+    pub fn swap_senate_term(old_hotkey: &T::AccountId, new_hotkey: &T::AccountId) {
+        if let Some(term_start) = SenateTermStart::<T>::take(old_hotkey) {
+            SenateTermStart::<T>::insert(new_hotkey, term_start);
+        }
+        if let Some(term_end) = SenateTermEnd::<T>::take(old_hotkey) {
+            SenateTermEnd::<T>::insert(new_hotkey, term_end);
+        }
+    }
+
+    pub fn get_senate_term_limit() -> u64 {
+        SenateTermLimit::<T>::get()
+    }
+    pub fn set_senate_term_limit(term_limit: u64) {
+        SenateTermLimit::<T>::put(term_limit);
+        Self::deposit_event(Event::SenateTermLimitSet(term_limit));
+    }
+}
@@ -1,3 +1,4 @@
 use super::*;
 pub mod math;
+pub mod pipeline;
 pub mod run_epoch;
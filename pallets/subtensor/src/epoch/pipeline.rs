@@ -0,0 +1,758 @@
+use super::math::*;
+use sp_std::fmt::Debug;
+use sp_std::vec;
+use sp_std::vec::Vec;
+use substrate_fixed::types::{I32F32, I64F64, I96F32};
+
+/// Subnet hyperparameters consumed by the epoch, in their on-chain representation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EpochParams {
+    /// Blocks after which a neuron which has not set weights is considered inactive.
+    pub activity_cutoff: u16,
+    /// Maximum number of validator permits.
+    pub max_allowed_validators: u16,
+    /// Consensus majority ratio normalized over u16::MAX.
+    pub kappa: u16,
+    /// Bonds moving average normalized over 1_000_000.
+    pub bonds_moving_average: u64,
+    /// Whether liquid alpha is used for the bonds EMA.
+    pub liquid_alpha_enabled: bool,
+    /// Liquid alpha lower bound normalized over u16::MAX.
+    pub alpha_low: u16,
+    /// Liquid alpha upper bound normalized over u16::MAX.
+    pub alpha_high: u16,
+}
+
+impl EpochParams {
+    pub fn float_kappa(&self) -> I32F32 {
+        I32F32::from_num(self.kappa).saturating_div(I32F32::from_num(u16::MAX))
+    }
+
+    /// Returns the `(alpha_low, alpha_high)` liquid alpha bounds.
+    pub fn float_alpha_values(&self) -> (I32F32, I32F32) {
+        (
+            I32F32::from_num(self.alpha_low).saturating_div(I32F32::from_num(u16::MAX)),
+            I32F32::from_num(self.alpha_high).saturating_div(I32F32::from_num(u16::MAX)),
+        )
+    }
+}
+
+/// Subnet state consumed by the epoch. Vectors are indexed by uid.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EpochInputs<AccountId> {
+    /// Number of neurons in the subnet.
+    pub n: u16,
+    /// Block at which the epoch is run.
+    pub current_block: u64,
+    /// Registered `(uid, hotkey)` pairs.
+    pub hotkeys: Vec<(u16, AccountId)>,
+    /// Unnormalized stake, including stake from parents and excluding stake to children.
+    pub stake: Vec<I64F64>,
+    /// Block of the last weights update per neuron.
+    pub last_update: Vec<u64>,
+    /// Block at which each neuron was last registered.
+    pub block_at_registration: Vec<u64>,
+    /// Validator permits before the epoch.
+    pub validator_permits: Vec<bool>,
+    /// Unnormalized sparse weights, row max-upscaled in u16.
+    pub weights: Vec<Vec<(u16, I32F32)>>,
+    /// Unnormalized sparse bonds, column max-upscaled in u16.
+    pub bonds: Vec<Vec<(u16, I32F32)>>,
+}
+
+/// Every intermediate and final term computed by one run of the epoch on a subnet.
+/// Vectors are indexed by uid, sparse matrices are rows of `(uid, value)` pairs.
+pub struct EpochTerms<AccountId> {
+    /// Number of neurons in the subnet.
+    pub n: u16,
+    /// Block at which the epoch was run.
+    pub current_block: u64,
+    /// Registered `(uid, hotkey)` pairs.
+    pub hotkeys: Vec<(u16, AccountId)>,
+    /// Neurons which updated within the activity cutoff.
+    pub active: Vec<bool>,
+    /// Block of the last weights update per neuron.
+    pub last_update: Vec<u64>,
+    /// Block at which each neuron was last registered.
+    pub block_at_registration: Vec<u64>,
+    /// Normalized stake.
+    pub stake: Vec<I32F32>,
+    /// Validator permits before the epoch.
+    pub validator_permits: Vec<bool>,
+    /// Validator permits after the epoch.
+    pub new_validator_permits: Vec<bool>,
+    /// Normalized stake of active permitted validators.
+    pub active_stake: Vec<I32F32>,
+    /// Masked and row normalized weights.
+    pub weights: Vec<Vec<(u16, I32F32)>>,
+    /// Ranks before consensus clipping.
+    pub preranks: Vec<I32F32>,
+    /// Stake weighted median of weights per column.
+    pub consensus: Vec<I32F32>,
+    /// Weights clipped at consensus.
+    pub clipped_weights: Vec<Vec<(u16, I32F32)>>,
+    pub validator_trust: Vec<I32F32>,
+    /// Normalized ranks after consensus clipping.
+    pub ranks: Vec<I32F32>,
+    pub trust: Vec<I32F32>,
+    pub incentive: Vec<I32F32>,
+    /// Masked and column normalized bonds read from storage.
+    pub bonds: Vec<Vec<(u16, I32F32)>>,
+    /// Column normalized bonds delta ΔB = W◦S.
+    pub bonds_delta: Vec<Vec<(u16, I32F32)>>,
+    /// Column normalized exponential moving average of bonds.
+    pub ema_bonds: Vec<Vec<(u16, I32F32)>>,
+    pub dividends: Vec<I32F32>,
+    pub normalized_server_emission: Vec<I32F32>,
+    pub normalized_validator_emission: Vec<I32F32>,
+    pub normalized_combined_emission: Vec<I32F32>,
+    /// Server emission in rao.
+    pub server_emission: Vec<u64>,
+    /// Validator emission in rao.
+    pub validator_emission: Vec<u64>,
+    /// Combined emission in rao.
+    pub combined_emission: Vec<u64>,
+    pub pruning_scores: Vec<I32F32>,
+}
+
+impl<AccountId: Clone> EpochTerms<AccountId> {
+    /// Returns the `(hotkey, server_emission, validator_emission)` tuples of the epoch.
+    pub fn emission_tuples(&self) -> Vec<(AccountId, u64, u64)> {
+        self.hotkeys
+            .iter()
+            .map(|(uid_i, hotkey)| {
+                (
+                    hotkey.clone(),
+                    self.server_emission
+                        .get(*uid_i as usize)
+                        .copied()
+                        .unwrap_or_default(),
+                    self.validator_emission
+                        .get(*uid_i as usize)
+                        .copied()
+                        .unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+
+    /// Converts the epoch results to the values written to storage.
+    pub fn into_storage(self) -> EpochStorage {
+        let to_u16 = |v: &[I32F32]| -> Vec<u16> {
+            v.iter()
+                .map(|xi| fixed_proportion_to_u16(*xi))
+                .collect::<Vec<u16>>()
+        };
+
+        // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
+        let mut ema_bonds: Vec<Vec<(u16, I32F32)>> = self.ema_bonds;
+        inplace_col_max_upscale_sparse(&mut ema_bonds, self.n);
+        let bonds: Vec<Option<Vec<(u16, u16)>>> = self
+            .new_validator_permits
+            .iter()
+            .zip(self.validator_permits)
+            .zip(ema_bonds)
+            .map(|((new_permit, validator_permit), ema_bond)| {
+                // Set bonds only if uid retains validator permit, otherwise clear bonds.
+                if *new_permit {
+                    Some(
+                        ema_bond
+                            .iter()
+                            .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
+                            .collect(),
+                    )
+                } else if validator_permit {
+                    // Only overwrite the intersection.
+                    Some(vec![])
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        EpochStorage {
+            stake_weight: to_u16(&self.stake),
+            active: self.active,
+            emission: self.combined_emission,
+            rank: to_u16(&self.ranks),
+            trust: to_u16(&self.trust),
+            consensus: to_u16(&self.consensus),
+            incentive: to_u16(&self.incentive),
+            dividends: to_u16(&self.dividends),
+            pruning_scores: vec_max_upscale_to_u16(&self.pruning_scores),
+            validator_trust: to_u16(&self.validator_trust),
+            validator_permit: self.new_validator_permits,
+            bonds,
+        }
+    }
+}
+
+/// Values written to storage by an epoch, as they are stored on chain.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EpochStorage {
+    pub stake_weight: Vec<u16>,
+    pub active: Vec<bool>,
+    pub emission: Vec<u64>,
+    pub rank: Vec<u16>,
+    pub trust: Vec<u16>,
+    pub consensus: Vec<u16>,
+    pub incentive: Vec<u16>,
+    pub dividends: Vec<u16>,
+    pub pruning_scores: Vec<u16>,
+    pub validator_trust: Vec<u16>,
+    pub validator_permit: Vec<bool>,
+    /// New bonds row per uid, `None` leaves the stored row untouched.
+    pub bonds: Vec<Option<Vec<(u16, u16)>>>,
+}
+
+/// Runs the epoch pipeline on `inputs` with the subnet `params` and distributes `rao_emission`.
+/// This function is pure, it is shared by the on-chain epoch, the epoch dry run and off-chain simulators.
+pub fn epoch_terms<AccountId: Debug>(
+    inputs: EpochInputs<AccountId>,
+    params: &EpochParams,
+    rao_emission: u64,
+) -> EpochTerms<AccountId> {
+    let EpochInputs {
+        n,
+        current_block,
+        hotkeys,
+        stake: mut stake_64,
+        last_update,
+        block_at_registration,
+        validator_permits,
+        mut weights,
+        mut bonds,
+    } = inputs;
+
+    // Subnetwork size.
+    log::trace!("Number of Neurons in Network: {:?}", n);
+
+    // ======================
+    // == Active & updated ==
+    // ======================
+
+    // Current block.
+    log::trace!("current_block: {:?}", current_block);
+
+    // Activity cutoff.
+    let activity_cutoff: u64 = params.activity_cutoff as u64;
+    log::trace!("activity_cutoff: {:?}", activity_cutoff);
+
+    // Last update vector.
+    log::trace!("Last update: {:?}", &last_update);
+
+    // Inactive mask.
+    let inactive: Vec<bool> = last_update
+        .iter()
+        .map(|updated| updated.saturating_add(activity_cutoff) < current_block)
+        .collect();
+    log::trace!("Inactive: {:?}", inactive.clone());
+
+    // Logical negation of inactive.
+    let active: Vec<bool> = inactive.iter().map(|&b| !b).collect();
+
+    // Block at registration vector (block when each neuron was most recently registered).
+    log::trace!("Block at registration: {:?}", &block_at_registration);
+
+    // ===========
+    // == Stake ==
+    // ===========
+
+    log::trace!("hotkeys: {:?}", &hotkeys);
+
+    // Network stake as normalized vector.
+    log::trace!("Stake : {:?}", &stake_64);
+    inplace_normalize_64(&mut stake_64);
+    let stake: Vec<I32F32> = vec_fixed64_to_fixed32(stake_64);
+    // range: I32F32(0, 1)
+    log::trace!("Normalised Stake: {:?}", &stake);
+
+    // =======================
+    // == Validator permits ==
+    // =======================
+
+    // Current validator permits.
+    log::trace!("validator_permits: {:?}", validator_permits);
+
+    // Logical negation of validator_permits.
+    let validator_forbids: Vec<bool> = validator_permits.iter().map(|&b| !b).collect();
+
+    // Get max allowed validators.
+    let max_allowed_validators: u16 = params.max_allowed_validators;
+    log::trace!("max_allowed_validators: {:?}", max_allowed_validators);
+
+    // Get new validator permits.
+    let new_validator_permits: Vec<bool> = is_topk(&stake, max_allowed_validators as usize);
+    log::trace!("new_validator_permits: {:?}", new_validator_permits);
+
+    // ==================
+    // == Active Stake ==
+    // ==================
+
+    let mut active_stake: Vec<I32F32> = stake.clone();
+
+    // Remove inactive stake.
+    inplace_mask_vector(&inactive, &mut active_stake);
+
+    // Remove non-validator stake.
+    inplace_mask_vector(&validator_forbids, &mut active_stake);
+
+    // Normalize active stake.
+    inplace_normalize(&mut active_stake);
+    log::trace!("Active Stake:\n{:?}\n", &active_stake);
+
+    // =============
+    // == Weights ==
+    // =============
+
+    // Network weights row unnormalized.
+    log::trace!("Weights: {:?}", &weights);
+
+    // Mask weights that are not from permitted validators.
+    weights = mask_rows_sparse(&validator_forbids, &weights);
+    log::trace!("Weights (permit): {:?}", &weights);
+
+    // Remove self-weight by masking diagonal.
+    weights = mask_diag_sparse(&weights);
+    log::trace!("Weights (permit+diag): {:?}", &weights);
+
+    // Remove weights referring to deregistered neurons.
+    weights = vec_mask_sparse_matrix(
+        &weights,
+        &last_update,
+        &block_at_registration,
+        &|updated, registered| updated <= registered,
+    );
+    log::trace!("Weights (permit+diag+outdate): {:?}", &weights);
+
+    // Normalize remaining weights.
+    inplace_row_normalize_sparse(&mut weights);
+    log::trace!("Weights (mask+norm): {:?}", &weights);
+
+    // ================================
+    // == Consensus, Validator Trust ==
+    // ================================
+
+    // Compute preranks: r_j = SUM(i) w_ij * s_i
+    let preranks: Vec<I32F32> = matmul_sparse(&weights, &active_stake, n);
+    log::trace!("Ranks (before): {:?}", &preranks);
+
+    // Clip weights at majority consensus
+    let kappa: I32F32 = params.float_kappa(); // consensus majority ratio, e.g. 51%.
+    let consensus: Vec<I32F32> = weighted_median_col_sparse(&active_stake, &weights, n, kappa);
+    log::trace!("Consensus: {:?}", &consensus);
+
+    let clipped_weights: Vec<Vec<(u16, I32F32)>> = col_clip_sparse(&weights, &consensus);
+    log::trace!("Weights (clipped): {:?}", &clipped_weights);
+
+    let validator_trust: Vec<I32F32> = row_sum_sparse(&clipped_weights);
+    log::trace!("Validator Trust: {:?}", &validator_trust);
+
+    // =============================
+    // == Ranks, Trust, Incentive ==
+    // =============================
+
+    // Compute ranks: r_j = SUM(i) w_ij * s_i.
+    let mut ranks: Vec<I32F32> = matmul_sparse(&clipped_weights, &active_stake, n);
+    log::trace!("Ranks (after): {:?}", &ranks);
+
+    // Compute server trust: ratio of rank after vs. rank before.
+    let trust: Vec<I32F32> = vecdiv(&ranks, &preranks); // range: I32F32(0, 1)
+    log::trace!("T: {:?}", &trust);
+
+    inplace_normalize(&mut ranks); // range: I32F32(0, 1)
+    let incentive: Vec<I32F32> = ranks.clone();
+    log::trace!("Incentive (=Rank): {:?}", &incentive);
+
+    // =========================
+    // == Bonds and Dividends ==
+    // =========================
+
+    // Network bonds.
+    log::trace!("B: {:?}", &bonds);
+
+    // Remove bonds referring to deregistered neurons.
+    bonds = vec_mask_sparse_matrix(
+        &bonds,
+        &last_update,
+        &block_at_registration,
+        &|updated, registered| updated <= registered,
+    );
+    log::trace!("B (outdatedmask): {:?}", &bonds);
+
+    // Normalize remaining bonds: sum_i b_ij = 1.
+    inplace_col_normalize_sparse(&mut bonds, n);
+    log::trace!("B (mask+norm): {:?}", &bonds);
+
+    // Compute bonds delta column normalized.
+    let mut bonds_delta: Vec<Vec<(u16, I32F32)>> =
+        row_hadamard_sparse(&clipped_weights, &active_stake); // ΔB = W◦S (outdated W masked)
+    log::trace!("ΔB: {:?}", &bonds_delta);
+
+    // Normalize bonds delta.
+    inplace_col_normalize_sparse(&mut bonds_delta, n); // sum_i b_ij = 1
+    log::trace!("ΔB (norm): {:?}", &bonds_delta);
+
+    // Compute the Exponential Moving Average (EMA) of bonds.
+    let mut ema_bonds = compute_ema_bonds_sparse(params, &consensus, &bonds_delta, &bonds);
+    // Normalize EMA bonds.
+    inplace_col_normalize_sparse(&mut ema_bonds, n); // sum_i b_ij = 1
+    log::trace!("Exponential Moving Average Bonds: {:?}", &ema_bonds);
+
+    // Compute dividends: d_i = SUM(j) b_ij * inc_j.
+    // range: I32F32(0, 1)
+    let mut dividends: Vec<I32F32> = matmul_transpose_sparse(&ema_bonds, &incentive);
+    inplace_normalize(&mut dividends);
+    log::trace!("Dividends: {:?}", &dividends);
+
+    // =================================
+    // == Emission and Pruning scores ==
+    // =================================
+
+    // Compute normalized emission scores. range: I32F32(0, 1)
+    let combined_emission: Vec<I32F32> = incentive
+        .iter()
+        .zip(dividends.clone())
+        .map(|(ii, di)| ii.saturating_add(di))
+        .collect();
+    let emission_sum: I32F32 = combined_emission.iter().sum();
+
+    let mut normalized_server_emission: Vec<I32F32> = incentive.clone(); // Servers get incentive.
+    let mut normalized_validator_emission: Vec<I32F32> = dividends.clone(); // Validators get dividends.
+    let mut normalized_combined_emission: Vec<I32F32> = combined_emission.clone();
+    // Normalize on the sum of incentive + dividends.
+    inplace_normalize_using_sum(&mut normalized_server_emission, emission_sum);
+    inplace_normalize_using_sum(&mut normalized_validator_emission, emission_sum);
+    inplace_normalize(&mut normalized_combined_emission);
+
+    // If emission is zero, replace emission with normalized stake.
+    if emission_sum == I32F32::from(0) {
+        // no weights set | outdated weights | self_weights
+        if is_zero(&active_stake) {
+            // no active stake
+            normalized_validator_emission.clone_from(&stake); // do not mask inactive, assumes stake is normalized
+            normalized_combined_emission.clone_from(&stake);
+        } else {
+            normalized_validator_emission.clone_from(&active_stake); // emission proportional to inactive-masked normalized stake
+            normalized_combined_emission.clone_from(&active_stake);
+        }
+    }
+
+    // Compute rao based emission scores. range: I96F32(0, rao_emission)
+    let float_rao_emission: I96F32 = I96F32::from_num(rao_emission);
+
+    let server_emission: Vec<I96F32> = normalized_server_emission
+        .iter()
+        .map(|se: &I32F32| I96F32::from_num(*se).saturating_mul(float_rao_emission))
+        .collect();
+    let server_emission: Vec<u64> = server_emission
+        .iter()
+        .map(|e: &I96F32| e.to_num::<u64>())
+        .collect();
+
+    let validator_emission: Vec<I96F32> = normalized_validator_emission
+        .iter()
+        .map(|ve: &I32F32| I96F32::from_num(*ve).saturating_mul(float_rao_emission))
+        .collect();
+    let validator_emission: Vec<u64> = validator_emission
+        .iter()
+        .map(|e: &I96F32| e.to_num::<u64>())
+        .collect();
+
+    // Only used to track emission in storage.
+    let combined_emission: Vec<I96F32> = normalized_combined_emission
+        .iter()
+        .map(|ce: &I32F32| I96F32::from_num(*ce).saturating_mul(float_rao_emission))
+        .collect();
+    let combined_emission: Vec<u64> = combined_emission
+        .iter()
+        .map(|e: &I96F32| e.to_num::<u64>())
+        .collect();
+
+    log::trace!(
+        "Normalized Server Emission: {:?}",
+        &normalized_server_emission
+    );
+    log::trace!("Server Emission: {:?}", &server_emission);
+    log::trace!(
+        "Normalized Validator Emission: {:?}",
+        &normalized_validator_emission
+    );
+    log::trace!("Validator Emission: {:?}", &validator_emission);
+    log::trace!(
+        "Normalized Combined Emission: {:?}",
+        &normalized_combined_emission
+    );
+    log::trace!("Combined Emission: {:?}", &combined_emission);
+
+    // Set pruning scores using combined emission scores.
+    let pruning_scores: Vec<I32F32> = normalized_combined_emission.clone();
+    log::trace!("Pruning Scores: {:?}", &pruning_scores);
+
+    EpochTerms {
+        n,
+        current_block,
+        hotkeys,
+        active,
+        last_update,
+        block_at_registration,
+        stake,
+        validator_permits,
+        new_validator_permits,
+        active_stake,
+        weights,
+        preranks,
+        consensus,
+        clipped_weights,
+        validator_trust,
+        ranks,
+        trust,
+        incentive,
+        bonds,
+        bonds_delta,
+        ema_bonds,
+        dividends,
+        normalized_server_emission,
+        normalized_validator_emission,
+        normalized_combined_emission,
+        server_emission,
+        validator_emission,
+        combined_emission,
+        pruning_scores,
+    }
+}
+
+/// Calculate the logistic function parameters 'a' and 'b' based on alpha and consensus values.
+///
+/// # Args:
+/// * `alpha_high` - The high alpha value.
+/// * `alpha_low` - The low alpha value.
+/// * `consensus_high` - The high consensus value.
+/// * `consensus_low` - The low consensus value.
+///
+/// # Returns:
+/// A tuple containing the slope 'a' and intercept 'b' for the logistic function.
+pub fn calculate_logistic_params(
+    alpha_high: I32F32,
+    alpha_low: I32F32,
+    consensus_high: I32F32,
+    consensus_low: I32F32,
+) -> (I32F32, I32F32) {
+    log::trace!("alpha_high: {:?}", alpha_high);
+    log::trace!("alpha_low: {:?}", alpha_low);
+    log::trace!("consensus_high: {:?}", consensus_high);
+    log::trace!("consensus_low: {:?}", consensus_low);
+    // Check for division by zero
+    // extra caution to ensure we never divide by zero
+    if consensus_high <= consensus_low || alpha_low == 0 || alpha_high == 0 {
+        // Return 0 for both 'a' and 'b' when consensus values are equal
+        return (I32F32::from_num(0.0), I32F32::from_num(0.0));
+    }
+
+    // Calculate the slope 'a' of the logistic function.
+    // a = (ln((1 / alpha_high - 1)) - ln((1 / alpha_low - 1))) / (consensus_low - consensus_high)
+    let a = (safe_ln(
+        (I32F32::from_num(1.0).saturating_div(alpha_high)).saturating_sub(I32F32::from_num(1.0)),
+    )
+    .saturating_sub(safe_ln(
+        (I32F32::from_num(1.0).saturating_div(alpha_low)).saturating_sub(I32F32::from_num(1.0)),
+    )))
+    .saturating_div(consensus_low.saturating_sub(consensus_high));
+    log::trace!("a: {:?}", a);
+
+    // Calculate the intercept 'b' of the logistic function.
+    // b = ln((1 / alpha_low - 1)) + a * consensus_low
+    let b = safe_ln(
+        (I32F32::from_num(1.0).saturating_div(alpha_low)).saturating_sub(I32F32::from_num(1.0)),
+    )
+    .saturating_add(a.saturating_mul(consensus_low));
+    log::trace!("b: {:?}", b);
+
+    // Return the calculated slope 'a' and intercept 'b'.
+    (a, b)
+}
+
+/// Compute the alpha values using the logistic function parameters 'a' and 'b'.
+///
+/// # Args:
+/// * `consensus` - A vector of consensus values.
+/// * `a` - The slope of the logistic function.
+/// * `b` - The intercept of the logistic function.
+///
+/// # Returns:
+/// A vector of computed alpha values.
+pub fn compute_alpha_values(consensus: &[I32F32], a: I32F32, b: I32F32) -> Vec<I32F32> {
+    // Compute the alpha values for each consensus value.
+    let alpha: Vec<I32F32> = consensus
+        .iter()
+        .map(|c| {
+            // Calculate the exponent value for the logistic function.
+            // exp_val = exp(b - a * c)
+            let exp_val = safe_exp(b.saturating_sub(a.saturating_mul(*c)));
+
+            // Compute the alpha value using the logistic function formula.
+            // alpha = 1 / (1 + exp_val)
+            I32F32::from_num(1.0).saturating_div(I32F32::from_num(1.0).saturating_add(exp_val))
+        })
+        .collect();
+
+    // Log the computed alpha values for debugging purposes.
+    log::trace!("alpha: {:?}", alpha);
+
+    // Return the computed alpha values.
+    alpha
+}
+
+/// Clamp the alpha values between alpha_high and alpha_low.
+///
+/// # Args:
+/// * `alpha` - A vector of alpha values.
+/// * `alpha_high` - The high alpha value.
+/// * `alpha_low` - The low alpha value.
+///
+/// # Returns:
+/// A vector of clamped alpha values.
+pub fn clamp_alpha_values(
+    alpha: Vec<I32F32>,
+    alpha_high: I32F32,
+    alpha_low: I32F32,
+) -> Vec<I32F32> {
+    let clamped_alpha: Vec<I32F32> = alpha
+        .iter()
+        .map(|a| {
+            // First, clamp the value to ensure it does not exceed the upper bound (alpha_high).
+            // If 'a' is greater than 'alpha_high', it will be set to 'alpha_high'.
+            // If 'a' is less than or equal to 'alpha_high', it remains unchanged.
+            let clamped_a = a
+                .min(&alpha_high)
+                // Next, clamp the value to ensure it does not go below the lower bound (alpha_low).
+                // If the value (after the first clamping) is less than 'alpha_low', it will be set to 'alpha_low'.
+                // If the value is greater than or equal to 'alpha_low', it remains unchanged.
+                .max(&alpha_low);
+            // Return the clamped value.
+            *clamped_a
+        })
+        .collect();
+    log::trace!("alpha_clamped: {:?}", clamped_alpha);
+    clamped_alpha
+}
+
+/// Compute the Exponential Moving Average (EMA) of bonds using the clamped alpha values for a sparse matrix.
+///
+/// # Args:
+/// * `bonds_delta` - A vector of bond deltas.
+/// * `bonds` - A vector of bonds.
+/// * `alpha` - A vector of clamped alpha values.
+///
+/// # Returns:
+/// A vector of EMA bonds.
+pub fn compute_ema_bonds_with_liquid_alpha_sparse(
+    bonds_delta: &[Vec<(u16, I32F32)>],
+    bonds: &[Vec<(u16, I32F32)>],
+    alpha: Vec<I32F32>,
+) -> Vec<Vec<(u16, I32F32)>> {
+    // Compute the Exponential Moving Average (EMA) of bonds using the provided clamped alpha values.
+    let ema_bonds = mat_ema_alpha_vec_sparse(bonds_delta, bonds, &alpha);
+
+    // Log the computed EMA bonds for debugging purposes.
+    log::trace!(
+        "Exponential Moving Average Bonds Liquid Alpha: {:?}",
+        ema_bonds
+    );
+
+    // Return the computed EMA bonds.
+    ema_bonds
+}
+
+/// Compute the Exponential Moving Average (EMA) of bonds using a normal alpha value for a sparse matrix.
+///
+/// # Args:
+/// * `bonds_delta` - A vector of bond deltas.
+/// * `bonds` - A vector of bonds.
+/// * `bonds_moving_average` - The bonds moving average hyperparameter.
+///
+/// # Returns:
+/// A vector of EMA bonds.
+pub fn compute_ema_bonds_normal_sparse(
+    bonds_delta: &[Vec<(u16, I32F32)>],
+    bonds: &[Vec<(u16, I32F32)>],
+    bonds_moving_average: u64,
+) -> Vec<Vec<(u16, I32F32)>> {
+    // Scale down the bonds moving average.
+    let bonds_moving_average: I64F64 =
+        I64F64::from_num(bonds_moving_average).saturating_div(I64F64::from_num(1_000_000));
+
+    // Calculate the alpha value for the EMA calculation.
+    // Alpha is derived by subtracting the scaled bonds moving average from 1.
+    let alpha: I32F32 = I32F32::from_num(1).saturating_sub(I32F32::from_num(bonds_moving_average));
+
+    // Compute the Exponential Moving Average (EMA) of bonds using the calculated alpha value.
+    let ema_bonds = mat_ema_sparse(bonds_delta, bonds, alpha);
+
+    // Log the computed EMA bonds for debugging purposes.
+    log::trace!("Exponential Moving Average Bonds Normal: {:?}", ema_bonds);
+
+    // Return the computed EMA bonds.
+    ema_bonds
+}
+
+/// Compute the Exponential Moving Average (EMA) of bonds based on the Liquid Alpha setting for a sparse matrix.
+///
+/// # Args:
+/// * `params` - The epoch hyperparameters of the subnet.
+/// * `consensus` - A vector of consensus values.
+/// * `bonds_delta` - A vector of bond deltas.
+/// * `bonds` - A vector of bonds.
+///
+/// # Returns:
+/// A vector of EMA bonds.
+pub fn compute_ema_bonds_sparse(
+    params: &EpochParams,
+    consensus: &[I32F32],
+    bonds_delta: &[Vec<(u16, I32F32)>],
+    bonds: &[Vec<(u16, I32F32)>],
+) -> Vec<Vec<(u16, I32F32)>> {
+    // Check if Liquid Alpha is enabled, consensus is not empty, and contains non-zero values.
+    // This way we avoid the quantil function panic.
+    if params.liquid_alpha_enabled
+        && !consensus.is_empty()
+        && consensus.iter().any(|&c| c != I32F32::from_num(0))
+    {
+        // Calculate the 75th percentile (high) and 25th percentile (low) of the consensus values.
+        let consensus_high = quantile(consensus, 0.75);
+        let consensus_low = quantile(consensus, 0.25);
+        // Further check if the high and low consensus values meet the required conditions.
+        if (consensus_high > consensus_low) || consensus_high != 0 || consensus_low < 0 {
+            // if (consensus_high > consensus_low) || consensus_high != 0) || consensus_low != 0 {
+            // if (consensus_high > consensus_low) || consensus_low != 0 {
+            log::trace!("Using Liquid Alpha");
+
+            // Get the high and low alpha values for the network.
+            let (alpha_low, alpha_high): (I32F32, I32F32) = params.float_alpha_values();
+            log::trace!("alpha_low: {:?} alpha_high: {:?}", alpha_low, alpha_high);
+
+            // Calculate the logistic function parameters 'a' and 'b' based on alpha and consensus values.
+            let (a, b) =
+                calculate_logistic_params(alpha_high, alpha_low, consensus_high, consensus_low);
+
+            // Compute the alpha values using the logistic function parameters.
+            let alpha = compute_alpha_values(consensus, a, b);
+
+            // Clamp the alpha values between alpha_high and alpha_low.
+            let clamped_alpha = clamp_alpha_values(alpha, alpha_high, alpha_low);
+
+            // Compute the Exponential Moving Average (EMA) of bonds using the clamped alpha values.
+            compute_ema_bonds_with_liquid_alpha_sparse(bonds_delta, bonds, clamped_alpha)
+        } else {
+            log::trace!("Using Bonds Moving Average");
+
+            // Compute the EMA of bonds using a normal alpha value.
+            compute_ema_bonds_normal_sparse(bonds_delta, bonds, params.bonds_moving_average)
+        }
+    } else {
+        log::trace!("Using Bonds Moving Average");
+
+        // Compute the EMA of bonds using a normal alpha value.
+        compute_ema_bonds_normal_sparse(bonds_delta, bonds, params.bonds_moving_average)
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::epoch::math::*;
+use crate::epoch::pipeline::{self, EpochInputs, EpochParams, EpochStorage, EpochTerms};
 use frame_support::IterableStorageDoubleMap;
 use sp_std::vec;
 use substrate_fixed::types::{I32F32, I64F64, I96F32};
@@ -8,11 +9,10 @@ impl<T: Config> Pallet<T> {
     /// Calculates the total stake held by a hotkey on the network, considering child/parent relationships.
     ///
     /// This function performs the following steps:
-    /// 1. Checks for self-loops in the delegation graph.
-    /// 2. Retrieves the initial stake of the hotkey.
-    /// 3. Calculates the stake allocated to children.
-    /// 4. Calculates the stake received from parents.
-    /// 5. Computes the final stake by adjusting the initial stake with child and parent contributions.
+    /// 1. Retrieves the initial stake of the hotkey.
+    /// 2. Calculates the stake allocated to children.
+    /// 3. Calculates the stake received from parents.
+    /// 4. Computes the final stake by adjusting the initial stake with child and parent contributions.
     ///
     /// # Arguments
     /// * `hotkey` - AccountId of the hotkey whose total network stake is to be calculated.
@@ -23,9 +23,8 @@ impl<T: Config> Pallet<T> {
     ///           from children and parents.
     ///
     /// # Note
-    /// This function now includes a check for self-loops in the delegation graph using the
-    /// `dfs_check_self_loops` method. However, it currently only logs warnings for detected loops
-    /// and does not alter the stake calculation based on these findings.
+    /// Cycles in the delegation graph are rejected when children are set, see
+    /// `ensure_valid_childkey_graph`, so the stake of a hotkey never flows back to itself.
     ///
     /// # Panics
     /// This function does not explicitly panic, but underlying arithmetic operations
@@ -45,14 +44,11 @@ impl<T: Config> Pallet<T> {
         // Iterate over children to calculate the total stake allocated to them.
         for (proportion, _) in children {
             // Calculate the stake proportion allocated to the child based on the initial stake.
-            let normalized_proportion: I96F32 =
-                I96F32::from_num(proportion).saturating_div(I96F32::from_num(u64::MAX));
-            let stake_proportion_to_child: I96F32 =
-                I96F32::from_num(initial_stake).saturating_mul(normalized_proportion);
+            let stake_proportion_to_child: u64 =
+                Self::get_proportional_stake(initial_stake, proportion);
 
             // Accumulate the total stake given to children.
-            stake_to_children =
-                stake_to_children.saturating_add(stake_proportion_to_child.to_num::<u64>());
+            stake_to_children = stake_to_children.saturating_add(stake_proportion_to_child);
         }
 
         // Iterate over parents to calculate the total stake received from them.
@@ -60,14 +56,11 @@ impl<T: Config> Pallet<T> {
             // Retrieve the parent's total stake.
             let parent_stake: u64 = Self::get_total_stake_for_hotkey(&parent);
             // Calculate the stake proportion received from the parent.
-            let normalized_proportion: I96F32 =
-                I96F32::from_num(proportion).saturating_div(I96F32::from_num(u64::MAX));
-            let stake_proportion_from_parent: I96F32 =
-                I96F32::from_num(parent_stake).saturating_mul(normalized_proportion);
+            let stake_proportion_from_parent: u64 =
+                Self::get_proportional_stake(parent_stake, proportion);
 
             // Accumulate the total stake received from parents.
-            stake_from_parents =
-                stake_from_parents.saturating_add(stake_proportion_from_parent.to_num::<u64>());
+            stake_from_parents = stake_from_parents.saturating_add(stake_proportion_from_parent);
         }
 
         // Calculate the final stake for the hotkey by adjusting the initial stake with the stakes
@@ -86,6 +79,16 @@ impl<T: Config> Pallet<T> {
         finalized_stake
     }
 
+    /// Returns the part of `stake` given by a parent to a child holding `proportion` of it,
+    /// where `u64::MAX` is the whole stake.
+    pub fn get_proportional_stake(stake: u64, proportion: u64) -> u64 {
+        let normalized_proportion: I96F32 =
+            I96F32::from_num(proportion).saturating_div(I96F32::from_num(u64::MAX));
+        I96F32::from_num(stake)
+            .saturating_mul(normalized_proportion)
+            .to_num::<u64>()
+    }
+
     /// Calculates reward consensus and returns the emissions for uids/hotkeys in a given `netuid`.
     /// (Dense version used only for testing purposes.)
     #[allow(clippy::indexing_slicing)]
@@ -431,369 +434,103 @@ impl<T: Config> Pallet<T> {
     ///  * 'rao_emission': ( u64 ):
     ///     - The total emission for the epoch.
     ///
-    ///  * 'debug' ( bool ):
-    ///     - Print debugging outputs.
-    ///
-    #[allow(clippy::indexing_slicing)]
     pub fn epoch(netuid: u16, rao_emission: u64) -> Vec<(T::AccountId, u64, u64)> {
-        // Get subnetwork size.
-        let n: u16 = Self::get_subnetwork_n(netuid);
-        log::trace!("Number of Neurons in Network: {:?}", n);
-
-        // ======================
-        // == Active & updated ==
-        // ======================
-
-        // Get current block.
-        let current_block: u64 = Self::get_current_block_as_u64();
-        log::trace!("current_block: {:?}", current_block);
+        // Run the epoch pipeline, this only reads from storage.
+        let terms: EpochTerms<T::AccountId> = Self::epoch_terms(netuid, rao_emission);
 
-        // Get activity cutoff.
-        let activity_cutoff: u64 = Self::get_activity_cutoff(netuid) as u64;
-        log::trace!("activity_cutoff: {:?}", activity_cutoff);
-
-        // Last update vector.
-        let last_update: Vec<u64> = Self::get_last_update(netuid);
-        log::trace!("Last update: {:?}", &last_update);
+        // Emission tuples ( hotkeys, server_emission, validator_emission )
+        let emission_tuples: Vec<(T::AccountId, u64, u64)> = terms.emission_tuples();
 
-        // Inactive mask.
-        let inactive: Vec<bool> = last_update
-            .iter()
-            .map(|updated| updated.saturating_add(activity_cutoff) < current_block)
-            .collect();
-        log::trace!("Inactive: {:?}", inactive.clone());
+        // Write the epoch results to storage.
+        Self::store_epoch_terms(netuid, terms);
 
-        // Logical negation of inactive.
-        let active: Vec<bool> = inactive.iter().map(|&b| !b).collect();
+        emission_tuples
+    }
 
-        // Block at registration vector (block when each neuron was most recently registered).
-        let block_at_registration: Vec<u64> = Self::get_block_at_registration(netuid);
-        log::trace!("Block at registration: {:?}", &block_at_registration);
+    /// Runs the epoch pipeline for `netuid` and returns every intermediate and final term
+    /// without writing to storage. Used by `epoch` and by the epoch dry-run runtime API.
+    ///
+    /// # Args:
+    ///  * 'netuid': ( u16 ):
+    ///     - The network to run the epoch on.
+    ///
+    ///  * 'rao_emission': ( u64 ):
+    ///     - The total emission for the epoch.
+    ///
+    pub fn epoch_terms(netuid: u16, rao_emission: u64) -> EpochTerms<T::AccountId> {
+        pipeline::epoch_terms(
+            Self::get_epoch_inputs(netuid),
+            &Self::get_epoch_params(netuid),
+            rao_emission,
+        )
+    }
 
-        // ===========
-        // == Stake ==
-        // ===========
+    /// Reads the subnet state consumed by the epoch from storage.
+    pub fn get_epoch_inputs(netuid: u16) -> EpochInputs<T::AccountId> {
+        // Get subnetwork size.
+        let n: u16 = Self::get_subnetwork_n(netuid);
 
+        // Registered hotkeys.
         let hotkeys: Vec<(u16, T::AccountId)> =
             <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(netuid)
                 .collect();
-        log::trace!("hotkeys: {:?}", &hotkeys);
 
-        // Access network stake as normalized vector.
-        let mut stake_64: Vec<I64F64> = vec![I64F64::from_num(0.0); n as usize];
+        // Access network stake, adjusted by parent and child stake.
+        let mut stake: Vec<I64F64> = vec![I64F64::from_num(0.0); n as usize];
         for (uid_i, hotkey) in &hotkeys {
-            stake_64[*uid_i as usize] =
-                I64F64::from_num(Self::get_stake_for_hotkey_on_subnet(hotkey, netuid));
+            if let Some(stake_i) = stake.get_mut(*uid_i as usize) {
+                *stake_i = I64F64::from_num(Self::get_stake_for_hotkey_on_subnet(hotkey, netuid));
+            }
         }
-        log::trace!("Stake : {:?}", &stake_64);
-        inplace_normalize_64(&mut stake_64);
-        let stake: Vec<I32F32> = vec_fixed64_to_fixed32(stake_64);
-        // range: I32F32(0, 1)
-        log::trace!("Normalised Stake: {:?}", &stake);
-
-        // =======================
-        // == Validator permits ==
-        // =======================
-
-        // Get current validator permits.
-        let validator_permits: Vec<bool> = Self::get_validator_permit(netuid);
-        log::trace!("validator_permits: {:?}", validator_permits);
-
-        // Logical negation of validator_permits.
-        let validator_forbids: Vec<bool> = validator_permits.iter().map(|&b| !b).collect();
-
-        // Get max allowed validators.
-        let max_allowed_validators: u16 = Self::get_max_allowed_validators(netuid);
-        log::trace!("max_allowed_validators: {:?}", max_allowed_validators);
-
-        // Get new validator permits.
-        let new_validator_permits: Vec<bool> = is_topk(&stake, max_allowed_validators as usize);
-        log::trace!("new_validator_permits: {:?}", new_validator_permits);
-
-        // ==================
-        // == Active Stake ==
-        // ==================
-
-        let mut active_stake: Vec<I32F32> = stake.clone();
-
-        // Remove inactive stake.
-        inplace_mask_vector(&inactive, &mut active_stake);
-
-        // Remove non-validator stake.
-        inplace_mask_vector(&validator_forbids, &mut active_stake);
-
-        // Normalize active stake.
-        inplace_normalize(&mut active_stake);
-        log::trace!("Active Stake:\n{:?}\n", &active_stake);
-
-        // =============
-        // == Weights ==
-        // =============
-
-        // Access network weights row unnormalized.
-        let mut weights: Vec<Vec<(u16, I32F32)>> = Self::get_weights_sparse(netuid);
-        log::trace!("Weights: {:?}", &weights);
-
-        // Mask weights that are not from permitted validators.
-        weights = mask_rows_sparse(&validator_forbids, &weights);
-        log::trace!("Weights (permit): {:?}", &weights);
-
-        // Remove self-weight by masking diagonal.
-        weights = mask_diag_sparse(&weights);
-        log::trace!("Weights (permit+diag): {:?}", &weights);
-
-        // Remove weights referring to deregistered neurons.
-        weights = vec_mask_sparse_matrix(
-            &weights,
-            &last_update,
-            &block_at_registration,
-            &|updated, registered| updated <= registered,
-        );
-        log::trace!("Weights (permit+diag+outdate): {:?}", &weights);
-
-        // Normalize remaining weights.
-        inplace_row_normalize_sparse(&mut weights);
-        log::trace!("Weights (mask+norm): {:?}", &weights);
-
-        // ================================
-        // == Consensus, Validator Trust ==
-        // ================================
-
-        // Compute preranks: r_j = SUM(i) w_ij * s_i
-        let preranks: Vec<I32F32> = matmul_sparse(&weights, &active_stake, n);
-        log::trace!("Ranks (before): {:?}", &preranks);
-
-        // Clip weights at majority consensus
-        let kappa: I32F32 = Self::get_float_kappa(netuid); // consensus majority ratio, e.g. 51%.
-        let consensus: Vec<I32F32> = weighted_median_col_sparse(&active_stake, &weights, n, kappa);
-        log::trace!("Consensus: {:?}", &consensus);
-
-        weights = col_clip_sparse(&weights, &consensus);
-        log::trace!("Weights: {:?}", &weights);
-
-        let validator_trust: Vec<I32F32> = row_sum_sparse(&weights);
-        log::trace!("Validator Trust: {:?}", &validator_trust);
-
-        // =============================
-        // == Ranks, Trust, Incentive ==
-        // =============================
-
-        // Compute ranks: r_j = SUM(i) w_ij * s_i.
-        let mut ranks: Vec<I32F32> = matmul_sparse(&weights, &active_stake, n);
-        log::trace!("Ranks (after): {:?}", &ranks);
-
-        // Compute server trust: ratio of rank after vs. rank before.
-        let trust: Vec<I32F32> = vecdiv(&ranks, &preranks); // range: I32F32(0, 1)
-        log::trace!("T: {:?}", &trust);
-
-        inplace_normalize(&mut ranks); // range: I32F32(0, 1)
-        let incentive: Vec<I32F32> = ranks.clone();
-        log::trace!("Incentive (=Rank): {:?}", &incentive);
-
-        // =========================
-        // == Bonds and Dividends ==
-        // =========================
-
-        // Access network bonds.
-        let mut bonds: Vec<Vec<(u16, I32F32)>> = Self::get_bonds_sparse(netuid);
-        log::trace!("B: {:?}", &bonds);
-
-        // Remove bonds referring to deregistered neurons.
-        bonds = vec_mask_sparse_matrix(
-            &bonds,
-            &last_update,
-            &block_at_registration,
-            &|updated, registered| updated <= registered,
-        );
-        log::trace!("B (outdatedmask): {:?}", &bonds);
-
-        // Normalize remaining bonds: sum_i b_ij = 1.
-        inplace_col_normalize_sparse(&mut bonds, n);
-        log::trace!("B (mask+norm): {:?}", &bonds);
-
-        // Compute bonds delta column normalized.
-        let mut bonds_delta: Vec<Vec<(u16, I32F32)>> = row_hadamard_sparse(&weights, &active_stake); // ΔB = W◦S (outdated W masked)
-        log::trace!("ΔB: {:?}", &bonds_delta);
-
-        // Normalize bonds delta.
-        inplace_col_normalize_sparse(&mut bonds_delta, n); // sum_i b_ij = 1
-        log::trace!("ΔB (norm): {:?}", &bonds_delta);
 
-        // Compute the Exponential Moving Average (EMA) of bonds.
-        let mut ema_bonds =
-            Self::compute_ema_bonds_sparse(netuid, consensus.clone(), bonds_delta, bonds);
-        // Normalize EMA bonds.
-        inplace_col_normalize_sparse(&mut ema_bonds, n); // sum_i b_ij = 1
-        log::trace!("Exponential Moving Average Bonds: {:?}", &ema_bonds);
-
-        // Compute dividends: d_i = SUM(j) b_ij * inc_j.
-        // range: I32F32(0, 1)
-        let mut dividends: Vec<I32F32> = matmul_transpose_sparse(&ema_bonds, &incentive);
-        inplace_normalize(&mut dividends);
-        log::trace!("Dividends: {:?}", &dividends);
-
-        // =================================
-        // == Emission and Pruning scores ==
-        // =================================
-
-        // Compute normalized emission scores. range: I32F32(0, 1)
-        let combined_emission: Vec<I32F32> = incentive
-            .iter()
-            .zip(dividends.clone())
-            .map(|(ii, di)| ii.saturating_add(di))
-            .collect();
-        let emission_sum: I32F32 = combined_emission.iter().sum();
+        EpochInputs {
+            n,
+            current_block: Self::get_current_block_as_u64(),
+            hotkeys,
+            stake,
+            last_update: Self::get_last_update(netuid),
+            block_at_registration: Self::get_block_at_registration(netuid),
+            validator_permits: Self::get_validator_permit(netuid),
+            weights: Self::get_weights_sparse(netuid),
+            bonds: Self::get_bonds_sparse(netuid),
+        }
+    }
 
-        let mut normalized_server_emission: Vec<I32F32> = incentive.clone(); // Servers get incentive.
-        let mut normalized_validator_emission: Vec<I32F32> = dividends.clone(); // Validators get dividends.
-        let mut normalized_combined_emission: Vec<I32F32> = combined_emission.clone();
-        // Normalize on the sum of incentive + dividends.
-        inplace_normalize_using_sum(&mut normalized_server_emission, emission_sum);
-        inplace_normalize_using_sum(&mut normalized_validator_emission, emission_sum);
-        inplace_normalize(&mut normalized_combined_emission);
+    /// Reads the subnet hyperparameters consumed by the epoch from storage.
+    pub fn get_epoch_params(netuid: u16) -> EpochParams {
+        let (alpha_low, alpha_high): (u16, u16) = Self::get_alpha_values(netuid);
+        EpochParams {
+            activity_cutoff: Self::get_activity_cutoff(netuid),
+            max_allowed_validators: Self::get_max_allowed_validators(netuid),
+            kappa: Self::get_kappa(netuid),
+            bonds_moving_average: Self::get_bonds_moving_average(netuid),
+            liquid_alpha_enabled: Self::get_liquid_alpha_enabled(netuid),
+            alpha_low,
+            alpha_high,
+        }
+    }
 
-        // If emission is zero, replace emission with normalized stake.
-        if emission_sum == I32F32::from(0) {
-            // no weights set | outdated weights | self_weights
-            if is_zero(&active_stake) {
-                // no active stake
-                normalized_validator_emission.clone_from(&stake); // do not mask inactive, assumes stake is normalized
-                normalized_combined_emission.clone_from(&stake);
-            } else {
-                normalized_validator_emission.clone_from(&active_stake); // emission proportional to inactive-masked normalized stake
-                normalized_combined_emission.clone_from(&active_stake);
+    /// Writes the results of an epoch run to storage: stake weight, active, emission, rank, trust,
+    /// consensus, incentive, dividends, pruning scores, validator trust, validator permits and bonds.
+    pub fn store_epoch_terms(netuid: u16, terms: EpochTerms<T::AccountId>) {
+        let storage: EpochStorage = terms.into_storage();
+        StakeWeight::<T>::insert(netuid, storage.stake_weight);
+        Active::<T>::insert(netuid, storage.active);
+        Emission::<T>::insert(netuid, storage.emission);
+        Rank::<T>::insert(netuid, storage.rank);
+        Trust::<T>::insert(netuid, storage.trust);
+        Consensus::<T>::insert(netuid, storage.consensus);
+        Incentive::<T>::insert(netuid, storage.incentive);
+        Dividends::<T>::insert(netuid, storage.dividends);
+        PruningScores::<T>::insert(netuid, storage.pruning_scores);
+        ValidatorTrust::<T>::insert(netuid, storage.validator_trust);
+        ValidatorPermit::<T>::insert(netuid, storage.validator_permit);
+
+        for (i, bonds_row) in storage.bonds.into_iter().enumerate() {
+            if let Some(bonds_row) = bonds_row {
+                Bonds::<T>::insert(netuid, i as u16, bonds_row);
             }
         }
-
-        // Compute rao based emission scores. range: I96F32(0, rao_emission)
-        let float_rao_emission: I96F32 = I96F32::from_num(rao_emission);
-
-        let server_emission: Vec<I96F32> = normalized_server_emission
-            .iter()
-            .map(|se: &I32F32| I96F32::from_num(*se).saturating_mul(float_rao_emission))
-            .collect();
-        let server_emission: Vec<u64> = server_emission
-            .iter()
-            .map(|e: &I96F32| e.to_num::<u64>())
-            .collect();
-
-        let validator_emission: Vec<I96F32> = normalized_validator_emission
-            .iter()
-            .map(|ve: &I32F32| I96F32::from_num(*ve).saturating_mul(float_rao_emission))
-            .collect();
-        let validator_emission: Vec<u64> = validator_emission
-            .iter()
-            .map(|e: &I96F32| e.to_num::<u64>())
-            .collect();
-
-        // Only used to track emission in storage.
-        let combined_emission: Vec<I96F32> = normalized_combined_emission
-            .iter()
-            .map(|ce: &I32F32| I96F32::from_num(*ce).saturating_mul(float_rao_emission))
-            .collect();
-        let combined_emission: Vec<u64> = combined_emission
-            .iter()
-            .map(|e: &I96F32| e.to_num::<u64>())
-            .collect();
-
-        log::trace!(
-            "Normalized Server Emission: {:?}",
-            &normalized_server_emission
-        );
-        log::trace!("Server Emission: {:?}", &server_emission);
-        log::trace!(
-            "Normalized Validator Emission: {:?}",
-            &normalized_validator_emission
-        );
-        log::trace!("Validator Emission: {:?}", &validator_emission);
-        log::trace!(
-            "Normalized Combined Emission: {:?}",
-            &normalized_combined_emission
-        );
-        log::trace!("Combined Emission: {:?}", &combined_emission);
-
-        // Set pruning scores using combined emission scores.
-        let pruning_scores: Vec<I32F32> = normalized_combined_emission.clone();
-        log::trace!("Pruning Scores: {:?}", &pruning_scores);
-
-        // ===================
-        // == Value storage ==
-        // ===================
-        let cloned_stake_weight: Vec<u16> = stake
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_emission: Vec<u64> = combined_emission.clone();
-        let cloned_ranks: Vec<u16> = ranks
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_trust: Vec<u16> = trust
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_consensus: Vec<u16> = consensus
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_incentive: Vec<u16> = incentive
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_dividends: Vec<u16> = dividends
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_pruning_scores: Vec<u16> = vec_max_upscale_to_u16(&pruning_scores);
-        let cloned_validator_trust: Vec<u16> = validator_trust
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        StakeWeight::<T>::insert(netuid, cloned_stake_weight.clone());
-        Active::<T>::insert(netuid, active.clone());
-        Emission::<T>::insert(netuid, cloned_emission);
-        Rank::<T>::insert(netuid, cloned_ranks);
-        Trust::<T>::insert(netuid, cloned_trust);
-        Consensus::<T>::insert(netuid, cloned_consensus);
-        Incentive::<T>::insert(netuid, cloned_incentive);
-        Dividends::<T>::insert(netuid, cloned_dividends);
-        PruningScores::<T>::insert(netuid, cloned_pruning_scores);
-        ValidatorTrust::<T>::insert(netuid, cloned_validator_trust);
-        ValidatorPermit::<T>::insert(netuid, new_validator_permits.clone());
-
-        // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
-        inplace_col_max_upscale_sparse(&mut ema_bonds, n);
-        new_validator_permits
-            .iter()
-            .zip(validator_permits)
-            .zip(ema_bonds)
-            .enumerate()
-            .for_each(|(i, ((new_permit, validator_permit), ema_bond))| {
-                // Set bonds only if uid retains validator permit, otherwise clear bonds.
-                if *new_permit {
-                    let new_bonds_row: Vec<(u16, u16)> = ema_bond
-                        .iter()
-                        .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
-                        .collect();
-                    Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
-                } else if validator_permit {
-                    // Only overwrite the intersection.
-                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
-                    Bonds::<T>::insert(netuid, i as u16, new_empty_bonds_row);
-                }
-            });
-
-        // Emission tuples ( hotkeys, server_emission, validator_emission )
-        hotkeys
-            .into_iter()
-            .map(|(uid_i, hotkey)| {
-                (
-                    hotkey,
-                    server_emission[uid_i as usize],
-                    validator_emission[uid_i as usize],
-                )
-            })
-            .collect()
     }
 
     pub fn get_float_rho(netuid: u16) -> I32F32 {
@@ -909,146 +646,40 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Calculate the logistic function parameters 'a' and 'b' based on alpha and consensus values.
-    ///
-    /// # Args:
-    /// * `alpha_high` - The high alpha value.
-    /// * `alpha_low` - The low alpha value.
-    /// * `consensus_high` - The high consensus value.
-    /// * `consensus_low` - The low consensus value.
-    ///
-    /// # Returns:
-    /// A tuple containing the slope 'a' and intercept 'b' for the logistic function.
+    /// See [`pipeline::calculate_logistic_params`].
     pub fn calculate_logistic_params(
         alpha_high: I32F32,
         alpha_low: I32F32,
         consensus_high: I32F32,
         consensus_low: I32F32,
     ) -> (I32F32, I32F32) {
-        log::trace!("alpha_high: {:?}", alpha_high);
-        log::trace!("alpha_low: {:?}", alpha_low);
-        log::trace!("consensus_high: {:?}", consensus_high);
-        log::trace!("consensus_low: {:?}", consensus_low);
-        // Check for division by zero
-        // extra caution to ensure we never divide by zero
-        if consensus_high <= consensus_low || alpha_low == 0 || alpha_high == 0 {
-            // Return 0 for both 'a' and 'b' when consensus values are equal
-            return (I32F32::from_num(0.0), I32F32::from_num(0.0));
-        }
-
-        // Calculate the slope 'a' of the logistic function.
-        // a = (ln((1 / alpha_high - 1)) - ln((1 / alpha_low - 1))) / (consensus_low - consensus_high)
-        let a = (safe_ln(
-            (I32F32::from_num(1.0).saturating_div(alpha_high))
-                .saturating_sub(I32F32::from_num(1.0)),
-        )
-        .saturating_sub(safe_ln(
-            (I32F32::from_num(1.0).saturating_div(alpha_low)).saturating_sub(I32F32::from_num(1.0)),
-        )))
-        .saturating_div(consensus_low.saturating_sub(consensus_high));
-        log::trace!("a: {:?}", a);
-
-        // Calculate the intercept 'b' of the logistic function.
-        // b = ln((1 / alpha_low - 1)) + a * consensus_low
-        let b = safe_ln(
-            (I32F32::from_num(1.0).saturating_div(alpha_low)).saturating_sub(I32F32::from_num(1.0)),
-        )
-        .saturating_add(a.saturating_mul(consensus_low));
-        log::trace!("b: {:?}", b);
-
-        // Return the calculated slope 'a' and intercept 'b'.
-        (a, b)
+        pipeline::calculate_logistic_params(alpha_high, alpha_low, consensus_high, consensus_low)
     }
 
     /// Compute the alpha values using the logistic function parameters 'a' and 'b'.
-    ///
-    /// # Args:
-    /// * `consensus` - A vector of consensus values.
-    /// * `a` - The slope of the logistic function.
-    /// * `b` - The intercept of the logistic function.
-    ///
-    /// # Returns:
-    /// A vector of computed alpha values.
+    /// See [`pipeline::compute_alpha_values`].
     pub fn compute_alpha_values(consensus: &[I32F32], a: I32F32, b: I32F32) -> Vec<I32F32> {
-        // Compute the alpha values for each consensus value.
-        let alpha: Vec<I32F32> = consensus
-            .iter()
-            .map(|c| {
-                // Calculate the exponent value for the logistic function.
-                // exp_val = exp(b - a * c)
-                let exp_val = safe_exp(b.saturating_sub(a.saturating_mul(*c)));
-
-                // Compute the alpha value using the logistic function formula.
-                // alpha = 1 / (1 + exp_val)
-                I32F32::from_num(1.0).saturating_div(I32F32::from_num(1.0).saturating_add(exp_val))
-            })
-            .collect();
-
-        // Log the computed alpha values for debugging purposes.
-        log::trace!("alpha: {:?}", alpha);
-
-        // Return the computed alpha values.
-        alpha
+        pipeline::compute_alpha_values(consensus, a, b)
     }
 
     /// Clamp the alpha values between alpha_high and alpha_low.
-    ///
-    /// # Args:
-    /// * `alpha` - A vector of alpha values.
-    /// * `alpha_high` - The high alpha value.
-    /// * `alpha_low` - The low alpha value.
-    ///
-    /// # Returns:
-    /// A vector of clamped alpha values.
+    /// See [`pipeline::clamp_alpha_values`].
     pub fn clamp_alpha_values(
         alpha: Vec<I32F32>,
         alpha_high: I32F32,
         alpha_low: I32F32,
     ) -> Vec<I32F32> {
-        let clamped_alpha: Vec<I32F32> = alpha
-            .iter()
-            .map(|a| {
-                // First, clamp the value to ensure it does not exceed the upper bound (alpha_high).
-                // If 'a' is greater than 'alpha_high', it will be set to 'alpha_high'.
-                // If 'a' is less than or equal to 'alpha_high', it remains unchanged.
-                let clamped_a = a
-                    .min(&alpha_high)
-                    // Next, clamp the value to ensure it does not go below the lower bound (alpha_low).
-                    // If the value (after the first clamping) is less than 'alpha_low', it will be set to 'alpha_low'.
-                    // If the value is greater than or equal to 'alpha_low', it remains unchanged.
-                    .max(&alpha_low);
-                // Return the clamped value.
-                *clamped_a
-            })
-            .collect();
-        log::trace!("alpha_clamped: {:?}", clamped_alpha);
-        clamped_alpha
+        pipeline::clamp_alpha_values(alpha, alpha_high, alpha_low)
     }
 
     /// Compute the Exponential Moving Average (EMA) of bonds using the clamped alpha values for a sparse matrix.
-    ///
-    /// # Args:
-    /// * `bonds_delta` - A vector of bond deltas.
-    /// * `bonds` - A vector of bonds.
-    /// * `alpha` - A vector of clamped alpha values.
-    ///
-    /// # Returns:
-    /// A vector of EMA bonds.
+    /// See [`pipeline::compute_ema_bonds_with_liquid_alpha_sparse`].
     pub fn compute_ema_bonds_with_liquid_alpha_sparse(
         bonds_delta: &[Vec<(u16, I32F32)>],
         bonds: &[Vec<(u16, I32F32)>],
         alpha: Vec<I32F32>,
     ) -> Vec<Vec<(u16, I32F32)>> {
-        // Compute the Exponential Moving Average (EMA) of bonds using the provided clamped alpha values.
-        let ema_bonds = mat_ema_alpha_vec_sparse(bonds_delta, bonds, &alpha);
-
-        // Log the computed EMA bonds for debugging purposes.
-        log::trace!(
-            "Exponential Moving Average Bonds Liquid Alpha: {:?}",
-            ema_bonds
-        );
-
-        // Return the computed EMA bonds.
-        ema_bonds
+        pipeline::compute_ema_bonds_with_liquid_alpha_sparse(bonds_delta, bonds, alpha)
     }
 
     /// Compute the Exponential Moving Average (EMA) of bonds using the clamped alpha values.
@@ -1079,36 +710,17 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Compute the Exponential Moving Average (EMA) of bonds using a normal alpha value for a sparse matrix.
-    ///
-    /// # Args:
-    /// * `bonds_delta` - A vector of bond deltas.
-    /// * `bonds` - A vector of bonds.
-    /// * `netuid` - The network ID.
-    ///
-    /// # Returns:
-    /// A vector of EMA bonds.
+    /// See [`pipeline::compute_ema_bonds_normal_sparse`].
     pub fn compute_ema_bonds_normal_sparse(
         bonds_delta: &[Vec<(u16, I32F32)>],
         bonds: &[Vec<(u16, I32F32)>],
         netuid: u16,
     ) -> Vec<Vec<(u16, I32F32)>> {
-        // Retrieve the bonds moving average for the given network ID and scale it down.
-        let bonds_moving_average: I64F64 = I64F64::from_num(Self::get_bonds_moving_average(netuid))
-            .saturating_div(I64F64::from_num(1_000_000));
-
-        // Calculate the alpha value for the EMA calculation.
-        // Alpha is derived by subtracting the scaled bonds moving average from 1.
-        let alpha: I32F32 =
-            I32F32::from_num(1).saturating_sub(I32F32::from_num(bonds_moving_average));
-
-        // Compute the Exponential Moving Average (EMA) of bonds using the calculated alpha value.
-        let ema_bonds = mat_ema_sparse(bonds_delta, bonds, alpha);
-
-        // Log the computed EMA bonds for debugging purposes.
-        log::trace!("Exponential Moving Average Bonds Normal: {:?}", ema_bonds);
-
-        // Return the computed EMA bonds.
-        ema_bonds
+        pipeline::compute_ema_bonds_normal_sparse(
+            bonds_delta,
+            bonds,
+            Self::get_bonds_moving_average(netuid),
+        )
     }
 
     /// Compute the Exponential Moving Average (EMA) of bonds using a normal alpha value.
@@ -1145,72 +757,19 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Compute the Exponential Moving Average (EMA) of bonds based on the Liquid Alpha setting for a sparse matrix.
-    ///
-    /// # Args:
-    /// * `netuid` - The network ID.
-    /// * `consensus` - A vector of consensus values.
-    /// * `bonds_delta` - A vector of bond deltas.
-    /// * `bonds` - A vector of bonds.
-    ///
-    /// # Returns:
-    /// A vector of EMA bonds.
+    /// See [`pipeline::compute_ema_bonds_sparse`].
     pub fn compute_ema_bonds_sparse(
         netuid: u16,
         consensus: Vec<I32F32>,
-        bonds_delta: Vec<Vec<(u16, I32F32)>>,
-        bonds: Vec<Vec<(u16, I32F32)>>,
+        bonds_delta: &[Vec<(u16, I32F32)>],
+        bonds: &[Vec<(u16, I32F32)>],
     ) -> Vec<Vec<(u16, I32F32)>> {
-        // Check if Liquid Alpha is enabled, consensus is not empty, and contains non-zero values.
-        // This way we avoid the quantil function panic.
-        if LiquidAlphaOn::<T>::get(netuid)
-            && !consensus.is_empty()
-            && consensus.iter().any(|&c| c != I32F32::from_num(0))
-        {
-            // Calculate the 75th percentile (high) and 25th percentile (low) of the consensus values.
-            let consensus_high = quantile(&consensus, 0.75);
-            let consensus_low = quantile(&consensus, 0.25);
-            // Further check if the high and low consensus values meet the required conditions.
-            if (consensus_high > consensus_low) || consensus_high != 0 || consensus_low < 0 {
-                // if (consensus_high > consensus_low) || consensus_high != 0) || consensus_low != 0 {
-                // if (consensus_high > consensus_low) || consensus_low != 0 {
-                log::trace!("Using Liquid Alpha");
-
-                // Get the high and low alpha values for the network.
-                let (alpha_low, alpha_high): (I32F32, I32F32) = Self::get_alpha_values_32(netuid);
-                log::trace!("alpha_low: {:?} alpha_high: {:?}", alpha_low, alpha_high);
-
-                // Calculate the logistic function parameters 'a' and 'b' based on alpha and consensus values.
-                let (a, b) = Self::calculate_logistic_params(
-                    alpha_high,
-                    alpha_low,
-                    consensus_high,
-                    consensus_low,
-                );
-
-                // Compute the alpha values using the logistic function parameters.
-                let alpha = Self::compute_alpha_values(&consensus, a, b);
-
-                // Clamp the alpha values between alpha_high and alpha_low.
-                let clamped_alpha = Self::clamp_alpha_values(alpha, alpha_high, alpha_low);
-
-                // Compute the Exponential Moving Average (EMA) of bonds using the clamped alpha values.
-                Self::compute_ema_bonds_with_liquid_alpha_sparse(
-                    &bonds_delta,
-                    &bonds,
-                    clamped_alpha,
-                )
-            } else {
-                log::trace!("Using Bonds Moving Average");
-
-                // Compute the EMA of bonds using a normal alpha value.
-                Self::compute_ema_bonds_normal_sparse(&bonds_delta, &bonds, netuid)
-            }
-        } else {
-            log::trace!("Using Bonds Moving Average");
-
-            // Compute the EMA of bonds using a normal alpha value.
-            Self::compute_ema_bonds_normal_sparse(&bonds_delta, &bonds, netuid)
-        }
+        pipeline::compute_ema_bonds_sparse(
+            &Self::get_epoch_params(netuid),
+            &consensus,
+            bonds_delta,
+            bonds,
+        )
     }
 
     /// Compute the Exponential Moving Average (EMA) of bonds based on the Liquid Alpha setting.
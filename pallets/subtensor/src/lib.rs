@@ -45,6 +45,7 @@ pub mod staking;
 pub mod subnets;
 pub mod swap;
 pub mod utils;
+use crate::utils::call_checks::{CallChecks, DispatchChecks};
 use crate::utils::rate_limiting::TransactionType;
 use macros::{config, dispatches, errors, events, genesis, hooks};
 
@@ -65,7 +66,8 @@ pub mod pallet {
         dispatch::GetDispatchInfo,
         pallet_prelude::{DispatchResult, StorageMap, ValueQuery, *},
         traits::{
-            tokens::fungible, OriginTrait, QueryPreimage, StorePreimage, UnfilteredDispatchable,
+            schedule::v3::Anon as ScheduleAnon, tokens::fungible, OriginTrait, QueryPreimage,
+            StorePreimage, UnfilteredDispatchable,
         },
         BoundedVec,
     };
@@ -89,6 +91,13 @@ pub mod pallet {
     /// Call type for the pallet
     pub type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
 
+    /// Address of a task scheduled by the pallet
+    pub type ScheduleAddressOf<T> = <<T as Config>::Scheduler as ScheduleAnon<
+        BlockNumberFor<T>,
+        <T as Config>::RuntimeCall,
+        PalletsOriginOf<T>,
+    >>::Address;
+
     /// Tracks version for migrations. Should be monotonic with respect to the
     /// order of migrations. (i.e. always increasing)
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
@@ -96,6 +105,73 @@ pub mod pallet {
     /// Minimum balance required to perform a coldkey swap
     pub const MIN_BALANCE_TO_PERFORM_COLDKEY_SWAP: u64 = 100_000_000; // 0.1 TAO in RAO
 
+    /// Maximum number of unbonding entries a coldkey can have queued at once
+    pub const MAX_UNBONDING_ENTRIES: usize = 32;
+
+    /// Maximum number of coldkeys staking on a hotkey, and of coldkeys unbonding from it, which a
+    /// slash of the hotkey goes through
+    pub const MAX_SLASHED_STAKERS: usize = 256;
+
+    /// Maximum number of coldkeys whose unbonded stake is released in a block, the others are
+    /// released in the following blocks
+    pub const MAX_UNBONDING_RELEASES_PER_BLOCK: usize = 64;
+
+    /// Maximum number of reward entries kept in the ledger of a nominator
+    pub const MAX_NOMINATOR_REWARD_ENTRIES: usize = 256;
+
+    /// Maximum number of conflicting destinations of a coldkey swap in arbitration
+    pub const MAX_COLDKEY_SWAP_DESTINATIONS: usize = 8;
+
+    /// Maximum number of guardians of a coldkey
+    pub const MAX_GUARDIANS: usize = 16;
+
+    /// Maximum number of pending guardian recoveries of a coldkey
+    pub const MAX_PENDING_COLDKEY_RECOVERIES: usize = 16;
+
+    /// Maximum number of parent to child links in a chain of child keys
+    pub const MAX_CHILDKEY_DEPTH: u32 = 5;
+
+    /// Maximum number of hotkeys visited when validating the child key graph for new children
+    pub const MAX_CHILDKEY_GRAPH_VISITS: u32 = 256;
+
+    /// Maximum number of epochs of emission considered by the lowest emission pruning policy
+    pub const MAX_PRUNING_EMISSION_EPOCHS: u16 = 32;
+
+    /// Maximum number of uids a subnet owner can protect from pruning
+    pub const MAX_PRUNING_PROTECTED_UIDS: usize = 16;
+
+    /// Maximum number of open bids of a subnet registration auction
+    pub const MAX_REGISTRATION_BIDS: usize = 64;
+
+    /// Maximum number of target registrations of accumulated error kept by the PID adjustment
+    /// controller, in either direction
+    pub const MAX_ADJUSTMENT_INTEGRAL_INTERVALS: i64 = 16;
+
+    /// Maximum number of adjustment intervals of a burn and difficulty projection
+    pub const MAX_PROJECTED_ADJUSTMENT_INTERVALS: u16 = 256;
+
+    /// Maximum number of registrars attesting identity fields
+    pub const MAX_IDENTITY_REGISTRARS: usize = 16;
+
+    /// Maximum size in bytes of a timelock encrypted weight commit
+    pub const MAX_TIMELOCK_COMMIT_SIZE_BYTES: u32 = 5000;
+
+    /// Maximum number of timelock encrypted weight commits of a subnet waiting to be revealed
+    pub const MAX_TIMELOCKED_COMMITS_PER_SUBNET: u32 = 1024;
+
+    /// Maximum number of timelock encrypted weight commits decrypted and set in a block
+    pub const MAX_TIMELOCKED_REVEALS_PER_BLOCK: u32 = 32;
+
+    /// Upper bound of the per-subnet maximum number of unrevealed weight commits
+    pub const MAX_UNREVEALED_COMMITS_LIMIT: u16 = 100;
+
+    /// Maximum number of target hotkeys of a stake rebalance, and of hotkeys a coldkey can stake
+    /// to when rebalancing
+    pub const MAX_REBALANCE_HOTKEYS: usize = 32;
+
+    /// Netuid under which rate limits applying to all subnets are stored
+    pub const GLOBAL_RATE_LIMIT_NETUID: u16 = u16::MAX;
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -185,7 +261,7 @@ pub mod pallet {
     pub type ChainIdentityOf = ChainIdentity;
 
     /// Data structure for Chain Identities.
-    #[crate::freeze_struct("bbfd00438dbe2b58")]
+    #[crate::freeze_struct("e8c1f172bb667dec")]
     #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
     pub struct ChainIdentity {
         /// The name of the chain identity
@@ -200,12 +276,18 @@ pub mod pallet {
         pub description: Vec<u8>,
         /// Additional information about the chain identity
         pub additional: Vec<u8>,
+        /// The GitHub repository or organization of the chain identity
+        pub github_repo: Vec<u8>,
+        /// The contact of the chain identity
+        pub contact: Vec<u8>,
+        /// The hash of the logo of the chain identity
+        pub logo_hash: Option<H256>,
     }
 
     ///  Struct for SubnetIdentities.
     pub type SubnetIdentityOf = SubnetIdentity;
     /// Data structure for Subnet Identities
-    #[crate::freeze_struct("f448dc3dad763108")]
+    #[crate::freeze_struct("41ffa7d70dd7329c")]
     #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
     pub struct SubnetIdentity {
         /// The name of the subnet
@@ -214,6 +296,186 @@ pub mod pallet {
         pub github_repo: Vec<u8>,
         /// The subnet's contact
         pub subnet_contact: Vec<u8>,
+        /// The URL of the subnet
+        pub subnet_url: Vec<u8>,
+        /// The Discord of the subnet
+        pub discord: Vec<u8>,
+        /// The hash of the logo of the subnet
+        pub logo_hash: Option<H256>,
+    }
+
+    /// Enum for the typed fields of chain and subnet identities which registrars attest.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum IdentityField {
+        /// The URL, an http or https link.
+        Url,
+        /// The GitHub repository or organization.
+        GithubRepo,
+        /// The Discord handle or invite.
+        Discord,
+        /// The contact.
+        Contact,
+        /// The hash of the logo.
+        LogoHash,
+    }
+
+    /// Data structure for the attestation of an identity field by a registrar.
+    #[crate::freeze_struct("62c80a81b6d08db8")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct IdentityAttestation<AccountId> {
+        /// The registrar which verified the field
+        pub registrar: AccountId,
+        /// The block of the attestation
+        pub block: u64,
+    }
+
+    /// Data structure for stake waiting in the unbonding queue.
+    #[crate::freeze_struct("3c1378709959b8c")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct UnbondingEntry<AccountId> {
+        /// The hotkey the stake was removed from
+        pub hotkey: AccountId,
+        /// The amount of unbonding stake in rao
+        pub amount: u64,
+        /// The block at which the stake is released to the coldkey
+        pub release_block: u64,
+    }
+
+    /// Enum for the destination of the rewards of a nomination.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum RewardDestination<AccountId> {
+        /// The rewards are added to the stake on the hotkey they were earned from.
+        Restake,
+        /// The rewards are paid to the free balance of the coldkey.
+        Free,
+        /// The rewards are staked on another hotkey.
+        Hotkey(AccountId),
+    }
+
+    impl<AccountId> Default for RewardDestination<AccountId> {
+        fn default() -> Self {
+            Self::Restake
+        }
+    }
+
+    /// Enum for the policy choosing the neuron replaced when a subnet is full.
+    /// Ties are broken by the earliest registration, then by the lowest uid.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug, Default)]
+    pub enum PruningPolicy {
+        /// The neuron with the lowest pruning score is pruned.
+        #[default]
+        LowestPruningScore,
+        /// The neuron with the lowest incentive is pruned.
+        LowestIncentive,
+        /// The neuron with the lowest emission over the given number of last epochs is pruned.
+        LowestEmission(u16),
+        /// The neuron with the lowest stake is pruned.
+        LowestStake,
+        /// The neuron registered earliest is pruned.
+        OldestFirst,
+    }
+
+    /// Enum for the controller adjusting the burn and difficulty of a subnet at the end of each
+    /// adjustment interval.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug, Default)]
+    pub enum AdjustmentController {
+        /// The value moves toward itself scaled by the registration rate, weighted by the
+        /// adjustment alpha.
+        #[default]
+        Ema,
+        /// The value is scaled by the registration error of the interval and the error accumulated
+        /// over the previous intervals, with gains normalized by u16::MAX.
+        Pid {
+            /// Gain on the registration error of the interval.
+            proportional_gain: u16,
+            /// Gain on the registration error accumulated over the intervals.
+            integral_gain: u16,
+        },
+        /// The value decays toward its minimum by the given fraction, normalized by u16::MAX, in
+        /// intervals without registrations, and follows the EMA controller otherwise.
+        IdleDecay(u16),
+    }
+
+    /// Enum for the reason a neuron has been pruned.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum PruningReason {
+        /// The neuron ranked lowest under the policy among neurons out of immunity.
+        OutOfImmunity(PruningPolicy),
+        /// All unprotected neurons were immune and the neuron ranked lowest under the policy among them.
+        AllImmune(PruningPolicy),
+        /// All neurons were protected by the subnet owner and the neuron ranked lowest under the policy.
+        AllProtected(PruningPolicy),
+    }
+
+    /// Data structure for a bid of a registration auction.
+    #[crate::freeze_struct("6c9b6eed3a735c3e")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct RegistrationBid<AccountId> {
+        /// The coldkey which placed the bid and is refunded if it loses
+        pub coldkey: AccountId,
+        /// The amount of the bid in rao
+        pub amount: u64,
+        /// The block of the last raise of the bid, earlier bids win ties
+        pub block: u64,
+    }
+
+    /// Enum for the destination of slashed stake.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum SlashDestination<AccountId> {
+        /// The slashed stake is burned.
+        Burn,
+        /// The slashed stake is paid to the free balance of an account.
+        Account(AccountId),
+    }
+
+    /// Data structure for the guardians able to recover a coldkey.
+    #[crate::freeze_struct("9dcd19405e69ae6f")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct GuardianSet<AccountId> {
+        /// The guardian accounts
+        pub guardians: Vec<AccountId>,
+        /// The number of guardians that must approve a recovery
+        pub threshold: u16,
+    }
+
+    /// Data structure for a pending guardian recovery of a coldkey to a new coldkey.
+    #[crate::freeze_struct("91b2115597f83512")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ColdkeyRecovery<AccountId> {
+        /// The guardians that approved the recovery
+        pub approvals: Vec<AccountId>,
+        /// The block from which the recovery can be finalized, once the threshold is reached
+        pub execution_block: Option<u64>,
+    }
+
+    /// Data structure for the reward of a nominator from one emission drain of a hotkey.
+    #[crate::freeze_struct("e60e77bf09145af3")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct NominatorRewardEntry<AccountId> {
+        /// The block at which the hotkey emission was drained
+        pub block: u64,
+        /// The hotkey the reward was earned from
+        pub hotkey: AccountId,
+        /// The delegate take of the hotkey at the drain
+        pub take: u16,
+        /// The reward paid to the reward destination of the nominator in rao
+        pub reward: u64,
+        /// The stake of the nominator added since the previous drain, excluded from the reward in rao
+        pub nonviable_stake: u64,
+    }
+
+    /// Data structure for the plaintext of a timelock encrypted weight commit.
+    #[crate::freeze_struct("c41b91e55aa7ff95")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct WeightsTlockPayload<AccountId> {
+        /// The hotkey which signed the commit
+        pub hotkey: AccountId,
+        /// The uids the weights are set on
+        pub uids: Vec<u16>,
+        /// The weight values
+        pub values: Vec<u16>,
+        /// The network version key
+        pub version_key: u64,
     }
     /// ============================
     /// ==== Staking + Accounts ====
@@ -300,6 +562,11 @@ pub mod pallet {
         360
     }
     #[pallet::type_value]
+    /// Default unbonding period, unbonding is disabled.
+    pub fn DefaultUnbondingPeriod<T: Config>() -> u64 {
+        0
+    }
+    #[pallet::type_value]
     /// Default account linkage
     pub fn DefaultAccountLinkage<T: Config>() -> Vec<(u64, T::AccountId)> {
         vec![]
@@ -579,6 +846,11 @@ pub mod pallet {
         1
     }
     #[pallet::type_value]
+    /// Default maximum number of unrevealed weight commits per hotkey
+    pub fn DefaultMaxUnrevealedCommits<T: Config>() -> u16 {
+        10
+    }
+    #[pallet::type_value]
     /// Value definition for vector of u16.
     pub fn EmptyU16Vec<T: Config>() -> Vec<u16> {
         vec![]
@@ -689,6 +961,17 @@ pub mod pallet {
     pub type SenateRequiredStakePercentage<T> =
         StorageValue<_, u64, ValueQuery, DefaultSenateRequiredStakePercentage<T>>;
 
+    #[pallet::storage] // --- ITEM ( senate_term_limit ) | Blocks a senate member serves before sitting out as long, zero for no limit.
+    pub type SenateTermLimit<T> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage] // --- MAP ( hotkey ) --> block at which the senate term of the member started
+    pub type SenateTermStart<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( hotkey ) --> block at which the term limit removed the hotkey from the senate
+    pub type SenateTermEnd<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
     /// ============================
     /// ==== Staking Variables ====
     /// ============================
@@ -721,6 +1004,52 @@ pub mod pallet {
         StorageValue<_, u64, ValueQuery, DefaultTargetStakesPerInterval<T>>;
     #[pallet::storage] // --- ITEM (default_stake_interval)
     pub type StakeInterval<T> = StorageValue<_, u64, ValueQuery, DefaultStakeInterval<T>>;
+    #[pallet::storage] // --- ITEM ( total_unbonding )
+    pub type TotalUnbonding<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
+    /// MAP ( cold ) --> Vec<unbonding_entry> | Returns the stake removed by a coldkey waiting to be released.
+    pub type UnbondingQueue<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<UnbondingEntry<T::AccountId>>,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// DMAP ( hot, cold ) --> bool | Returns true if the coldkey has stake unbonding from the hotkey.
+    pub type UnbondingColdkeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// MAP ( block ) --> Vec<cold> | Returns the coldkeys with unbonding stake released at a block.
+    pub type UnbondingReleases<T: Config> =
+        StorageMap<_, Identity, u64, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage]
+    /// DMAP ( cold, hot ) --> reward_destination | Returns where the rewards of a nomination are paid.
+    pub type RewardDestinations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        RewardDestination<T::AccountId>,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// MAP ( cold ) --> VecDeque<nominator_reward_entry> | Returns the latest rewards of a coldkey from the emission drains of its hotkeys.
+    pub type NominatorRewards<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        VecDeque<NominatorRewardEntry<T::AccountId>>,
+        ValueQuery,
+    >;
     #[pallet::storage] // --- MAP ( hot ) --> stake | Returns the total amount of stake under a hotkey.
     pub type TotalHotkeyStake<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultAccountTake<T>>;
@@ -841,6 +1170,28 @@ pub mod pallet {
         ValueQuery,
         DefaultAccountLinkage<T>,
     >;
+    #[pallet::storage]
+    /// DMAP ( netuid, parent ) --> Vec<(proportion,child,expiry_block)> | Children set at the next epoch of the subnet
+    pub type PendingChildKeys<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<(u64, T::AccountId, Option<u64>)>,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// DMAP ( netuid, parent ) --> Vec<(child,expiry_block)> | Children removed at the first epoch of the subnet from their expiry block
+    pub type ChildKeyExpiries<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<(T::AccountId, u64)>,
+        ValueQuery,
+    >;
     #[pallet::storage] // --- DMAP ( cold ) --> Vec<hot> | Maps coldkey to hotkeys that stake to it
     pub type StakingHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
@@ -851,6 +1202,25 @@ pub mod pallet {
     #[pallet::storage] // --- DMAP ( cold ) --> () | Maps coldkey to if a coldkey swap is scheduled.
     pub type ColdkeySwapScheduled<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, (), ValueQuery>;
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<new_cold> | Destinations of the scheduled swap of a coldkey, more than one while in arbitration.
+    pub type ColdkeySwapDestinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( cold ) --> task | The scheduler task executing the scheduled swap of a coldkey.
+    pub type ColdkeySwapTask<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ScheduleAddressOf<T>, OptionQuery>;
+    #[pallet::storage] // --- MAP ( cold ) --> guardians | The guardians able to recover a coldkey.
+    pub type ColdkeyGuardians<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, GuardianSet<T::AccountId>, OptionQuery>;
+    #[pallet::storage] // --- DMAP ( cold, new_cold ) --> recovery | The pending guardian recoveries of a coldkey.
+    pub type ColdkeyRecoveries<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        ColdkeyRecovery<T::AccountId>,
+        OptionQuery,
+    >;
 
     /// ============================
     /// ==== Global Parameters =====
@@ -977,6 +1347,10 @@ pub mod pallet {
     /// --- MAP ( netuid ) --> Kappa
     pub type Kappa<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultKappa<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> unbonding_period | Blocks removed stake waits before it is released.
+    pub type UnbondingPeriod<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultUnbondingPeriod<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> uid, we use to record uids to prune at next epoch.
     pub type NeuronsToPruneAtNextEpoch<T: Config> = StorageMap<_, Identity, u16, u16, ValueQuery>;
     #[pallet::storage]
@@ -1047,6 +1421,13 @@ pub mod pallet {
     pub type AdjustmentAlpha<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultAdjustmentAlpha<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> controller adjusting the burn and difficulty
+    pub type SubnetAdjustmentController<T: Config> =
+        StorageMap<_, Identity, u16, AdjustmentController, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> registrations above target accumulated by the PID controller
+    pub type AdjustmentIntegral<T: Config> = StorageMap<_, Identity, u16, i64, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> interval
     pub type CommitRevealWeightsEnabled<T> =
         StorageMap<_, Identity, u16, bool, ValueQuery, DefaultCommitRevealWeightsEnabled<T>>;
@@ -1094,6 +1475,10 @@ pub mod pallet {
     pub type TxChildkeyTakeRateLimit<T> =
         StorageValue<_, u64, ValueQuery, DefaultTxChildKeyTakeRateLimit<T>>;
     #[pallet::storage]
+    /// --- DMAP ( tx_type, netuid ) --> rate_limit | The rate limit in blocks of a transaction type on a subnet,
+    /// or on all subnets under GLOBAL_RATE_LIMIT_NETUID. Unset limits fall back to the legacy rate limit items.
+    pub type TxRateLimits<T> = StorageDoubleMap<_, Identity, u16, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> Whether or not Liquid Alpha is enabled
     pub type LiquidAlphaOn<T> =
         StorageMap<_, Blake2_128Concat, u16, bool, ValueQuery, DefaultLiquidAlpha<T>>;
@@ -1236,6 +1621,31 @@ pub mod pallet {
     pub type SubnetIdentities<T: Config> =
         StorageMap<_, Blake2_128Concat, u16, SubnetIdentityOf, OptionQuery>;
 
+    #[pallet::storage] // --- ITEM ( identity_registrars )
+    pub type IdentityRegistrars<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+    #[pallet::storage] // --- DMAP ( coldkey, field ) --> attestation
+    pub type IdentityAttestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        IdentityField,
+        IdentityAttestation<T::AccountId>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage] // --- DMAP ( netuid, field ) --> attestation
+    pub type SubnetIdentityAttestations<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Twox64Concat,
+        IdentityField,
+        IdentityAttestation<T::AccountId>,
+        OptionQuery,
+    >;
+
     /// =================================
     /// ==== Axon / Promo Endpoints =====
     /// =================================
@@ -1280,6 +1690,57 @@ pub mod pallet {
     /// --- Map (netuid) --> Number of epochs allowed for commit reveal periods
     pub type RevealPeriodEpochs<T: Config> =
         StorageMap<_, Twox64Concat, u16, u64, ValueQuery, DefaultRevealPeriodEpochs<T>>;
+    #[pallet::storage]
+    /// --- Map (netuid) --> Maximum number of unrevealed weight commits per hotkey
+    pub type MaxUnrevealedCommits<T: Config> =
+        StorageMap<_, Twox64Concat, u16, u16, ValueQuery, DefaultMaxUnrevealedCommits<T>>;
+    #[pallet::storage]
+    /// --- Map (netuid) --> Policy choosing the neuron to prune
+    pub type SubnetPruningPolicy<T: Config> =
+        StorageMap<_, Identity, u16, PruningPolicy, ValueQuery>;
+    #[pallet::storage]
+    /// --- Map (netuid) --> uids the subnet owner protects from pruning
+    pub type PruningProtectedUids<T: Config> =
+        StorageMap<_, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
+    #[pallet::storage]
+    /// --- Map (netuid) --> emission by uid of the last epochs, oldest first, kept for the lowest emission pruning policy
+    pub type PruningEmissionHistory<T: Config> =
+        StorageMap<_, Identity, u16, Vec<Vec<u64>>, ValueQuery>;
+    #[pallet::storage]
+    /// --- Map (netuid) --> Whether neurons register through the registration auction
+    pub type RegistrationAuctionEnabled<T: Config> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP (netuid, hotkey) --> bid | The open bids of the registration auction of a subnet.
+    pub type RegistrationBids<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        RegistrationBid<T::AccountId>,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- ITEM ( total_registration_bids )
+    pub type TotalRegistrationBids<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- NMAP (netuid, reveal_epoch, who) --> Vec<(committer, ciphertext, reveal_round)> | Stores the timelock encrypted weight commits of a hotkey revealed at the end of an epoch. `who` follows hotkey swaps, `committer` is the hotkey which signed the commit.
+    pub type TimelockedWeightCommits<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, u16>,          // netuid
+            NMapKey<Twox64Concat, u64>,          // reveal_epoch
+            NMapKey<Twox64Concat, T::AccountId>, // who
+        ),
+        Vec<(
+            T::AccountId,
+            BoundedVec<u8, ConstU32<MAX_TIMELOCK_COMMIT_SIZE_BYTES>>,
+            u64,
+        )>,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP (netuid) --> count | The number of timelock encrypted weight commits of a subnet waiting to be revealed.
+    pub type TimelockedWeightCommitCount<T: Config> = StorageMap<_, Identity, u16, u32, ValueQuery>;
 
     /// ==================
     /// ==== Genesis =====
@@ -1369,12 +1830,82 @@ pub enum CallType {
 #[derive(Debug, PartialEq)]
 pub enum CustomTransactionError {
     ColdkeyInSwapSchedule,
+    ColdkeyInArbitration,
 }
 
 impl From<CustomTransactionError> for u8 {
     fn from(variant: CustomTransactionError) -> u8 {
         match variant {
             CustomTransactionError::ColdkeyInSwapSchedule => 0,
+            CustomTransactionError::ColdkeyInArbitration => 7,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_priority_vanilla() -> u64 {
+        // Return high priority so that every extrinsic except set_weights function will
+        // have a higher priority than the set_weights call
+        u64::MAX
+    }
+
+    /// Validates a subtensor call signed by `who` before it enters the transaction pool, as done
+    /// by the `SubtensorSignedExtension` and reported by the pre-dispatch check runtime API.
+    /// Returns the validity of the transaction, or the pallet error the call is rejected for
+    /// with the custom code of the invalid transaction.
+    pub fn validate_signed_call(
+        who: &T::AccountId,
+        call: &Call<T>,
+    ) -> Result<ValidTransaction, (Error<T>, u8)> {
+        // A coldkey in arbitration cannot move stake or funds until governance resolves it.
+        if Self::call_moves_coldkey_value(call) && Self::coldkey_in_arbitration(who) {
+            return Err((
+                Error::<T>::ColdkeyIsInArbitration,
+                CustomTransactionError::ColdkeyInArbitration.into(),
+            ));
+        }
+
+        // Weights are only accepted from hotkeys with the min stake, at the weights priority.
+        let validate_weights = |hotkey: &T::AccountId, netuid: u16, code: u8| {
+            if Self::check_weights_min_stake(hotkey, netuid) {
+                Ok(ValidTransaction {
+                    priority: Self::get_priority_set_weights(hotkey, netuid),
+                    longevity: 1,
+                    ..Default::default()
+                })
+            } else {
+                Err((Error::<T>::NotEnoughStakeToSetWeights, code))
+            }
+        };
+
+        match call {
+            // Timelocked commits are only accepted while a key source can reveal them.
+            Call::commit_timelocked_weights { .. }
+                if T::TimelockKeySource::latest_round().is_none() =>
+            {
+                Err((Error::<T>::TimelockKeySourceUnavailable, 8))
+            }
+            Call::commit_weights { netuid, .. }
+            | Call::commit_timelocked_weights { netuid, .. } => validate_weights(who, *netuid, 1),
+            Call::reveal_weights { netuid, .. } => validate_weights(who, *netuid, 2),
+            Call::batch_reveal_weights { netuid, .. } => validate_weights(who, *netuid, 6),
+            Call::set_weights { netuid, .. } => validate_weights(who, *netuid, 3),
+            Call::set_root_weights { netuid, hotkey, .. } => validate_weights(hotkey, *netuid, 4),
+            Call::register { netuid, .. } | Call::burned_register { netuid, .. }
+                if Self::get_registrations_this_interval(*netuid)
+                    >= Self::get_target_registrations_per_interval(*netuid).saturating_mul(3) =>
+            {
+                // If the registration limit for the interval is exceeded, reject the transaction
+                Err((Error::<T>::TooManyRegistrationsThisInterval, 5))
+            }
+            Call::dissolve_network { .. } if ColdkeySwapScheduled::<T>::contains_key(who) => Err((
+                Error::<T>::SwapAlreadyScheduled,
+                CustomTransactionError::ColdkeyInSwapSchedule.into(),
+            )),
+            _ => Ok(ValidTransaction {
+                priority: Self::get_priority_vanilla(),
+                ..Default::default()
+            }),
         }
     }
 }
@@ -1405,9 +1936,7 @@ where
     }
 
     pub fn get_priority_vanilla() -> u64 {
-        // Return high priority so that every extrinsic except set_weights function will
-        // have a higher priority than the set_weights call
-        u64::MAX
+        Pallet::<T>::get_priority_vanilla()
     }
 
     pub fn get_priority_set_weights(who: &T::AccountId, netuid: u16) -> u64 {
@@ -1419,6 +1948,56 @@ where
     }
 }
 
+impl<T: Config + Send + Sync + TypeInfo + pallet_balances::Config> SubtensorSignedExtension<T>
+where
+    <T as frame_system::Config>::RuntimeCall:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<BalancesCall<T>>,
+{
+    /// Validates `call` signed by `who`, and the calls nested in it as if signed by the account
+    /// they are dispatched for. Returns the validity of `call`, or the pallet error the first
+    /// rejected call is rejected for with the custom code of the invalid transaction.
+    pub fn validate_runtime_call(
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+    ) -> Result<ValidTransaction, (Error<T>, u8)> {
+        let validity = if let Some(pallet_call) = call.is_sub_type() {
+            Pallet::<T>::validate_signed_call(who, pallet_call)?
+        } else {
+            if let Some(
+                BalancesCall::transfer_keep_alive { .. }
+                | BalancesCall::transfer_all { .. }
+                | BalancesCall::transfer_allow_death { .. },
+            ) = call.is_sub_type()
+            {
+                if Pallet::<T>::coldkey_in_arbitration(who) {
+                    return Err((
+                        Error::<T>::ColdkeyIsInArbitration,
+                        CustomTransactionError::ColdkeyInArbitration.into(),
+                    ));
+                }
+                if ColdkeySwapScheduled::<T>::contains_key(who) {
+                    return Err((
+                        Error::<T>::SwapAlreadyScheduled,
+                        CustomTransactionError::ColdkeyInSwapSchedule.into(),
+                    ));
+                }
+            }
+            ValidTransaction {
+                priority: Self::get_priority_vanilla(),
+                ..Default::default()
+            }
+        };
+
+        // Batched, proxied and multisig calls are checked for the account they move value from.
+        for (account, nested_call) in T::NestedCalls::nested_calls(who, call) {
+            Self::validate_runtime_call(&account, nested_call)?;
+        }
+        Ok(validity)
+    }
+}
+
 impl<T: Config + Send + Sync + TypeInfo> sp_std::fmt::Debug for SubtensorSignedExtension<T> {
     fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
         write!(f, "SubtensorSignedExtension")
@@ -1451,125 +2030,8 @@ where
         _info: &DispatchInfoOf<Self::Call>,
         _len: usize,
     ) -> TransactionValidity {
-        match call.is_sub_type() {
-            Some(Call::commit_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
-                    Ok(ValidTransaction {
-                        priority,
-                        longevity: 1,
-                        ..Default::default()
-                    })
-                } else {
-                    Err(InvalidTransaction::Custom(1).into())
-                }
-            }
-            Some(Call::reveal_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
-                    Ok(ValidTransaction {
-                        priority,
-                        longevity: 1,
-                        ..Default::default()
-                    })
-                } else {
-                    Err(InvalidTransaction::Custom(2).into())
-                }
-            }
-            Some(Call::batch_reveal_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
-                    Ok(ValidTransaction {
-                        priority,
-                        longevity: 1,
-                        ..Default::default()
-                    })
-                } else {
-                    Err(InvalidTransaction::Custom(6).into())
-                }
-            }
-            Some(Call::set_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
-                    Ok(ValidTransaction {
-                        priority,
-                        longevity: 1,
-                        ..Default::default()
-                    })
-                } else {
-                    Err(InvalidTransaction::Custom(3).into())
-                }
-            }
-            Some(Call::set_root_weights { netuid, hotkey, .. }) => {
-                if Self::check_weights_min_stake(hotkey, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(hotkey, *netuid);
-                    Ok(ValidTransaction {
-                        priority,
-                        longevity: 1,
-                        ..Default::default()
-                    })
-                } else {
-                    Err(InvalidTransaction::Custom(4).into())
-                }
-            }
-            Some(Call::add_stake { .. }) => Ok(ValidTransaction {
-                priority: Self::get_priority_vanilla(),
-                ..Default::default()
-            }),
-            Some(Call::remove_stake { .. }) => Ok(ValidTransaction {
-                priority: Self::get_priority_vanilla(),
-                ..Default::default()
-            }),
-            Some(Call::register { netuid, .. } | Call::burned_register { netuid, .. }) => {
-                let registrations_this_interval =
-                    Pallet::<T>::get_registrations_this_interval(*netuid);
-                let max_registrations_per_interval =
-                    Pallet::<T>::get_target_registrations_per_interval(*netuid);
-                if registrations_this_interval >= (max_registrations_per_interval.saturating_mul(3))
-                {
-                    // If the registration limit for the interval is exceeded, reject the transaction
-                    return Err(InvalidTransaction::Custom(5).into());
-                }
-                Ok(ValidTransaction {
-                    priority: Self::get_priority_vanilla(),
-                    ..Default::default()
-                })
-            }
-            Some(Call::register_network { .. }) => Ok(ValidTransaction {
-                priority: Self::get_priority_vanilla(),
-                ..Default::default()
-            }),
-            Some(Call::dissolve_network { .. }) => {
-                if ColdkeySwapScheduled::<T>::contains_key(who) {
-                    InvalidTransaction::Custom(CustomTransactionError::ColdkeyInSwapSchedule.into())
-                        .into()
-                } else {
-                    Ok(ValidTransaction {
-                        priority: Self::get_priority_vanilla(),
-                        ..Default::default()
-                    })
-                }
-            }
-            _ => {
-                if let Some(
-                    BalancesCall::transfer_keep_alive { .. }
-                    | BalancesCall::transfer_all { .. }
-                    | BalancesCall::transfer_allow_death { .. },
-                ) = call.is_sub_type()
-                {
-                    if ColdkeySwapScheduled::<T>::contains_key(who) {
-                        return InvalidTransaction::Custom(
-                            CustomTransactionError::ColdkeyInSwapSchedule.into(),
-                        )
-                        .into();
-                    }
-                }
-                Ok(ValidTransaction {
-                    priority: Self::get_priority_vanilla(),
-                    ..Default::default()
-                })
-            }
-        }
+        Self::validate_runtime_call(who, call)
+            .map_err(|(_, code)| InvalidTransaction::Custom(code).into())
     }
 
     // NOTE: Add later when we put in a pre and post dispatch step.
@@ -1580,6 +2042,10 @@ where
         _info: &DispatchInfoOf<Self::Call>,
         _len: usize,
     ) -> Result<Self::Pre, TransactionValidityError> {
+        // Transactions included without going through the pool are validated as well.
+        Self::validate_runtime_call(who, call)
+            .map_err(|(_, code)| InvalidTransaction::Custom(code))?;
+
         match call.is_sub_type() {
             Some(Call::add_stake { .. }) => {
                 let transaction_fee = 100000;
@@ -1597,6 +2063,10 @@ where
                 let transaction_fee = 0;
                 Ok((CallType::SetWeights, transaction_fee, who.clone()))
             }
+            Some(Call::commit_timelocked_weights { .. }) => {
+                let transaction_fee = 0;
+                Ok((CallType::SetWeights, transaction_fee, who.clone()))
+            }
             Some(Call::reveal_weights { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::SetWeights, transaction_fee, who.clone()))
@@ -1736,3 +2206,36 @@ impl<T, H, P> CollectiveInterface<T, H, P> for () {
         Ok(true)
     }
 }
+
+/// Trait for the calls dispatching other calls, such as batches, proxies and multisigs, whose
+/// nested calls the `SubtensorSignedExtension` validates for the account they are dispatched for
+pub trait NestedCalls<AccountId, Call> {
+    /// Get the calls nested in `call` signed by `who`, with the account each is dispatched for
+    fn nested_calls<'a>(who: &AccountId, call: &'a Call) -> Vec<(AccountId, &'a Call)>;
+}
+
+impl<AccountId, Call> NestedCalls<AccountId, Call> for () {
+    fn nested_calls<'a>(_: &AccountId, _: &'a Call) -> Vec<(AccountId, &'a Call)> {
+        Vec::new()
+    }
+}
+
+/// Trait for the source of the decryption keys of timelock encrypted weight commits
+pub trait TimelockKeySource {
+    /// Get the most recent round whose decryption key is published, if the source is available
+    fn latest_round() -> Option<u64>;
+
+    /// Decrypt a ciphertext encrypted to a round. Returns `None` if the key of the round is not
+    /// published yet or the ciphertext cannot be decrypted with it.
+    fn decrypt(round: u64, ciphertext: &[u8]) -> Option<Vec<u8>>;
+}
+
+impl TimelockKeySource for () {
+    fn latest_round() -> Option<u64> {
+        None
+    }
+
+    fn decrypt(_: u64, _: &[u8]) -> Option<Vec<u8>> {
+        None
+    }
+}
@@ -47,6 +47,15 @@ mod config {
         /// the preimage to store the call data.
         type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
 
+        /// Source of the decryption keys for timelock encrypted weight commits.
+        type TimelockKeySource: crate::TimelockKeySource;
+
+        /// Calls dispatching other calls, validated for the account they are dispatched for.
+        type NestedCalls: crate::NestedCalls<
+            Self::AccountId,
+            <Self as frame_system::Config>::RuntimeCall,
+        >;
+
         /// =================================
         /// ==== Initial Value Constants ====
         /// =================================
@@ -233,6 +233,48 @@ mod dispatches {
             )
         }
 
+        /// ---- Used to commit timelock encrypted weight values which the chain decrypts and sets
+        /// at the end of the reveal epoch, without a reveal by the committer.
+        ///
+        /// # Args:
+        /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+        ///   - The signature of the committing hotkey.
+        ///
+        /// * `netuid` (`u16`):
+        ///   - The u16 network identifier.
+        ///
+        /// * `commit` (`BoundedVec<u8, ConstU32<MAX_TIMELOCK_COMMIT_SIZE_BYTES>>`):
+        ///   - The SCALE encoded `WeightsTlockPayload` encrypted to `reveal_round`.
+        ///
+        /// * `reveal_round` (`u64`):
+        ///   - The round whose decryption key decrypts the commit.
+        ///
+        /// # Raises:
+        /// * `CommitRevealDisabled`:
+        ///   - Attempting to commit when the commit-reveal mechanism is disabled.
+        ///
+        /// * `InvalidRevealRound`:
+        ///   - Attempting to commit to a round whose key has already been published.
+        ///
+        /// * `TooManyUnrevealedCommits`:
+        ///   - Attempting to commit when the user has more than the allowed limit of unrevealed commits.
+        ///
+        /// * `TooManyTimelockedCommits`:
+        ///   - Attempting to commit when the subnet has the maximum number of commits waiting to be revealed.
+        ///
+        #[pallet::call_index(83)]
+        #[pallet::weight((Weight::from_parts(46_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(7))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        pub fn commit_timelocked_weights(
+            origin: T::RuntimeOrigin,
+            netuid: u16,
+            commit: BoundedVec<u8, ConstU32<MAX_TIMELOCK_COMMIT_SIZE_BYTES>>,
+            reveal_round: u64,
+        ) -> DispatchResult {
+            Self::do_commit_timelocked_weights(origin, netuid, commit, reveal_round)
+        }
+
         /// # Args:
         /// * `origin`: (<T as frame_system::Config>Origin):
         /// 	- The caller, a hotkey who wishes to set their weights.
@@ -442,6 +484,9 @@ mod dispatches {
         ///  * StakeAdded;
         /// 	- On the successfully adding stake to a global account.
         ///
+        ///  * ColdkeyStakeAdded;
+        /// 	- Along with StakeAdded, recording the coldkey which staked.
+        ///
         /// # Raises:
         ///  * 'NotEnoughBalanceToStake':
         /// 	- Not enough balance on the coldkey to add onto the global account.
@@ -482,6 +527,9 @@ mod dispatches {
         /// * StakeRemoved;
         /// 	- On the successfully removing stake from the hotkey account.
         ///
+        /// * ColdkeyStakeRemoved;
+        /// 	- Along with StakeRemoved, recording the coldkey which unstaked.
+        ///
         /// # Raises:
         /// * 'NotRegistered':
         /// 	- Thrown if the account we are attempting to unstake from is non existent.
@@ -841,6 +889,7 @@ mod dispatches {
             ensure_root(origin)?;
             log::info!("swap_coldkey: {:?} -> {:?}", old_coldkey, new_coldkey);
 
+            Self::ensure_coldkey_swap_executable(&old_coldkey, &new_coldkey)?;
             Self::do_swap_coldkey(&old_coldkey, &new_coldkey)
         }
 
@@ -1144,6 +1193,7 @@ mod dispatches {
         #[pallet::call_index(67)]
         #[pallet::weight((Weight::from_parts(119_000_000, 0)
 		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().reads(u64::from(MAX_CHILDKEY_GRAPH_VISITS)))
 		.saturating_add(T::DbWeight::get().writes(31)), DispatchClass::Operational, Pays::Yes))]
         pub fn set_children(
             origin: T::RuntimeOrigin,
@@ -1151,8 +1201,7 @@ mod dispatches {
             netuid: u16,
             children: Vec<(u64, T::AccountId)>,
         ) -> DispatchResultWithPostInfo {
-            Self::do_set_children(origin, hotkey, netuid, children)?;
-            Ok(().into())
+            Self::do_set_children(origin, hotkey, netuid, children)
         }
 
         /// Schedules a coldkey swap operation to be executed at a future block.
@@ -1196,10 +1245,16 @@ mod dispatches {
             new_coldkey: T::AccountId,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            ensure!(
-                !ColdkeySwapScheduled::<T>::contains_key(&who),
-                Error::<T>::SwapAlreadyScheduled
-            );
+
+            // A conflicting schedule during the swap window puts the coldkey in arbitration.
+            if ColdkeySwapScheduled::<T>::contains_key(&who) {
+                let destinations = ColdkeySwapDestinations::<T>::get(&who);
+                ensure!(
+                    !destinations.is_empty() && !destinations.contains(&new_coldkey),
+                    Error::<T>::SwapAlreadyScheduled
+                );
+                return Self::do_dispute_coldkey_swap(&who, new_coldkey);
+            }
 
             let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
             let duration: BlockNumberFor<T> = ColdkeySwapScheduleDuration::<T>::get();
@@ -1213,7 +1268,7 @@ mod dispatches {
             let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
                 .map_err(|_| Error::<T>::FailedToSchedule)?;
 
-            T::Scheduler::schedule(
+            let task = T::Scheduler::schedule(
                 DispatchTime::At(when),
                 None,
                 63,
@@ -1223,6 +1278,8 @@ mod dispatches {
             .map_err(|_| Error::<T>::FailedToSchedule)?;
 
             ColdkeySwapScheduled::<T>::insert(&who, ());
+            ColdkeySwapTask::<T>::insert(&who, task);
+            ColdkeySwapDestinations::<T>::insert(&who, vec![new_coldkey.clone()]);
             // Emit the SwapScheduled event
             Self::deposit_event(Event::ColdkeySwapScheduled {
                 old_coldkey: who.clone(),
@@ -1366,5 +1423,715 @@ mod dispatches {
         ) -> DispatchResult {
             Self::user_add_network(origin, identity)
         }
+
+        /// --- Moves stake from one hotkey to another without it leaving the staking system.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'from_hotkey' (T::AccountId):
+        /// 	- The hotkey the stake is moved from.
+        ///
+        /// * 'to_hotkey' (T::AccountId):
+        /// 	- The hotkey the stake is moved to.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The amount of stake to be moved.
+        ///
+        /// # Event:
+        /// * StakeMoved;
+        /// 	- On the successfully moving stake between the hotkeys.
+        ///
+        /// # Raises:
+        /// * 'CannotMoveStakeToSameHotkey':
+        /// 	- The source and destination hotkeys are the same.
+        ///
+        /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+        /// 	- The destination hotkey is not a delegate and not owned by the caller.
+        ///
+        /// * 'NotEnoughStakeToWithdraw':
+        /// 	- Thrown if there is not enough stake on the source hotkey to move this amount.
+        ///
+        #[pallet::call_index(80)]
+        #[pallet::weight((Weight::from_parts(150_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(16))
+		.saturating_add(T::DbWeight::get().writes(12)), DispatchClass::Normal, Pays::No))]
+        pub fn move_stake(
+            origin: OriginFor<T>,
+            from_hotkey: T::AccountId,
+            to_hotkey: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_move_stake(origin, from_hotkey, to_hotkey, amount)
+        }
+
+        /// --- Atomically redistributes the total stake of the calling coldkey over a set of
+        /// hotkeys. Stake on hotkeys which are not listed is moved to the listed ones.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'targets' (Vec<(T::AccountId, u16)>):
+        /// 	- The hotkeys and their proportion of the stake, normalized over u16::MAX.
+        ///
+        /// # Event:
+        /// * StakeRebalanced;
+        /// 	- On the successfully redistributing the stake.
+        ///
+        /// # Raises:
+        /// * 'InvalidStakeProportions':
+        /// 	- The targets are empty, contain duplicates or do not sum to u16::MAX.
+        ///
+        /// * 'TooManyRebalanceHotkeys':
+        /// 	- The targets, or the hotkeys the caller stakes to, exceed MAX_REBALANCE_HOTKEYS.
+        ///
+        /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+        /// 	- A target hotkey is not a delegate and not owned by the caller.
+        ///
+        /// * 'StakeRateLimitExceeded':
+        /// 	- A changed hotkey has hit the stake rate limit for the caller.
+        ///
+        #[pallet::call_index(81)]
+        #[pallet::weight((Weight::from_parts(150_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(8))
+		.saturating_add(T::DbWeight::get().reads((targets.len().saturating_add(MAX_REBALANCE_HOTKEYS) as u64).saturating_mul(8)))
+		.saturating_add(T::DbWeight::get().writes((targets.len().saturating_add(MAX_REBALANCE_HOTKEYS) as u64).saturating_mul(8))), DispatchClass::Normal, Pays::Yes))]
+        pub fn rebalance_stake(
+            origin: OriginFor<T>,
+            targets: Vec<(T::AccountId, u16)>,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_rebalance_stake(origin, targets)
+        }
+
+        /// --- Cancels the unbonding of the stake the calling coldkey removed from a hotkey and
+        /// adds it back onto that hotkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey the unbonding stake was removed from.
+        ///
+        /// # Event:
+        /// * UnbondCancelled;
+        /// 	- On the successfully restaking the unbonding stake.
+        ///
+        /// # Raises:
+        /// * 'NoUnbondingStake':
+        /// 	- The caller has no stake unbonding from the hotkey.
+        ///
+        /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+        /// 	- The hotkey is not a delegate and not owned by the caller.
+        ///
+        /// * 'StakeRateLimitExceeded':
+        /// 	- The hotkey has hit the stake rate limit for the caller.
+        ///
+        #[pallet::call_index(82)]
+        #[pallet::weight((Weight::from_parts(100_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(10))
+		.saturating_add(T::DbWeight::get().writes(8)), DispatchClass::Normal, Pays::No))]
+        pub fn cancel_unbond(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_cancel_unbond(origin, hotkey)
+        }
+
+        /// --- Sets where the rewards of the nomination of the calling coldkey on a hotkey are
+        /// paid when the hotkey emission is drained.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey the rewards are earned from.
+        ///
+        /// * 'destination' (RewardDestination<T::AccountId>):
+        /// 	- `Restake` onto the hotkey, `Free` to the coldkey balance or `Hotkey` to stake on another hotkey.
+        ///
+        /// # Event:
+        /// * RewardDestinationSet;
+        /// 	- On the successfully setting the reward destination.
+        ///
+        /// # Raises:
+        /// * 'HotKeyAccountNotExists':
+        /// 	- The hotkey or the destination hotkey does not exist.
+        ///
+        /// * 'InvalidRewardDestination':
+        /// 	- The destination hotkey is the hotkey the rewards are earned from.
+        ///
+        /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+        /// 	- The destination hotkey is not a delegate and not owned by the caller.
+        ///
+        #[pallet::call_index(84)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn set_reward_destination(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            destination: RewardDestination<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_reward_destination(origin, hotkey, destination)
+        }
+
+        /// --- Slashes a proportion of the stake on a misbehaving hotkey. The stake of the
+        /// hotkey owner is always slashed, the stake of the nominators optionally.
+        ///
+        /// The dispatch origin for this call must be root or a council majority.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- Root or a council majority.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey to slash.
+        ///
+        /// * 'proportion' (u16):
+        /// 	- The proportion of the stake to slash, normalized over u16::MAX.
+        ///
+        /// * 'include_nominators' (bool):
+        /// 	- Whether the stake of the nominators of the hotkey is slashed as well.
+        ///
+        /// * 'destination' (SlashDestination<T::AccountId>):
+        /// 	- `Burn` the slashed stake or pay it to the free balance of an `Account`.
+        ///
+        /// # Event:
+        /// * Slashed;
+        /// 	- On the successfully slashing the hotkey.
+        ///
+        /// # Raises:
+        /// * 'HotKeyAccountNotExists':
+        /// 	- The hotkey does not exist.
+        ///
+        /// * 'InvalidSlashProportion':
+        /// 	- The proportion is zero.
+        ///
+        /// * 'TooManySlashedStakers':
+        /// 	- More than MAX_SLASHED_STAKERS coldkeys stake on or unbond from the hotkey.
+        ///
+        /// * 'CannotDepositSlashedStake':
+        /// 	- The slashed stake cannot be paid to the destination account.
+        ///
+        #[pallet::call_index(85)]
+        #[pallet::weight((Pallet::<T>::get_slash_hotkey_weight(
+            MAX_SLASHED_STAKERS as u64,
+            MAX_SLASHED_STAKERS as u64
+        ), DispatchClass::Operational, Pays::No))]
+        pub fn slash_hotkey(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            proportion: u16,
+            include_nominators: bool,
+            destination: SlashDestination<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_slash_hotkey(origin, hotkey, proportion, include_nominators, destination)
+        }
+
+        /// --- Resolves the arbitration of a coldkey whose swap was scheduled to conflicting
+        /// destinations, by swapping it to one of them or cancelling the swap.
+        ///
+        /// The dispatch origin for this call must be root or a council majority.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- Root or a council majority.
+        ///
+        /// * 'coldkey' (T::AccountId):
+        /// 	- The coldkey in arbitration.
+        ///
+        /// * 'new_coldkey' (Option<T::AccountId>):
+        /// 	- The destination to swap the coldkey to, or `None` to cancel the swap.
+        ///
+        /// # Event:
+        /// * ColdkeySwapArbitrationResolved;
+        /// 	- On successfully resolving the arbitration.
+        ///
+        /// # Raises:
+        /// * 'ColdkeyNotInArbitration':
+        /// 	- The coldkey is not in arbitration.
+        ///
+        /// * 'InvalidColdkeySwapDestination':
+        /// 	- The destination is not one of the scheduled destinations.
+        ///
+        #[pallet::call_index(86)]
+        #[pallet::weight((Weight::from_parts(127_713_000, 0)
+		.saturating_add(T::DbWeight::get().reads(20))
+		.saturating_add(T::DbWeight::get().writes(14)), DispatchClass::Operational, Pays::No))]
+        pub fn resolve_coldkey_swap_arbitration(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_resolve_coldkey_swap_arbitration(origin, coldkey, new_coldkey)
+        }
+
+        /// --- Sets the guardians able to jointly recover the calling coldkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'guardians' (Vec<T::AccountId>):
+        /// 	- The guardian accounts, empty to remove the guardians.
+        ///
+        /// * 'threshold' (u16):
+        /// 	- The number of guardians that must approve a recovery.
+        ///
+        /// # Event:
+        /// * GuardiansSet;
+        /// 	- On successfully setting the guardians.
+        ///
+        /// # Raises:
+        /// * 'InvalidGuardians':
+        /// 	- The guardians or the threshold are invalid.
+        ///
+        /// * 'ColdkeyRecoveryInProgress':
+        /// 	- A recovery of the coldkey is pending.
+        ///
+        #[pallet::call_index(87)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_guardians(
+            origin: OriginFor<T>,
+            guardians: Vec<T::AccountId>,
+            threshold: u16,
+        ) -> DispatchResult {
+            Self::do_set_guardians(origin, guardians, threshold)
+        }
+
+        /// --- Approves, as a guardian, the recovery of a coldkey to a new coldkey. Once the
+        /// threshold of guardians approved it, the recovery can be finalized after the coldkey
+        /// swap schedule duration, unless the coldkey cancels it.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the guardian.
+        ///
+        /// * 'coldkey' (T::AccountId):
+        /// 	- The coldkey to recover.
+        ///
+        /// * 'new_coldkey' (T::AccountId):
+        /// 	- The coldkey to swap the recovered coldkey to.
+        ///
+        /// # Event:
+        /// * ColdkeyRecoveryApproved;
+        /// 	- On successfully approving the recovery.
+        ///
+        /// * ColdkeyRecoveryScheduled;
+        /// 	- When the approval reaches the threshold of guardians.
+        ///
+        /// # Raises:
+        /// * 'NotGuardian':
+        /// 	- The caller is not a guardian of the coldkey.
+        ///
+        /// * 'SameColdkey':
+        /// 	- The new coldkey is the recovered coldkey.
+        ///
+        /// * 'ColdkeyRecoveryAlreadyApproved':
+        /// 	- The guardian already approved the recovery to the new coldkey.
+        ///
+        #[pallet::call_index(88)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn approve_coldkey_recovery(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_approve_coldkey_recovery(origin, coldkey, new_coldkey)
+        }
+
+        /// --- Cancels all the pending guardian recoveries of the calling coldkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// # Event:
+        /// * ColdkeyRecoveryCancelled;
+        /// 	- On successfully cancelling the recoveries.
+        ///
+        /// # Raises:
+        /// * 'ColdkeyRecoveryNotFound':
+        /// 	- No recovery of the coldkey is pending.
+        ///
+        #[pallet::call_index(89)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(16)), DispatchClass::Operational, Pays::No))]
+        pub fn cancel_coldkey_recovery(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            Self::do_cancel_coldkey_recovery(origin)
+        }
+
+        /// --- Swaps a recovered coldkey to the new coldkey approved by its guardians once the
+        /// delay passed. The key swap cost is not burned.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- Any signed account.
+        ///
+        /// * 'coldkey' (T::AccountId):
+        /// 	- The coldkey to recover.
+        ///
+        /// * 'new_coldkey' (T::AccountId):
+        /// 	- The coldkey approved by the guardians.
+        ///
+        /// # Event:
+        /// * ColdkeyRecovered;
+        /// 	- On successfully recovering the coldkey.
+        ///
+        /// # Raises:
+        /// * 'ColdkeyRecoveryNotFound':
+        /// 	- No recovery of the coldkey to the new coldkey is pending.
+        ///
+        /// * 'ColdkeyRecoveryNotReady':
+        /// 	- The threshold is not reached or the delay has not passed.
+        ///
+        /// * 'ColdkeyIsInArbitration':
+        /// 	- The coldkey is in arbitration.
+        ///
+        #[pallet::call_index(90)]
+        #[pallet::weight((Weight::from_parts(127_713_000, 0)
+		.saturating_add(T::DbWeight::get().reads(20))
+		.saturating_add(T::DbWeight::get().writes(14)), DispatchClass::Normal, Pays::Yes))]
+        pub fn finalize_coldkey_recovery(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_finalize_coldkey_recovery(origin, coldkey, new_coldkey)
+        }
+
+        /// --- Swaps the hotkey of the calling coldkey on a single subnet. The UID, axon,
+        /// prometheus, weight commits and child relations of the old hotkey on the subnet move to
+        /// the new hotkey, while stake stays on the old hotkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey to swap on the subnet.
+        ///
+        /// * 'new_hotkey' (T::AccountId):
+        /// 	- The hotkey replacing it on the subnet.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet to swap the hotkey on.
+        ///
+        /// # Event:
+        /// * HotkeySwappedOnSubnet;
+        /// 	- On successfully swapping the hotkey on the subnet.
+        ///
+        /// # Raises:
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey is not registered on the subnet.
+        ///
+        /// * 'HotKeyAlreadyRegisteredInSubNet':
+        /// 	- The new hotkey is already registered on the subnet.
+        ///
+        /// * 'NonAssociatedColdKey':
+        /// 	- The caller does not own the hotkey or the new hotkey.
+        ///
+        #[pallet::call_index(91)]
+        #[pallet::weight((Weight::from_parts(100_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(30))
+		.saturating_add(T::DbWeight::get().writes(30)), DispatchClass::Operational, Pays::No))]
+        pub fn swap_hotkey_on_subnet(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            new_hotkey: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_swap_hotkey_on_subnet(origin, &hotkey, &new_hotkey, netuid)
+        }
+
+        /// --- Schedules the children of a hotkey to be replaced at the next epoch of the subnet,
+        /// with an optional expiry block per child from which the child is removed at the next
+        /// epoch. Shares the `SetChildren` rate limit with `set_children`.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the calling coldkey owning the hotkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey which will be assigned the children.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The u16 network identifier where the child keys will exist.
+        ///
+        /// * 'children' (Vec<(u64, T::AccountId, Option<u64>)>):
+        /// 	- The proportions, children and optional expiry blocks.
+        ///
+        /// # Event:
+        /// * ChildrenScheduled;
+        /// 	- On successfully scheduling the children.
+        ///
+        /// # Raises:
+        /// * 'SubnetEpochNeverRuns':
+        /// 	- The subnet never runs its epoch.
+        ///
+        /// * 'InvalidChildkeyExpiry':
+        /// 	- An expiry block is not after the epoch applying the children.
+        ///
+        #[pallet::call_index(92)]
+        #[pallet::weight((Weight::from_parts(119_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().reads(u64::from(MAX_CHILDKEY_GRAPH_VISITS)))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Operational, Pays::Yes))]
+        pub fn schedule_children(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+            children: Vec<(u64, T::AccountId, Option<u64>)>,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_schedule_children(origin, hotkey, netuid, children)
+        }
+
+        /// Places or raises the bid of a hotkey in the registration auction of a subnet.
+        ///
+        /// The bid is withdrawn from the coldkey. At the end of the adjustment interval of the
+        /// subnet the highest bids, up to the target registrations per interval, are registered
+        /// and pay the lowest registered bid, and the other bids are refunded.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the caller's coldkey.
+        ///
+        /// * `netuid` (u16):
+        ///     - The subnet of the auction.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The hotkey to register.
+        ///
+        /// * `amount` (u64):
+        ///     - The total bid in rao, at least the burn of the subnet for a new bid.
+        ///
+        /// # Event:
+        /// * `RegistrationBidPlaced`:
+        ///     - On successfully placing or raising the bid.
+        ///
+        /// # Raises:
+        /// * `RegistrationAuctionDisabled`:
+        ///     - The subnet does not register neurons through a registration auction.
+        ///
+        /// * `RegistrationBidTooLow`:
+        ///     - The bid is below the burn, does not raise the previous bid of the hotkey, or does
+        ///       not outbid the lowest bid of a full auction.
+        ///
+        /// * `NotEnoughBalanceToStake`:
+        ///     - The coldkey cannot pay the bid.
+        ///
+        #[pallet::call_index(93)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(10))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn bid_for_registration(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_bid_for_registration(origin, netuid, hotkey, amount)
+        }
+
+        /// ---- Attests or revokes a typed field of the identity of a coldkey.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of an identity registrar.
+        ///
+        /// * `coldkey` (T::AccountId):
+        ///     - The coldkey whose identity is attested.
+        ///
+        /// * `field` (IdentityField):
+        ///     - The typed field of the identity.
+        ///
+        /// * `verified` (bool):
+        ///     - Whether the field is attested or its attestation revoked.
+        ///
+        /// # Event:
+        /// * `IdentityFieldAttested`:
+        ///     - On successfully attesting or revoking the field.
+        ///
+        /// # Raises:
+        /// * `NotIdentityRegistrar`:
+        ///     - The caller is not an identity registrar.
+        ///
+        /// * `IdentityFieldNotSet`:
+        ///     - The field of the identity is not set.
+        ///
+        #[pallet::call_index(94)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn attest_identity_field(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            field: IdentityField,
+            verified: bool,
+        ) -> DispatchResult {
+            Self::do_attest_identity_field(origin, coldkey, field, verified)
+        }
+
+        /// ---- Attests or revokes a typed field of the identity of a subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of an identity registrar.
+        ///
+        /// * `netuid` (u16):
+        ///     - The subnet whose identity is attested.
+        ///
+        /// * `field` (IdentityField):
+        ///     - The typed field of the identity.
+        ///
+        /// * `verified` (bool):
+        ///     - Whether the field is attested or its attestation revoked.
+        ///
+        /// # Event:
+        /// * `SubnetIdentityFieldAttested`:
+        ///     - On successfully attesting or revoking the field.
+        ///
+        /// # Raises:
+        /// * `NotIdentityRegistrar`:
+        ///     - The caller is not an identity registrar.
+        ///
+        /// * `IdentityFieldNotSet`:
+        ///     - The field of the identity is not set.
+        ///
+        #[pallet::call_index(95)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn attest_subnet_identity_field(
+            origin: OriginFor<T>,
+            netuid: u16,
+            field: IdentityField,
+            verified: bool,
+        ) -> DispatchResult {
+            Self::do_attest_subnet_identity_field(origin, netuid, field, verified)
+        }
+
+        /// ---- Set the identity information for a coldkey, including its typed fields.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the calling coldkey.
+        ///
+        /// * `name`, `url`, `image`, `discord`, `description`, `additional` (Vec<u8>):
+        ///     - The identity information, as for set_identity.
+        ///
+        /// * `github_repo` (Vec<u8>):
+        ///     - The GitHub repository or organization of the identity.
+        ///
+        /// * `contact` (Vec<u8>):
+        ///     - The contact of the identity.
+        ///
+        /// * `logo_hash` (Option<H256>):
+        ///     - The hash of the logo of the identity.
+        ///
+        /// # Event:
+        /// * `ChainIdentitySet`:
+        ///     - On successfully setting the identity.
+        ///
+        /// # Raises:
+        /// * `HotKeyNotRegisteredInNetwork`:
+        ///     - None of the hotkeys of the caller is registered.
+        ///
+        /// * `InvalidIdentity`:
+        ///     - A field of the identity is too long or malformed.
+        ///
+        #[pallet::call_index(99)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_typed_identity(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+            url: Vec<u8>,
+            image: Vec<u8>,
+            discord: Vec<u8>,
+            description: Vec<u8>,
+            additional: Vec<u8>,
+            github_repo: Vec<u8>,
+            contact: Vec<u8>,
+            logo_hash: Option<H256>,
+        ) -> DispatchResult {
+            Self::do_set_typed_identity(
+                origin,
+                name,
+                url,
+                image,
+                discord,
+                description,
+                additional,
+                github_repo,
+                contact,
+                logo_hash,
+            )
+        }
+
+        /// ---- Set the identity information for a subnet, including its typed fields.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the calling coldkey, which must be the owner of the subnet.
+        ///
+        /// * `netuid` (u16):
+        ///     - The unique network identifier of the subnet.
+        ///
+        /// * `subnet_name`, `github_repo`, `subnet_contact` (Vec<u8>):
+        ///     - The identity information, as for set_subnet_identity.
+        ///
+        /// * `subnet_url` (Vec<u8>):
+        ///     - The URL of the subnet.
+        ///
+        /// * `discord` (Vec<u8>):
+        ///     - The Discord information for the subnet.
+        ///
+        /// * `logo_hash` (Option<H256>):
+        ///     - The hash of the logo of the subnet.
+        ///
+        /// # Event:
+        /// * `SubnetIdentitySet`:
+        ///     - On successfully setting the identity.
+        ///
+        /// # Raises:
+        /// * `NotSubnetOwner`:
+        ///     - The caller does not own the subnet.
+        ///
+        /// * `InvalidIdentity`:
+        ///     - A field of the identity is too long or malformed.
+        ///
+        #[pallet::call_index(100)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_typed_subnet_identity(
+            origin: OriginFor<T>,
+            netuid: u16,
+            subnet_name: Vec<u8>,
+            github_repo: Vec<u8>,
+            subnet_contact: Vec<u8>,
+            subnet_url: Vec<u8>,
+            discord: Vec<u8>,
+            logo_hash: Option<H256>,
+        ) -> DispatchResult {
+            Self::do_set_typed_subnet_identity(
+                origin,
+                netuid,
+                subnet_name,
+                github_repo,
+                subnet_contact,
+                subnet_url,
+                discord,
+                logo_hash,
+            )
+        }
     }
 }
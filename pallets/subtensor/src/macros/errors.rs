@@ -190,5 +190,79 @@ mod errors {
         InputLengthsUnequal,
         /// A transactor exceeded the rate limit for setting weights.
         CommittingWeightsTooFast,
+        /// Attempted to move stake to the hotkey it is moved from.
+        CannotMoveStakeToSameHotkey,
+        /// The stake proportions are empty, contain duplicate hotkeys or do not sum to u16::MAX.
+        InvalidStakeProportions,
+        /// The coldkey has reached the maximum number of pending unbonding entries.
+        TooManyUnbondingEntries,
+        /// There is no unbonding stake for the coldkey on this hotkey.
+        NoUnbondingStake,
+        /// The stake would move to a hotkey with a shorter unbonding period than the one it leaves.
+        UnbondingPeriodTooShort,
+        /// No source of timelock decryption keys is available.
+        TimelockKeySourceUnavailable,
+        /// The reveal round of a timelock encrypted commit has already been published.
+        InvalidRevealRound,
+        /// A timelock encrypted commit could not be decrypted or belongs to another hotkey.
+        InvalidTimelockedCommit,
+        /// The rewards of a nomination cannot be redirected to the hotkey they are earned from.
+        InvalidRewardDestination,
+        /// The proportion of stake to slash is zero.
+        InvalidSlashProportion,
+        /// The slashed stake cannot be deposited to the destination account.
+        CannotDepositSlashedStake,
+        /// The coldkey swap was not scheduled to this destination.
+        ColdkeySwapNotScheduled,
+        /// The coldkey is not in arbitration.
+        ColdkeyNotInArbitration,
+        /// The destination is not one of the destinations of the coldkey in arbitration.
+        InvalidColdkeySwapDestination,
+        /// The guardians of a coldkey or their threshold are invalid.
+        InvalidGuardians,
+        /// The caller is not a guardian of the coldkey.
+        NotGuardian,
+        /// A guardian recovery of the coldkey is pending.
+        ColdkeyRecoveryInProgress,
+        /// No guardian recovery of the coldkey to this new coldkey is pending.
+        ColdkeyRecoveryNotFound,
+        /// The guardian already approved the recovery of the coldkey to this new coldkey.
+        ColdkeyRecoveryAlreadyApproved,
+        /// The recovery has not been approved by enough guardians or its delay has not passed.
+        ColdkeyRecoveryNotReady,
+        /// The coldkey has the maximum number of pending guardian recoveries.
+        TooManyColdkeyRecoveries,
+        /// The new hotkey already has child or parent keys on the subnet.
+        NewHotkeyHasChildRelations,
+        /// The expiry block of a child is not after the epoch applying the children.
+        InvalidChildkeyExpiry,
+        /// The subnet has a tempo of zero and never runs its epoch.
+        SubnetEpochNeverRuns,
+        /// A child is already an ancestor of the hotkey.
+        ChildkeyCycle,
+        /// The children would make a chain of child keys longer than MAX_CHILDKEY_DEPTH.
+        ChildkeyDepthExceeded,
+        /// The subnet registers neurons through its registration auction only.
+        RegistrationByAuctionOnly,
+        /// The subnet does not register neurons through a registration auction.
+        RegistrationAuctionDisabled,
+        /// The bid is below the burn, does not raise the previous bid of the hotkey, or does not
+        /// outbid the lowest bid of a full auction.
+        RegistrationBidTooLow,
+        /// The caller is not an identity registrar.
+        NotIdentityRegistrar,
+        /// The attested identity field is not set.
+        IdentityFieldNotSet,
+        /// The hotkey sits out a senate term after reaching the term limit.
+        SenateTermLimitReached,
+        /// The rebalance targets, or the hotkeys the coldkey stakes to, exceed MAX_REBALANCE_HOTKEYS.
+        TooManyRebalanceHotkeys,
+        /// Validating the children would visit more than MAX_CHILDKEY_GRAPH_VISITS hotkeys.
+        ChildkeyGraphTooLarge,
+        /// The subnet has MAX_TIMELOCKED_COMMITS_PER_SUBNET timelocked weight commits waiting to
+        /// be revealed.
+        TooManyTimelockedCommits,
+        /// More than MAX_SLASHED_STAKERS coldkeys stake on or unbond from the slashed hotkey.
+        TooManySlashedStakers,
     }
 }
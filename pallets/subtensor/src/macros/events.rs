@@ -224,5 +224,277 @@ mod events {
         /// - **netuid**: The network identifier.
         /// - **revealed_hashes**: A vector of hashes representing each revealed weight set.
         WeightsBatchRevealed(T::AccountId, u16, Vec<H256>),
+
+        /// A weight commit expired before it was revealed and has been removed.
+        ///
+        /// - **who**: The account ID of the user who committed the weights.
+        /// - **netuid**: The network identifier.
+        /// - **commit_hash**: The hash of the expired commit.
+        WeightCommitExpired(T::AccountId, u16, H256),
+
+        /// Timelock encrypted weights have been successfully committed.
+        ///
+        /// - **who**: The account ID of the user committing the weights.
+        /// - **netuid**: The network identifier.
+        /// - **commit_hash**: The hash of the encrypted commit.
+        /// - **reveal_round**: The round whose key decrypts the commit.
+        TimelockedWeightsCommitted(T::AccountId, u16, H256, u64),
+
+        /// Timelock encrypted weights have been decrypted and set.
+        ///
+        /// - **who**: The account ID of the user who committed the weights.
+        /// - **netuid**: The network identifier.
+        /// - **commit_hash**: The hash of the encrypted commit.
+        TimelockedWeightsRevealed(T::AccountId, u16, H256),
+
+        /// Timelock encrypted weights could not be decrypted or set and have been dropped.
+        ///
+        /// - **who**: The account ID of the user who committed the weights.
+        /// - **netuid**: The network identifier.
+        /// - **commit_hash**: The hash of the encrypted commit.
+        TimelockedWeightsRevealFailed(T::AccountId, u16, H256),
+
+        /// The pending emission of a hotkey has been drained into stake.
+        ///
+        /// - **hotkey**: The hotkey whose emission was drained.
+        /// - **emission**: The total emission drained, in rao.
+        /// - **owner_emission**: The part of the emission staked for the hotkey owner, in rao.
+        /// - **nominators**: The number of nominators that received a reward.
+        HotkeyEmissionDrained(T::AccountId, u64, u64, u32),
+
+        /// stake has been moved from one hotkey to another.
+        ///
+        /// - **coldkey**: The coldkey owning the stake.
+        /// - **from_hotkey**: The hotkey the stake was moved from.
+        /// - **to_hotkey**: The hotkey the stake was moved to.
+        /// - **amount**: The amount of stake moved in rao.
+        StakeMoved(T::AccountId, T::AccountId, T::AccountId, u64),
+
+        /// the stake of a coldkey has been redistributed over its hotkeys.
+        ///
+        /// - **coldkey**: The coldkey owning the stake.
+        /// - **changes**: The changed hotkeys with their stake before and after, in rao.
+        StakeRebalanced(T::AccountId, Vec<(T::AccountId, u64, u64)>),
+
+        /// removed stake has entered the unbonding queue.
+        ///
+        /// - **coldkey**: The coldkey the stake will be released to.
+        /// - **hotkey**: The hotkey the stake was removed from.
+        /// - **amount**: The amount of stake unbonding in rao.
+        /// - **release_block**: The block at which the stake is released.
+        StakeUnbonding(T::AccountId, T::AccountId, u64, u64),
+
+        /// unbonding stake has been released to the coldkey balance.
+        ///
+        /// - **coldkey**: The coldkey the stake was released to.
+        /// - **hotkey**: The hotkey the stake was removed from.
+        /// - **amount**: The amount released in rao.
+        UnbondedStakeReleased(T::AccountId, T::AccountId, u64),
+
+        /// unbonding stake has been cancelled and added back to the hotkey.
+        ///
+        /// - **coldkey**: The coldkey owning the stake.
+        /// - **hotkey**: The hotkey the stake was added back to.
+        /// - **amount**: The amount restaked in rao.
+        UnbondCancelled(T::AccountId, T::AccountId, u64),
+
+        /// the reward destination of a nomination has been set.
+        ///
+        /// - **coldkey**: The coldkey owning the stake.
+        /// - **hotkey**: The hotkey the rewards are earned from.
+        /// - **destination**: Where the rewards are paid.
+        RewardDestinationSet(T::AccountId, T::AccountId, RewardDestination<T::AccountId>),
+
+        /// the reward of a nomination could not be paid to its reward destination and has been restaked.
+        ///
+        /// - **coldkey**: The coldkey owning the stake.
+        /// - **hotkey**: The hotkey the reward was earned from and restaked on.
+        /// - **reward**: The reward restaked in rao.
+        NominatorRewardRestaked(T::AccountId, T::AccountId, u64),
+
+        /// the stake on a hotkey has been slashed.
+        ///
+        /// - **hotkey**: The slashed hotkey.
+        /// - **slashed**: The stake slashed from each coldkey, including its stake still unbonding
+        ///   from the hotkey, in rao.
+        /// - **destination**: Where the slashed stake went.
+        Slashed(
+            T::AccountId,
+            Vec<(T::AccountId, u64)>,
+            SlashDestination<T::AccountId>,
+        ),
+
+        /// a coldkey swap has been scheduled to a conflicting destination, freezing the coldkey
+        /// until the arbitration is resolved.
+        ///
+        /// - **coldkey**: The coldkey in arbitration.
+        /// - **new_coldkeys**: The destinations the swap has been scheduled to.
+        ColdkeySwapDisputed(T::AccountId, Vec<T::AccountId>),
+
+        /// the arbitration of a coldkey swap has been resolved.
+        ///
+        /// - **coldkey**: The coldkey that was in arbitration.
+        /// - **new_coldkey**: The destination the coldkey was swapped to, `None` if cancelled.
+        ColdkeySwapArbitrationResolved(T::AccountId, Option<T::AccountId>),
+
+        /// the guardians of a coldkey have been set.
+        ///
+        /// - **coldkey**: The coldkey the guardians can recover.
+        /// - **guardians**: The guardian accounts, empty if removed.
+        /// - **threshold**: The number of guardians that must approve a recovery.
+        GuardiansSet(T::AccountId, Vec<T::AccountId>, u16),
+
+        /// a guardian has approved the recovery of a coldkey.
+        ///
+        /// - **coldkey**: The coldkey to recover.
+        /// - **guardian**: The approving guardian.
+        /// - **new_coldkey**: The coldkey to swap the recovered coldkey to.
+        ColdkeyRecoveryApproved(T::AccountId, T::AccountId, T::AccountId),
+
+        /// the recovery of a coldkey has been approved by enough guardians.
+        ///
+        /// - **coldkey**: The coldkey to recover.
+        /// - **new_coldkey**: The coldkey to swap the recovered coldkey to.
+        /// - **execution_block**: The block from which the recovery can be finalized.
+        ColdkeyRecoveryScheduled(T::AccountId, T::AccountId, u64),
+
+        /// the pending recoveries of a coldkey have been cancelled by the coldkey.
+        ///
+        /// - **coldkey**: The coldkey that cancelled the recoveries.
+        ColdkeyRecoveryCancelled(T::AccountId),
+
+        /// a coldkey has been recovered by its guardians.
+        ///
+        /// - **coldkey**: The recovered coldkey.
+        /// - **new_coldkey**: The coldkey it was swapped to.
+        ColdkeyRecovered(T::AccountId, T::AccountId),
+
+        /// a hotkey has been swapped on a single subnet.
+        ///
+        /// - **coldkey**: The coldkey owning both hotkeys.
+        /// - **old_hotkey**: The hotkey swapped out of the subnet.
+        /// - **new_hotkey**: The hotkey registered in its place.
+        /// - **netuid**: The subnet the hotkey was swapped on.
+        HotkeySwappedOnSubnet(T::AccountId, T::AccountId, T::AccountId, u16),
+
+        /// the children of a hotkey have been scheduled for the next epoch of a subnet.
+        ///
+        /// - **hotkey**: The parent hotkey.
+        /// - **netuid**: The subnet the children are set on.
+        /// - **children**: The proportions, children and optional expiry blocks.
+        /// - **apply_block**: The block of the epoch setting the children.
+        ChildrenScheduled(
+            T::AccountId,
+            u16,
+            Vec<(u64, T::AccountId, Option<u64>)>,
+            u64,
+        ),
+
+        /// a child of a hotkey has been removed on expiry.
+        ///
+        /// - **hotkey**: The parent hotkey.
+        /// - **netuid**: The subnet of the child.
+        /// - **child**: The expired child.
+        ChildKeyExpired(T::AccountId, u16, T::AccountId),
+
+        /// the scheduled children of a hotkey have been dropped as no longer valid.
+        ///
+        /// - **hotkey**: The parent hotkey.
+        /// - **netuid**: The subnet of the children.
+        /// - **error**: Why the children could not be set.
+        ScheduledChildrenDropped(T::AccountId, u16, DispatchError),
+
+        /// a bid has been placed or raised in the registration auction of a subnet.
+        ///
+        /// - **coldkey**: The coldkey paying the bid.
+        /// - **netuid**: The subnet of the auction.
+        /// - **hotkey**: The hotkey bid for.
+        /// - **amount**: The total bid in rao.
+        RegistrationBidPlaced(T::AccountId, u16, T::AccountId, u64),
+
+        /// a bid of the registration auction of a subnet has been refunded.
+        ///
+        /// - **coldkey**: The coldkey refunded.
+        /// - **netuid**: The subnet of the auction.
+        /// - **hotkey**: The hotkey bid for.
+        /// - **amount**: The refunded bid in rao.
+        RegistrationBidRefunded(T::AccountId, u16, T::AccountId, u64),
+
+        /// the registration auction of a subnet has been settled.
+        ///
+        /// - **netuid**: The subnet of the auction.
+        /// - **hotkeys**: The hotkeys registered by the auction.
+        /// - **clearing_price**: The price paid by each registered hotkey, if any.
+        RegistrationAuctionSettled(u16, Vec<T::AccountId>, Option<u64>),
+
+        /// a typed field of the identity of a coldkey has been attested or revoked.
+        ///
+        /// - **registrar**: The identity registrar.
+        /// - **coldkey**: The coldkey whose identity is attested.
+        /// - **field**: The typed field of the identity.
+        /// - **verified**: Whether the field is attested or its attestation revoked.
+        IdentityFieldAttested(T::AccountId, T::AccountId, IdentityField, bool),
+
+        /// a typed field of the identity of a subnet has been attested or revoked.
+        ///
+        /// - **registrar**: The identity registrar.
+        /// - **netuid**: The subnet whose identity is attested.
+        /// - **field**: The typed field of the identity.
+        /// - **verified**: Whether the field is attested or its attestation revoked.
+        SubnetIdentityFieldAttested(T::AccountId, u16, IdentityField, bool),
+
+        /// the senate has been rebalanced at the root epoch.
+        SenateRebalanced {
+            /// the hotkeys which joined the senate
+            added: Vec<T::AccountId>,
+            /// the hotkeys which left the senate, outstaked or at the end of their term
+            removed: Vec<T::AccountId>,
+        },
+
+        /// stake has been added by a coldkey, emitted along with StakeAdded.
+        ///
+        /// - **coldkey**: The coldkey which staked.
+        /// - **hotkey**: The hotkey receiving the stake.
+        /// - **amount**: The amount of stake added in rao.
+        ColdkeyStakeAdded(T::AccountId, T::AccountId, u64),
+
+        /// stake has been removed by a coldkey, emitted along with StakeRemoved.
+        ///
+        /// - **coldkey**: The coldkey which unstaked.
+        /// - **hotkey**: The hotkey the stake was removed from.
+        /// - **amount**: The amount of stake removed in rao.
+        ColdkeyStakeRemoved(T::AccountId, T::AccountId, u64),
+
+        /// the unbonding period is set for a subnet.
+        UnbondingPeriodSet(u16, u64),
+
+        /// a neuron has been pruned to make room for a new registration.
+        ///
+        /// - **netuid**: The subnet of the neuron.
+        /// - **uid**: The uid of the pruned neuron.
+        /// - **hotkey**: The hotkey of the pruned neuron.
+        /// - **reason**: The pruning policy and the group of neurons the pruned one ranked lowest in.
+        NeuronPruned(u16, u16, T::AccountId, PruningReason),
+
+        /// pruning policy is set for a subnet.
+        PruningPolicySet(u16, PruningPolicy),
+
+        /// the uids protected from pruning are set for a subnet.
+        PruningProtectedUidsSet(u16, Vec<u16>),
+
+        /// registration auction is enabled or disabled for a subnet.
+        RegistrationAuctionEnabledSet(u16, bool),
+
+        /// the rate limit of a transaction type is set on a subnet, or globally under netuid u16::MAX.
+        RateLimitSet(u16, u16, u64),
+
+        /// the term limit of senate members is set by sudo/admin transaction.
+        SenateTermLimitSet(u64),
+
+        /// the controller adjusting the burn and difficulty is set for a subnet.
+        AdjustmentControllerSet(u16, AdjustmentController),
+
+        /// The identity registrars have been set
+        IdentityRegistrarsSet(Vec<T::AccountId>),
     }
 }
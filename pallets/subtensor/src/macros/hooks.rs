@@ -16,13 +16,17 @@ mod hooks {
         // 		- The number of the block we are initializing.
         fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
             let block_step_result = Self::block_step();
+            // --- Release the unbonded stake due at this block.
+            let unbonding_weight = Self::release_unbonded_stake(Self::get_current_block_as_u64());
             match block_step_result {
-                Ok(_) => {
+                Ok(block_step_weight) => {
                     // --- If the block step was successful, return the weight.
                     log::debug!("Successfully ran block step.");
                     Weight::from_parts(110_634_229_000_u64, 0)
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
+                        .saturating_add(block_step_weight)
+                        .saturating_add(unbonding_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -30,6 +34,7 @@ mod hooks {
                     Weight::from_parts(110_634_229_000_u64, 0)
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
+                        .saturating_add(unbonding_weight)
                 }
             }
         }
@@ -72,13 +77,20 @@ mod hooks {
                 // Migrate Delegate Ids on chain
                 .saturating_add(migrations::migrate_chain_identity::migrate_set_hotkey_identities::<T>())
                 // Migrate Commit-Reval 2.0
-                .saturating_add(migrations::migrate_commit_reveal_v2::migrate_commit_reveal_2::<T>());
+                .saturating_add(migrations::migrate_commit_reveal_v2::migrate_commit_reveal_2::<T>())
+                // Move the rate limits into TxRateLimits
+                .saturating_add(migrations::migrate_rate_limits::migrate_rate_limits::<T>())
+                // Translate the identities to the layouts with typed fields
+                .saturating_add(migrations::migrate_identity_fields::migrate_identity_fields::<T>())
+                // Move the stake and registration limits into TxRateLimits
+                .saturating_add(migrations::migrate_stake_and_registration_rate_limits::migrate_stake_and_registration_rate_limits::<T>());
             weight
         }
 
         #[cfg(feature = "try-runtime")]
         fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
             Self::check_accounting_invariants()?;
+            Self::check_childkey_invariants()?;
             Ok(())
         }
     }
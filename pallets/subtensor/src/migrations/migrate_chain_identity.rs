@@ -106,6 +106,9 @@ pub fn migrate_set_hotkey_identities<T: Config>() -> Weight {
                 discord: discord.into(),
                 description: description.into(),
                 additional: additional.into(),
+                github_repo: Vec::new(),
+                contact: Vec::new(),
+                logo_hash: None,
             };
 
             // Log the identity details
@@ -0,0 +1,103 @@
+use super::*;
+use crate::HasMigrationRun;
+use codec::{Decode, Encode};
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// The layout of [`ChainIdentity`] before the typed GitHub, contact and logo fields.
+#[derive(Encode, Decode)]
+pub struct OldChainIdentity {
+    pub name: Vec<u8>,
+    pub url: Vec<u8>,
+    pub image: Vec<u8>,
+    pub discord: Vec<u8>,
+    pub description: Vec<u8>,
+    pub additional: Vec<u8>,
+}
+
+/// The layout of [`SubnetIdentity`] before the typed URL, Discord and logo fields.
+#[derive(Encode, Decode)]
+pub struct OldSubnetIdentity {
+    pub subnet_name: Vec<u8>,
+    pub github_repo: Vec<u8>,
+    pub subnet_contact: Vec<u8>,
+}
+
+/// Translates the stored coldkey and subnet identities to the layouts with typed fields, leaving
+/// the new fields unset.
+pub fn migrate_identity_fields<T: Config>() -> Weight {
+    let migration_name = b"migrate_identity_fields".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // ------------------------------
+    // Step 1: Translate the coldkey identities
+    // ------------------------------
+
+    let mut identities_translated: u64 = 0;
+    Identities::<T>::translate::<OldChainIdentity, _>(|_, old| {
+        identities_translated = identities_translated.saturating_add(1);
+        Some(ChainIdentity {
+            name: old.name,
+            url: old.url,
+            image: old.image,
+            discord: old.discord,
+            description: old.description,
+            additional: old.additional,
+            github_repo: Vec::new(),
+            contact: Vec::new(),
+            logo_hash: None,
+        })
+    });
+    weight = weight.saturating_add(
+        T::DbWeight::get().reads_writes(identities_translated, identities_translated),
+    );
+
+    // ------------------------------
+    // Step 2: Translate the subnet identities
+    // ------------------------------
+
+    let mut subnet_identities_translated: u64 = 0;
+    SubnetIdentities::<T>::translate::<OldSubnetIdentity, _>(|_, old| {
+        subnet_identities_translated = subnet_identities_translated.saturating_add(1);
+        Some(SubnetIdentity {
+            subnet_name: old.subnet_name,
+            github_repo: old.github_repo,
+            subnet_contact: old.subnet_contact,
+            subnet_url: Vec::new(),
+            discord: Vec::new(),
+            logo_hash: None,
+        })
+    });
+    weight = weight.saturating_add(
+        T::DbWeight::get().reads_writes(subnet_identities_translated, subnet_identities_translated),
+    );
+
+    // ------------------------------
+    // Step 3: Mark the migration as completed
+    // ------------------------------
+
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed. Translated {} identities and {} subnet identities.",
+        String::from_utf8_lossy(&migration_name),
+        identities_translated,
+        subnet_identities_translated
+    );
+
+    weight
+}
@@ -28,7 +28,8 @@ pub mod initialise_total_issuance {
         ///
         /// This function does the following:
         /// 1. Calculates the total locked tokens across all subnets
-        /// 2. Retrieves the total account balances and total stake
+        /// 2. Retrieves the total account balances, total stake, total unbonding stake and total
+        ///    registration bids
         /// 3. Computes and updates the new total issuance
         ///
         /// Returns the weight of the migration operation.
@@ -46,12 +47,20 @@ pub mod initialise_total_issuance {
             // Get the total stake from the system
             let total_stake = crate::TotalStake::<T>::get();
 
+            // Get the stake waiting in the unbonding queues
+            let total_unbonding = crate::TotalUnbonding::<T>::get();
+
+            // Get the balance held by the open registration bids
+            let total_registration_bids = crate::TotalRegistrationBids::<T>::get();
+
             // Retrieve the previous total issuance for logging purposes
             let prev_total_issuance = crate::TotalIssuance::<T>::get();
 
             // Calculate the new total issuance
             let new_total_issuance = total_account_balances
                 .saturating_add(total_stake)
+                .saturating_add(total_unbonding)
+                .saturating_add(total_registration_bids)
                 .saturating_add(total_subnet_locked);
 
             // Update the total issuance in storage
@@ -65,9 +74,9 @@ pub mod initialise_total_issuance {
             );
 
             // Return the weight of the operation
-            // We performed subnets_len + 5 reads and 1 write
+            // We performed subnets_len + 7 reads and 1 write
             <T as frame_system::Config>::DbWeight::get()
-                .reads_writes(subnets_len.saturating_add(5), 1)
+                .reads_writes(subnets_len.saturating_add(7), 1)
         }
 
         /// Performs post-upgrade checks to ensure the migration was successful.
@@ -0,0 +1,94 @@
+use super::*;
+use crate::HasMigrationRun;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Moves the limits stored in the legacy rate limit items into `TxRateLimits`.
+///
+/// Global limits are stored under GLOBAL_RATE_LIMIT_NETUID and the per subnet serving and
+/// weights set limits under their netuid. The legacy items are cleared afterwards, so they only
+/// provide the configured defaults for transaction types without a limit in `TxRateLimits`.
+pub fn migrate_rate_limits<T: Config>() -> Weight {
+    let migration_name = b"migrate_rate_limits".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // ------------------------------
+    // Step 1: Move the global limits
+    // ------------------------------
+
+    let global_limits: Vec<(Vec<TransactionType>, Option<u64>)> = Vec::from([
+        (
+            Vec::from([TransactionType::RegisterNetwork]),
+            NetworkRateLimit::<T>::try_get().ok(),
+        ),
+        (
+            Vec::from([TransactionType::BecomeDelegate, TransactionType::SwapHotkey]),
+            TxRateLimit::<T>::try_get().ok(),
+        ),
+        (
+            Vec::from([TransactionType::IncreaseDelegateTake]),
+            TxDelegateTakeRateLimit::<T>::try_get().ok(),
+        ),
+        (
+            Vec::from([TransactionType::SetChildkeyTake]),
+            TxChildkeyTakeRateLimit::<T>::try_get().ok(),
+        ),
+    ]);
+    NetworkRateLimit::<T>::kill();
+    TxRateLimit::<T>::kill();
+    TxDelegateTakeRateLimit::<T>::kill();
+    TxChildkeyTakeRateLimit::<T>::kill();
+    weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 4));
+
+    for (tx_types, maybe_limit) in global_limits {
+        if let Some(limit) = maybe_limit {
+            for tx_type in tx_types {
+                TxRateLimits::<T>::insert(u16::from(tx_type), GLOBAL_RATE_LIMIT_NETUID, limit);
+                weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            }
+        }
+    }
+
+    // ------------------------------
+    // Step 2: Move the subnet limits
+    // ------------------------------
+
+    for (netuid, limit) in ServingRateLimit::<T>::drain() {
+        for tx_type in [TransactionType::ServeAxon, TransactionType::ServePrometheus] {
+            TxRateLimits::<T>::insert(u16::from(tx_type), netuid, limit);
+        }
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 3));
+    }
+
+    for (netuid, limit) in WeightsSetRateLimit::<T>::drain() {
+        TxRateLimits::<T>::insert(u16::from(TransactionType::SetWeights), netuid, limit);
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+    }
+
+    // ------------------------------
+    // Step 3: Mark Migration as Completed
+    // ------------------------------
+
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    weight
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::HasMigrationRun;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Moves the stake and neuron registration limits into `TxRateLimits`.
+///
+/// The number of stakes allowed per stake interval is stored under GLOBAL_RATE_LIMIT_NETUID and
+/// the number of registrations allowed per block under the netuid of each subnet. The legacy
+/// items are cleared afterwards, so they only provide the configured defaults.
+pub fn migrate_stake_and_registration_rate_limits<T: Config>() -> Weight {
+    let migration_name = b"migrate_stake_and_registration_rate_limits".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // ------------------------------
+    // Step 1: Move the stake limit
+    // ------------------------------
+
+    if let Ok(limit) = TargetStakesPerInterval::<T>::try_get() {
+        TxRateLimits::<T>::insert(
+            u16::from(TransactionType::Stake),
+            GLOBAL_RATE_LIMIT_NETUID,
+            limit,
+        );
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+    TargetStakesPerInterval::<T>::kill();
+    weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+    // ------------------------------
+    // Step 2: Move the registration limits
+    // ------------------------------
+
+    for (netuid, limit) in MaxRegistrationsPerBlock::<T>::drain() {
+        TxRateLimits::<T>::insert(
+            u16::from(TransactionType::Register),
+            netuid,
+            u64::from(limit),
+        );
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+    }
+
+    // ------------------------------
+    // Step 3: Mark Migration as Completed
+    // ------------------------------
+
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    weight
+}
@@ -6,9 +6,12 @@ pub mod migrate_delete_subnet_21;
 pub mod migrate_delete_subnet_3;
 pub mod migrate_fix_pending_emission;
 pub mod migrate_fix_total_coldkey_stake;
+pub mod migrate_identity_fields;
 pub mod migrate_init_total_issuance;
 pub mod migrate_populate_owned_hotkeys;
 pub mod migrate_populate_staking_hotkeys;
+pub mod migrate_rate_limits;
+pub mod migrate_stake_and_registration_rate_limits;
 pub mod migrate_to_v1_separate_emission;
 pub mod migrate_to_v2_fixed_total_stake;
 pub mod migrate_total_issuance;
@@ -7,17 +7,19 @@ extern crate alloc;
 use codec::Compact;
 use sp_core::hexdisplay::AsBytesRef;
 
-#[freeze_struct("5752e4c650a83e0d")]
+#[freeze_struct("f901bc0844992f23")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct DelegateInfo<T: Config> {
-    delegate_ss58: T::AccountId,
-    take: Compact<u16>,
-    nominators: Vec<(T::AccountId, Compact<u64>)>, // map of nominator_ss58 to stake amount
-    owner_ss58: T::AccountId,
-    registrations: Vec<Compact<u16>>, // Vec of netuid this delegate is registered on
-    validator_permits: Vec<Compact<u16>>, // Vec of netuid this delegate has validator permit on
-    return_per_1000: Compact<u64>, // Delegators current daily return per 1000 TAO staked minus take fee
-    total_daily_return: Compact<u64>, // Delegators current daily return
+    pub delegate_ss58: T::AccountId,
+    pub take: Compact<u16>,
+    pub nominators: Vec<(T::AccountId, Compact<u64>)>, // map of nominator_ss58 to stake amount
+    pub owner_ss58: T::AccountId,
+    pub registrations: Vec<Compact<u16>>, // Vec of netuid this delegate is registered on
+    pub validator_permits: Vec<Compact<u16>>, // Vec of netuid this delegate has validator permit on
+    pub return_per_1000: Compact<u64>, // Delegators current daily return per 1000 TAO staked minus take fee
+    pub total_daily_return: Compact<u64>, // Delegators current daily return
+    pub identity: Option<ChainIdentity>, // Identity of the owner coldkey
+    pub verified_identity_fields: Vec<IdentityField>, // Fields of the identity attested by a registrar
 }
 
 impl<T: Config> Pallet<T> {
@@ -79,6 +81,8 @@ impl<T: Config> Pallet<T> {
             validator_permits,
             return_per_1000: U64F64::to_num::<u64>(return_per_1000).into(),
             total_daily_return: U64F64::to_num::<u64>(emissions_per_day).into(),
+            identity: Identities::<T>::get(&owner),
+            verified_identity_fields: Self::get_verified_identity_fields(&owner),
         }
     }
 
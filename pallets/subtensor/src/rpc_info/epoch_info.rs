@@ -0,0 +1,93 @@
+use super::*;
+use crate::epoch::pipeline::EpochTerms;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use substrate_fixed::types::I32F32;
+
+#[freeze_struct("31ce7f235b7e852")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct EpochSimulation<T: Config> {
+    pub netuid: Compact<u16>,
+    pub rao_emission: Compact<u64>,
+    pub current_block: Compact<u64>,
+    pub n: Compact<u16>,
+    pub hotkeys: Vec<(Compact<u16>, T::AccountId)>, // Vec of (uid, hotkey)
+    pub active: Vec<bool>,
+    pub last_update: Vec<Compact<u64>>,
+    pub block_at_registration: Vec<Compact<u64>>,
+    pub stake: Vec<I32F32>,
+    pub validator_permits: Vec<bool>,
+    pub new_validator_permits: Vec<bool>,
+    pub active_stake: Vec<I32F32>,
+    pub weights: Vec<Vec<(u16, I32F32)>>, // masked and row normalized weights
+    pub preranks: Vec<I32F32>,
+    pub consensus: Vec<I32F32>,
+    pub clipped_weights: Vec<Vec<(u16, I32F32)>>, // weights clipped at consensus
+    pub validator_trust: Vec<I32F32>,
+    pub ranks: Vec<I32F32>,
+    pub trust: Vec<I32F32>,
+    pub incentive: Vec<I32F32>,
+    pub bonds: Vec<Vec<(u16, I32F32)>>,
+    pub bonds_delta: Vec<Vec<(u16, I32F32)>>,
+    pub ema_bonds: Vec<Vec<(u16, I32F32)>>,
+    pub dividends: Vec<I32F32>,
+    pub normalized_server_emission: Vec<I32F32>,
+    pub normalized_validator_emission: Vec<I32F32>,
+    pub normalized_combined_emission: Vec<I32F32>,
+    pub server_emission: Vec<Compact<u64>>,
+    pub validator_emission: Vec<Compact<u64>>,
+    pub combined_emission: Vec<Compact<u64>>,
+    pub pruning_scores: Vec<I32F32>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Runs the epoch on `netuid` with `rao_emission` as a dry run and returns all the intermediate
+    /// vectors and matrices. Nothing is written to storage.
+    pub fn get_epoch_simulation(netuid: u16, rao_emission: u64) -> Option<EpochSimulation<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let terms: EpochTerms<T::AccountId> = Self::epoch_terms(netuid, rao_emission);
+        let compact_u64 = |v: Vec<u64>| v.into_iter().map(Compact).collect::<Vec<_>>();
+
+        Some(EpochSimulation {
+            netuid: netuid.into(),
+            rao_emission: rao_emission.into(),
+            current_block: terms.current_block.into(),
+            n: terms.n.into(),
+            hotkeys: terms
+                .hotkeys
+                .into_iter()
+                .map(|(uid, hotkey)| (uid.into(), hotkey))
+                .collect(),
+            active: terms.active,
+            last_update: compact_u64(terms.last_update),
+            block_at_registration: compact_u64(terms.block_at_registration),
+            stake: terms.stake,
+            validator_permits: terms.validator_permits,
+            new_validator_permits: terms.new_validator_permits,
+            active_stake: terms.active_stake,
+            weights: terms.weights,
+            preranks: terms.preranks,
+            consensus: terms.consensus,
+            clipped_weights: terms.clipped_weights,
+            validator_trust: terms.validator_trust,
+            ranks: terms.ranks,
+            trust: terms.trust,
+            incentive: terms.incentive,
+            bonds: terms.bonds,
+            bonds_delta: terms.bonds_delta,
+            ema_bonds: terms.ema_bonds,
+            dividends: terms.dividends,
+            normalized_server_emission: terms.normalized_server_emission,
+            normalized_validator_emission: terms.normalized_validator_emission,
+            normalized_combined_emission: terms.normalized_combined_emission,
+            server_emission: compact_u64(terms.server_emission),
+            validator_emission: compact_u64(terms.validator_emission),
+            combined_emission: compact_u64(terms.combined_emission),
+            pruning_scores: terms.pruning_scores,
+        })
+    }
+}
@@ -1,5 +1,10 @@
 use super::*;
 pub mod delegate_info;
+pub mod epoch_info;
 pub mod neuron_info;
+pub mod pre_dispatch_info;
+pub mod registration_info;
+pub mod schedule_info;
+pub mod stake_graph_info;
 pub mod stake_info;
 pub mod subnet_info;
@@ -0,0 +1,285 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+/// A reason for which a call would be rejected, as reported by the pre-dispatch check.
+#[freeze_struct("ffbdf1ef820577b1")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct PreDispatchFailure {
+    /// The name of the pallet error the call would fail with.
+    pub error: Vec<u8>,
+    /// The number of blocks until the call is allowed, for failures caused by a rate limit.
+    pub blocks_until_allowed: Option<Compact<u64>>,
+}
+
+impl PreDispatchFailure {
+    fn new<T: Config>(error: &Error<T>) -> Self {
+        Self {
+            error: error.as_str().as_bytes().to_vec(),
+            blocks_until_allowed: None,
+        }
+    }
+
+    fn rate_limited<T: Config>(error: &Error<T>, blocks_until_allowed: u64) -> Self {
+        Self {
+            blocks_until_allowed: Some(blocks_until_allowed.into()),
+            ..Self::new(error)
+        }
+    }
+}
+
+/// Collects the failures of the checks run against a call, see `CallChecks`.
+struct PreDispatchFailures<T: Config> {
+    failures: Vec<PreDispatchFailure>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Config> PreDispatchFailures<T> {
+    fn new() -> Self {
+        Self {
+            failures: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Records `failure`, unless the same error is already recorded, e.g. by the signed
+    /// extension.
+    fn record(&mut self, failure: PreDispatchFailure) {
+        if !self
+            .failures
+            .iter()
+            .any(|recorded| recorded.error == failure.error)
+        {
+            self.failures.push(failure);
+        }
+    }
+}
+
+impl<T: Config> CallChecks<T> for PreDispatchFailures<T> {
+    fn require(&mut self, passes: bool, error: Error<T>) -> Result<(), Error<T>> {
+        if passes {
+            return Ok(());
+        }
+        self.record(PreDispatchFailure::new(&error));
+        Err(error)
+    }
+
+    fn ensure(&mut self, passes: bool, error: Error<T>) -> Result<(), Error<T>> {
+        if !passes {
+            self.record(PreDispatchFailure::new(&error));
+        }
+        Ok(())
+    }
+
+    fn ensure_rate_limit(
+        &mut self,
+        blocks_until_allowed: u64,
+        error: Error<T>,
+    ) -> Result<(), Error<T>> {
+        if blocks_until_allowed > 0 {
+            self.record(PreDispatchFailure::rate_limited(
+                &error,
+                blocks_until_allowed,
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync + TypeInfo + pallet_balances::Config> Pallet<T>
+where
+    <T as frame_system::Config>::RuntimeCall:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<BalancesCall<T>>,
+{
+    /// Runs the validation rules of `call` signed by the encoded `signer_account_vec` against the
+    /// current state without changing it. Returns the reasons the call would be rejected, an empty
+    /// list if none is found, or `None` if the signer cannot be decoded.
+    ///
+    /// Every call is validated as by the signed extension, see `validate_runtime_call`. The calls
+    /// of this pallet also run the checks of their dispatchable, see `CallChecks`. Checks
+    /// depending on a failed precondition, e.g. the subnet existing, are skipped.
+    pub fn pre_dispatch_check(
+        call: &<T as frame_system::Config>::RuntimeCall,
+        signer_account_vec: Vec<u8>,
+    ) -> Option<Vec<PreDispatchFailure>> {
+        if signer_account_vec.len() != 32 {
+            return None; // Invalid signer
+        }
+        let who = T::AccountId::decode(&mut signer_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::get_pre_dispatch_failures(call, &who))
+    }
+
+    /// Returns the reasons `call` signed by `who` would be rejected in the current block.
+    pub fn get_pre_dispatch_failures(
+        call: &<T as frame_system::Config>::RuntimeCall,
+        who: &T::AccountId,
+    ) -> Vec<PreDispatchFailure> {
+        let mut checks = PreDispatchFailures::<T>::new();
+
+        // The transaction pool rejects the call before any other check.
+        if let Err((error, _)) = SubtensorSignedExtension::<T>::validate_runtime_call(who, call) {
+            let _ = checks.ensure(false, error);
+        }
+
+        if let Some(pallet_call) = call.is_sub_type() {
+            // A failed required check has been recorded and skips the checks after it.
+            let _ = Self::pallet_call_checks(&mut checks, pallet_call, who);
+        }
+
+        checks.failures
+    }
+
+    /// Runs the checks of the dispatchable of `call` signed by `who`.
+    fn pallet_call_checks(
+        checks: &mut PreDispatchFailures<T>,
+        call: &Call<T>,
+        who: &T::AccountId,
+    ) -> Result<(), Error<T>> {
+        match call {
+            Call::set_weights {
+                netuid,
+                dests,
+                weights,
+                version_key,
+            } => {
+                checks.require(
+                    !Self::get_commit_reveal_weights_enabled(*netuid),
+                    Error::<T>::CommitRevealEnabled,
+                )?;
+                Self::set_weights_checks(checks, who, *netuid, dests, weights, *version_key)
+            }
+            Call::commit_weights { netuid, .. } => {
+                Self::commit_weights_checks(checks, who, *netuid)
+            }
+            Call::commit_timelocked_weights {
+                netuid,
+                reveal_round,
+                ..
+            } => Self::commit_timelocked_weights_checks(checks, who, *netuid, *reveal_round),
+            Call::reveal_weights {
+                netuid,
+                uids,
+                values,
+                version_key,
+                ..
+            } => {
+                Self::reveal_weights_checks(checks, who, *netuid)?;
+                Self::set_weights_checks(checks, who, *netuid, uids, values, *version_key)
+            }
+            Call::batch_reveal_weights {
+                netuid,
+                uids_list,
+                values_list,
+                salts_list,
+                version_keys,
+            } => {
+                let num_reveals = uids_list.len();
+                checks.require(
+                    num_reveals == values_list.len()
+                        && num_reveals == salts_list.len()
+                        && num_reveals == version_keys.len(),
+                    Error::<T>::InputLengthsUnequal,
+                )?;
+                Self::reveal_weights_checks(checks, who, *netuid)?;
+                for ((uids, values), version_key) in
+                    uids_list.iter().zip(values_list).zip(version_keys)
+                {
+                    Self::set_weights_checks(checks, who, *netuid, uids, values, *version_key)?;
+                }
+                Ok(())
+            }
+            Call::add_stake {
+                hotkey,
+                amount_staked,
+            } => Self::add_stake_checks(checks, who, hotkey, *amount_staked),
+            Call::remove_stake {
+                hotkey,
+                amount_unstaked,
+            } => Self::remove_stake_checks(checks, who, hotkey, *amount_unstaked),
+            Call::register { netuid, hotkey, .. } => {
+                Self::pow_registration_checks(checks, who, *netuid, hotkey)
+            }
+            Call::burned_register { netuid, hotkey } => {
+                Self::burned_registration_checks(checks, who, *netuid, hotkey)
+            }
+            Call::serve_axon {
+                netuid,
+                ip,
+                ip_type,
+                ..
+            }
+            | Call::serve_axon_tls {
+                netuid,
+                ip,
+                ip_type,
+                ..
+            } => Self::serving_checks(
+                checks,
+                who,
+                *netuid,
+                *ip_type,
+                *ip,
+                &TransactionType::ServeAxon,
+                Self::get_axon_info(*netuid, who).block,
+            ),
+            Call::serve_prometheus {
+                netuid,
+                ip,
+                ip_type,
+                ..
+            } => Self::serving_checks(
+                checks,
+                who,
+                *netuid,
+                *ip_type,
+                *ip,
+                &TransactionType::ServePrometheus,
+                Self::get_prometheus_info(*netuid, who).block,
+            ),
+            Call::register_network {} | Call::register_network_with_identity { .. } => {
+                Self::register_network_checks(checks, who)
+            }
+            Call::become_delegate { hotkey } => {
+                Self::become_delegate_checks(checks, who, hotkey, Self::get_default_delegate_take())
+            }
+            Call::increase_take { hotkey, take } => {
+                Self::increase_take_checks(checks, who, hotkey, *take)
+            }
+            Call::swap_hotkey { hotkey, new_hotkey } => {
+                Self::swap_hotkey_checks(checks, who, hotkey, new_hotkey)
+            }
+            Call::swap_hotkey_on_subnet {
+                hotkey,
+                new_hotkey,
+                netuid,
+            } => Self::swap_hotkey_on_subnet_checks(checks, who, hotkey, new_hotkey, *netuid),
+            Call::set_children {
+                hotkey,
+                netuid,
+                children,
+            } => Self::children_checks(checks, who, hotkey, *netuid, children),
+            Call::schedule_children {
+                hotkey,
+                netuid,
+                children,
+            } => {
+                let proportions: Vec<(u64, T::AccountId)> = children
+                    .iter()
+                    .map(|(proportion, child, _)| (*proportion, child.clone()))
+                    .collect();
+                Self::children_checks(checks, who, hotkey, *netuid, &proportions)
+            }
+            Call::set_childkey_take {
+                hotkey,
+                netuid,
+                take,
+            } => Self::childkey_take_checks(checks, who, hotkey, *netuid, *take),
+            _ => Ok(()),
+        }
+    }
+}
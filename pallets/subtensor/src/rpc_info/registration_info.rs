@@ -0,0 +1,91 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+#[freeze_struct("23c5fded62f2a9fe")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct RegistrationTermsProjection {
+    pub interval: Compact<u16>, // Adjustment intervals from now
+    pub burn: Compact<u64>,
+    pub difficulty: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Projects the burn and difficulty of the subnet at the end of each of the next adjustment
+    /// intervals, up to MAX_PROJECTED_ADJUSTMENT_INTERVALS, if every interval had the given pow
+    /// and burn registrations. On a subnet registering through an auction, the registrations are
+    /// bids cleared at the burn.
+    pub fn project_registration_terms(
+        netuid: u16,
+        pow_registrations: u16,
+        burn_registrations: u16,
+        intervals: u16,
+    ) -> Option<Vec<RegistrationTermsProjection>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let registrations: u16 = pow_registrations.saturating_add(burn_registrations);
+        let target_registrations: u16 = Self::get_target_registrations_per_interval(netuid);
+        let auction_enabled: bool = Self::get_registration_auction_enabled(netuid);
+        let is_pid: bool = matches!(
+            Self::get_adjustment_controller(netuid),
+            AdjustmentController::Pid { .. }
+        );
+
+        let mut burn: u64 = Self::get_burn_as_u64(netuid);
+        let mut difficulty: u64 = Self::get_difficulty_as_u64(netuid);
+        let mut adjustment_integral: i64 = Self::get_adjustment_integral(netuid);
+        let mut projection: Vec<RegistrationTermsProjection> = Vec::new();
+        for interval in 1..=intervals.min(MAX_PROJECTED_ADJUSTMENT_INTERVALS) {
+            // The burn and difficulty adjusted by the cases of adjust_registration_terms_for_networks.
+            let (adjusts_burn, adjusts_difficulty): (bool, bool) = if auction_enabled {
+                (true, false)
+            } else if registrations > target_registrations {
+                (
+                    pow_registrations <= burn_registrations,
+                    pow_registrations >= burn_registrations,
+                )
+            } else {
+                (
+                    pow_registrations >= burn_registrations,
+                    pow_registrations <= burn_registrations,
+                )
+            };
+            if adjusts_burn {
+                burn = Self::upgraded_burn(
+                    netuid,
+                    burn,
+                    registrations,
+                    target_registrations,
+                    adjustment_integral,
+                );
+            }
+            if adjusts_difficulty {
+                difficulty = Self::upgraded_difficulty(
+                    netuid,
+                    difficulty,
+                    registrations,
+                    target_registrations,
+                    adjustment_integral,
+                );
+            }
+            if is_pid {
+                adjustment_integral = Self::accumulated_registration_error(
+                    adjustment_integral,
+                    registrations,
+                    target_registrations,
+                );
+            }
+
+            projection.push(RegistrationTermsProjection {
+                interval: interval.into(),
+                burn: burn.into(),
+                difficulty: difficulty.into(),
+            });
+        }
+
+        Some(projection)
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+#[freeze_struct("23d67355896db528")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct HotkeyDrainInfo<T: Config> {
+    pub hotkey: T::AccountId,
+    pub next_drain_block: Compact<u64>,
+    pub pending_emission: Compact<u64>, // Including the untouchable part
+    pub pending_emission_untouchable: Compact<u64>, // Mining emission and childkey take, not shared with nominators
+}
+
+#[freeze_struct("8b104f0f47df1ad")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetEpochSchedule {
+    pub netuid: Compact<u16>,
+    pub tempo: Compact<u16>,
+    pub next_epoch_block: Option<Compact<u64>>, // None if the subnet never runs its epoch
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the next block on which the emission of the hotkey is drained to its nominators,
+    /// and the emission pending until then.
+    pub fn get_hotkey_drain_info(hotkey_account_vec: Vec<u8>) -> Option<HotkeyDrainInfo<T>> {
+        if hotkey_account_vec.len() != 32 {
+            return None; // Invalid hotkey
+        }
+
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let next_drain_block: u64 =
+            Self::get_next_drain_block(&hotkey, current_block, Self::get_hotkey_emission_tempo());
+
+        Some(HotkeyDrainInfo {
+            next_drain_block: next_drain_block.into(),
+            pending_emission: PendingdHotkeyEmission::<T>::get(&hotkey).into(),
+            pending_emission_untouchable: PendingdHotkeyEmissionUntouchable::<T>::get(&hotkey)
+                .into(),
+            hotkey,
+        })
+    }
+
+    /// Returns the next block on which each subnet runs its epoch.
+    pub fn get_subnets_epoch_schedule() -> Vec<SubnetEpochSchedule> {
+        let current_block: u64 = Self::get_current_block_as_u64();
+
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .map(|netuid| SubnetEpochSchedule {
+                netuid: netuid.into(),
+                tempo: Self::get_tempo(netuid).into(),
+                next_epoch_block: Self::get_next_epoch_block(netuid, current_block).map(Compact),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+#[freeze_struct("6c74e36bbeae88f6")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct StakeGraphEdge<T: Config> {
+    pub parent: T::AccountId,
+    pub child: T::AccountId,
+    pub proportion: Compact<u64>, // u64::MAX is the whole stake of the parent
+    pub stake: Compact<u64>,      // Stake of the parent flowing to the child
+}
+
+#[freeze_struct("f1b18cc544f40e3a")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct HotkeyStakeGraphInfo<T: Config> {
+    pub hotkey: T::AccountId,
+    pub uid: Option<Compact<u16>>, // None if the hotkey is not registered on the subnet
+    pub own_stake: Compact<u64>,
+    pub inherited_stake: Compact<u64>, // From the parents
+    pub delegated_stake: Compact<u64>, // To the children
+    pub effective_stake: Compact<u64>, // Stake seen by the epoch, capped at the max stake
+}
+
+#[freeze_struct("9806a54cbb11a74f")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetStakeGraph<T: Config> {
+    pub netuid: Compact<u16>,
+    pub edges: Vec<StakeGraphEdge<T>>,
+    pub hotkeys: Vec<HotkeyStakeGraphInfo<T>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the parent to child stake graph of the subnet, along with the own, inherited,
+    /// delegated and effective stake of every registered hotkey and every hotkey in the graph.
+    pub fn get_subnet_stake_graph(netuid: u16) -> Option<SubnetStakeGraph<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        // Registered hotkeys come first, in uid order, followed by the unregistered parents
+        // and children.
+        let mut hotkeys: Vec<(T::AccountId, Option<u16>)> = (0..Self::get_subnetwork_n(netuid))
+            .map(|uid| (Keys::<T>::get(netuid, uid), Some(uid)))
+            .collect();
+
+        let mut edges: Vec<StakeGraphEdge<T>> = Vec::new();
+        for (parent, parent_netuid, children) in ChildKeys::<T>::iter() {
+            if parent_netuid != netuid {
+                continue;
+            }
+            let parent_stake: u64 = Self::get_total_stake_for_hotkey(&parent);
+            for (proportion, child) in children {
+                for hotkey in [&parent, &child] {
+                    if !hotkeys.iter().any(|(known, _)| known == hotkey) {
+                        hotkeys.push((hotkey.clone(), None));
+                    }
+                }
+                edges.push(StakeGraphEdge {
+                    parent: parent.clone(),
+                    child,
+                    proportion: proportion.into(),
+                    stake: Self::get_proportional_stake(parent_stake, proportion).into(),
+                });
+            }
+        }
+
+        let hotkeys: Vec<HotkeyStakeGraphInfo<T>> = hotkeys
+            .into_iter()
+            .map(|(hotkey, uid)| {
+                let inherited_stake: u64 = edges
+                    .iter()
+                    .filter(|edge| edge.child == hotkey)
+                    .fold(0u64, |total, edge| total.saturating_add(edge.stake.0));
+                let delegated_stake: u64 = edges
+                    .iter()
+                    .filter(|edge| edge.parent == hotkey)
+                    .fold(0u64, |total, edge| total.saturating_add(edge.stake.0));
+
+                HotkeyStakeGraphInfo {
+                    uid: uid.map(Compact),
+                    own_stake: Self::get_total_stake_for_hotkey(&hotkey).into(),
+                    inherited_stake: inherited_stake.into(),
+                    delegated_stake: delegated_stake.into(),
+                    effective_stake: Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid).into(),
+                    hotkey,
+                }
+            })
+            .collect();
+
+        Some(SubnetStakeGraph {
+            netuid: netuid.into(),
+            edges,
+            hotkeys,
+        })
+    }
+}
@@ -4,12 +4,13 @@ extern crate alloc;
 use codec::Compact;
 use sp_core::hexdisplay::AsBytesRef;
 
-#[freeze_struct("86d64c14d71d44b9")]
+#[freeze_struct("2631dd2bf10e56af")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct StakeInfo<T: Config> {
-    hotkey: T::AccountId,
-    coldkey: T::AccountId,
-    stake: Compact<u64>,
+    pub hotkey: T::AccountId,
+    pub coldkey: T::AccountId,
+    pub stake: Compact<u64>,
+    pub reward_destination: RewardDestination<T::AccountId>,
 }
 
 impl<T: Config> Pallet<T> {
@@ -27,6 +28,7 @@ impl<T: Config> Pallet<T> {
             for (hotkey, coldkey, stake) in <Stake<T>>::iter() {
                 if coldkey == coldkey_ {
                     stake_info_for_coldkey.push(StakeInfo {
+                        reward_destination: Self::get_reward_destination(&coldkey, &hotkey),
                         hotkey,
                         coldkey,
                         stake: stake.into(),
@@ -81,4 +83,24 @@ impl<T: Config> Pallet<T> {
             first.1.clone()
         }
     }
+
+    /// Returns the ledger entries of rewards a coldkey earned as nominator from emission drains
+    /// at or after `from_block`, oldest first.
+    pub fn get_nominator_rewards(
+        coldkey_account_vec: Vec<u8>,
+        from_block: u64,
+    ) -> Vec<NominatorRewardEntry<T::AccountId>> {
+        if coldkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid coldkey
+        }
+
+        let Ok(coldkey) = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        NominatorRewards::<T>::get(coldkey)
+            .into_iter()
+            .filter(|entry| entry.block >= from_block)
+            .collect()
+    }
 }
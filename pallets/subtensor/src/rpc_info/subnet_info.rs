@@ -4,83 +4,89 @@ use frame_support::storage::IterableStorageMap;
 extern crate alloc;
 use codec::Compact;
 
-#[freeze_struct("fe79d58173da662a")]
+#[freeze_struct("5b5a51b62180c143")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct SubnetInfo<T: Config> {
-    netuid: Compact<u16>,
-    rho: Compact<u16>,
-    kappa: Compact<u16>,
-    difficulty: Compact<u64>,
-    immunity_period: Compact<u16>,
-    max_allowed_validators: Compact<u16>,
-    min_allowed_weights: Compact<u16>,
-    max_weights_limit: Compact<u16>,
-    scaling_law_power: Compact<u16>,
-    subnetwork_n: Compact<u16>,
-    max_allowed_uids: Compact<u16>,
-    blocks_since_last_step: Compact<u64>,
-    tempo: Compact<u16>,
-    network_modality: Compact<u16>,
-    network_connect: Vec<[u16; 2]>,
-    emission_values: Compact<u64>,
-    burn: Compact<u64>,
-    owner: T::AccountId,
+    pub netuid: Compact<u16>,
+    pub rho: Compact<u16>,
+    pub kappa: Compact<u16>,
+    pub difficulty: Compact<u64>,
+    pub immunity_period: Compact<u16>,
+    pub max_allowed_validators: Compact<u16>,
+    pub min_allowed_weights: Compact<u16>,
+    pub max_weights_limit: Compact<u16>,
+    pub scaling_law_power: Compact<u16>,
+    pub subnetwork_n: Compact<u16>,
+    pub max_allowed_uids: Compact<u16>,
+    pub blocks_since_last_step: Compact<u64>,
+    pub tempo: Compact<u16>,
+    pub network_modality: Compact<u16>,
+    pub network_connect: Vec<[u16; 2]>,
+    pub emission_values: Compact<u64>,
+    pub burn: Compact<u64>,
+    pub owner: T::AccountId,
 }
 
-#[freeze_struct("65f931972fa13222")]
+#[freeze_struct("a6e9c98a75cf7540")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct SubnetInfov2<T: Config> {
-    netuid: Compact<u16>,
-    rho: Compact<u16>,
-    kappa: Compact<u16>,
-    difficulty: Compact<u64>,
-    immunity_period: Compact<u16>,
-    max_allowed_validators: Compact<u16>,
-    min_allowed_weights: Compact<u16>,
-    max_weights_limit: Compact<u16>,
-    scaling_law_power: Compact<u16>,
-    subnetwork_n: Compact<u16>,
-    max_allowed_uids: Compact<u16>,
-    blocks_since_last_step: Compact<u64>,
-    tempo: Compact<u16>,
-    network_modality: Compact<u16>,
-    network_connect: Vec<[u16; 2]>,
-    emission_values: Compact<u64>,
-    burn: Compact<u64>,
-    owner: T::AccountId,
-    identity: Option<SubnetIdentity>,
+    pub netuid: Compact<u16>,
+    pub rho: Compact<u16>,
+    pub kappa: Compact<u16>,
+    pub difficulty: Compact<u64>,
+    pub immunity_period: Compact<u16>,
+    pub max_allowed_validators: Compact<u16>,
+    pub min_allowed_weights: Compact<u16>,
+    pub max_weights_limit: Compact<u16>,
+    pub scaling_law_power: Compact<u16>,
+    pub subnetwork_n: Compact<u16>,
+    pub max_allowed_uids: Compact<u16>,
+    pub blocks_since_last_step: Compact<u64>,
+    pub tempo: Compact<u16>,
+    pub network_modality: Compact<u16>,
+    pub network_connect: Vec<[u16; 2]>,
+    pub emission_values: Compact<u64>,
+    pub burn: Compact<u64>,
+    pub owner: T::AccountId,
+    pub identity: Option<SubnetIdentity>,
+    pub verified_identity_fields: Vec<IdentityField>,
 }
 
-#[freeze_struct("55b472510f10e76a")]
+#[freeze_struct("3fb88f15497df6f")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct SubnetHyperparams {
-    rho: Compact<u16>,
-    kappa: Compact<u16>,
-    immunity_period: Compact<u16>,
-    min_allowed_weights: Compact<u16>,
-    max_weights_limit: Compact<u16>,
-    tempo: Compact<u16>,
-    min_difficulty: Compact<u64>,
-    max_difficulty: Compact<u64>,
-    weights_version: Compact<u64>,
-    weights_rate_limit: Compact<u64>,
-    adjustment_interval: Compact<u16>,
-    activity_cutoff: Compact<u16>,
+    pub rho: Compact<u16>,
+    pub kappa: Compact<u16>,
+    pub immunity_period: Compact<u16>,
+    pub min_allowed_weights: Compact<u16>,
+    pub max_weights_limit: Compact<u16>,
+    pub tempo: Compact<u16>,
+    pub min_difficulty: Compact<u64>,
+    pub max_difficulty: Compact<u64>,
+    pub weights_version: Compact<u64>,
+    pub weights_rate_limit: Compact<u64>,
+    pub adjustment_interval: Compact<u16>,
+    pub activity_cutoff: Compact<u16>,
     pub registration_allowed: bool,
-    target_regs_per_interval: Compact<u16>,
-    min_burn: Compact<u64>,
-    max_burn: Compact<u64>,
-    bonds_moving_avg: Compact<u64>,
-    max_regs_per_block: Compact<u16>,
-    serving_rate_limit: Compact<u64>,
-    max_validators: Compact<u16>,
-    adjustment_alpha: Compact<u64>,
-    difficulty: Compact<u64>,
-    commit_reveal_weights_interval: Compact<u64>,
-    commit_reveal_weights_enabled: bool,
-    alpha_high: Compact<u16>,
-    alpha_low: Compact<u16>,
-    liquid_alpha_enabled: bool,
+    pub target_regs_per_interval: Compact<u16>,
+    pub min_burn: Compact<u64>,
+    pub max_burn: Compact<u64>,
+    pub bonds_moving_avg: Compact<u64>,
+    pub max_regs_per_block: Compact<u16>,
+    pub serving_rate_limit: Compact<u64>,
+    pub max_validators: Compact<u16>,
+    pub adjustment_alpha: Compact<u64>,
+    pub difficulty: Compact<u64>,
+    pub commit_reveal_weights_interval: Compact<u64>,
+    pub commit_reveal_weights_enabled: bool,
+    pub alpha_high: Compact<u16>,
+    pub alpha_low: Compact<u16>,
+    pub liquid_alpha_enabled: bool,
+    pub max_unrevealed_commits: Compact<u16>,
+    pub pruning_policy: PruningPolicy,
+    pub pruning_protected_uids: Vec<Compact<u16>>,
+    pub registration_auction_enabled: bool,
+    pub adjustment_controller: AdjustmentController,
 }
 
 impl<T: Config> Pallet<T> {
@@ -202,6 +208,7 @@ impl<T: Config> Pallet<T> {
             burn,
             owner: Self::get_subnet_owner(netuid),
             identity,
+            verified_identity_fields: Self::get_verified_subnet_identity_fields(netuid),
         })
     }
     pub fn get_subnets_info_v2() -> Vec<Option<SubnetInfo<T>>> {
@@ -256,6 +263,11 @@ impl<T: Config> Pallet<T> {
         let commit_reveal_weights_enabled = Self::get_commit_reveal_weights_enabled(netuid);
         let liquid_alpha_enabled = Self::get_liquid_alpha_enabled(netuid);
         let (alpha_low, alpha_high): (u16, u16) = Self::get_alpha_values(netuid);
+        let max_unrevealed_commits = Self::get_max_unrevealed_commits(netuid);
+        let pruning_policy = Self::get_pruning_policy(netuid);
+        let pruning_protected_uids = Self::get_pruning_protected_uids(netuid);
+        let registration_auction_enabled = Self::get_registration_auction_enabled(netuid);
+        let adjustment_controller = Self::get_adjustment_controller(netuid);
 
         Some(SubnetHyperparams {
             rho: rho.into(),
@@ -285,6 +297,11 @@ impl<T: Config> Pallet<T> {
             alpha_high: alpha_high.into(),
             alpha_low: alpha_low.into(),
             liquid_alpha_enabled,
+            max_unrevealed_commits: max_unrevealed_commits.into(),
+            pruning_policy,
+            pruning_protected_uids: pruning_protected_uids.into_iter().map(Compact).collect(),
+            registration_auction_enabled,
+            adjustment_controller,
         })
     }
 }
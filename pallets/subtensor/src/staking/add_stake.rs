@@ -17,6 +17,9 @@ impl<T: Config> Pallet<T> {
     /// * StakeAdded;
     ///     -  On the successfully adding stake to a global account.
     ///
+    /// * ColdkeyStakeAdded;
+    ///     -  Along with StakeAdded, recording the coldkey which staked.
+    ///
     /// # Raises:
     /// * 'NotEnoughBalanceToStake':
     ///     -  Not enough balance on the coldkey to add onto the global account.
@@ -44,48 +47,16 @@ impl<T: Config> Pallet<T> {
             stake_to_be_added
         );
 
-        // Ensure the callers coldkey has enough stake to perform the transaction.
-        ensure!(
-            Self::can_remove_balance_from_coldkey_account(&coldkey, stake_to_be_added),
-            Error::<T>::NotEnoughBalanceToStake
-        );
-
-        // Ensure that the hotkey account exists this is only possible through registration.
-        ensure!(
-            Self::hotkey_account_exists(&hotkey),
-            Error::<T>::HotKeyAccountNotExists
-        );
-
-        // Ensure that the hotkey allows delegation or that the hotkey is owned by the calling coldkey.
-        ensure!(
-            Self::hotkey_is_delegate(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
-            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
-        );
-
-        // Ensure we don't exceed stake rate limit
+        // Ensure the coldkey can stake to the hotkey.
+        Self::add_stake_checks(&mut DispatchChecks, &coldkey, &hotkey, stake_to_be_added)?;
         let stakes_this_interval =
             Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
-        ensure!(
-            stakes_this_interval < Self::get_target_stakes_per_interval(),
-            Error::<T>::StakeRateLimitExceeded
-        );
 
         // Track this addition in the stake delta.
         StakeDeltaSinceLastEmissionDrain::<T>::mutate(&hotkey, &coldkey, |stake_delta| {
             *stake_delta = stake_delta.saturating_add_unsigned(stake_to_be_added as u128);
         });
 
-        // If coldkey is not owner of the hotkey, it's a nomination stake.
-        if !Self::coldkey_owns_hotkey(&coldkey, &hotkey) {
-            let total_stake_after_add =
-                Stake::<T>::get(&hotkey, &coldkey).saturating_add(stake_to_be_added);
-
-            ensure!(
-                total_stake_after_add >= NominatorMinRequiredStake::<T>::get(),
-                Error::<T>::NomStakeBelowMinimumThreshold
-            );
-        }
-
         // Ensure the remove operation from the coldkey is a success.
         let actual_amount_to_stake =
             Self::remove_balance_from_coldkey_account(&coldkey, stake_to_be_added)?;
@@ -105,13 +76,65 @@ impl<T: Config> Pallet<T> {
             block,
         );
         log::debug!(
-            "StakeAdded( hotkey:{:?}, stake_to_be_added:{:?} )",
+            "StakeAdded( coldkey:{:?}, hotkey:{:?}, stake_to_be_added:{:?} )",
+            coldkey,
             hotkey,
             actual_amount_to_stake
         );
-        Self::deposit_event(Event::StakeAdded(hotkey, actual_amount_to_stake));
+        Self::deposit_event(Event::StakeAdded(hotkey.clone(), actual_amount_to_stake));
+        Self::deposit_event(Event::ColdkeyStakeAdded(
+            coldkey,
+            hotkey,
+            actual_amount_to_stake,
+        ));
 
         // Ok and return.
         Ok(())
     }
+
+    /// Checks `coldkey` staking `stake_to_be_added` to `hotkey` before the stake is added.
+    /// Shared by `do_add_stake` and the pre-dispatch check, see `CallChecks`.
+    pub fn add_stake_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        stake_to_be_added: u64,
+    ) -> Result<(), Error<T>> {
+        // Ensure the callers coldkey has enough stake to perform the transaction.
+        checks.ensure(
+            Self::can_remove_balance_from_coldkey_account(coldkey, stake_to_be_added),
+            Error::<T>::NotEnoughBalanceToStake,
+        )?;
+
+        // Ensure that the hotkey account exists this is only possible through registration.
+        checks.require(
+            Self::hotkey_account_exists(hotkey),
+            Error::<T>::HotKeyAccountNotExists,
+        )?;
+
+        // Ensure that the hotkey allows delegation or that the hotkey is owned by the calling coldkey.
+        checks.require(
+            Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey,
+        )?;
+
+        // Ensure we don't exceed stake rate limit
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_stake_allowed(coldkey, hotkey),
+            Error::<T>::StakeRateLimitExceeded,
+        )?;
+
+        // If coldkey is not owner of the hotkey, it's a nomination stake.
+        if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
+            let total_stake_after_add =
+                Stake::<T>::get(hotkey, coldkey).saturating_add(stake_to_be_added);
+
+            checks.ensure(
+                total_stake_after_add >= NominatorMinRequiredStake::<T>::get(),
+                Error::<T>::NomStakeBelowMinimumThreshold,
+            )?;
+        }
+
+        Ok(())
+    }
 }
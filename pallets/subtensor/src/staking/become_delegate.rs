@@ -41,28 +41,9 @@ impl<T: Config> Pallet<T> {
             take
         );
 
-        // --- 2. Ensure we are delegating an known key.
-        // --- 3. Ensure that the coldkey is the owner.
-        Self::do_take_checks(&coldkey, &hotkey)?;
-
-        // --- 4. Ensure we are not already a delegate (dont allow changing delegate take.)
-        ensure!(
-            !Self::hotkey_is_delegate(&hotkey),
-            Error::<T>::HotKeyAlreadyDelegate
-        );
-
-        // --- 5. Ensure we don't exceed tx rate limit
+        // --- 2. Ensure the coldkey can make the hotkey a delegate with the take.
+        Self::become_delegate_checks(&mut DispatchChecks, &coldkey, &hotkey, take)?;
         let block: u64 = Self::get_current_block_as_u64();
-        ensure!(
-            !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&coldkey), block),
-            Error::<T>::DelegateTxRateLimitExceeded
-        );
-
-        // --- 5.1 Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range
-        let min_take = MinDelegateTake::<T>::get();
-        let max_take = MaxDelegateTake::<T>::get();
-        ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
-        ensure!(take <= max_take, Error::<T>::DelegateTakeTooHigh);
 
         // --- 6. Delegate the key.
         Self::delegate_hotkey(&hotkey, take);
@@ -83,4 +64,45 @@ impl<T: Config> Pallet<T> {
         // --- 8. Ok and return.
         Ok(())
     }
+
+    /// Checks `coldkey` making `hotkey` a delegate with `take` before the hotkey is delegated.
+    /// Shared by `do_become_delegate` and the pre-dispatch check, see `CallChecks`.
+    pub fn become_delegate_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        take: u16,
+    ) -> Result<(), Error<T>> {
+        // --- 1. Ensure we are delegating an known key.
+        // --- 2. Ensure that the coldkey is the owner.
+        if let Err(error) = Self::do_take_checks(coldkey, hotkey) {
+            checks.ensure(false, error)?;
+        }
+
+        // --- 3. Ensure we are not already a delegate (dont allow changing delegate take.)
+        checks.ensure(
+            !Self::hotkey_is_delegate(hotkey),
+            Error::<T>::HotKeyAlreadyDelegate,
+        )?;
+
+        // --- 4. Ensure we don't exceed tx rate limit
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_allowed(
+                &TransactionType::BecomeDelegate,
+                coldkey,
+                GLOBAL_RATE_LIMIT_NETUID,
+            ),
+            Error::<T>::DelegateTxRateLimitExceeded,
+        )?;
+
+        // --- 5. Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range
+        checks.ensure(
+            take >= MinDelegateTake::<T>::get(),
+            Error::<T>::DelegateTakeTooLow,
+        )?;
+        checks.ensure(
+            take <= MaxDelegateTake::<T>::get(),
+            Error::<T>::DelegateTakeTooHigh,
+        )
+    }
 }
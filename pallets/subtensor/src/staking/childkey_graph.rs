@@ -0,0 +1,163 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+use sp_std::collections::btree_map::BTreeMap;
+
+impl<T: Config> Pallet<T> {
+    /// Ensures that giving the hotkey these children on the network keeps the child key graph
+    /// of the network free of cycles, with no chain longer than `MAX_CHILDKEY_DEPTH` links.
+    ///
+    /// Each hotkey of the graph is read at most once, and at most `MAX_CHILDKEY_GRAPH_VISITS`
+    /// hotkeys are read. The weight of the reads is added to `weight`.
+    ///
+    /// # Arguments:
+    /// * `hotkey` (&T::AccountId):
+    ///     - The hotkey which will be assigned the children.
+    ///
+    /// * `netuid` (u16):
+    ///     - The network of the child keys.
+    ///
+    /// * `children` (&[(u64, T::AccountId)]):
+    ///     - The new children of the hotkey, replacing its current ones.
+    ///
+    /// # Errors:
+    /// * `ChildkeyCycle`:
+    ///     - A child is the hotkey itself or one of its ancestors.
+    /// * `ChildkeyDepthExceeded`:
+    ///     - A chain through the hotkey would be longer than `MAX_CHILDKEY_DEPTH`.
+    /// * `ChildkeyGraphTooLarge`:
+    ///     - The chains through the hotkey span more than `MAX_CHILDKEY_GRAPH_VISITS` hotkeys.
+    ///
+    pub fn ensure_valid_childkey_graph(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        children: &[(u64, T::AccountId)],
+        weight: &mut Weight,
+    ) -> DispatchResult {
+        if children.is_empty() {
+            return Ok(());
+        }
+
+        let mut visits: u32 = 0;
+        let result = Self::ensure_valid_childkey_graph_inner(hotkey, netuid, children, &mut visits);
+        weight.saturating_accrue(T::DbWeight::get().reads(u64::from(visits)));
+        result
+    }
+
+    fn ensure_valid_childkey_graph_inner(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        children: &[(u64, T::AccountId)],
+        visits: &mut u32,
+    ) -> DispatchResult {
+        // --- 1. The hotkey links to its children, leaving room for one link less above it.
+        let ancestor_depth: u32 = Self::get_childkey_ancestor_depth(
+            hotkey,
+            netuid,
+            MAX_CHILDKEY_DEPTH.saturating_sub(1),
+            &mut BTreeMap::new(),
+            visits,
+        )?;
+
+        // --- 2. The chains below the children must fit in the remaining links and must not
+        // lead back to the hotkey.
+        let max_descendant_depth: u32 = MAX_CHILDKEY_DEPTH
+            .saturating_sub(1)
+            .saturating_sub(ancestor_depth);
+        let mut visited: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+        for (_, child) in children {
+            ensure!(child != hotkey, Error::<T>::ChildkeyCycle);
+            Self::get_childkey_descendant_depth(
+                child,
+                hotkey,
+                netuid,
+                max_descendant_depth,
+                &mut visited,
+                visits,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Counts the read of a hotkey of the child key graph, failing with `ChildkeyGraphTooLarge`
+    /// once more than `MAX_CHILDKEY_GRAPH_VISITS` hotkeys are read.
+    fn visit_childkey_graph(visits: &mut u32) -> DispatchResult {
+        ensure!(
+            *visits < MAX_CHILDKEY_GRAPH_VISITS,
+            Error::<T>::ChildkeyGraphTooLarge
+        );
+        *visits = visits.saturating_add(1);
+        Ok(())
+    }
+
+    /// Returns the number of links in the longest chain of parents above the hotkey on the
+    /// network, failing with `ChildkeyDepthExceeded` if it is longer than `max_depth`.
+    ///
+    /// `visited` holds the depth of the hotkeys already walked, which are not read again.
+    pub fn get_childkey_ancestor_depth(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        max_depth: u32,
+        visited: &mut BTreeMap<T::AccountId, u32>,
+        visits: &mut u32,
+    ) -> Result<u32, DispatchError> {
+        if let Some(depth) = visited.get(hotkey) {
+            ensure!(*depth <= max_depth, Error::<T>::ChildkeyDepthExceeded);
+            return Ok(*depth);
+        }
+
+        Self::visit_childkey_graph(visits)?;
+        let mut depth: u32 = 0;
+        for (_, parent) in ParentKeys::<T>::get(hotkey, netuid) {
+            ensure!(max_depth > 0, Error::<T>::ChildkeyDepthExceeded);
+            let parent_depth: u32 = Self::get_childkey_ancestor_depth(
+                &parent,
+                netuid,
+                max_depth.saturating_sub(1),
+                visited,
+                visits,
+            )?;
+            depth = depth.max(parent_depth.saturating_add(1));
+        }
+        visited.insert(hotkey.clone(), depth);
+        Ok(depth)
+    }
+
+    /// Returns the number of links in the longest chain of children below the hotkey on the
+    /// network, failing with `ChildkeyCycle` if the chain reaches `origin`, or with
+    /// `ChildkeyDepthExceeded` if it is longer than `max_depth`.
+    ///
+    /// `visited` holds the depth of the hotkeys already walked, which are not read again.
+    pub fn get_childkey_descendant_depth(
+        hotkey: &T::AccountId,
+        origin: &T::AccountId,
+        netuid: u16,
+        max_depth: u32,
+        visited: &mut BTreeMap<T::AccountId, u32>,
+        visits: &mut u32,
+    ) -> Result<u32, DispatchError> {
+        if let Some(depth) = visited.get(hotkey) {
+            ensure!(*depth <= max_depth, Error::<T>::ChildkeyDepthExceeded);
+            return Ok(*depth);
+        }
+
+        Self::visit_childkey_graph(visits)?;
+        let mut depth: u32 = 0;
+        for (_, child) in ChildKeys::<T>::get(hotkey, netuid) {
+            ensure!(child != *origin, Error::<T>::ChildkeyCycle);
+            ensure!(max_depth > 0, Error::<T>::ChildkeyDepthExceeded);
+            let child_depth: u32 = Self::get_childkey_descendant_depth(
+                &child,
+                origin,
+                netuid,
+                max_depth.saturating_sub(1),
+                visited,
+                visits,
+            )?;
+            depth = depth.max(child_depth.saturating_add(1));
+        }
+        visited.insert(hotkey.clone(), depth);
+        Ok(depth)
+    }
+}
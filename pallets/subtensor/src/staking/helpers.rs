@@ -96,7 +96,7 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn get_target_stakes_per_interval() -> u64 {
-        TargetStakesPerInterval::<T>::get()
+        Self::get_rate_limit(&TransactionType::Stake)
     }
 
     // Creates a cold - hot pairing account if the hotkey is not already an active account.
@@ -313,11 +313,20 @@ impl<T: Config> Pallet<T> {
         if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
             // If the stake is below the minimum required, it's considered a small nomination and needs to be cleared.
             if stake < Self::get_nominator_min_required_stake() {
+                // Keep the nomination if its stake has to unbond and the unbonding queue is full.
+                if Self::get_unbonding_period_for_hotkey(hotkey) > 0
+                    && !Self::can_queue_unbonding_stake(coldkey, hotkey)
+                {
+                    return;
+                }
                 // Remove the stake from the nominator account. (this is a more forceful unstake operation which )
                 // Actually deletes the staking account.
                 let cleared_stake = Self::empty_stake_on_coldkey_hotkey_account(coldkey, hotkey);
-                // Add the stake to the coldkey account.
-                Self::add_balance_to_coldkey_account(coldkey, cleared_stake);
+                // Add the stake to the coldkey account, once unbonded if the hotkey requires it.
+                // Cannot fail, the queue was checked to take the stake above.
+                if cleared_stake > 0 {
+                    let _ = Self::credit_removed_stake(coldkey, hotkey, cleared_stake);
+                }
             }
         }
     }
@@ -437,6 +446,48 @@ impl<T: Config> Pallet<T> {
 
             // Remove stake delta
             StakeDeltaSinceLastEmissionDrain::<T>::remove(hotkey, &delegate_coldkey_i);
+
+            // Drop the reward ledger of a coldkey left without stake.
+            Self::clear_nominator_rewards_if_unstaked(&delegate_coldkey_i);
+        }
+    }
+
+    /// Ensures a nomination of `stake` from `coldkey` on `hotkey` is at least the minimum
+    /// nominator stake. Stake on owned hotkeys is not a nomination and is always allowed.
+    pub fn ensure_nomination_above_minimum(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        stake: u64,
+    ) -> dispatch::DispatchResult {
+        if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
+            ensure!(
+                stake >= NominatorMinRequiredStake::<T>::get(),
+                Error::<T>::NomStakeBelowMinimumThreshold
+            );
+        }
+        Ok(())
+    }
+
+    /// Changes the stake of `coldkey` on `hotkey` from `old_stake` to `new_stake` and tracks the
+    /// change in the stake delta since the last emission drain.
+    pub fn apply_stake_change(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        old_stake: u64,
+        new_stake: u64,
+    ) {
+        if new_stake > old_stake {
+            let increase = new_stake.saturating_sub(old_stake);
+            Self::increase_stake_on_coldkey_hotkey_account(coldkey, hotkey, increase);
+            StakeDeltaSinceLastEmissionDrain::<T>::mutate(hotkey, coldkey, |stake_delta| {
+                *stake_delta = stake_delta.saturating_add_unsigned(increase as u128);
+            });
+        } else {
+            let decrease = old_stake.saturating_sub(new_stake);
+            Self::decrease_stake_on_coldkey_hotkey_account(coldkey, hotkey, decrease);
+            StakeDeltaSinceLastEmissionDrain::<T>::mutate(hotkey, coldkey, |stake_delta| {
+                *stake_delta = stake_delta.saturating_sub_unsigned(decrease as u128);
+            });
         }
     }
 }
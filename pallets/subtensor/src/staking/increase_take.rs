@@ -44,28 +44,9 @@ impl<T: Config> Pallet<T> {
             take
         );
 
-        // --- 2. Ensure we are delegating a known key.
-        //        Ensure that the coldkey is the owner.
-        Self::do_take_checks(&coldkey, &hotkey)?;
-
-        // --- 3. Ensure we are strinctly increasing take
-        if let Ok(current_take) = Delegates::<T>::try_get(&hotkey) {
-            ensure!(take > current_take, Error::<T>::DelegateTakeTooLow);
-        }
-
-        // --- 4. Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range
-        let max_take = MaxDelegateTake::<T>::get();
-        ensure!(take <= max_take, Error::<T>::DelegateTakeTooHigh);
-
-        // --- 5. Enforce the rate limit (independently on do_add_stake rate limits)
+        // --- 2. Ensure the coldkey can increase the take of the hotkey.
+        Self::increase_take_checks(&mut DispatchChecks, &coldkey, &hotkey, take)?;
         let block: u64 = Self::get_current_block_as_u64();
-        ensure!(
-            !Self::exceeds_tx_delegate_take_rate_limit(
-                Self::get_last_tx_block_delegate_take(&coldkey),
-                block
-            ),
-            Error::<T>::DelegateTxRateLimitExceeded
-        );
 
         // Set last block for rate limiting
         Self::set_last_tx_block_delegate_take(&coldkey, block);
@@ -85,4 +66,40 @@ impl<T: Config> Pallet<T> {
         // --- 8. Ok and return.
         Ok(())
     }
+
+    /// Checks `coldkey` increasing the take of `hotkey` to `take` before the take is set.
+    /// Shared by `do_increase_take` and the pre-dispatch check, see `CallChecks`.
+    pub fn increase_take_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        take: u16,
+    ) -> Result<(), Error<T>> {
+        // --- 1. Ensure we are delegating a known key.
+        //        Ensure that the coldkey is the owner.
+        if let Err(error) = Self::do_take_checks(coldkey, hotkey) {
+            checks.ensure(false, error)?;
+        }
+
+        // --- 2. Ensure we are strinctly increasing take
+        if let Ok(current_take) = Delegates::<T>::try_get(hotkey) {
+            checks.ensure(take > current_take, Error::<T>::DelegateTakeTooLow)?;
+        }
+
+        // --- 3. Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range
+        checks.ensure(
+            take <= MaxDelegateTake::<T>::get(),
+            Error::<T>::DelegateTakeTooHigh,
+        )?;
+
+        // --- 4. Enforce the rate limit (independently on do_add_stake rate limits)
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_allowed(
+                &TransactionType::IncreaseDelegateTake,
+                coldkey,
+                GLOBAL_RATE_LIMIT_NETUID,
+            ),
+            Error::<T>::DelegateTxRateLimitExceeded,
+        )
+    }
 }
@@ -1,8 +1,13 @@
 use super::*;
 pub mod add_stake;
 pub mod become_delegate;
+pub mod childkey_graph;
 pub mod decrease_take;
 pub mod helpers;
 pub mod increase_take;
+pub mod move_stake;
 pub mod remove_stake;
+pub mod reward_destination;
 pub mod set_children;
+pub mod slash;
+pub mod unbonding;
@@ -0,0 +1,370 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+use sp_std::collections::btree_set::BTreeSet;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic move_stake: Moves stake from one hotkey to another
+    /// without it leaving the staking system.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'from_hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is moved from.
+    ///
+    /// * 'to_hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is moved to.
+    ///
+    /// * 'amount' (u64):
+    ///     -  The amount of stake to be moved.
+    ///
+    /// # Event:
+    /// * StakeMoved;
+    ///     -  On the successfully moving stake between the hotkeys.
+    ///
+    /// # Raises:
+    /// * 'CannotMoveStakeToSameHotkey':
+    ///     -  Thrown if the source and destination hotkeys are the same.
+    ///
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if either hotkey does not exist.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     -  Thrown if the destination hotkey is not a delegate and not owned by the caller.
+    ///
+    /// * 'NotEnoughStakeToWithdraw':
+    ///     -  Thrown if there is not enough stake on the source hotkey to move this amount.
+    ///
+    /// * 'UnbondingPeriodTooShort':
+    ///     -  Thrown if the destination hotkey has a shorter unbonding period than the source.
+    ///
+    /// * 'NomStakeBelowMinimumThreshold':
+    ///     -  Thrown if the resulting nomination on the destination hotkey is below the minimum.
+    ///
+    /// * 'StakeRateLimitExceeded' / 'UnstakeRateLimitExceeded':
+    ///     -  Thrown if either hotkey has hit the stake rate limit for the caller.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     -  Thrown if the coldkey is frozen by the arbitration of its swap.
+    ///
+    pub fn do_move_stake(
+        origin: T::RuntimeOrigin,
+        from_hotkey: T::AccountId,
+        to_hotkey: T::AccountId,
+        amount: u64,
+    ) -> dispatch::DispatchResult {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_move_stake( origin:{:?} from_hotkey:{:?}, to_hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            from_hotkey,
+            to_hotkey,
+            amount
+        );
+
+        // Ensure the coldkey is not frozen by the arbitration of its swap.
+        Self::ensure_coldkey_not_in_arbitration(&coldkey)?;
+
+        ensure!(
+            from_hotkey != to_hotkey,
+            Error::<T>::CannotMoveStakeToSameHotkey
+        );
+        ensure!(amount > 0, Error::<T>::StakeToWithdrawIsZero);
+
+        // Ensure that both hotkeys exist and that the destination accepts the caller's stake.
+        ensure!(
+            Self::hotkey_account_exists(&from_hotkey) && Self::hotkey_account_exists(&to_hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            Self::hotkey_is_delegate(&to_hotkey) || Self::coldkey_owns_hotkey(&coldkey, &to_hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // Ensure that the source hotkey has enough stake to move.
+        let from_stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &from_hotkey);
+        ensure!(from_stake >= amount, Error::<T>::NotEnoughStakeToWithdraw);
+
+        // Ensure the stake does not skip the unbonding period of the source hotkey.
+        Self::ensure_unbonding_period_kept(&from_hotkey, &to_hotkey)?;
+
+        // Ensure the resulting nomination on the destination is above the minimum.
+        let to_stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &to_hotkey);
+        Self::ensure_nomination_above_minimum(
+            &coldkey,
+            &to_hotkey,
+            to_stake.saturating_add(amount),
+        )?;
+
+        // Ensure we don't exceed the stake rate limit on either hotkey.
+        let unstakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &from_hotkey);
+        ensure!(
+            unstakes_this_interval < Self::get_rate_limit(&TransactionType::Stake),
+            Error::<T>::UnstakeRateLimitExceeded
+        );
+        let stakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &to_hotkey);
+        ensure!(
+            stakes_this_interval < Self::get_rate_limit(&TransactionType::Stake),
+            Error::<T>::StakeRateLimitExceeded
+        );
+
+        // Move the stake and track it in the stake deltas of both hotkeys.
+        Self::apply_stake_change(
+            &coldkey,
+            &from_hotkey,
+            from_stake,
+            from_stake.saturating_sub(amount),
+        );
+        Self::apply_stake_change(
+            &coldkey,
+            &to_hotkey,
+            to_stake,
+            to_stake.saturating_add(amount),
+        );
+
+        // If the stake left on the source is below the minimum, we clear the nomination.
+        Self::clear_small_nomination_if_required(
+            &from_hotkey,
+            &coldkey,
+            from_stake.saturating_sub(amount),
+        );
+
+        // The move counts as a single operation on each hotkey.
+        let block: u64 = Self::get_current_block_as_u64();
+        Self::set_last_tx_block(&coldkey, block);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &from_hotkey,
+            unstakes_this_interval.saturating_add(1),
+            block,
+        );
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &to_hotkey,
+            stakes_this_interval.saturating_add(1),
+            block,
+        );
+
+        log::debug!(
+            "StakeMoved( coldkey:{:?}, from_hotkey:{:?}, to_hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            from_hotkey,
+            to_hotkey,
+            amount
+        );
+        Self::deposit_event(Event::StakeMoved(coldkey, from_hotkey, to_hotkey, amount));
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic rebalance_stake: Atomically redistributes the
+    /// total stake of a coldkey over a set of hotkeys.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'targets' (Vec<(T::AccountId, u16)>):
+    ///     -  The hotkeys to hold the stake and their proportion of it, normalized over u16::MAX.
+    ///        Stake on hotkeys which are not listed is moved to the listed ones.
+    ///
+    /// # Event:
+    /// * StakeRebalanced;
+    ///     -  On the successfully redistributing the stake.
+    ///
+    /// # Raises:
+    /// * 'InvalidStakeProportions':
+    ///     -  Thrown if the targets are empty, contain duplicates or do not sum to u16::MAX.
+    ///
+    /// * 'TooManyRebalanceHotkeys':
+    ///     -  Thrown if the targets, or the hotkeys the caller stakes to, exceed
+    ///        MAX_REBALANCE_HOTKEYS.
+    ///
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if a target hotkey does not exist.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     -  Thrown if a target hotkey is not a delegate and not owned by the caller.
+    ///
+    /// * 'NotEnoughStakeToWithdraw':
+    ///     -  Thrown if the caller has no stake to redistribute.
+    ///
+    /// * 'NomStakeBelowMinimumThreshold':
+    ///     -  Thrown if a resulting nomination is below the minimum.
+    ///
+    /// * 'UnbondingPeriodTooShort':
+    ///     -  Thrown if stake would move to a hotkey with a shorter unbonding period.
+    ///
+    /// * 'StakeRateLimitExceeded':
+    ///     -  Thrown if a changed hotkey has hit the stake rate limit for the caller.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     -  Thrown if the coldkey is frozen by the arbitration of its swap.
+    ///
+    pub fn do_rebalance_stake(
+        origin: T::RuntimeOrigin,
+        targets: Vec<(T::AccountId, u16)>,
+    ) -> DispatchResultWithPostInfo {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_rebalance_stake( origin:{:?} targets:{:?} )",
+            coldkey,
+            targets
+        );
+
+        // Ensure the coldkey is not frozen by the arbitration of its swap.
+        Self::ensure_coldkey_not_in_arbitration(&coldkey)?;
+
+        // Ensure the targets are unique and their proportions add up to one.
+        ensure!(!targets.is_empty(), Error::<T>::InvalidStakeProportions);
+        ensure!(
+            targets.len() <= MAX_REBALANCE_HOTKEYS,
+            Error::<T>::TooManyRebalanceHotkeys
+        );
+        let unique: BTreeSet<&T::AccountId> = targets.iter().map(|(hotkey, _)| hotkey).collect();
+        ensure!(
+            unique.len() == targets.len(),
+            Error::<T>::InvalidStakeProportions
+        );
+        let proportion_sum: u32 = targets.iter().fold(0, |acc, (_, proportion)| {
+            acc.saturating_add(*proportion as u32)
+        });
+        ensure!(
+            proportion_sum == u16::MAX as u32,
+            Error::<T>::InvalidStakeProportions
+        );
+
+        // Ensure every target accepts the caller's stake.
+        for (hotkey, _) in targets.iter() {
+            ensure!(
+                Self::hotkey_account_exists(hotkey),
+                Error::<T>::HotKeyAccountNotExists
+            );
+            ensure!(
+                Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(&coldkey, hotkey),
+                Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+            );
+        }
+
+        // Split the total stake of the coldkey according to the proportions. The rounding
+        // remainder goes to the target with the largest proportion.
+        let staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(&coldkey);
+        ensure!(
+            staking_hotkeys.len() <= MAX_REBALANCE_HOTKEYS,
+            Error::<T>::TooManyRebalanceHotkeys
+        );
+        let total_stake: u64 = staking_hotkeys
+            .iter()
+            .map(|hotkey| Self::get_stake_for_coldkey_and_hotkey(&coldkey, hotkey))
+            .fold(0, |acc, stake| acc.saturating_add(stake));
+        ensure!(total_stake > 0, Error::<T>::NotEnoughStakeToWithdraw);
+
+        let mut new_stakes: Vec<(T::AccountId, u64)> = targets
+            .iter()
+            .map(|(hotkey, proportion)| {
+                let share = (total_stake as u128)
+                    .saturating_mul(*proportion as u128)
+                    .checked_div(u16::MAX as u128)
+                    .unwrap_or(0);
+                (hotkey.clone(), share as u64)
+            })
+            .collect();
+        let allocated: u64 = new_stakes
+            .iter()
+            .fold(0, |acc, (_, stake)| acc.saturating_add(*stake));
+        let largest = targets
+            .iter()
+            .enumerate()
+            .max_by_key(|(index, (_, proportion))| (*proportion, sp_std::cmp::Reverse(*index)))
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        if let Some((_, stake)) = new_stakes.get_mut(largest) {
+            *stake = stake.saturating_add(total_stake.saturating_sub(allocated));
+        }
+
+        // Hotkeys currently staked to which are not targets are emptied.
+        for hotkey in staking_hotkeys.iter() {
+            if !unique.contains(hotkey) {
+                new_stakes.push((hotkey.clone(), 0));
+            }
+        }
+
+        // Collect the changes and validate them before touching storage.
+        let mut changes: Vec<(T::AccountId, u64, u64, u64)> = Vec::new();
+        for (hotkey, new_stake) in new_stakes.into_iter() {
+            let old_stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+            if old_stake == new_stake {
+                continue;
+            }
+            if new_stake > 0 {
+                Self::ensure_nomination_above_minimum(&coldkey, &hotkey, new_stake)?;
+            }
+            let stakes_this_interval =
+                Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
+            ensure!(
+                stakes_this_interval < Self::get_rate_limit(&TransactionType::Stake),
+                Error::<T>::StakeRateLimitExceeded
+            );
+            changes.push((hotkey, old_stake, new_stake, stakes_this_interval));
+        }
+
+        // Apply the decreases before the increases so the totals never overshoot.
+        changes.sort_by_key(|(_, old_stake, new_stake, _)| new_stake > old_stake);
+
+        // Ensure no stake skips the unbonding period of the hotkey it leaves.
+        for (from_hotkey, from_old, from_new, _) in changes.iter() {
+            if from_new >= from_old {
+                continue;
+            }
+            for (to_hotkey, to_old, to_new, _) in changes.iter() {
+                if to_new > to_old {
+                    Self::ensure_unbonding_period_kept(from_hotkey, to_hotkey)?;
+                }
+            }
+        }
+        let block: u64 = Self::get_current_block_as_u64();
+        for (hotkey, old_stake, new_stake, stakes_this_interval) in changes.iter() {
+            Self::apply_stake_change(&coldkey, hotkey, *old_stake, *new_stake);
+            if *new_stake == 0 {
+                Self::clear_small_nomination_if_required(hotkey, &coldkey, 0);
+            }
+
+            // The rebalance counts as a single operation on each changed hotkey.
+            Self::set_stakes_this_interval_for_coldkey_hotkey(
+                &coldkey,
+                hotkey,
+                stakes_this_interval.saturating_add(1),
+                block,
+            );
+        }
+        Self::set_last_tx_block(&coldkey, block);
+
+        let changes: Vec<(T::AccountId, u64, u64)> = changes
+            .into_iter()
+            .map(|(hotkey, old_stake, new_stake, _)| (hotkey, old_stake, new_stake))
+            .collect();
+        log::debug!(
+            "StakeRebalanced( coldkey:{:?}, changes:{:?} )",
+            coldkey,
+            changes
+        );
+        Self::deposit_event(Event::StakeRebalanced(coldkey, changes));
+
+        // Charge for the hotkeys actually read and written.
+        let hotkeys = targets.len().saturating_add(staking_hotkeys.len()) as u64;
+        let weight = Weight::from_parts(150_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads(hotkeys.saturating_mul(8)))
+            .saturating_add(T::DbWeight::get().writes(hotkeys.saturating_mul(8)));
+
+        // Ok and return.
+        Ok(Some(weight).into())
+    }
+}
@@ -17,6 +17,9 @@ impl<T: Config> Pallet<T> {
     /// * StakeRemoved;
     ///     -  On the successfully removing stake from the hotkey account.
     ///
+    /// * StakeUnbonding;
+    ///     -  If the hotkey is subject to an unbonding period and the stake enters the unbonding queue.
+    ///
     /// # Raises:
     /// * 'NotRegistered':
     ///     -  Thrown if the account we are attempting to unstake from is non existent.
@@ -30,6 +33,13 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded':
     ///     -  Thrown if key has hit transaction rate limit
     ///
+    /// * 'TooManyUnbondingEntries':
+    ///     -  Thrown if the stake would enter the unbonding queue and the queue of the coldkey is full
+    ///        without an entry of the hotkey.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     -  Thrown if the coldkey is frozen by the arbitration of its swap.
+    ///
     pub fn do_remove_stake(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
@@ -44,34 +54,11 @@ impl<T: Config> Pallet<T> {
             stake_to_be_removed
         );
 
-        // Ensure that the hotkey account exists this is only possible through registration.
-        ensure!(
-            Self::hotkey_account_exists(&hotkey),
-            Error::<T>::HotKeyAccountNotExists
-        );
-
-        // Ensure that the hotkey allows delegation or that the hotkey is owned by the calling coldkey.
-        ensure!(
-            Self::hotkey_is_delegate(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
-            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
-        );
-
-        // Ensure that the stake amount to be removed is above zero.
-        ensure!(stake_to_be_removed > 0, Error::<T>::StakeToWithdrawIsZero);
-
-        // Ensure that the hotkey has enough stake to withdraw.
-        ensure!(
-            Self::has_enough_stake(&coldkey, &hotkey, stake_to_be_removed),
-            Error::<T>::NotEnoughStakeToWithdraw
-        );
-
-        // Ensure we don't exceed stake rate limit
+        // Ensure the coldkey can unstake from the hotkey.
+        Self::remove_stake_checks(&mut DispatchChecks, &coldkey, &hotkey, stake_to_be_removed)?;
         let unstakes_this_interval =
             Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
-        ensure!(
-            unstakes_this_interval < Self::get_target_stakes_per_interval(),
-            Error::<T>::UnstakeRateLimitExceeded
-        );
+        let unbonding_period = Self::get_unbonding_period_for_hotkey(&hotkey);
 
         // We remove the balance from the hotkey.
         Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
@@ -82,7 +69,12 @@ impl<T: Config> Pallet<T> {
         });
 
         // We add the balance to the coldkey.  If the above fails we will not credit this coldkey.
-        Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_removed);
+        // If the hotkey is subject to an unbonding period the balance is credited once released.
+        if unbonding_period > 0 {
+            Self::queue_unbonding_stake(&coldkey, &hotkey, stake_to_be_removed, unbonding_period)?;
+        } else {
+            Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_removed);
+        }
 
         // If the stake is below the minimum, we clear the nomination from storage.
         // This only applies to nominator stakes.
@@ -90,6 +82,9 @@ impl<T: Config> Pallet<T> {
         let new_stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
         Self::clear_small_nomination_if_required(&hotkey, &coldkey, new_stake);
 
+        // Drop the reward ledger of a coldkey which has removed all of its stake.
+        Self::clear_nominator_rewards_if_unstaked(&coldkey);
+
         // Set last block for rate limiting
         let block: u64 = Self::get_current_block_as_u64();
         Self::set_last_tx_block(&coldkey, block);
@@ -102,13 +97,71 @@ impl<T: Config> Pallet<T> {
             block,
         );
         log::debug!(
-            "StakeRemoved( hotkey:{:?}, stake_to_be_removed:{:?} )",
+            "StakeRemoved( coldkey:{:?}, hotkey:{:?}, stake_to_be_removed:{:?} )",
+            coldkey,
             hotkey,
             stake_to_be_removed
         );
-        Self::deposit_event(Event::StakeRemoved(hotkey, stake_to_be_removed));
+        Self::deposit_event(Event::StakeRemoved(hotkey.clone(), stake_to_be_removed));
+        Self::deposit_event(Event::ColdkeyStakeRemoved(
+            coldkey,
+            hotkey,
+            stake_to_be_removed,
+        ));
 
         // Done and ok.
         Ok(())
     }
+
+    /// Checks `coldkey` unstaking `stake_to_be_removed` from `hotkey` before the stake is removed.
+    /// Shared by `do_remove_stake` and the pre-dispatch check, see `CallChecks`.
+    pub fn remove_stake_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        stake_to_be_removed: u64,
+    ) -> Result<(), Error<T>> {
+        // Ensure the coldkey is not frozen by the arbitration of its swap.
+        checks.ensure(
+            !Self::coldkey_in_arbitration(coldkey),
+            Error::<T>::ColdkeyIsInArbitration,
+        )?;
+
+        // Ensure that the hotkey account exists this is only possible through registration.
+        checks.require(
+            Self::hotkey_account_exists(hotkey),
+            Error::<T>::HotKeyAccountNotExists,
+        )?;
+
+        // Ensure that the hotkey allows delegation or that the hotkey is owned by the calling coldkey.
+        checks.require(
+            Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey,
+        )?;
+
+        // Ensure that the stake amount to be removed is above zero.
+        checks.ensure(stake_to_be_removed > 0, Error::<T>::StakeToWithdrawIsZero)?;
+
+        // Ensure that the hotkey has enough stake to withdraw.
+        checks.ensure(
+            Self::has_enough_stake(coldkey, hotkey, stake_to_be_removed),
+            Error::<T>::NotEnoughStakeToWithdraw,
+        )?;
+
+        // Ensure we don't exceed stake rate limit
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_stake_allowed(coldkey, hotkey),
+            Error::<T>::UnstakeRateLimitExceeded,
+        )?;
+
+        // Ensure there is room in the unbonding queue if the stake has to unbond.
+        if Self::get_unbonding_period_for_hotkey(hotkey) > 0 {
+            checks.ensure(
+                Self::can_queue_unbonding_stake(coldkey, hotkey),
+                Error::<T>::TooManyUnbondingEntries,
+            )?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,154 @@
+use super::*;
+use frame_support::traits::tokens::{fungible::Mutate as _, Precision};
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_reward_destination: Sets where the rewards of
+    /// the nomination of the calling coldkey on a hotkey are paid.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey the rewards are earned from.
+    ///
+    /// * 'destination' (RewardDestination<T::AccountId>):
+    ///     -  Where the rewards are paid: restaked, to the free balance or staked on another hotkey.
+    ///
+    /// # Event:
+    /// * RewardDestinationSet;
+    ///     -  On successfully setting the reward destination.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if the hotkey or the destination hotkey does not exist.
+    ///
+    /// * 'InvalidRewardDestination':
+    ///     -  Thrown if the destination hotkey is the hotkey the rewards are earned from.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     -  Thrown if the destination hotkey is not a delegate and not owned by the caller.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     -  Thrown if the coldkey is frozen by the arbitration of its swap.
+    ///
+    pub fn do_set_reward_destination(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        destination: RewardDestination<T::AccountId>,
+    ) -> dispatch::DispatchResult {
+        // We check that the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_reward_destination( origin:{:?} hotkey:{:?}, destination:{:?} )",
+            coldkey,
+            hotkey,
+            destination
+        );
+
+        // Ensure the coldkey is not frozen by the arbitration of its swap.
+        Self::ensure_coldkey_not_in_arbitration(&coldkey)?;
+
+        // Ensure that the hotkey account exists.
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        // Ensure the destination hotkey accepts stake from the coldkey.
+        if let RewardDestination::Hotkey(destination_hotkey) = &destination {
+            ensure!(
+                *destination_hotkey != hotkey,
+                Error::<T>::InvalidRewardDestination
+            );
+            ensure!(
+                Self::hotkey_account_exists(destination_hotkey),
+                Error::<T>::HotKeyAccountNotExists
+            );
+            ensure!(
+                Self::hotkey_is_delegate(destination_hotkey)
+                    || Self::coldkey_owns_hotkey(&coldkey, destination_hotkey),
+                Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+            );
+        }
+
+        // Restaking is the default and is not stored.
+        if destination == RewardDestination::Restake {
+            RewardDestinations::<T>::remove(&coldkey, &hotkey);
+        } else {
+            RewardDestinations::<T>::insert(&coldkey, &hotkey, destination.clone());
+        }
+
+        log::debug!(
+            "RewardDestinationSet( coldkey:{:?}, hotkey:{:?}, destination:{:?} )",
+            coldkey,
+            hotkey,
+            destination
+        );
+        Self::deposit_event(Event::RewardDestinationSet(coldkey, hotkey, destination));
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// Returns where the rewards of the nomination of `coldkey` on `hotkey` are paid.
+    pub fn get_reward_destination(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+    ) -> RewardDestination<T::AccountId> {
+        RewardDestinations::<T>::get(coldkey, hotkey)
+    }
+
+    /// Pays the reward `coldkey` earned from the emission drain of `hotkey` to the reward
+    /// destination of the nomination. Returns the hotkey the reward was staked on, or `None` if
+    /// it was paid to the free balance.
+    ///
+    /// Rewards are restaked on `hotkey`, emitting `NominatorRewardRestaked`, if the destination
+    /// hotkey no longer accepts stake from the coldkey, or if the reward cannot be deposited to
+    /// the free balance, e.g. because it is below the existential deposit of an empty account.
+    pub fn pay_nominator_reward(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        reward: u64,
+    ) -> Option<T::AccountId> {
+        match Self::get_reward_destination(coldkey, hotkey) {
+            RewardDestination::Free => {
+                if reward == 0 || T::Currency::deposit(coldkey, reward, Precision::Exact).is_ok() {
+                    return None;
+                }
+            }
+            RewardDestination::Hotkey(destination_hotkey) => {
+                if Self::hotkey_account_exists(&destination_hotkey)
+                    && (Self::hotkey_is_delegate(&destination_hotkey)
+                        || Self::coldkey_owns_hotkey(coldkey, &destination_hotkey))
+                {
+                    Self::increase_stake_on_coldkey_hotkey_account(
+                        coldkey,
+                        &destination_hotkey,
+                        reward,
+                    );
+                    return Some(destination_hotkey);
+                }
+            }
+            RewardDestination::Restake => {
+                Self::increase_stake_on_coldkey_hotkey_account(coldkey, hotkey, reward);
+                return Some(hotkey.clone());
+            }
+        }
+
+        // The destination cannot receive the reward, which is restaked instead.
+        Self::increase_stake_on_coldkey_hotkey_account(coldkey, hotkey, reward);
+        log::debug!(
+            "NominatorRewardRestaked( coldkey:{:?}, hotkey:{:?}, reward:{:?} )",
+            coldkey,
+            hotkey,
+            reward
+        );
+        Self::deposit_event(Event::NominatorRewardRestaked(
+            coldkey.clone(),
+            hotkey.clone(),
+            reward,
+        ));
+        Some(hotkey.clone())
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
 
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic do_set_child_singular: Sets a single child.
@@ -31,6 +33,8 @@ impl<T: Config> Pallet<T> {
     ///     - The coldkey does not own the hotkey or the child is the same as the hotkey.
     /// * `HotKeyAccountNotExists`:
     ///     - The hotkey account does not exist.
+    /// * `ChildkeyGraphTooLarge`:
+    ///     - Validating the children would read more than `MAX_CHILDKEY_GRAPH_VISITS` hotkeys.
     ///
     /// # Detailed Explanation of Checks:
     /// 1. **Signature Verification**: Ensures that the caller has signed the transaction, verifying the coldkey.
@@ -47,7 +51,7 @@ impl<T: Config> Pallet<T> {
         hotkey: T::AccountId,
         netuid: u16,
         children: Vec<(u64, T::AccountId)>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         // --- 1. Check that the caller has signed the transaction. (the coldkey of the pairing)
         let coldkey = ensure_signed(origin)?;
         log::trace!(
@@ -58,15 +62,8 @@ impl<T: Config> Pallet<T> {
             children
         );
 
-        // Ensure the hotkey passes the rate limit.
-        ensure!(
-            Self::passes_rate_limit_on_subnet(
-                &TransactionType::SetChildren, // Set children.
-                &hotkey,                       // Specific to a hotkey.
-                netuid,                        // Specific to a subnet.
-            ),
-            Error::<T>::TxRateLimitExceeded
-        );
+        // --- 2. Check the rate limit and that the children are valid for the hotkey on this network.
+        Self::children_checks(&mut DispatchChecks, &coldkey, &hotkey, netuid, &children)?;
 
         // Set last transaction block
         let current_block = Self::get_current_block_as_u64();
@@ -77,94 +74,351 @@ impl<T: Config> Pallet<T> {
             current_block,
         );
 
-        // --- 2. Check that this delegation is not on the root network. Child hotkeys are not valid on root.
-        ensure!(
-            netuid != Self::get_root_netuid(),
-            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        // --- 3. Ensure the children do not form a cycle or too long a chain of child keys.
+        let mut weight: Weight = Weight::from_parts(119_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(31));
+        Self::ensure_valid_childkey_graph(&hotkey, netuid, &children, &mut weight)?;
+
+        // --- 4. Replace the children, dropping any scheduled change or expiry of the old ones.
+        Self::set_children_unchecked(&hotkey, netuid, &children);
+        PendingChildKeys::<T>::remove(netuid, &hotkey);
+        ChildKeyExpiries::<T>::remove(netuid, &hotkey);
+
+        // --- 5. Log and return.
+        log::trace!(
+            "SetChildren( netuid:{:?}, hotkey:{:?}, children:{:?} )",
+            hotkey,
+            netuid,
+            children.clone()
         );
+        Self::deposit_event(Event::SetChildren(hotkey.clone(), netuid, children.clone()));
 
-        // --- 3. Check that the network we are trying to create the child on exists.
-        ensure!(
-            Self::if_subnet_exist(netuid),
-            Error::<T>::SubNetworkDoesNotExist
+        // Ok and return.
+        Ok(Some(weight).into())
+    }
+
+    /// ---- The implementation for the extrinsic schedule_children: Schedules the children of a
+    /// hotkey to be replaced at the next epoch of the subnet, optionally expiring each child.
+    ///
+    /// # Arguments:
+    /// * `origin` (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the calling coldkey owning the hotkey.
+    ///
+    /// * `hotkey` (T::AccountId):
+    ///     - The hotkey which will be assigned the children.
+    ///
+    /// * `netuid` (u16):
+    ///     - The u16 network identifier where the child keys will exist.
+    ///
+    /// * `children` Vec[(u64, T::AccountId, Option<u64>)]:
+    ///     - A list of children with their proportions and the optional block from which each
+    ///       child is removed at the next epoch of the subnet.
+    ///
+    /// # Events:
+    /// * `ChildrenScheduled`:
+    ///     - On successfully scheduling the children.
+    ///
+    /// # Errors:
+    /// * `TxRateLimitExceeded`:
+    ///     - The children of the hotkey were set or scheduled within the rate limit.
+    /// * `SubnetEpochNeverRuns`:
+    ///     - The subnet has a tempo of zero and never applies the scheduled children.
+    /// * `InvalidChildkeyExpiry`:
+    ///     - An expiry is not after the epoch applying the children.
+    /// * Any error of `children_checks` or `ensure_valid_childkey_graph`.
+    ///
+    pub fn do_schedule_children(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+        children: Vec<(u64, T::AccountId, Option<u64>)>,
+    ) -> DispatchResultWithPostInfo {
+        // --- 1. Check that the caller has signed the transaction.
+        let coldkey = ensure_signed(origin)?;
+        log::trace!(
+            "do_schedule_children( coldkey:{:?} hotkey:{:?} netuid:{:?} children:{:?} )",
+            coldkey,
+            hotkey,
+            netuid,
+            children
         );
 
-        // --- 4. Check that the coldkey owns the hotkey.
-        ensure!(
-            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
-            Error::<T>::NonAssociatedColdKey
+        // --- 2. Ensure the hotkey passes the rate limit shared with setting children and the
+        // children are valid for the hotkey on this network.
+        let proportions: Vec<(u64, T::AccountId)> = children
+            .iter()
+            .map(|(proportion, child, _)| (*proportion, child.clone()))
+            .collect();
+        Self::children_checks(&mut DispatchChecks, &coldkey, &hotkey, netuid, &proportions)?;
+        let current_block = Self::get_current_block_as_u64();
+        Self::set_last_transaction_block(
+            &hotkey,
+            netuid,
+            &TransactionType::SetChildren,
+            current_block,
         );
 
-        // --- 4.1. Ensure that the number of children does not exceed 5.
-        ensure!(children.len() <= 5, Error::<T>::TooManyChildren);
+        // --- 3. Ensure the children do not form a cycle or too long a chain of child keys.
+        let mut weight: Weight = Weight::from_parts(119_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(2));
+        Self::ensure_valid_childkey_graph(&hotkey, netuid, &proportions, &mut weight)?;
 
-        // --- 5. Ensure that each child is not the hotkey.
-        for (_, child_i) in &children {
-            ensure!(child_i != &hotkey, Error::<T>::InvalidChild);
+        // --- 4. Ensure the children apply at the next epoch and expire after it.
+        let apply_block = Self::get_next_epoch_block(netuid, current_block)
+            .ok_or(Error::<T>::SubnetEpochNeverRuns)?;
+        for (_, _, expiry) in &children {
+            if let Some(expiry_block) = expiry {
+                ensure!(
+                    *expiry_block > apply_block,
+                    Error::<T>::InvalidChildkeyExpiry
+                );
+            }
         }
-        // --- 5.1. Ensure that the sum of the proportions does not exceed u64::MAX.
-        let _total_proportion: u64 = children
-            .iter()
-            .try_fold(0u64, |acc, &(proportion, _)| acc.checked_add(proportion))
-            .ok_or(Error::<T>::ProportionOverflow)?;
 
-        // --- 5.2. Ensure there are no duplicates in the list of children.
-        let mut unique_children = Vec::new();
-        for (_, child_i) in &children {
-            ensure!(
-                !unique_children.contains(child_i),
-                Error::<T>::DuplicateChild
+        // --- 5. Schedule the children, replacing any previously scheduled change.
+        PendingChildKeys::<T>::insert(netuid, &hotkey, children.clone());
+
+        // --- 6. Log and return.
+        log::trace!(
+            "ChildrenScheduled( hotkey:{:?}, netuid:{:?}, children:{:?}, apply_block:{:?} )",
+            hotkey,
+            netuid,
+            children,
+            apply_block
+        );
+        Self::deposit_event(Event::ChildrenScheduled(
+            hotkey,
+            netuid,
+            children,
+            apply_block,
+        ));
+
+        // Ok and return.
+        Ok(Some(weight).into())
+    }
+
+    /// Applies the child key changes of the subnet due at its epoch on `current_block`: children
+    /// whose expiry block has been reached are removed, then the scheduled children are set.
+    ///
+    /// The weight of reading and writing the expiries, the scheduled children and the child keys,
+    /// and of validating the child key graph for the scheduled children, is added to `weight`.
+    pub fn apply_scheduled_children(netuid: u16, current_block: u64, weight: &mut Weight) {
+        // --- 1. Remove the expired children.
+        let expiries: Vec<(T::AccountId, Vec<(T::AccountId, u64)>)> =
+            ChildKeyExpiries::<T>::iter_prefix(netuid).collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(expiries.len() as u64));
+        for (hotkey, child_expiries) in expiries {
+            let (expired, remaining): (Vec<_>, Vec<_>) = child_expiries
+                .into_iter()
+                .partition(|(_, expiry_block)| *expiry_block <= current_block);
+            if expired.is_empty() {
+                continue;
+            }
+
+            let old_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(&hotkey, netuid);
+            let old_children_len = old_children.len();
+            let children: Vec<(u64, T::AccountId)> = old_children
+                .into_iter()
+                .filter(|(_, child)| {
+                    !expired
+                        .iter()
+                        .any(|(expired_child, _)| expired_child == child)
+                })
+                .collect();
+            Self::set_children_unchecked(&hotkey, netuid, &children);
+
+            // The children are read, replaced and their expiries written.
+            weight.saturating_accrue(Self::set_children_unchecked_weight(
+                old_children_len,
+                children.len(),
+            ));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            if remaining.is_empty() {
+                ChildKeyExpiries::<T>::remove(netuid, &hotkey);
+            } else {
+                ChildKeyExpiries::<T>::insert(netuid, &hotkey, remaining);
+            }
+
+            for (child, _) in expired {
+                log::trace!(
+                    "ChildKeyExpired( hotkey:{:?}, netuid:{:?}, child:{:?} )",
+                    hotkey,
+                    netuid,
+                    child
+                );
+                Self::deposit_event(Event::ChildKeyExpired(hotkey.clone(), netuid, child));
+            }
+        }
+
+        // --- 2. Set the scheduled children and record their expiries.
+        for (hotkey, scheduled) in PendingChildKeys::<T>::drain_prefix(netuid) {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            let children: Vec<(u64, T::AccountId)> = scheduled
+                .iter()
+                .map(|(proportion, child, _)| (*proportion, child.clone()))
+                .collect();
+            let child_expiries: Vec<(T::AccountId, u64)> = scheduled
+                .into_iter()
+                .filter_map(|(_, child, expiry)| expiry.map(|expiry_block| (child, expiry_block)))
+                .collect();
+
+            // Children scheduled together may have formed a cycle or too long a chain since.
+            if let Err(error) =
+                Self::ensure_valid_childkey_graph(&hotkey, netuid, &children, weight)
+            {
+                log::trace!(
+                    "ScheduledChildrenDropped( hotkey:{:?}, netuid:{:?}, error:{:?} )",
+                    hotkey,
+                    netuid,
+                    error
+                );
+                Self::deposit_event(Event::ScheduledChildrenDropped(hotkey, netuid, error));
+                continue;
+            }
+
+            let old_children_len = ChildKeys::<T>::decode_len(&hotkey, netuid).unwrap_or(0);
+            Self::set_children_unchecked(&hotkey, netuid, &children);
+
+            // The children are read, replaced and their expiries written.
+            weight.saturating_accrue(Self::set_children_unchecked_weight(
+                old_children_len,
+                children.len(),
+            ));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            if child_expiries.is_empty() {
+                ChildKeyExpiries::<T>::remove(netuid, &hotkey);
+            } else {
+                ChildKeyExpiries::<T>::insert(netuid, &hotkey, child_expiries);
+            }
+
+            log::trace!(
+                "SetChildren( netuid:{:?}, hotkey:{:?}, children:{:?} )",
+                hotkey,
+                netuid,
+                children
             );
+            Self::deposit_event(Event::SetChildren(hotkey, netuid, children));
+        }
+    }
+
+    /// Checks the children can be set for the hotkey of the coldkey on the network: the hotkey
+    /// passes the rate limit of setting children, the network is not root and exists, the
+    /// coldkey owns the hotkey and there are at most 5 distinct children other than the hotkey
+    /// whose proportions do not overflow. The child key graph is checked separately by
+    /// `ensure_valid_childkey_graph`, as walking it has a weight.
+    /// Shared by the children dispatchables and the pre-dispatch check, see `CallChecks`.
+    pub fn children_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: u16,
+        children: &[(u64, T::AccountId)],
+    ) -> Result<(), Error<T>> {
+        // Ensure the hotkey passes the rate limit.
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_allowed(&TransactionType::SetChildren, hotkey, netuid),
+            Error::<T>::TxRateLimitExceeded,
+        )?;
+
+        // --- 1. Check that this delegation is not on the root network. Child hotkeys are not valid on root.
+        checks.require(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet,
+        )?;
+
+        // --- 2. Check that the network we are trying to create the child on exists.
+        checks.require(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist,
+        )?;
+
+        // --- 3. Check that the coldkey owns the hotkey.
+        checks.ensure(
+            Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::NonAssociatedColdKey,
+        )?;
+
+        // --- 3.1. Ensure that the number of children does not exceed 5.
+        checks.ensure(children.len() <= 5, Error::<T>::TooManyChildren)?;
+
+        // --- 4. Ensure that each child is not the hotkey.
+        checks.ensure(
+            children.iter().all(|(_, child_i)| child_i != hotkey),
+            Error::<T>::InvalidChild,
+        )?;
+
+        // --- 4.1. Ensure that the sum of the proportions does not exceed u64::MAX.
+        checks.ensure(
+            children
+                .iter()
+                .try_fold(0u64, |acc, &(proportion, _)| acc.checked_add(proportion))
+                .is_some(),
+            Error::<T>::ProportionOverflow,
+        )?;
+
+        // --- 4.2. Ensure there are no duplicates in the list of children.
+        let mut unique_children = Vec::new();
+        let mut has_duplicates = false;
+        for (_, child_i) in children {
+            has_duplicates = has_duplicates || unique_children.contains(child_i);
             unique_children.push(child_i.clone());
         }
+        checks.ensure(!has_duplicates, Error::<T>::DuplicateChild)
+    }
 
-        // --- 6. Erase myself from old children's parents.
-        let old_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(hotkey.clone(), netuid);
+    /// Replaces the children of the hotkey on the network, updating the parents of its old and
+    /// new children. The children must have been checked with `children_checks` and
+    /// `ensure_valid_childkey_graph`.
+    pub fn set_children_unchecked(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        children: &[(u64, T::AccountId)],
+    ) {
+        // --- 1. Erase myself from old children's parents.
+        let old_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(hotkey, netuid);
 
-        // --- 6.0. Iterate over all my old children and remove myself from their parent's map.
+        // --- 1.0. Iterate over all my old children and remove myself from their parent's map.
         for (_, old_child_i) in old_children.clone().iter() {
-            // --- 6.1. Get the old child's parents on this network.
+            // --- 1.1. Get the old child's parents on this network.
             let my_old_child_parents: Vec<(u64, T::AccountId)> =
                 ParentKeys::<T>::get(old_child_i.clone(), netuid);
 
-            // --- 6.2. Filter my hotkey from my old children's parents list.
+            // --- 1.2. Filter my hotkey from my old children's parents list.
             let filtered_parents: Vec<(u64, T::AccountId)> = my_old_child_parents
                 .into_iter()
-                .filter(|(_, parent)| *parent != hotkey)
+                .filter(|(_, parent)| parent != hotkey)
                 .collect();
 
-            // --- 6.3. Update the parent list in storage
+            // --- 1.3. Update the parent list in storage
             ParentKeys::<T>::insert(old_child_i, netuid, filtered_parents);
         }
 
-        // --- 7.1. Insert my new children + proportion list into the map.
-        ChildKeys::<T>::insert(hotkey.clone(), netuid, children.clone());
+        // --- 2.1. Insert my new children + proportion list into the map.
+        ChildKeys::<T>::insert(hotkey, netuid, children.to_vec());
 
-        // --- 7.2. Update the parents list for my new children.
-        for (proportion, new_child_i) in children.clone().iter() {
-            // --- 8.2.1. Get the child's parents on this network.
+        // --- 2.2. Update the parents list for my new children.
+        for (proportion, new_child_i) in children.iter() {
+            // --- 2.2.1. Get the child's parents on this network.
             let mut new_child_previous_parents: Vec<(u64, T::AccountId)> =
                 ParentKeys::<T>::get(new_child_i.clone(), netuid);
 
-            // --- 7.2.2. Append my hotkey and proportion to my new child's parents list.
+            // --- 2.2.2. Append my hotkey and proportion to my new child's parents list.
             // NOTE: There are no duplicates possible because I previously removed my self from my old children.
             new_child_previous_parents.push((*proportion, hotkey.clone()));
 
-            // --- 7.2.3. Update the parents list in storage.
+            // --- 2.2.3. Update the parents list in storage.
             ParentKeys::<T>::insert(new_child_i.clone(), netuid, new_child_previous_parents);
         }
+    }
 
-        // --- 8. Log and return.
-        log::trace!(
-            "SetChildren( netuid:{:?}, hotkey:{:?}, children:{:?} )",
-            hotkey,
-            netuid,
-            children.clone()
-        );
-        Self::deposit_event(Event::SetChildren(hotkey.clone(), netuid, children.clone()));
-
-        // Ok and return.
-        Ok(())
+    /// The weight of `set_children_unchecked` replacing `old_children` children with
+    /// `new_children` children: the children are read and written, as are the parents of each
+    /// old and new child.
+    fn set_children_unchecked_weight(old_children: usize, new_children: usize) -> Weight {
+        let parents = old_children.saturating_add(new_children) as u64;
+        T::DbWeight::get().reads_writes(parents.saturating_add(1), parents.saturating_add(1))
     }
 
     /* Retrieves the list of children for a given hotkey and network.
@@ -234,27 +488,9 @@ impl<T: Config> Pallet<T> {
         netuid: u16,
         take: u16,
     ) -> DispatchResult {
-        // Ensure the coldkey owns the hotkey
-        ensure!(
-            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
-            Error::<T>::NonAssociatedColdKey
-        );
-
-        // Ensure the take value is valid
-        ensure!(
-            take <= Self::get_max_childkey_take(),
-            Error::<T>::InvalidChildkeyTake
-        );
-
-        // Ensure the hotkey passes the rate limit.
-        ensure!(
-            Self::passes_rate_limit_on_subnet(
-                &TransactionType::SetChildkeyTake, // Set childkey take.
-                &hotkey,                           // Specific to a hotkey.
-                netuid,                            // Specific to a subnet.
-            ),
-            Error::<T>::TxChildkeyTakeRateLimitExceeded
-        );
+        // Ensure the coldkey owns the hotkey, the take value is valid and the hotkey passes the
+        // rate limit.
+        Self::childkey_take_checks(&mut DispatchChecks, &coldkey, &hotkey, netuid, take)?;
 
         // Set last transaction block
         let current_block = Self::get_current_block_as_u64();
@@ -286,6 +522,35 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Checks `coldkey` setting the childkey take of `hotkey` on the network to `take` before
+    /// the take is set.
+    /// Shared by `do_set_childkey_take` and the pre-dispatch check, see `CallChecks`.
+    pub fn childkey_take_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: u16,
+        take: u16,
+    ) -> Result<(), Error<T>> {
+        // Ensure the coldkey owns the hotkey
+        checks.ensure(
+            Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::NonAssociatedColdKey,
+        )?;
+
+        // Ensure the take value is valid
+        checks.ensure(
+            take <= Self::get_max_childkey_take(),
+            Error::<T>::InvalidChildkeyTake,
+        )?;
+
+        // Ensure the hotkey passes the rate limit.
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_allowed(&TransactionType::SetChildkeyTake, hotkey, netuid),
+            Error::<T>::TxChildkeyTakeRateLimitExceeded,
+        )
+    }
+
     /// Gets the childkey take for a given hotkey.
     ///
     /// This function retrieves the current childkey take value for a specified hotkey.
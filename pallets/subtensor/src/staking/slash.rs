@@ -0,0 +1,203 @@
+use super::*;
+use frame_support::traits::tokens::{fungible::Mutate as _, Precision};
+use frame_support::traits::EnsureOrigin;
+use frame_support::weights::Weight;
+use frame_system::ensure_root;
+use sp_core::Get;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic slash_hotkey: Removes a proportion of the stake
+    /// on a hotkey and burns it or pays it to an account.
+    ///
+    /// The stake still unbonding from the hotkey is slashed by the same proportion, and the
+    /// nominations left below the minimum are cleared.
+    ///
+    /// At most MAX_SLASHED_STAKERS coldkeys staking on the hotkey, and as many unbonding from it,
+    /// are slashed. Returns the actual weight of the slash.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  Root or a council majority.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey to slash.
+    ///
+    /// * 'proportion' (u16):
+    ///     -  The proportion of the stake to slash, normalized over u16::MAX.
+    ///
+    /// * 'include_nominators' (bool):
+    ///     -  Whether the stake of the nominators is slashed, otherwise only the owner's stake is.
+    ///
+    /// * 'destination' (SlashDestination<T::AccountId>):
+    ///     -  Where the slashed stake goes.
+    ///
+    /// # Event:
+    /// * Slashed;
+    ///     -  On successfully slashing the hotkey.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if the hotkey does not exist.
+    ///
+    /// * 'InvalidSlashProportion':
+    ///     -  Thrown if the proportion is zero.
+    ///
+    /// * 'TooManySlashedStakers':
+    ///     -  Thrown if more than MAX_SLASHED_STAKERS coldkeys stake on or unbond from the hotkey.
+    ///
+    /// * 'CannotDepositSlashedStake':
+    ///     -  Thrown if the slashed stake cannot be paid to the destination account.
+    ///
+    pub fn do_slash_hotkey(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        proportion: u16,
+        include_nominators: bool,
+        destination: SlashDestination<T::AccountId>,
+    ) -> DispatchResultWithPostInfo {
+        // Ensure the origin is root or a council majority.
+        if ensure_root(origin.clone()).is_err() {
+            T::CouncilOrigin::ensure_origin(origin)?;
+        }
+        log::debug!(
+            "do_slash_hotkey( hotkey:{:?}, proportion:{:?}, include_nominators:{:?}, destination:{:?} )",
+            hotkey,
+            proportion,
+            include_nominators,
+            destination
+        );
+
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(proportion > 0, Error::<T>::InvalidSlashProportion);
+
+        let owner: T::AccountId = Self::get_owning_coldkey_for_hotkey(&hotkey);
+        let mut slashed: Vec<(T::AccountId, u64)> = Vec::new();
+
+        // Collect the coldkeys unbonding from and staking on the hotkey, at most
+        // MAX_SLASHED_STAKERS of each so the slash fits its declared weight.
+        let (unbonding_coldkeys, stakes): (Vec<T::AccountId>, Vec<(T::AccountId, u64)>) =
+            if include_nominators {
+                (
+                    UnbondingColdkeys::<T>::iter_key_prefix(&hotkey)
+                        .take(MAX_SLASHED_STAKERS.saturating_add(1))
+                        .collect(),
+                    Stake::<T>::iter_prefix(&hotkey)
+                        .take(MAX_SLASHED_STAKERS.saturating_add(1))
+                        .collect(),
+                )
+            } else {
+                let unbonding_coldkeys = if UnbondingColdkeys::<T>::get(&hotkey, &owner) {
+                    vec![owner.clone()]
+                } else {
+                    Vec::new()
+                };
+                let stake: u64 = Stake::<T>::get(&hotkey, &owner);
+                (unbonding_coldkeys, vec![(owner.clone(), stake)])
+            };
+        ensure!(
+            unbonding_coldkeys.len() <= MAX_SLASHED_STAKERS && stakes.len() <= MAX_SLASHED_STAKERS,
+            Error::<T>::TooManySlashedStakers
+        );
+        let weight: Weight =
+            Self::get_slash_hotkey_weight(unbonding_coldkeys.len() as u64, stakes.len() as u64);
+
+        // Slash the stake still unbonding from the hotkey, so removing stake does not escape the
+        // slash. This runs first so the nominations cleared below are not slashed twice.
+        for coldkey in unbonding_coldkeys {
+            let mut queue: Vec<UnbondingEntry<T::AccountId>> = UnbondingQueue::<T>::get(&coldkey);
+            let mut amount: u64 = 0;
+            for entry in queue.iter_mut().filter(|entry| entry.hotkey == hotkey) {
+                let entry_slash: u64 = Self::get_slash_amount(entry.amount, proportion);
+                entry.amount = entry.amount.saturating_sub(entry_slash);
+                amount = amount.saturating_add(entry_slash);
+            }
+            if amount == 0 {
+                continue;
+            }
+            queue.retain(|entry| entry.amount > 0);
+            if !queue.iter().any(|entry| entry.hotkey == hotkey) {
+                UnbondingColdkeys::<T>::remove(&hotkey, &coldkey);
+            }
+            if queue.is_empty() {
+                UnbondingQueue::<T>::remove(&coldkey);
+            } else {
+                UnbondingQueue::<T>::insert(&coldkey, queue);
+            }
+            TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(amount));
+            Self::record_slash(&mut slashed, coldkey, amount);
+        }
+
+        // Remove the slashed stake from the owner and, if included, the nominators.
+        for (coldkey, stake) in stakes {
+            let amount: u64 = Self::get_slash_amount(stake, proportion);
+            if amount == 0 {
+                continue;
+            }
+            let new_stake: u64 = stake.saturating_sub(amount);
+            Self::apply_stake_change(&coldkey, &hotkey, stake, new_stake);
+            Self::clear_small_nomination_if_required(&hotkey, &coldkey, new_stake);
+            Self::record_slash(&mut slashed, coldkey, amount);
+        }
+        let total_slashed: u64 = slashed
+            .iter()
+            .fold(0, |acc, (_, amount)| acc.saturating_add(*amount));
+
+        // Burn the slashed stake or pay it to the destination account.
+        match &destination {
+            SlashDestination::Burn => Self::burn_tokens(total_slashed),
+            SlashDestination::Account(account) => {
+                if total_slashed > 0 {
+                    T::Currency::deposit(account, total_slashed, Precision::Exact)
+                        .map_err(|_| Error::<T>::CannotDepositSlashedStake)?;
+                }
+            }
+        }
+
+        log::debug!(
+            "Slashed( hotkey:{:?}, total_slashed:{:?}, destination:{:?} )",
+            hotkey,
+            total_slashed,
+            destination
+        );
+        Self::deposit_event(Event::Slashed(hotkey, slashed, destination));
+
+        // Ok and return.
+        Ok(Some(weight).into())
+    }
+
+    /// Returns the weight of slashing a hotkey with `unbonding_coldkeys` coldkeys unbonding from
+    /// it and `stakes` coldkeys staking on it.
+    pub fn get_slash_hotkey_weight(unbonding_coldkeys: u64, stakes: u64) -> Weight {
+        Weight::from_parts(500_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(5, 2))
+            .saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(2, 3)
+                    .saturating_mul(unbonding_coldkeys),
+            )
+            .saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(10, 9)
+                    .saturating_mul(stakes),
+            )
+    }
+
+    /// Adds `amount` to the stake slashed from `coldkey`.
+    fn record_slash(slashed: &mut Vec<(T::AccountId, u64)>, coldkey: T::AccountId, amount: u64) {
+        match slashed.iter_mut().find(|(account, _)| *account == coldkey) {
+            Some((_, total)) => *total = total.saturating_add(amount),
+            None => slashed.push((coldkey, amount)),
+        }
+    }
+
+    /// Returns the part of `stake` slashed for a proportion normalized over u16::MAX.
+    pub fn get_slash_amount(stake: u64, proportion: u16) -> u64 {
+        (stake as u128)
+            .saturating_mul(proportion as u128)
+            .checked_div(u16::MAX as u128)
+            .unwrap_or(0) as u64
+    }
+}
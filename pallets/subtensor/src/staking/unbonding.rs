@@ -0,0 +1,331 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+
+impl<T: Config> Pallet<T> {
+    /// Returns the unbonding period applying to stake removed from `hotkey`. Stake is not held
+    /// per subnet, so the longest unbonding period of the subnets the hotkey is registered on
+    /// applies. A period of zero means removed stake is released immediately.
+    pub fn get_unbonding_period_for_hotkey(hotkey: &T::AccountId) -> u64 {
+        IsNetworkMember::<T>::iter_prefix(hotkey)
+            .filter(|(_, is_member)| *is_member)
+            .map(|(netuid, _)| UnbondingPeriod::<T>::get(netuid))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the total stake of `coldkey` removed from `hotkey` that is still unbonding.
+    pub fn get_unbonding_stake_for_coldkey_and_hotkey(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+    ) -> u64 {
+        UnbondingQueue::<T>::get(coldkey)
+            .iter()
+            .filter(|entry| entry.hotkey == *hotkey)
+            .fold(0, |acc, entry| acc.saturating_add(entry.amount))
+    }
+
+    /// Returns true if stake removed by `coldkey` from `hotkey` can enter the unbonding queue,
+    /// either as a new entry or by joining an entry of the same hotkey once the queue is full.
+    pub fn can_queue_unbonding_stake(coldkey: &T::AccountId, hotkey: &T::AccountId) -> bool {
+        let queue = UnbondingQueue::<T>::get(coldkey);
+        queue.len() < MAX_UNBONDING_ENTRIES || queue.iter().any(|entry| entry.hotkey == *hotkey)
+    }
+
+    /// Credits `amount` of stake removed by `coldkey` from `hotkey` to the coldkey, through the
+    /// unbonding queue if the hotkey is subject to an unbonding period.
+    ///
+    /// # Raises:
+    /// * 'TooManyUnbondingEntries':
+    ///     - The unbonding queue of the coldkey is full and holds no entry of the hotkey.
+    pub fn credit_removed_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) -> dispatch::DispatchResult {
+        let unbonding_period = Self::get_unbonding_period_for_hotkey(hotkey);
+        if unbonding_period > 0 {
+            Self::queue_unbonding_stake(coldkey, hotkey, amount, unbonding_period)?;
+        } else {
+            Self::add_balance_to_coldkey_account(coldkey, amount);
+        }
+        Ok(())
+    }
+
+    /// Places `amount` of stake removed by `coldkey` from `hotkey` in the unbonding queue.
+    /// The stake is released to the coldkey `unbonding_period` blocks from now.
+    ///
+    /// If the queue is full the stake joins the last entry of the same hotkey, whose release is
+    /// delayed to the release block of the stake, so the stake never skips its unbonding period
+    /// and stays charged to its hotkey.
+    ///
+    /// # Raises:
+    /// * 'TooManyUnbondingEntries':
+    ///     - The unbonding queue of the coldkey is full and holds no entry of the hotkey.
+    pub fn queue_unbonding_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+        unbonding_period: u64,
+    ) -> dispatch::DispatchResult {
+        let release_block = Self::get_current_block_as_u64().saturating_add(unbonding_period);
+
+        UnbondingQueue::<T>::try_mutate(coldkey, |queue| -> dispatch::DispatchResult {
+            if queue.len() < MAX_UNBONDING_ENTRIES {
+                queue.push(UnbondingEntry {
+                    hotkey: hotkey.clone(),
+                    amount,
+                    release_block,
+                });
+            } else {
+                let entry = queue
+                    .iter_mut()
+                    .rev()
+                    .find(|entry| entry.hotkey == *hotkey)
+                    .ok_or(Error::<T>::TooManyUnbondingEntries)?;
+                entry.amount = entry.amount.saturating_add(amount);
+                entry.release_block = entry.release_block.max(release_block);
+            }
+            Ok(())
+        })?;
+        UnbondingColdkeys::<T>::insert(hotkey, coldkey, true);
+        UnbondingReleases::<T>::mutate(release_block, |coldkeys| {
+            if !coldkeys.contains(coldkey) {
+                coldkeys.push(coldkey.clone());
+            }
+        });
+        TotalUnbonding::<T>::mutate(|total| *total = total.saturating_add(amount));
+
+        log::debug!(
+            "StakeUnbonding( coldkey:{:?}, hotkey:{:?}, amount:{:?}, release_block:{:?} )",
+            coldkey,
+            hotkey,
+            amount,
+            release_block
+        );
+        Self::deposit_event(Event::StakeUnbonding(
+            coldkey.clone(),
+            hotkey.clone(),
+            amount,
+            release_block,
+        ));
+        Ok(())
+    }
+
+    /// Ensures stake moved from `from_hotkey` to `to_hotkey` keeps at least its unbonding period,
+    /// so moving stake cannot be used to skip it.
+    pub fn ensure_unbonding_period_kept(
+        from_hotkey: &T::AccountId,
+        to_hotkey: &T::AccountId,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            Self::get_unbonding_period_for_hotkey(to_hotkey)
+                >= Self::get_unbonding_period_for_hotkey(from_hotkey),
+            Error::<T>::UnbondingPeriodTooShort
+        );
+        Ok(())
+    }
+
+    /// Releases the unbonding stake due at `block_number` to the coldkey balances.
+    ///
+    /// At most MAX_UNBONDING_RELEASES_PER_BLOCK coldkeys are released, the others are deferred to
+    /// the next block. Returns the weight of the release.
+    pub fn release_unbonded_stake(block_number: u64) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        let mut coldkeys: Vec<T::AccountId> = UnbondingReleases::<T>::take(block_number);
+        if coldkeys.len() > MAX_UNBONDING_RELEASES_PER_BLOCK {
+            let deferred: Vec<T::AccountId> = coldkeys.split_off(MAX_UNBONDING_RELEASES_PER_BLOCK);
+            UnbondingReleases::<T>::mutate(block_number.saturating_add(1), |next| {
+                for coldkey in deferred {
+                    if !next.contains(&coldkey) {
+                        next.push(coldkey);
+                    }
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        for coldkey in coldkeys {
+            // Split the queue in the entries which are due and the ones still unbonding.
+            let mut released: Vec<UnbondingEntry<T::AccountId>> = Vec::new();
+            UnbondingQueue::<T>::mutate_exists(&coldkey, |maybe_queue| {
+                if let Some(queue) = maybe_queue {
+                    let (due, pending): (Vec<_>, Vec<_>) = queue
+                        .drain(..)
+                        .partition(|entry| entry.release_block <= block_number);
+                    released = due;
+                    *maybe_queue = if pending.is_empty() {
+                        None
+                    } else {
+                        Some(pending)
+                    };
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+            // Unindex the hotkeys the coldkey no longer has stake unbonding from.
+            let mut released_hotkeys: Vec<T::AccountId> =
+                released.iter().map(|entry| entry.hotkey.clone()).collect();
+            released_hotkeys.dedup();
+            Self::unindex_unbonding_hotkeys(&coldkey, released_hotkeys, &mut weight);
+
+            for entry in released {
+                TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(entry.amount));
+                Self::add_balance_to_coldkey_account(&coldkey, entry.amount);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+                log::debug!(
+                    "UnbondedStakeReleased( coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+                    coldkey,
+                    entry.hotkey,
+                    entry.amount
+                );
+                Self::deposit_event(Event::UnbondedStakeReleased(
+                    coldkey.clone(),
+                    entry.hotkey,
+                    entry.amount,
+                ));
+            }
+        }
+
+        weight
+    }
+
+    /// ---- The implementation for the extrinsic cancel_unbond: Adds the stake of a coldkey which
+    /// is still unbonding from a hotkey back onto that hotkey.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey the unbonding stake was removed from.
+    ///
+    /// # Event:
+    /// * UnbondCancelled;
+    ///     -  On the successfully restaking the unbonding stake.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if the hotkey does not exist.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     -  Thrown if the hotkey is not a delegate and not owned by the caller.
+    ///
+    /// * 'NoUnbondingStake':
+    ///     -  Thrown if the caller has no stake unbonding from the hotkey.
+    ///
+    /// * 'NomStakeBelowMinimumThreshold':
+    ///     -  Thrown if the resulting nomination is below the minimum.
+    ///
+    /// * 'StakeRateLimitExceeded':
+    ///     -  Thrown if the hotkey has hit the stake rate limit for the caller.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     -  Thrown if the coldkey is frozen by the arbitration of its swap.
+    ///
+    pub fn do_cancel_unbond(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_cancel_unbond( origin:{:?} hotkey:{:?} )",
+            coldkey,
+            hotkey
+        );
+
+        // Ensure the coldkey is not frozen by the arbitration of its swap.
+        Self::ensure_coldkey_not_in_arbitration(&coldkey)?;
+
+        // Ensure that the hotkey exists and accepts the caller's stake.
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            Self::hotkey_is_delegate(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // Ensure there is stake unbonding from the hotkey.
+        let amount = Self::get_unbonding_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+        ensure!(amount > 0, Error::<T>::NoUnbondingStake);
+
+        // Ensure the resulting nomination is above the minimum.
+        let current_stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+        Self::ensure_nomination_above_minimum(
+            &coldkey,
+            &hotkey,
+            current_stake.saturating_add(amount),
+        )?;
+
+        // Ensure we don't exceed stake rate limit
+        let stakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
+        ensure!(
+            stakes_this_interval < Self::get_rate_limit(&TransactionType::Stake),
+            Error::<T>::StakeRateLimitExceeded
+        );
+
+        // Remove the entries from the queue. The release schedule is left as is, releasing a
+        // coldkey without due entries is a no-op.
+        UnbondingQueue::<T>::mutate_exists(&coldkey, |maybe_queue| {
+            if let Some(queue) = maybe_queue {
+                queue.retain(|entry| entry.hotkey != hotkey);
+                if queue.is_empty() {
+                    *maybe_queue = None;
+                }
+            }
+        });
+        UnbondingColdkeys::<T>::remove(&hotkey, &coldkey);
+        TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(amount));
+
+        // Add the stake back onto the hotkey.
+        Self::apply_stake_change(
+            &coldkey,
+            &hotkey,
+            current_stake,
+            current_stake.saturating_add(amount),
+        );
+
+        // Set last block for rate limiting
+        let block: u64 = Self::get_current_block_as_u64();
+        Self::set_last_tx_block(&coldkey, block);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &hotkey,
+            stakes_this_interval.saturating_add(1),
+            block,
+        );
+
+        log::debug!(
+            "UnbondCancelled( coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            hotkey,
+            amount
+        );
+        Self::deposit_event(Event::UnbondCancelled(coldkey, hotkey, amount));
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// Removes `coldkey` from the index of the coldkeys unbonding from each of `hotkeys`, unless
+    /// the coldkey still has stake unbonding from it.
+    pub fn unindex_unbonding_hotkeys(
+        coldkey: &T::AccountId,
+        hotkeys: Vec<T::AccountId>,
+        weight: &mut Weight,
+    ) {
+        let queue = UnbondingQueue::<T>::get(coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        for hotkey in hotkeys {
+            if !queue.iter().any(|entry| entry.hotkey == hotkey) {
+                UnbondingColdkeys::<T>::remove(&hotkey, coldkey);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+        }
+    }
+}
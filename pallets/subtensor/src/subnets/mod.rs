@@ -1,5 +1,7 @@
 use super::*;
+pub mod pruning;
 pub mod registration;
+pub mod registration_auction;
 pub mod serving;
 pub mod uids;
 pub mod weights;
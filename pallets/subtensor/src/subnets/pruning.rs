@@ -0,0 +1,132 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Determine which peer to prune from the network, and why. The neuron ranked lowest under the
+    /// pruning policy of the subnet is pruned among the neurons out of immunity and not protected
+    /// by the subnet owner. If all of them are in immunity period, the lowest ranked immune neuron
+    /// which is not protected is pruned, and if all neurons are protected, the lowest ranked one.
+    /// Ties are broken by the earliest registration, then by the lowest uid.
+    pub fn get_neuron_to_prune_with_reason(netuid: u16) -> (u16, PruningReason) {
+        let policy: PruningPolicy = Self::get_pruning_policy(netuid);
+        let protected_uids: Vec<u16> = Self::get_pruning_protected_uids(netuid);
+
+        let neurons_n = Self::get_subnetwork_n(netuid);
+        if neurons_n == 0 {
+            // If there are no neurons in this network.
+            return (0, PruningReason::OutOfImmunity(policy));
+        }
+
+        // The lowest (rank, block at registration, uid) of the non-immune, immune and protected
+        // neurons, in this order of preference.
+        let mut lowest_out_of_immunity: Option<(u64, u64, u16)> = None;
+        let mut lowest_in_immunity: Option<(u64, u64, u16)> = None;
+        let mut lowest_protected: Option<(u64, u64, u16)> = None;
+
+        let emission_history: Vec<Vec<u64>> = match policy {
+            PruningPolicy::LowestEmission(_) => PruningEmissionHistory::<T>::get(netuid),
+            _ => Vec::new(),
+        };
+
+        for neuron_uid in 0..neurons_n {
+            let block_at_registration: u64 =
+                Self::get_neuron_block_at_registration(netuid, neuron_uid);
+            let rank: u64 = match policy {
+                PruningPolicy::LowestPruningScore => {
+                    Self::get_pruning_score_for_uid(netuid, neuron_uid).into()
+                }
+                PruningPolicy::LowestIncentive => {
+                    Self::get_incentive_for_uid(netuid, neuron_uid).into()
+                }
+                PruningPolicy::LowestEmission(_) => {
+                    emission_history.iter().fold(0u64, |total, emission| {
+                        total
+                            .saturating_add(emission.get(neuron_uid as usize).copied().unwrap_or(0))
+                    })
+                }
+                PruningPolicy::LowestStake => {
+                    Self::get_stake_for_uid_and_subnetwork(netuid, neuron_uid)
+                }
+                PruningPolicy::OldestFirst => block_at_registration,
+            };
+            let candidate: (u64, u64, u16) = (rank, block_at_registration, neuron_uid);
+
+            let lowest: &mut Option<(u64, u64, u16)> = if protected_uids.contains(&neuron_uid) {
+                &mut lowest_protected
+            } else if Self::get_neuron_is_immune(netuid, neuron_uid) {
+                &mut lowest_in_immunity
+            } else {
+                &mut lowest_out_of_immunity
+            };
+            // Uids are visited in increasing order, so equal ranks and registrations keep the
+            // lowest uid.
+            let is_lower: bool = match lowest {
+                Some((rank, block, _)) => (candidate.0, candidate.1) < (*rank, *block),
+                None => true,
+            };
+            if is_lower {
+                *lowest = Some(candidate);
+            }
+        }
+
+        let (uid_to_prune, reason) = if let Some((_, _, uid)) = lowest_out_of_immunity {
+            (uid, PruningReason::OutOfImmunity(policy))
+        } else if let Some((_, _, uid)) = lowest_in_immunity {
+            (uid, PruningReason::AllImmune(policy))
+        } else {
+            let uid: u16 = lowest_protected.map(|(_, _, uid)| uid).unwrap_or(0);
+            (uid, PruningReason::AllProtected(policy))
+        };
+
+        Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
+        (uid_to_prune, reason)
+    }
+
+    /// Records the emission of each uid at the epoch of the subnet when it prunes the neuron with
+    /// the lowest emission, keeping the number of epochs the policy considers.
+    pub fn record_pruning_emission(netuid: u16) {
+        let PruningPolicy::LowestEmission(epochs) = Self::get_pruning_policy(netuid) else {
+            return;
+        };
+
+        PruningEmissionHistory::<T>::mutate(netuid, |history| {
+            history.push(Emission::<T>::get(netuid));
+            let excess: usize = history.len().saturating_sub(epochs as usize);
+            history.drain(..excess);
+        });
+    }
+
+    /// Clears the emission recorded for a uid, whose neuron has been replaced.
+    pub fn clear_pruning_emission_for_uid(netuid: u16, uid: u16) {
+        if !PruningEmissionHistory::<T>::contains_key(netuid) {
+            return;
+        }
+
+        PruningEmissionHistory::<T>::mutate(netuid, |history| {
+            for emission in history.iter_mut() {
+                if let Some(uid_emission) = emission.get_mut(uid as usize) {
+                    *uid_emission = 0;
+                }
+            }
+        });
+    }
+
+    pub fn get_pruning_policy(netuid: u16) -> PruningPolicy {
+        SubnetPruningPolicy::<T>::get(netuid)
+    }
+    pub fn set_pruning_policy(netuid: u16, policy: PruningPolicy) {
+        SubnetPruningPolicy::<T>::insert(netuid, policy);
+        // Only the lowest emission policy keeps an emission history.
+        if !matches!(policy, PruningPolicy::LowestEmission(_)) {
+            PruningEmissionHistory::<T>::remove(netuid);
+        }
+        Self::deposit_event(Event::PruningPolicySet(netuid, policy));
+    }
+
+    pub fn get_pruning_protected_uids(netuid: u16) -> Vec<u16> {
+        PruningProtectedUids::<T>::get(netuid)
+    }
+    pub fn set_pruning_protected_uids(netuid: u16, uids: Vec<u16>) {
+        PruningProtectedUids::<T>::insert(netuid, uids.clone());
+        Self::deposit_event(Event::PruningProtectedUidsSet(netuid, uids));
+    }
+}
@@ -34,6 +34,9 @@ impl<T: Config> Pallet<T> {
     /// * 'HotKeyAlreadyRegisteredInSubNet':
     ///     - The hotkey is already registered on this network.
     ///
+    /// * 'RegistrationByAuctionOnly':
+    ///     - The network admits neurons through its registration auction.
+    ///
     pub fn do_burned_registration(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -48,41 +51,9 @@ impl<T: Config> Pallet<T> {
             hotkey
         );
 
-        // --- 2. Ensure the passed network is valid.
-        ensure!(
-            netuid != Self::get_root_netuid(),
-            Error::<T>::RegistrationNotPermittedOnRootSubnet
-        );
-        ensure!(
-            Self::if_subnet_exist(netuid),
-            Error::<T>::SubNetworkDoesNotExist
-        );
-
-        // --- 3. Ensure the passed network allows registrations.
-        ensure!(
-            Self::get_network_registration_allowed(netuid),
-            Error::<T>::SubNetRegistrationDisabled
-        );
-
-        // --- 4. Ensure we are not exceeding the max allowed registrations per block.
-        ensure!(
-            Self::get_registrations_this_block(netuid)
-                < Self::get_max_registrations_per_block(netuid),
-            Error::<T>::TooManyRegistrationsThisBlock
-        );
-
-        // --- 4. Ensure we are not exceeding the max allowed registrations per interval.
-        ensure!(
-            Self::get_registrations_this_interval(netuid)
-                < Self::get_target_registrations_per_interval(netuid).saturating_mul(3),
-            Error::<T>::TooManyRegistrationsThisInterval
-        );
-
-        // --- 4. Ensure that the key is not already registered.
-        ensure!(
-            !Uids::<T>::contains_key(netuid, &hotkey),
-            Error::<T>::HotKeyAlreadyRegisteredInSubNet
-        );
+        // --- 2. Ensure the network accepts the registration of the hotkey and the callers
+        // coldkey has enough balance to burn.
+        Self::burned_registration_checks(&mut DispatchChecks, &coldkey, netuid, &hotkey)?;
 
         // DEPRECATED --- 6. Ensure that the key passes the registration requirement
         // ensure!(
@@ -90,13 +61,9 @@ impl<T: Config> Pallet<T> {
         //     Error::<T>::DidNotPassConnectedNetworkRequirement
         // );
 
-        // --- 7. Ensure the callers coldkey has enough stake to perform the transaction.
+        // --- 7. Get the registration cost.
         let current_block_number: u64 = Self::get_current_block_as_u64();
         let registration_cost = Self::get_burn_as_u64(netuid);
-        ensure!(
-            Self::can_remove_balance_from_coldkey_account(&coldkey, registration_cost),
-            Error::<T>::NotEnoughBalanceToStake
-        );
 
         // --- 8. Ensure the remove operation from the coldkey is a success.
         let actual_burn_amount =
@@ -115,32 +82,13 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 11. Append neuron or prune it.
-        let subnetwork_uid: u16;
-        let current_subnetwork_n: u16 = Self::get_subnetwork_n(netuid);
-
         // Possibly there is no neuron slots at all.
         ensure!(
             Self::get_max_allowed_uids(netuid) != 0,
             Error::<T>::NoNeuronIdAvailable
         );
-
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
-            // --- 12.1.1 No replacement required, the uid appends the subnetwork.
-            // We increment the subnetwork count here but not below.
-            subnetwork_uid = current_subnetwork_n;
-
-            // --- 12.1.2 Expand subnetwork with new account.
-            Self::append_neuron(netuid, &hotkey, current_block_number);
-            log::debug!("add new neuron account");
-        } else {
-            // --- 13.1.1 Replacement required.
-            // We take the neuron with the lowest pruning score here.
-            subnetwork_uid = Self::get_neuron_to_prune(netuid);
-
-            // --- 13.1.1 Replace the neuron account with the new info.
-            Self::replace_neuron(netuid, subnetwork_uid, &hotkey, current_block_number);
-            log::debug!("prune neuron");
-        }
+        let subnetwork_uid: u16 =
+            Self::append_or_replace_neuron(netuid, &hotkey, current_block_number);
 
         // --- 14. Record the registration and increment block and interval counters.
         BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
@@ -208,6 +156,9 @@ impl<T: Config> Pallet<T> {
     /// *'InvalidSeal':
     ///     - The seal is incorrect.
     ///
+    /// *'RegistrationByAuctionOnly':
+    ///     - The network admits neurons through its registration auction.
+    ///
     pub fn do_registration(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -228,46 +179,8 @@ impl<T: Config> Pallet<T> {
             coldkey
         );
 
-        ensure!(
-            signing_origin == hotkey,
-            Error::<T>::TransactorAccountShouldBeHotKey
-        );
-
-        // --- 2. Ensure the passed network is valid.
-        ensure!(
-            netuid != Self::get_root_netuid(),
-            Error::<T>::RegistrationNotPermittedOnRootSubnet
-        );
-        ensure!(
-            Self::if_subnet_exist(netuid),
-            Error::<T>::SubNetworkDoesNotExist
-        );
-
-        // --- 3. Ensure the passed network allows registrations.
-        ensure!(
-            Self::get_network_pow_registration_allowed(netuid),
-            Error::<T>::SubNetRegistrationDisabled
-        );
-
-        // --- 4. Ensure we are not exceeding the max allowed registrations per block.
-        ensure!(
-            Self::get_registrations_this_block(netuid)
-                < Self::get_max_registrations_per_block(netuid),
-            Error::<T>::TooManyRegistrationsThisBlock
-        );
-
-        // --- 5. Ensure we are not exceeding the max allowed registrations per interval.
-        ensure!(
-            Self::get_registrations_this_interval(netuid)
-                < Self::get_target_registrations_per_interval(netuid).saturating_mul(3),
-            Error::<T>::TooManyRegistrationsThisInterval
-        );
-
-        // --- 6. Ensure that the key is not already registered.
-        ensure!(
-            !Uids::<T>::contains_key(netuid, &hotkey),
-            Error::<T>::HotKeyAlreadyRegisteredInSubNet
-        );
+        // --- 2. Ensure the hotkey signed and the network accepts its registration.
+        Self::pow_registration_checks(&mut DispatchChecks, &signing_origin, netuid, &hotkey)?;
 
         // --- 7. Ensure the passed block number is valid, not in the future or too old.
         // Work must have been done within 3 blocks (stops long range attacks).
@@ -310,32 +223,13 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 11. Append neuron or prune it.
-        let subnetwork_uid: u16;
-        let current_subnetwork_n: u16 = Self::get_subnetwork_n(netuid);
-
         // Possibly there is no neuron slots at all.
         ensure!(
             Self::get_max_allowed_uids(netuid) != 0,
             Error::<T>::NoNeuronIdAvailable
         );
-
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
-            // --- 11.1.1 No replacement required, the uid appends the subnetwork.
-            // We increment the subnetwork count here but not below.
-            subnetwork_uid = current_subnetwork_n;
-
-            // --- 11.1.2 Expand subnetwork with new account.
-            Self::append_neuron(netuid, &hotkey, current_block_number);
-            log::debug!("add new neuron account");
-        } else {
-            // --- 11.1.1 Replacement required.
-            // We take the neuron with the lowest pruning score here.
-            subnetwork_uid = Self::get_neuron_to_prune(netuid);
-
-            // --- 11.1.1 Replace the neuron account with the new info.
-            Self::replace_neuron(netuid, subnetwork_uid, &hotkey, current_block_number);
-            log::debug!("prune neuron");
-        }
+        let subnetwork_uid: u16 =
+            Self::append_or_replace_neuron(netuid, &hotkey, current_block_number);
 
         // --- 12. Record the registration and increment block and interval counters.
         POWRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
@@ -355,6 +249,86 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Checks the registration of `hotkey` on the subnet before the hotkey is registered, by
+    /// proof of work if `pow_registration` or else by burn.
+    /// Shared by the registration dispatchables and the pre-dispatch check, see `CallChecks`.
+    pub fn registration_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        netuid: u16,
+        hotkey: &T::AccountId,
+        pow_registration: bool,
+    ) -> Result<(), Error<T>> {
+        // --- 1. Ensure the passed network is valid.
+        checks.require(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet,
+        )?;
+        checks.require(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist,
+        )?;
+
+        // --- 2. Ensure the passed network allows registrations.
+        let registration_allowed = if pow_registration {
+            Self::get_network_pow_registration_allowed(netuid)
+        } else {
+            Self::get_network_registration_allowed(netuid)
+        };
+        checks.ensure(registration_allowed, Error::<T>::SubNetRegistrationDisabled)?;
+        checks.ensure(
+            !Self::get_registration_auction_enabled(netuid),
+            Error::<T>::RegistrationByAuctionOnly,
+        )?;
+
+        // --- 3. Ensure we are not exceeding the max allowed registrations per block.
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_registration_allowed(netuid),
+            Error::<T>::TooManyRegistrationsThisBlock,
+        )?;
+
+        // --- 4. Ensure we are not exceeding the max allowed registrations per interval.
+        checks.ensure(
+            Self::get_registrations_this_interval(netuid)
+                < Self::get_target_registrations_per_interval(netuid).saturating_mul(3),
+            Error::<T>::TooManyRegistrationsThisInterval,
+        )?;
+
+        // --- 5. Ensure that the key is not already registered.
+        checks.ensure(
+            !Uids::<T>::contains_key(netuid, hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet,
+        )
+    }
+
+    /// Checks `signer` registering `hotkey` on the subnet by proof of work, before the work is
+    /// verified.
+    pub fn pow_registration_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        signer: &T::AccountId,
+        netuid: u16,
+        hotkey: &T::AccountId,
+    ) -> Result<(), Error<T>> {
+        checks.ensure(
+            signer == hotkey,
+            Error::<T>::TransactorAccountShouldBeHotKey,
+        )?;
+        Self::registration_checks(checks, netuid, hotkey, true)
+    }
+
+    /// Checks `coldkey` registering `hotkey` on the subnet by burn, before the burn.
+    pub fn burned_registration_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        netuid: u16,
+        hotkey: &T::AccountId,
+    ) -> Result<(), Error<T>> {
+        Self::registration_checks(checks, netuid, hotkey, false)?;
+        checks.ensure(
+            Self::can_remove_balance_from_coldkey_account(coldkey, Self::get_burn_as_u64(netuid)),
+            Error::<T>::NotEnoughBalanceToStake,
+        )
+    }
+
     pub fn do_faucet(
         origin: T::RuntimeOrigin,
         block_number: u64,
@@ -423,68 +397,47 @@ impl<T: Config> Pallet<T> {
         real_hash
     }
 
-    /// Determine which peer to prune from the network by finding the element with the lowest pruning score out of
-    /// immunity period. If there is a tie for lowest pruning score, the neuron registered earliest is pruned.
-    /// If all neurons are in immunity period, the neuron with the lowest pruning score is pruned. If there is a tie for
-    /// the lowest pruning score, the immune neuron registered earliest is pruned.
-    /// Ties for earliest registration are broken by the neuron with the lowest uid.
+    /// Determine which peer to prune from the network under the pruning policy of the subnet, see
+    /// `get_neuron_to_prune_with_reason`.
     pub fn get_neuron_to_prune(netuid: u16) -> u16 {
-        let mut min_score: u16 = u16::MAX;
-        let mut min_score_in_immunity: u16 = u16::MAX;
-        let mut earliest_registration: u64 = u64::MAX;
-        let mut earliest_registration_in_immunity: u64 = u64::MAX;
-        let mut uid_to_prune: u16 = 0;
-        let mut uid_to_prune_in_immunity: u16 = 0;
-
-        // This boolean is used instead of checking if min_score == u16::MAX, to avoid the case
-        // where all non-immune neurons have pruning score u16::MAX
-        // This may be unlikely in practice.
-        let mut found_non_immune = false;
-
-        let neurons_n = Self::get_subnetwork_n(netuid);
-        if neurons_n == 0 {
-            return 0; // If there are no neurons in this network.
-        }
-
-        for neuron_uid in 0..neurons_n {
-            let pruning_score: u16 = Self::get_pruning_score_for_uid(netuid, neuron_uid);
-            let block_at_registration: u64 =
-                Self::get_neuron_block_at_registration(netuid, neuron_uid);
-            let is_immune = Self::get_neuron_is_immune(netuid, neuron_uid);
-
-            if is_immune {
-                // if the immune neuron has a lower pruning score than the minimum for immune neurons,
-                // or, if the pruning scores are equal and the immune neuron was registered earlier than the current minimum for immune neurons,
-                // then update the minimum pruning score and the uid to prune for immune neurons
-                if pruning_score < min_score_in_immunity
-                    || (pruning_score == min_score_in_immunity
-                        && block_at_registration < earliest_registration_in_immunity)
-                {
-                    min_score_in_immunity = pruning_score;
-                    earliest_registration_in_immunity = block_at_registration;
-                    uid_to_prune_in_immunity = neuron_uid;
-                }
-            } else {
-                found_non_immune = true;
-                // if the non-immune neuron has a lower pruning score than the minimum for non-immune neurons,
-                // or, if the pruning scores are equal and the non-immune neuron was registered earlier than the current minimum for non-immune neurons,
-                // then update the minimum pruning score and the uid to prune for non-immune neurons
-                if pruning_score < min_score
-                    || (pruning_score == min_score && block_at_registration < earliest_registration)
-                {
-                    min_score = pruning_score;
-                    earliest_registration = block_at_registration;
-                    uid_to_prune = neuron_uid;
-                }
-            }
-        }
+        Self::get_neuron_to_prune_with_reason(netuid).0
+    }
 
-        if found_non_immune {
-            Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
-            uid_to_prune
+    /// Registers the hotkey on the subnet, appending a new uid while the subnet has free slots and
+    /// replacing the neuron to prune otherwise. Returns the uid of the hotkey.
+    pub fn append_or_replace_neuron(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        current_block_number: u64,
+    ) -> u16 {
+        let current_subnetwork_n: u16 = Self::get_subnetwork_n(netuid);
+        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
+            // --- 1. No replacement required, the uid appends the subnetwork.
+            Self::append_neuron(netuid, hotkey, current_block_number);
+            log::debug!("add new neuron account");
+            current_subnetwork_n
         } else {
-            Self::set_pruning_score_for_uid(netuid, uid_to_prune_in_immunity, u16::MAX);
-            uid_to_prune_in_immunity
+            // --- 2. Replacement required.
+            // We take the neuron to prune under the pruning policy of the subnet here.
+            let (uid_to_prune, reason) = Self::get_neuron_to_prune_with_reason(netuid);
+            let pruned_hotkey: T::AccountId = Keys::<T>::get(netuid, uid_to_prune);
+
+            // --- 3. Replace the neuron account with the new info.
+            Self::replace_neuron(netuid, uid_to_prune, hotkey, current_block_number);
+            log::debug!(
+                "NeuronPruned( netuid:{:?} uid:{:?} hotkey:{:?} reason:{:?} ) ",
+                netuid,
+                uid_to_prune,
+                pruned_hotkey,
+                reason
+            );
+            Self::deposit_event(Event::NeuronPruned(
+                netuid,
+                uid_to_prune,
+                pruned_hotkey,
+                reason,
+            ));
+            uid_to_prune
         }
     }
 
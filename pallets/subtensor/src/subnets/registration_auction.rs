@@ -0,0 +1,294 @@
+use super::*;
+use sp_std::cmp::Reverse;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic bid_for_registration: Places or raises the bid of
+    /// a hotkey in the registration auction of a subnet.
+    ///
+    /// The bid is withdrawn from the coldkey balance. At the end of the adjustment interval the
+    /// highest bids, up to the target registrations per interval, are admitted and pay the
+    /// lowest admitted bid, and the other bids are refunded.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the calling coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'hotkey' ( T::AccountId ):
+    ///     - Hotkey to be registered to the network.
+    ///
+    /// * 'amount' (u64):
+    ///     - The total bid of the hotkey in rao.
+    ///
+    /// # Event:
+    /// * RegistrationBidPlaced;
+    ///     - On successfully placing or raising the bid.
+    ///
+    /// # Raises:
+    /// * 'RegistrationAuctionDisabled':
+    ///     - The network does not register neurons through an auction.
+    ///
+    /// * 'HotKeyAlreadyRegisteredInSubNet':
+    ///     - The hotkey is already registered on this network.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey is owned by, or bid for by, another coldkey.
+    ///
+    /// * 'RegistrationBidTooLow':
+    ///     - The bid is below the burn, does not raise the previous bid of the hotkey, or does
+    ///       not outbid the lowest bid of a full auction.
+    ///
+    /// * 'NotEnoughBalanceToStake':
+    ///     - The coldkey cannot pay the bid.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     - The coldkey is frozen by the arbitration of its swap.
+    ///
+    pub fn do_bid_for_registration(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+        amount: u64,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller has signed the transaction. (the coldkey of the pairing)
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_bid_for_registration( coldkey:{:?} netuid:{:?} hotkey:{:?} amount:{:?} )",
+            coldkey,
+            netuid,
+            hotkey,
+            amount
+        );
+
+        // Ensure the coldkey is not frozen by the arbitration of its swap.
+        Self::ensure_coldkey_not_in_arbitration(&coldkey)?;
+
+        // --- 2. Ensure the passed network is valid and registers through an auction.
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::get_network_registration_allowed(netuid),
+            Error::<T>::SubNetRegistrationDisabled
+        );
+        ensure!(
+            Self::get_registration_auction_enabled(netuid),
+            Error::<T>::RegistrationAuctionDisabled
+        );
+
+        // --- 3. Ensure that the key is not already registered and can be paired with the coldkey.
+        ensure!(
+            !Uids::<T>::contains_key(netuid, &hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // --- 4. Ensure the bid raises the previous bid of the hotkey, or is at least the burn and
+        // outbids the lowest bid if the auction is full.
+        let mut outbid: Option<(T::AccountId, RegistrationBid<T::AccountId>)> = None;
+        let previous_amount: u64 = match RegistrationBids::<T>::get(netuid, &hotkey) {
+            Some(previous) => {
+                ensure!(
+                    previous.coldkey == coldkey,
+                    Error::<T>::NonAssociatedColdKey
+                );
+                ensure!(amount > previous.amount, Error::<T>::RegistrationBidTooLow);
+                previous.amount
+            }
+            None => {
+                ensure!(
+                    amount >= Self::get_burn_as_u64(netuid),
+                    Error::<T>::RegistrationBidTooLow
+                );
+                let bids: Vec<(T::AccountId, RegistrationBid<T::AccountId>)> =
+                    RegistrationBids::<T>::iter_prefix(netuid).collect();
+                if bids.len() >= MAX_REGISTRATION_BIDS {
+                    // The lowest and latest bid is outbid.
+                    outbid = bids
+                        .into_iter()
+                        .min_by_key(|(_, bid)| (bid.amount, Reverse(bid.block)));
+                    let outbids_lowest: bool = match &outbid {
+                        Some((_, lowest)) => amount > lowest.amount,
+                        None => true,
+                    };
+                    ensure!(outbids_lowest, Error::<T>::RegistrationBidTooLow);
+                }
+                0
+            }
+        };
+
+        // --- 5. Withdraw the raise of the bid from the coldkey.
+        let raise: u64 = amount.saturating_sub(previous_amount);
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, raise),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+        let actual_raise: u64 = Self::remove_balance_from_coldkey_account(&coldkey, raise)?;
+        TotalRegistrationBids::<T>::mutate(|total| *total = total.saturating_add(actual_raise));
+
+        // --- 6. Record the bid and refund the outbid one.
+        let bid_amount: u64 = previous_amount.saturating_add(actual_raise);
+        RegistrationBids::<T>::insert(
+            netuid,
+            &hotkey,
+            RegistrationBid {
+                coldkey: coldkey.clone(),
+                amount: bid_amount,
+                block: Self::get_current_block_as_u64(),
+            },
+        );
+        if let Some((outbid_hotkey, outbid_bid)) = outbid {
+            RegistrationBids::<T>::remove(netuid, &outbid_hotkey);
+            Self::refund_registration_bid(netuid, outbid_hotkey, outbid_bid);
+        }
+
+        // --- 7. Deposit successful event.
+        log::debug!(
+            "RegistrationBidPlaced( coldkey:{:?} netuid:{:?} hotkey:{:?} amount:{:?} )",
+            coldkey,
+            netuid,
+            hotkey,
+            bid_amount
+        );
+        Self::deposit_event(Event::RegistrationBidPlaced(
+            coldkey, netuid, hotkey, bid_amount,
+        ));
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// Settles the registration auction of the subnet at the end of its adjustment interval.
+    ///
+    /// The highest bids, earliest first on ties, are admitted up to the target registrations per
+    /// interval, skipping hotkeys which have been registered or taken by another coldkey since
+    /// bidding. The admitted bids pay the lowest admitted bid, the clearing price, which is
+    /// burned, and the rest of every bid is refunded.
+    ///
+    /// Returns the clearing price, if any bid was admitted, and the number of bids.
+    pub fn settle_registration_auction(netuid: u16, current_block: u64) -> (Option<u64>, u16) {
+        let mut bids: Vec<(T::AccountId, RegistrationBid<T::AccountId>)> =
+            RegistrationBids::<T>::drain_prefix(netuid).collect();
+        if bids.is_empty() {
+            return (None, 0);
+        }
+        let bid_count: u16 = bids.len().try_into().unwrap_or(u16::MAX);
+        bids.sort_by_key(|(_, bid)| (Reverse(bid.amount), bid.block));
+
+        // --- 1. Pick the winners, refunding the bids which can no longer be admitted.
+        let slots: usize = if Self::get_max_allowed_uids(netuid) == 0 {
+            0
+        } else {
+            Self::get_target_registrations_per_interval(netuid).into()
+        };
+        let mut winners: Vec<(T::AccountId, RegistrationBid<T::AccountId>)> = Vec::new();
+        for (hotkey, bid) in bids {
+            let admissible: bool = winners.len() < slots
+                && !Uids::<T>::contains_key(netuid, &hotkey)
+                && (!Self::hotkey_account_exists(&hotkey)
+                    || Self::coldkey_owns_hotkey(&bid.coldkey, &hotkey));
+            if admissible {
+                winners.push((hotkey, bid));
+            } else {
+                Self::refund_registration_bid(netuid, hotkey, bid);
+            }
+        }
+
+        // --- 2. Register the winners at the clearing price.
+        let clearing_price: Option<u64> = winners.last().map(|(_, bid)| bid.amount);
+        let price: u64 = clearing_price.unwrap_or(0);
+        let mut admitted: Vec<T::AccountId> = Vec::new();
+        for (hotkey, bid) in winners {
+            TotalRegistrationBids::<T>::mutate(|total| *total = total.saturating_sub(bid.amount));
+            Self::burn_tokens(price);
+            Self::add_balance_to_coldkey_account(&bid.coldkey, bid.amount.saturating_sub(price));
+            Self::increase_rao_recycled(netuid, price);
+
+            Self::create_account_if_non_existent(&bid.coldkey, &hotkey);
+            let subnetwork_uid: u16 =
+                Self::append_or_replace_neuron(netuid, &hotkey, current_block);
+
+            log::debug!(
+                "NeuronRegistered( netuid:{:?} uid:{:?} hotkey:{:?}  ) ",
+                netuid,
+                subnetwork_uid,
+                hotkey
+            );
+            Self::deposit_event(Event::NeuronRegistered(
+                netuid,
+                subnetwork_uid,
+                hotkey.clone(),
+            ));
+            admitted.push(hotkey);
+        }
+
+        log::debug!(
+            "RegistrationAuctionSettled( netuid:{:?} admitted:{:?} clearing_price:{:?} )",
+            netuid,
+            admitted,
+            clearing_price
+        );
+        Self::deposit_event(Event::RegistrationAuctionSettled(
+            netuid,
+            admitted,
+            clearing_price,
+        ));
+
+        (clearing_price, bid_count)
+    }
+
+    /// Refunds every open bid of the registration auction of the subnet.
+    pub fn refund_registration_bids(netuid: u16) {
+        let bids: Vec<(T::AccountId, RegistrationBid<T::AccountId>)> =
+            RegistrationBids::<T>::drain_prefix(netuid).collect();
+        for (hotkey, bid) in bids {
+            Self::refund_registration_bid(netuid, hotkey, bid);
+        }
+    }
+
+    /// Pays a bid removed from the registration auction back to its coldkey.
+    pub fn refund_registration_bid(
+        netuid: u16,
+        hotkey: T::AccountId,
+        bid: RegistrationBid<T::AccountId>,
+    ) {
+        TotalRegistrationBids::<T>::mutate(|total| *total = total.saturating_sub(bid.amount));
+        Self::add_balance_to_coldkey_account(&bid.coldkey, bid.amount);
+
+        log::debug!(
+            "RegistrationBidRefunded( coldkey:{:?} netuid:{:?} hotkey:{:?} amount:{:?} )",
+            bid.coldkey,
+            netuid,
+            hotkey,
+            bid.amount
+        );
+        Self::deposit_event(Event::RegistrationBidRefunded(
+            bid.coldkey,
+            netuid,
+            hotkey,
+            bid.amount,
+        ));
+    }
+
+    pub fn get_registration_auction_enabled(netuid: u16) -> bool {
+        RegistrationAuctionEnabled::<T>::get(netuid)
+    }
+    pub fn set_registration_auction_enabled(netuid: u16, enabled: bool) {
+        RegistrationAuctionEnabled::<T>::insert(netuid, enabled);
+        // Bids of a closed auction are never settled.
+        if !enabled {
+            Self::refund_registration_bids(netuid);
+        }
+        Self::deposit_event(Event::RegistrationAuctionEnabledSet(netuid, enabled));
+    }
+}
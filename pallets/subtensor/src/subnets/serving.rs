@@ -69,26 +69,17 @@ impl<T: Config> Pallet<T> {
         // We check the callers (hotkey) signature.
         let hotkey_id = ensure_signed(origin)?;
 
-        // Ensure the hotkey is registered somewhere.
-        ensure!(
-            Self::is_hotkey_registered_on_any_network(&hotkey_id),
-            Error::<T>::HotKeyNotRegisteredInNetwork
-        );
-
-        // Check the ip signature validity.
-        ensure!(Self::is_valid_ip_type(ip_type), Error::<T>::InvalidIpType);
-        ensure!(
-            Self::is_valid_ip_address(ip_type, ip),
-            Error::<T>::InvalidIpAddress
-        );
-
-        // Get the previous axon information.
+        // Get the previous axon information and check the hotkey, the ip and the rate limit.
         let mut prev_axon = Self::get_axon_info(netuid, &hotkey_id);
-        let current_block: u64 = Self::get_current_block_as_u64();
-        ensure!(
-            Self::axon_passes_rate_limit(netuid, &prev_axon, current_block),
-            Error::<T>::ServingRateLimitExceeded
-        );
+        Self::serving_checks(
+            &mut DispatchChecks,
+            &hotkey_id,
+            netuid,
+            ip_type,
+            ip,
+            &TransactionType::ServeAxon,
+            prev_axon.block,
+        )?;
 
         // Check certificate
         if let Some(certificate) = certificate {
@@ -176,26 +167,18 @@ impl<T: Config> Pallet<T> {
         // We check the callers (hotkey) signature.
         let hotkey_id = ensure_signed(origin)?;
 
-        // Ensure the hotkey is registered somewhere.
-        ensure!(
-            Self::is_hotkey_registered_on_any_network(&hotkey_id),
-            Error::<T>::HotKeyNotRegisteredInNetwork
-        );
-
-        // Check the ip signature validity.
-        ensure!(Self::is_valid_ip_type(ip_type), Error::<T>::InvalidIpType);
-        ensure!(
-            Self::is_valid_ip_address(ip_type, ip),
-            Error::<T>::InvalidIpAddress
-        );
-
-        // We get the previous axon info assoicated with this ( netuid, uid )
+        // We get the previous axon info assoicated with this ( netuid, uid ) and check the hotkey,
+        // the ip and the rate limit.
         let mut prev_prometheus = Self::get_prometheus_info(netuid, &hotkey_id);
-        let current_block: u64 = Self::get_current_block_as_u64();
-        ensure!(
-            Self::prometheus_passes_rate_limit(netuid, &prev_prometheus, current_block),
-            Error::<T>::ServingRateLimitExceeded
-        );
+        Self::serving_checks(
+            &mut DispatchChecks,
+            &hotkey_id,
+            netuid,
+            ip_type,
+            ip,
+            &TransactionType::ServePrometheus,
+            prev_prometheus.block,
+        )?;
 
         // We insert the prometheus meta.
         prev_prometheus.block = Self::get_current_block_as_u64();
@@ -226,14 +209,54 @@ impl<T: Config> Pallet<T> {
      --==[[  Helper functions   ]]==--
     *********************************/
 
+    /// Checks `hotkey` serving on `ip` for a `tx_type` last served at `last_served_block`, before
+    /// the serving information is updated.
+    /// Shared by the serving dispatchables and the pre-dispatch check, see `CallChecks`.
+    pub fn serving_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        hotkey: &T::AccountId,
+        netuid: u16,
+        ip_type: u8,
+        ip: u128,
+        tx_type: &TransactionType,
+        last_served_block: u64,
+    ) -> Result<(), Error<T>> {
+        // Ensure the hotkey is registered somewhere.
+        checks.ensure(
+            Self::is_hotkey_registered_on_any_network(hotkey),
+            Error::<T>::HotKeyNotRegisteredInNetwork,
+        )?;
+
+        // Check the ip signature validity.
+        checks.ensure(Self::is_valid_ip_type(ip_type), Error::<T>::InvalidIpType)?;
+        checks.ensure(
+            Self::is_valid_ip_address(ip_type, ip),
+            Error::<T>::InvalidIpAddress,
+        )?;
+
+        // Ensure the hotkey is not serving faster than the rate limit.
+        checks.ensure_rate_limit(
+            Self::get_rate_limit_blocks_remaining(
+                tx_type,
+                netuid,
+                last_served_block,
+                Self::get_current_block_as_u64(),
+            ),
+            Error::<T>::ServingRateLimitExceeded,
+        )
+    }
+
     pub fn axon_passes_rate_limit(
         netuid: u16,
         prev_axon_info: &AxonInfoOf,
         current_block: u64,
     ) -> bool {
-        let rate_limit: u64 = Self::get_serving_rate_limit(netuid);
-        let last_serve = prev_axon_info.block;
-        rate_limit == 0 || last_serve == 0 || current_block.saturating_sub(last_serve) >= rate_limit
+        Self::get_rate_limit_blocks_remaining(
+            &TransactionType::ServeAxon,
+            netuid,
+            prev_axon_info.block,
+            current_block,
+        ) == 0
     }
 
     pub fn prometheus_passes_rate_limit(
@@ -241,9 +264,12 @@ impl<T: Config> Pallet<T> {
         prev_prometheus_info: &PrometheusInfoOf,
         current_block: u64,
     ) -> bool {
-        let rate_limit: u64 = Self::get_serving_rate_limit(netuid);
-        let last_serve = prev_prometheus_info.block;
-        rate_limit == 0 || last_serve == 0 || current_block.saturating_sub(last_serve) >= rate_limit
+        Self::get_rate_limit_blocks_remaining(
+            &TransactionType::ServePrometheus,
+            netuid,
+            prev_prometheus_info.block,
+            current_block,
+        ) == 0
     }
 
     pub fn has_axon_info(netuid: u16, hotkey: &T::AccountId) -> bool {
@@ -49,6 +49,9 @@ impl<T: Config> Pallet<T> {
 
         // 4. Clear neuron certificates
         NeuronCertificates::<T>::remove(netuid, old_hotkey.clone());
+
+        // 5. Clear the emission of the old neuron kept for pruning
+        Self::clear_pruning_emission_for_uid(netuid, uid_to_replace);
     }
 
     /// Appends the uid to the network.
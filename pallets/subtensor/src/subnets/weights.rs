@@ -1,6 +1,7 @@
 use super::*;
 use crate::epoch::math::*;
-use sp_core::H256;
+use frame_support::weights::Weight;
+use sp_core::{Get, H256};
 use sp_runtime::traits::{BlakeTwo256, Hash};
 use sp_std::{collections::vec_deque::VecDeque, vec};
 
@@ -43,47 +44,30 @@ impl<T: Config> Pallet<T> {
 
         log::debug!("do_commit_weights(hotkey: {:?}, netuid: {:?})", who, netuid);
 
-        // 2. Ensure commit-reveal is enabled.
-        ensure!(
-            Self::get_commit_reveal_weights_enabled(netuid),
-            Error::<T>::CommitRevealDisabled
-        );
-
-        // 3. Ensure the hotkey is registered on the network.
-        ensure!(
-            Self::is_hotkey_registered_on_network(netuid, &who),
-            Error::<T>::HotKeyNotRegisteredInSubNet
-        );
-
-        // 4. Check that the commit rate does not exceed the allowed frequency.
+        // 2. Ensure commit-reveal is enabled, the hotkey is registered on the network and the
+        // commit rate does not exceed the allowed frequency.
+        Self::commit_weights_checks(&mut DispatchChecks, &who, netuid)?;
         let commit_block = Self::get_current_block_as_u64();
         let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &who)?;
-        ensure!(
-            Self::check_rate_limit(netuid, neuron_uid, commit_block),
-            Error::<T>::CommittingWeightsTooFast
-        );
 
-        // 5. Calculate the reveal blocks based on network tempo and reveal period.
+        // 3. Calculate the reveal blocks based on network tempo and reveal period.
         let (first_reveal_block, last_reveal_block) = Self::get_reveal_blocks(netuid, commit_block);
 
-        // 6. Retrieve or initialize the VecDeque of commits for the hotkey.
+        // 4. Retrieve or initialize the VecDeque of commits for the hotkey.
         WeightCommits::<T>::try_mutate(netuid, &who, |maybe_commits| -> DispatchResult {
             let mut commits: VecDeque<(H256, u64, u64, u64)> =
                 maybe_commits.take().unwrap_or_default();
 
-            // 7. Remove any expired commits from the front of the queue.
-            while let Some((_, commit_block_existing, _, _)) = commits.front() {
-                if Self::is_commit_expired(netuid, *commit_block_existing) {
-                    commits.pop_front();
-                } else {
-                    break;
-                }
-            }
+            // 5. Remove any expired commits from the front of the queue.
+            Self::remove_expired_weight_commits(netuid, &who, &mut commits);
 
-            // 8. Verify that the number of unrevealed commits is within the allowed limit.
-            ensure!(commits.len() < 10, Error::<T>::TooManyUnrevealedCommits);
+            // 6. Verify that the number of unrevealed commits is within the allowed limit.
+            ensure!(
+                commits.len() < Self::get_max_unrevealed_commits(netuid) as usize,
+                Error::<T>::TooManyUnrevealedCommits
+            );
 
-            // 9. Append the new commit with calculated reveal blocks.
+            // 7. Append the new commit with calculated reveal blocks.
             commits.push_back((
                 commit_hash,
                 commit_block,
@@ -91,16 +75,16 @@ impl<T: Config> Pallet<T> {
                 last_reveal_block,
             ));
 
-            // 10. Store the updated commits queue back to storage.
+            // 8. Store the updated commits queue back to storage.
             *maybe_commits = Some(commits);
 
-            // 11. Emit the WeightsCommitted event
+            // 9. Emit the WeightsCommitted event
             Self::deposit_event(Event::WeightsCommitted(who.clone(), netuid, commit_hash));
 
-            // 12. Update the last commit block for the hotkey's UID.
+            // 10. Update the last commit block for the hotkey's UID.
             Self::set_last_update_for_uid(netuid, neuron_uid, commit_block);
 
-            // 13. Return success.
+            // 11. Return success.
             Ok(())
         })
     }
@@ -154,11 +138,8 @@ impl<T: Config> Pallet<T> {
 
         log::debug!("do_reveal_weights( hotkey:{:?} netuid:{:?})", who, netuid);
 
-        // --- 2. Ensure commit-reveal is enabled for the network.
-        ensure!(
-            Self::get_commit_reveal_weights_enabled(netuid),
-            Error::<T>::CommitRevealDisabled
-        );
+        // --- 2. Ensure commit-reveal is enabled for the network and the hotkey has commits.
+        Self::reveal_weights_checks(&mut DispatchChecks, &who, netuid)?;
 
         // --- 3. Mutate the WeightCommits to retrieve existing commits for the user.
         WeightCommits::<T>::try_mutate_exists(netuid, &who, |maybe_commits| -> DispatchResult {
@@ -167,16 +148,7 @@ impl<T: Config> Pallet<T> {
                 .ok_or(Error::<T>::NoWeightsCommitFound)?;
 
             // --- 4. Remove any expired commits from the front of the queue, collecting their hashes.
-            let mut expired_hashes = Vec::new();
-            while let Some((hash, commit_block, _, _)) = commits.front() {
-                if Self::is_commit_expired(netuid, *commit_block) {
-                    // Collect the expired commit hash
-                    expired_hashes.push(*hash);
-                    commits.pop_front();
-                } else {
-                    break;
-                }
-            }
+            let expired_hashes = Self::remove_expired_weight_commits(netuid, &who, commits);
 
             // --- 5. Hash the provided data.
             let provided_hash: H256 = BlakeTwo256::hash_of(&(
@@ -308,11 +280,8 @@ impl<T: Config> Pallet<T> {
             netuid
         );
 
-        // --- 3. Ensure commit-reveal is enabled for the network.
-        ensure!(
-            Self::get_commit_reveal_weights_enabled(netuid),
-            Error::<T>::CommitRevealDisabled
-        );
+        // --- 3. Ensure commit-reveal is enabled for the network and the hotkey has commits.
+        Self::reveal_weights_checks(&mut DispatchChecks, &who, netuid)?;
 
         // --- 4. Mutate the WeightCommits to retrieve existing commits for the user.
         WeightCommits::<T>::try_mutate_exists(netuid, &who, |maybe_commits| -> DispatchResult {
@@ -321,16 +290,7 @@ impl<T: Config> Pallet<T> {
                 .ok_or(Error::<T>::NoWeightsCommitFound)?;
 
             // --- 5. Remove any expired commits from the front of the queue, collecting their hashes.
-            let mut expired_hashes = Vec::new();
-            while let Some((hash, commit_block, _, _)) = commits.front() {
-                if Self::is_commit_expired(netuid, *commit_block) {
-                    // Collect the expired commit hash
-                    expired_hashes.push(*hash);
-                    commits.pop_front();
-                } else {
-                    break;
-                }
-            }
+            let expired_hashes = Self::remove_expired_weight_commits(netuid, &who, commits);
 
             // --- 6. Prepare to collect all provided hashes and their corresponding reveals.
             let mut provided_hashes = Vec::new();
@@ -422,6 +382,236 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    /// ---- The implementation for committing timelock encrypted weights.
+    ///
+    /// # Args:
+    /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+    ///   - The signature of the committing hotkey.
+    ///
+    /// * `netuid` (`u16`):
+    ///   - The u16 network identifier.
+    ///
+    /// * `commit` (`BoundedVec<u8, ConstU32<MAX_TIMELOCK_COMMIT_SIZE_BYTES>>`):
+    ///   - The SCALE encoded `WeightsTlockPayload` encrypted to `reveal_round`.
+    ///
+    /// * `reveal_round` (`u64`):
+    ///   - The round whose decryption key decrypts the commit.
+    ///
+    /// # Raises:
+    /// * `CommitRevealDisabled`:
+    ///   - Raised if commit-reveal is disabled for the specified network.
+    ///
+    /// * `HotKeyNotRegisteredInSubNet`:
+    ///   - Raised if the hotkey is not registered on the specified network.
+    ///
+    /// * `CommittingWeightsTooFast`:
+    ///   - Raised if the hotkey's commit rate exceeds the permitted limit.
+    ///
+    /// * `TimelockKeySourceUnavailable`:
+    ///   - Raised if no source of timelock decryption keys is available.
+    ///
+    /// * `InvalidRevealRound`:
+    ///   - Raised if the key of the reveal round has already been published.
+    ///
+    /// * `TooManyUnrevealedCommits`:
+    ///   - Raised if the hotkey has reached the maximum number of unrevealed commits.
+    ///
+    /// * `TooManyTimelockedCommits`:
+    ///   - Raised if the subnet has reached the maximum number of commits waiting to be revealed.
+    ///
+    /// # Events:
+    /// * `TimelockedWeightsCommitted`:
+    ///   - Emitted upon successfully storing the encrypted commit.
+    pub fn do_commit_timelocked_weights(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        commit: BoundedVec<u8, ConstU32<MAX_TIMELOCK_COMMIT_SIZE_BYTES>>,
+        reveal_round: u64,
+    ) -> DispatchResult {
+        // 1. Verify the caller's signature (hotkey).
+        let who = ensure_signed(origin)?;
+
+        log::debug!(
+            "do_commit_timelocked_weights(hotkey: {:?}, netuid: {:?}, reveal_round: {:?})",
+            who,
+            netuid,
+            reveal_round
+        );
+
+        // 2. Ensure commit-reveal is enabled, the hotkey is registered on the network, the commit
+        // rate does not exceed the allowed frequency, the commit cannot be decrypted yet and the
+        // unrevealed commits of the hotkey and of the subnet are within the allowed limits.
+        Self::commit_timelocked_weights_checks(&mut DispatchChecks, &who, netuid, reveal_round)?;
+        let commit_block = Self::get_current_block_as_u64();
+        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &who)?;
+
+        // 3. The commit is revealed at the end of the reveal epoch.
+        let reveal_epoch = Self::get_epoch_index(netuid, commit_block)
+            .saturating_add(Self::get_reveal_period(netuid));
+        let commit_hash: H256 = BlakeTwo256::hash_of(&commit);
+        let subnet_commits = TimelockedWeightCommitCount::<T>::get(netuid);
+
+        // 4. Append the new commit without reading the commits already stored.
+        TimelockedWeightCommits::<T>::append(
+            (netuid, reveal_epoch, who.clone()),
+            (who.clone(), commit, reveal_round),
+        );
+        TimelockedWeightCommitCount::<T>::insert(netuid, subnet_commits.saturating_add(1));
+
+        // 5. Emit the TimelockedWeightsCommitted event.
+        Self::deposit_event(Event::TimelockedWeightsCommitted(
+            who.clone(),
+            netuid,
+            commit_hash,
+            reveal_round,
+        ));
+
+        // 6. Update the last commit block for the hotkey's UID.
+        Self::set_last_update_for_uid(netuid, neuron_uid, commit_block);
+
+        // 7. Return success.
+        Ok(())
+    }
+
+    /// Decrypts the timelock encrypted weight commits of a network which are due at the end of the
+    /// current epoch and sets them, up to `reveals_left` commits shared by the networks running
+    /// their epoch in the block.
+    ///
+    /// Commits whose round key is not published yet, and commits beyond the budget of the block,
+    /// are kept and retried at the next epoch. Commits which cannot be decrypted or set, and
+    /// commits whose key is still not published a reveal period after their reveal epoch, are
+    /// dropped. Nothing is revealed while no key source is available.
+    ///
+    /// Runs at the epoch of the network, before the weights are consumed. Returns the weight of
+    /// the reveals.
+    pub fn reveal_timelocked_weight_commits(
+        netuid: u16,
+        current_block: u64,
+        reveals_left: &mut u32,
+    ) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+        let Some(latest_round) = T::TimelockKeySource::latest_round() else {
+            return weight;
+        };
+        let mut pending_commits = TimelockedWeightCommitCount::<T>::get(netuid);
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        if pending_commits == 0 {
+            return weight;
+        }
+        let current_epoch = Self::get_epoch_index(netuid, current_block);
+        let retry_epochs = Self::get_reveal_period(netuid);
+
+        // Commits of earlier epochs are left behind if the tempo changed or were kept, reveal
+        // them as well. The keys are bounded by MAX_TIMELOCKED_COMMITS_PER_SUBNET.
+        let keys: Vec<(u64, T::AccountId)> =
+            TimelockedWeightCommits::<T>::iter_key_prefix((netuid,)).collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(keys.len() as u64));
+
+        for (reveal_epoch, who) in keys {
+            if *reveals_left == 0 {
+                break;
+            }
+            if reveal_epoch > current_epoch {
+                continue;
+            }
+            let commits = TimelockedWeightCommits::<T>::take((netuid, reveal_epoch, who.clone()));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+            let mut kept = Vec::new();
+            for (committer, commit, reveal_round) in commits {
+                let commit_hash: H256 = BlakeTwo256::hash_of(&commit);
+                let result = if reveal_round > latest_round {
+                    // The key is not published yet, retry until the end of the retry period.
+                    if current_epoch < reveal_epoch.saturating_add(retry_epochs) {
+                        kept.push((committer, commit, reveal_round));
+                        continue;
+                    }
+                    Err(Error::<T>::InvalidRevealRound.into())
+                } else if *reveals_left == 0 {
+                    kept.push((committer, commit, reveal_round));
+                    continue;
+                } else {
+                    // Each reveal decrypts the commit and sets the weights like reveal_weights.
+                    *reveals_left = reveals_left.saturating_sub(1);
+                    weight.saturating_accrue(
+                        Weight::from_parts(103_000_000, 0)
+                            .saturating_add(T::DbWeight::get().reads(11))
+                            .saturating_add(T::DbWeight::get().writes(3)),
+                    );
+                    Self::reveal_timelocked_weight_commit(
+                        netuid,
+                        &who,
+                        &committer,
+                        &commit,
+                        reveal_round,
+                    )
+                };
+                pending_commits = pending_commits.saturating_sub(1);
+
+                match result {
+                    Ok(()) => {
+                        Self::deposit_event(Event::TimelockedWeightsRevealed(
+                            who.clone(),
+                            netuid,
+                            commit_hash,
+                        ));
+                    }
+                    Err(e) => {
+                        log::debug!(
+                            "Failed to reveal timelocked weights( hotkey:{:?} netuid:{:?} ): {:?}",
+                            who,
+                            netuid,
+                            e
+                        );
+                        Self::deposit_event(Event::TimelockedWeightsRevealFailed(
+                            who.clone(),
+                            netuid,
+                            commit_hash,
+                        ));
+                    }
+                }
+            }
+
+            if !kept.is_empty() {
+                TimelockedWeightCommits::<T>::insert((netuid, reveal_epoch, who), kept);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+        }
+
+        TimelockedWeightCommitCount::<T>::insert(netuid, pending_commits);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+        weight
+    }
+
+    /// Decrypts a single timelock encrypted weight commit and sets the weights it contains for
+    /// `who`, the hotkey which made the commit or the hotkey it was swapped to.
+    fn reveal_timelocked_weight_commit(
+        netuid: u16,
+        who: &T::AccountId,
+        committer: &T::AccountId,
+        commit: &[u8],
+        reveal_round: u64,
+    ) -> DispatchResult {
+        let plaintext = T::TimelockKeySource::decrypt(reveal_round, commit)
+            .ok_or(Error::<T>::InvalidTimelockedCommit)?;
+        let payload = WeightsTlockPayload::<T::AccountId>::decode(&mut plaintext.as_slice())
+            .map_err(|_| Error::<T>::InvalidTimelockedCommit)?;
+
+        // A commit only sets the weights of the hotkey which made it, so copied commits are useless.
+        ensure!(
+            payload.hotkey == *committer,
+            Error::<T>::InvalidTimelockedCommit
+        );
+
+        Self::do_set_weights(
+            frame_system::RawOrigin::Signed(who.clone()).into(),
+            netuid,
+            payload.uids,
+            payload.values,
+            payload.version_key,
+        )
+    }
+
     /// ---- The implementation for the extrinsic set_weights.
     ///
     /// # Args:
@@ -495,103 +685,36 @@ impl<T: Config> Pallet<T> {
             values
         );
 
-        // --- Check that the netuid is not the root network.
-        ensure!(
-            netuid != Self::get_root_netuid(),
-            Error::<T>::CanNotSetRootNetworkWeights
-        );
-
-        // --- 2. Check that the length of uid list and value list are equal for this network.
-        ensure!(
-            Self::uids_match_values(&uids, &values),
-            Error::<T>::WeightVecNotEqualSize
-        );
-
-        // --- 3. Check to see if this is a valid network.
-        ensure!(
-            Self::if_subnet_exist(netuid),
-            Error::<T>::SubNetworkDoesNotExist
-        );
-
-        // --- 4. Check to see if the number of uids is within the max allowed uids for this network.
-        ensure!(
-            Self::check_len_uids_within_allowed(netuid, &uids),
-            Error::<T>::UidsLengthExceedUidsInSubNet
-        );
-
-        // --- 5. Check to see if the hotkey is registered to the passed network.
-        ensure!(
-            Self::is_hotkey_registered_on_network(netuid, &hotkey),
-            Error::<T>::HotKeyNotRegisteredInSubNet
-        );
-
-        // --- 6. Check to see if the hotkey has enough stake to set weights.
-        ensure!(
-            Self::check_weights_min_stake(&hotkey, netuid),
-            Error::<T>::NotEnoughStakeToSetWeights
-        );
-
-        // --- 7. Ensure version_key is up-to-date.
-        ensure!(
-            Self::check_version_key(netuid, version_key),
-            Error::<T>::IncorrectWeightVersionKey
-        );
-
-        // --- 9. Ensure the uid is not setting weights faster than the weights_set_rate_limit.
+        // --- 2. Check the weights, the hotkey and its rate limit.
+        Self::set_weights_checks(
+            &mut DispatchChecks,
+            &hotkey,
+            netuid,
+            &uids,
+            &values,
+            version_key,
+        )?;
         let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
         let current_block: u64 = Self::get_current_block_as_u64();
-        if !Self::get_commit_reveal_weights_enabled(netuid) {
-            ensure!(
-                Self::check_rate_limit(netuid, neuron_uid, current_block),
-                Error::<T>::SettingWeightsTooFast
-            );
-        }
-
-        // --- 10. Check that the neuron uid is an allowed validator permitted to set non-self weights.
-        ensure!(
-            Self::check_validator_permit(netuid, neuron_uid, &uids, &values),
-            Error::<T>::NeuronNoValidatorPermit
-        );
-
-        // --- 11. Ensure the passed uids contain no duplicates.
-        ensure!(!Self::has_duplicate_uids(&uids), Error::<T>::DuplicateUids);
-
-        // --- 12. Ensure that the passed uids are valid for the network.
-        ensure!(
-            !Self::contains_invalid_uids(netuid, &uids),
-            Error::<T>::UidVecContainInvalidOne
-        );
 
-        // --- 13. Ensure that the weights have the required length.
-        ensure!(
-            Self::check_length(netuid, neuron_uid, &uids, &values),
-            Error::<T>::WeightVecLengthIsLow
-        );
-
-        // --- 14. Max-upscale the weights.
+        // --- 3. Max-upscale the weights.
         let max_upscaled_weights: Vec<u16> = vec_u16_max_upscale_to_u16(&values);
 
-        // --- 15. Ensure the weights are max weight limited
-        ensure!(
-            Self::max_weight_limited(netuid, neuron_uid, &uids, &max_upscaled_weights),
-            Error::<T>::MaxWeightExceeded
-        );
-
-        // --- 16. Zip weights for sinking to storage map.
+        // --- 4. Zip weights for sinking to storage map.
         let mut zipped_weights: Vec<(u16, u16)> = vec![];
         for (uid, val) in uids.iter().zip(max_upscaled_weights.iter()) {
             zipped_weights.push((*uid, *val))
         }
 
-        // --- 17. Set weights under netuid, uid double map entry.
+        // --- 5. Set weights under netuid, uid double map entry.
         Weights::<T>::insert(netuid, neuron_uid, zipped_weights);
 
-        // --- 18. Set the activity for the weights on this network.
+        // --- 6. Set the activity for the weights on this network.
         if !Self::get_commit_reveal_weights_enabled(netuid) {
             Self::set_last_update_for_uid(netuid, neuron_uid, current_block);
         }
 
-        // --- 19. Emit the tracking event.
+        // --- 7. Emit the tracking event.
         log::debug!(
             "WeightsSet( netuid:{:?}, neuron_uid:{:?} )",
             netuid,
@@ -599,10 +722,184 @@ impl<T: Config> Pallet<T> {
         );
         Self::deposit_event(Event::WeightsSet(netuid, neuron_uid));
 
-        // --- 20. Return ok.
+        // --- 8. Return ok.
         Ok(())
     }
 
+    /// Checks `hotkey` committing weights on the subnet before the commit is stored.
+    /// Shared by `do_commit_weights` and the pre-dispatch check, see `CallChecks`.
+    pub fn commit_weights_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> Result<(), Error<T>> {
+        // --- 1. Ensure commit-reveal is enabled.
+        checks.ensure(
+            Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealDisabled,
+        )?;
+
+        // --- 2. Ensure the hotkey is registered on the network.
+        let Ok(neuron_uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) else {
+            return checks.require(false, Error::<T>::HotKeyNotRegisteredInSubNet);
+        };
+
+        // --- 3. Check that the commit rate does not exceed the allowed frequency.
+        checks.ensure_rate_limit(
+            Self::get_weights_blocks_until_allowed(netuid, neuron_uid),
+            Error::<T>::CommittingWeightsTooFast,
+        )
+    }
+
+    /// Checks `hotkey` committing timelock encrypted weights on the subnet, revealed at
+    /// `reveal_round`, before the commit is stored.
+    /// Shared by `do_commit_timelocked_weights` and the pre-dispatch check, see `CallChecks`.
+    pub fn commit_timelocked_weights_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        hotkey: &T::AccountId,
+        netuid: u16,
+        reveal_round: u64,
+    ) -> Result<(), Error<T>> {
+        // --- 1. Check the hotkey and its commit rate.
+        Self::commit_weights_checks(checks, hotkey, netuid)?;
+
+        // --- 2. Ensure the commit cannot be decrypted yet.
+        let Some(latest_round) = T::TimelockKeySource::latest_round() else {
+            return checks.require(false, Error::<T>::TimelockKeySourceUnavailable);
+        };
+        checks.ensure(reveal_round > latest_round, Error::<T>::InvalidRevealRound)?;
+
+        // --- 3. Verify that the number of unrevealed commits of the hotkey and of the subnet
+        // are within the allowed limits.
+        let reveal_epoch = Self::get_epoch_index(netuid, Self::get_current_block_as_u64())
+            .saturating_add(Self::get_reveal_period(netuid));
+        let unrevealed_commits =
+            TimelockedWeightCommits::<T>::decode_len((netuid, reveal_epoch, hotkey.clone()))
+                .unwrap_or(0);
+        checks.ensure(
+            unrevealed_commits < Self::get_max_unrevealed_commits(netuid) as usize,
+            Error::<T>::TooManyUnrevealedCommits,
+        )?;
+        checks.ensure(
+            TimelockedWeightCommitCount::<T>::get(netuid) < MAX_TIMELOCKED_COMMITS_PER_SUBNET,
+            Error::<T>::TooManyTimelockedCommits,
+        )
+    }
+
+    /// Checks `hotkey` revealing weights committed on the subnet before the commits are read.
+    /// The revealed weights are checked by `set_weights_checks`.
+    /// Shared by `do_reveal_weights`, `do_batch_reveal_weights` and the pre-dispatch check, see
+    /// `CallChecks`.
+    pub fn reveal_weights_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> Result<(), Error<T>> {
+        // --- 1. Ensure commit-reveal is enabled for the network.
+        checks.ensure(
+            Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealDisabled,
+        )?;
+
+        // --- 2. Ensure the hotkey has commits to reveal.
+        checks.ensure(
+            WeightCommits::<T>::contains_key(netuid, hotkey),
+            Error::<T>::NoWeightsCommitFound,
+        )
+    }
+
+    /// Checks `hotkey` setting `uids` and `values` as weights on the subnet before they are set.
+    /// Shared by `do_set_weights` and the pre-dispatch check, see `CallChecks`.
+    pub fn set_weights_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        hotkey: &T::AccountId,
+        netuid: u16,
+        uids: &[u16],
+        values: &[u16],
+        version_key: u64,
+    ) -> Result<(), Error<T>> {
+        // --- 1. Check that the netuid is not the root network.
+        checks.ensure(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::CanNotSetRootNetworkWeights,
+        )?;
+
+        // --- 2. Check that the length of uid list and value list are equal for this network.
+        checks.ensure(
+            Self::uids_match_values(uids, values),
+            Error::<T>::WeightVecNotEqualSize,
+        )?;
+
+        // --- 3. Check to see if this is a valid network.
+        checks.require(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist,
+        )?;
+
+        // --- 4. Check to see if the number of uids is within the max allowed uids for this network.
+        checks.ensure(
+            Self::check_len_uids_within_allowed(netuid, uids),
+            Error::<T>::UidsLengthExceedUidsInSubNet,
+        )?;
+
+        // --- 5. Check to see if the hotkey is registered to the passed network.
+        let Ok(neuron_uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) else {
+            return checks.require(false, Error::<T>::HotKeyNotRegisteredInSubNet);
+        };
+
+        // --- 6. Check to see if the hotkey has enough stake to set weights.
+        checks.ensure(
+            Self::check_weights_min_stake(hotkey, netuid),
+            Error::<T>::NotEnoughStakeToSetWeights,
+        )?;
+
+        // --- 7. Ensure version_key is up-to-date.
+        checks.ensure(
+            Self::check_version_key(netuid, version_key),
+            Error::<T>::IncorrectWeightVersionKey,
+        )?;
+
+        // --- 8. Ensure the uid is not setting weights faster than the weights_set_rate_limit.
+        if !Self::get_commit_reveal_weights_enabled(netuid) {
+            checks.ensure_rate_limit(
+                Self::get_weights_blocks_until_allowed(netuid, neuron_uid),
+                Error::<T>::SettingWeightsTooFast,
+            )?;
+        }
+
+        // --- 9. Check that the neuron uid is an allowed validator permitted to set non-self weights.
+        checks.ensure(
+            Self::check_validator_permit(netuid, neuron_uid, uids, values),
+            Error::<T>::NeuronNoValidatorPermit,
+        )?;
+
+        // --- 10. Ensure the passed uids contain no duplicates.
+        checks.ensure(!Self::has_duplicate_uids(uids), Error::<T>::DuplicateUids)?;
+
+        // --- 11. Ensure that the passed uids are valid for the network.
+        checks.ensure(
+            !Self::contains_invalid_uids(netuid, uids),
+            Error::<T>::UidVecContainInvalidOne,
+        )?;
+
+        // --- 12. Ensure that the weights have the required length.
+        checks.ensure(
+            Self::check_length(netuid, neuron_uid, uids, values),
+            Error::<T>::WeightVecLengthIsLow,
+        )?;
+
+        // --- 13. Ensure the max-upscaled weights are max weight limited.
+        checks.ensure(
+            Self::max_weight_limited(
+                netuid,
+                neuron_uid,
+                uids,
+                &vec_u16_max_upscale_to_u16(values),
+            ),
+            Error::<T>::MaxWeightExceeded,
+        )
+    }
+
     // ==========================
     // ==== Helper functions ====
     // ==========================
@@ -619,17 +916,29 @@ impl<T: Config> Pallet<T> {
         network_version_key == 0 || version_key >= network_version_key
     }
 
+    /// Get the number of blocks until the neuron is allowed to set or commit weights again.
+    pub fn get_weights_blocks_until_allowed(netuid: u16, neuron_uid: u16) -> u64 {
+        Self::get_rate_limit_blocks_remaining(
+            &TransactionType::SetWeights,
+            netuid,
+            Self::get_last_update_for_uid(netuid, neuron_uid),
+            Self::get_current_block_as_u64(),
+        )
+    }
+
     /// Checks if the neuron has set weights within the weights_set_rate_limit.
     ///
     pub fn check_rate_limit(netuid: u16, neuron_uid: u16, current_block: u64) -> bool {
         if Self::is_uid_exist_on_network(netuid, neuron_uid) {
             // --- 1. Ensure that the diff between current and last_set weights is greater than limit.
+            //        (Storage default) Never set weights passes.
             let last_set_weights: u64 = Self::get_last_update_for_uid(netuid, neuron_uid);
-            if last_set_weights == 0 {
-                return true;
-            } // (Storage default) Never set weights.
-            return current_block.saturating_sub(last_set_weights)
-                >= Self::get_weights_set_rate_limit(netuid);
+            return Self::get_rate_limit_blocks_remaining(
+                &TransactionType::SetWeights,
+                netuid,
+                last_set_weights,
+                current_block,
+            ) == 0;
         }
         // --- 3. Non registered peers cant pass.
         false
@@ -783,6 +1092,53 @@ impl<T: Config> Pallet<T> {
         current_epoch > commit_epoch.saturating_add(reveal_period)
     }
 
+    /// Removes the expired commits from the front of a commit queue and emits a
+    /// `WeightCommitExpired` event for each of them. Returns the hashes of the removed commits.
+    pub fn remove_expired_weight_commits(
+        netuid: u16,
+        who: &T::AccountId,
+        commits: &mut VecDeque<(H256, u64, u64, u64)>,
+    ) -> Vec<H256> {
+        let mut expired_hashes = Vec::new();
+        while let Some((hash, commit_block, _, _)) = commits.front() {
+            if Self::is_commit_expired(netuid, *commit_block) {
+                // Collect the expired commit hash
+                let hash = *hash;
+                commits.pop_front();
+                Self::deposit_event(Event::WeightCommitExpired(who.clone(), netuid, hash));
+                expired_hashes.push(hash);
+            } else {
+                break;
+            }
+        }
+        expired_hashes
+    }
+
+    /// Removes the expired weight commits of every hotkey on the subnet and emits a
+    /// `WeightCommitExpired` event for each of them. Runs in the epoch of the subnet, where the
+    /// commits of the previous reveal period expire, and returns the weight consumed.
+    pub fn expire_weight_commits(netuid: u16) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads(2);
+        let hotkeys: Vec<T::AccountId> = WeightCommits::<T>::iter_key_prefix(netuid).collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(hotkeys.len() as u64));
+
+        for who in hotkeys {
+            WeightCommits::<T>::mutate_exists(netuid, &who, |maybe_commits| {
+                if let Some(commits) = maybe_commits {
+                    let expired_hashes = Self::remove_expired_weight_commits(netuid, &who, commits);
+                    if !expired_hashes.is_empty() {
+                        weight.saturating_accrue(T::DbWeight::get().writes(1));
+                    }
+                    if commits.is_empty() {
+                        *maybe_commits = None;
+                    }
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+        }
+        weight
+    }
+
     pub fn get_reveal_blocks(netuid: u16, commit_block: u64) -> (u64, u64) {
         let reveal_period: u64 = Self::get_reveal_period(netuid);
         let tempo: u64 = Self::get_tempo(netuid) as u64;
@@ -806,4 +1162,11 @@ impl<T: Config> Pallet<T> {
     pub fn get_reveal_period(netuid: u16) -> u64 {
         RevealPeriodEpochs::<T>::get(netuid)
     }
+
+    pub fn set_max_unrevealed_commits(netuid: u16, max_unrevealed_commits: u16) {
+        MaxUnrevealedCommits::<T>::insert(netuid, max_unrevealed_commits);
+    }
+    pub fn get_max_unrevealed_commits(netuid: u16) -> u16 {
+        MaxUnrevealedCommits::<T>::get(netuid)
+    }
 }
@@ -0,0 +1,178 @@
+use super::*;
+use frame_support::traits::{schedule::v3::Anon as ScheduleAnon, EnsureOrigin};
+use frame_system::ensure_root;
+use sp_core::Get;
+
+impl<T: Config> Pallet<T> {
+    /// Puts a coldkey in arbitration after its swap was scheduled to a conflicting destination.
+    ///
+    /// While in arbitration the coldkey is frozen: the scheduled swap does not execute, and the
+    /// calls moving stake or funds away from the coldkey, see `call_moves_coldkey_value`, are
+    /// rejected when they dispatch and by the `SubtensorSignedExtension`, until governance
+    /// resolves the arbitration with `resolve_coldkey_swap_arbitration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `coldkey` - The coldkey whose swap is disputed.
+    /// * `new_coldkey` - The conflicting destination of the swap.
+    ///
+    /// # Errors
+    ///
+    /// * `MaxColdkeyDestinationsReached` - If the swap already has the maximum number of destinations.
+    ///
+    /// # Events
+    ///
+    /// Emits a `ColdkeySwapDisputed` event with all the destinations of the swap.
+    pub fn do_dispute_coldkey_swap(
+        coldkey: &T::AccountId,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        let mut destinations = ColdkeySwapDestinations::<T>::get(coldkey);
+        ensure!(
+            destinations.len() < MAX_COLDKEY_SWAP_DESTINATIONS,
+            Error::<T>::MaxColdkeyDestinationsReached
+        );
+        destinations.push(new_coldkey);
+        ColdkeySwapDestinations::<T>::insert(coldkey, destinations.clone());
+
+        log::debug!(
+            "ColdkeySwapDisputed( coldkey:{:?}, new_coldkeys:{:?} )",
+            coldkey,
+            destinations
+        );
+        Self::deposit_event(Event::ColdkeySwapDisputed(coldkey.clone(), destinations));
+
+        Ok(().into())
+    }
+
+    /// Resolves the arbitration of a coldkey by swapping it to one of the destinations it was
+    /// scheduled to, or by cancelling the swap when `new_coldkey` is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Root or a council majority.
+    /// * `coldkey` - The coldkey in arbitration.
+    /// * `new_coldkey` - The destination chosen by governance, if any.
+    ///
+    /// # Errors
+    ///
+    /// * `ColdkeyNotInArbitration` - If the coldkey is not in arbitration.
+    /// * `InvalidColdkeySwapDestination` - If the destination was not scheduled.
+    /// * Any error of `do_swap_coldkey` when swapping to the destination.
+    ///
+    /// # Events
+    ///
+    /// Emits a `ColdkeySwapArbitrationResolved` event, preceded by `ColdkeySwapped` if the
+    /// coldkey was swapped.
+    pub fn do_resolve_coldkey_swap_arbitration(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        new_coldkey: Option<T::AccountId>,
+    ) -> DispatchResultWithPostInfo {
+        // Ensure the origin is root or a council majority.
+        if ensure_root(origin.clone()).is_err() {
+            T::CouncilOrigin::ensure_origin(origin)?;
+        }
+        log::debug!(
+            "do_resolve_coldkey_swap_arbitration( coldkey:{:?}, new_coldkey:{:?} )",
+            coldkey,
+            new_coldkey
+        );
+
+        ensure!(
+            Self::coldkey_in_arbitration(&coldkey),
+            Error::<T>::ColdkeyNotInArbitration
+        );
+        let destinations = ColdkeySwapDestinations::<T>::get(&coldkey);
+
+        // Clear the schedule and cancel the pending scheduled swap so it no longer executes.
+        ColdkeySwapDestinations::<T>::remove(&coldkey);
+        ColdkeySwapScheduled::<T>::remove(&coldkey);
+        Self::cancel_coldkey_swap_task(&coldkey);
+
+        let mut weight = T::DbWeight::get().reads_writes(3, 4);
+        if let Some(new_coldkey) = &new_coldkey {
+            ensure!(
+                destinations.contains(new_coldkey),
+                Error::<T>::InvalidColdkeySwapDestination
+            );
+            let post_info = Self::do_swap_coldkey(&coldkey, new_coldkey)?;
+            weight.saturating_accrue(post_info.actual_weight.unwrap_or_default());
+        }
+
+        log::debug!(
+            "ColdkeySwapArbitrationResolved( coldkey:{:?}, new_coldkey:{:?} )",
+            coldkey,
+            new_coldkey
+        );
+        Self::deposit_event(Event::ColdkeySwapArbitrationResolved(coldkey, new_coldkey));
+
+        Ok(Some(weight).into())
+    }
+
+    /// Ensures the swap of `old_coldkey` to `new_coldkey` can execute.
+    ///
+    /// The coldkey must not be in arbitration. If a swap of the coldkey is scheduled, it must be
+    /// scheduled to `new_coldkey`. Swaps scheduled before their destinations were recorded, and
+    /// direct swaps of coldkeys without a scheduled swap, are not restricted. Swaps cancelled or
+    /// superseded by an arbitration have their scheduler task cancelled.
+    pub fn ensure_coldkey_swap_executable(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            !Self::coldkey_in_arbitration(old_coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+        if ColdkeySwapScheduled::<T>::contains_key(old_coldkey) {
+            let destinations = ColdkeySwapDestinations::<T>::get(old_coldkey);
+            ensure!(
+                destinations.is_empty() || destinations.contains(new_coldkey),
+                Error::<T>::ColdkeySwapNotScheduled
+            );
+        }
+        Ok(())
+    }
+
+    /// Cancels the scheduler task of the scheduled swap of `coldkey`, if any, so it does not
+    /// execute once the coldkey was swapped or its swap was cancelled otherwise.
+    pub fn cancel_coldkey_swap_task(coldkey: &T::AccountId) {
+        if let Some(task) = ColdkeySwapTask::<T>::take(coldkey) {
+            let _ = T::Scheduler::cancel(task);
+        }
+    }
+
+    /// Ensures `coldkey` is not frozen by the arbitration of its swap.
+    ///
+    /// Checked when the calls of `call_moves_coldkey_value` dispatch, so the freeze also holds
+    /// for calls nested in batches, proxies and multisigs.
+    pub fn ensure_coldkey_not_in_arbitration(coldkey: &T::AccountId) -> DispatchResult {
+        ensure!(
+            !Self::coldkey_in_arbitration(coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+        Ok(())
+    }
+
+    /// Returns true if `call` moves stake or funds away from the signing coldkey, which a coldkey
+    /// in arbitration cannot do.
+    pub fn call_moves_coldkey_value(call: &Call<T>) -> bool {
+        matches!(
+            call,
+            Call::remove_stake { .. }
+                | Call::move_stake { .. }
+                | Call::rebalance_stake { .. }
+                | Call::cancel_unbond { .. }
+                | Call::set_reward_destination { .. }
+                | Call::swap_hotkey { .. }
+                | Call::swap_hotkey_on_subnet { .. }
+                | Call::bid_for_registration { .. }
+        )
+    }
+
+    /// Returns true if the swap of `coldkey` was scheduled to conflicting destinations and is
+    /// awaiting resolution by governance.
+    pub fn coldkey_in_arbitration(coldkey: &T::AccountId) -> bool {
+        ColdkeySwapDestinations::<T>::decode_len(coldkey).unwrap_or(0) > 1
+    }
+}
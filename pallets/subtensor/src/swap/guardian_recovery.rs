@@ -0,0 +1,304 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_guardians: Sets the guardians able to
+    /// recover the calling coldkey.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'guardians' (Vec<T::AccountId>):
+    ///     -  The guardian accounts, empty to remove the guardians.
+    ///
+    /// * 'threshold' (u16):
+    ///     -  The number of guardians that must approve a recovery.
+    ///
+    /// # Event:
+    /// * GuardiansSet;
+    ///     -  On successfully setting the guardians.
+    ///
+    /// # Raises:
+    /// * 'InvalidGuardians':
+    ///     -  Thrown if there are too many guardians, duplicates, the coldkey itself is a guardian,
+    ///        or the threshold is zero or above the number of guardians.
+    ///
+    /// * 'ColdkeyRecoveryInProgress':
+    ///     -  Thrown if a recovery of the coldkey is pending.
+    ///
+    pub fn do_set_guardians(
+        origin: T::RuntimeOrigin,
+        guardians: Vec<T::AccountId>,
+        threshold: u16,
+    ) -> dispatch::DispatchResult {
+        // We check that the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_guardians( origin:{:?} guardians:{:?}, threshold:{:?} )",
+            coldkey,
+            guardians,
+            threshold
+        );
+
+        // Ensure the guardians cannot be changed under a pending recovery.
+        ensure!(
+            ColdkeyRecoveries::<T>::iter_prefix(&coldkey)
+                .next()
+                .is_none(),
+            Error::<T>::ColdkeyRecoveryInProgress
+        );
+
+        if guardians.is_empty() {
+            ColdkeyGuardians::<T>::remove(&coldkey);
+        } else {
+            let mut unique_guardians = guardians.clone();
+            unique_guardians.sort();
+            unique_guardians.dedup();
+            ensure!(
+                guardians.len() <= MAX_GUARDIANS
+                    && unique_guardians.len() == guardians.len()
+                    && !guardians.contains(&coldkey)
+                    && threshold > 0
+                    && usize::from(threshold) <= guardians.len(),
+                Error::<T>::InvalidGuardians
+            );
+            ColdkeyGuardians::<T>::insert(
+                &coldkey,
+                GuardianSet {
+                    guardians: guardians.clone(),
+                    threshold,
+                },
+            );
+        }
+
+        log::debug!(
+            "GuardiansSet( coldkey:{:?}, guardians:{:?}, threshold:{:?} )",
+            coldkey,
+            guardians,
+            threshold
+        );
+        Self::deposit_event(Event::GuardiansSet(coldkey, guardians, threshold));
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic approve_coldkey_recovery: Approves, as a
+    /// guardian, the recovery of a coldkey to a new coldkey. Once the threshold of guardians
+    /// approved it, the recovery can be finalized after the coldkey swap schedule duration.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the guardian.
+    ///
+    /// * 'coldkey' (T::AccountId):
+    ///     -  The coldkey to recover.
+    ///
+    /// * 'new_coldkey' (T::AccountId):
+    ///     -  The coldkey to swap the recovered coldkey to.
+    ///
+    /// # Event:
+    /// * ColdkeyRecoveryApproved;
+    ///     -  On successfully approving the recovery.
+    ///
+    /// * ColdkeyRecoveryScheduled;
+    ///     -  When the approval reaches the threshold of guardians.
+    ///
+    /// # Raises:
+    /// * 'NotGuardian':
+    ///     -  Thrown if the caller is not a guardian of the coldkey.
+    ///
+    /// * 'SameColdkey':
+    ///     -  Thrown if the new coldkey is the recovered coldkey.
+    ///
+    /// * 'ColdkeyRecoveryAlreadyApproved':
+    ///     -  Thrown if the guardian already approved the recovery to the new coldkey.
+    ///
+    /// * 'TooManyColdkeyRecoveries':
+    ///     -  Thrown if the recovery is new and the coldkey has the maximum number of pending
+    ///        recoveries.
+    ///
+    pub fn do_approve_coldkey_recovery(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        new_coldkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        let guardian = ensure_signed(origin)?;
+        log::debug!(
+            "do_approve_coldkey_recovery( origin:{:?} coldkey:{:?}, new_coldkey:{:?} )",
+            guardian,
+            coldkey,
+            new_coldkey
+        );
+
+        let guardian_set = ColdkeyGuardians::<T>::get(&coldkey).ok_or(Error::<T>::NotGuardian)?;
+        ensure!(
+            guardian_set.guardians.contains(&guardian),
+            Error::<T>::NotGuardian
+        );
+        ensure!(new_coldkey != coldkey, Error::<T>::SameColdkey);
+
+        let mut recovery = match ColdkeyRecoveries::<T>::get(&coldkey, &new_coldkey) {
+            Some(recovery) => recovery,
+            None => {
+                ensure!(
+                    ColdkeyRecoveries::<T>::iter_prefix(&coldkey).count()
+                        < MAX_PENDING_COLDKEY_RECOVERIES,
+                    Error::<T>::TooManyColdkeyRecoveries
+                );
+                ColdkeyRecovery {
+                    approvals: vec![],
+                    execution_block: None,
+                }
+            }
+        };
+        ensure!(
+            !recovery.approvals.contains(&guardian),
+            Error::<T>::ColdkeyRecoveryAlreadyApproved
+        );
+        recovery.approvals.push(guardian.clone());
+        Self::deposit_event(Event::ColdkeyRecoveryApproved(
+            coldkey.clone(),
+            guardian,
+            new_coldkey.clone(),
+        ));
+
+        // Start the delay the coldkey can cancel the recovery in once the threshold is reached.
+        if recovery.execution_block.is_none()
+            && recovery.approvals.len() >= usize::from(guardian_set.threshold)
+        {
+            let duration: u64 =
+                TryInto::try_into(ColdkeySwapScheduleDuration::<T>::get()).unwrap_or(u64::MAX);
+            let execution_block = Self::get_current_block_as_u64().saturating_add(duration);
+            recovery.execution_block = Some(execution_block);
+
+            log::debug!(
+                "ColdkeyRecoveryScheduled( coldkey:{:?}, new_coldkey:{:?}, execution_block:{:?} )",
+                coldkey,
+                new_coldkey,
+                execution_block
+            );
+            Self::deposit_event(Event::ColdkeyRecoveryScheduled(
+                coldkey.clone(),
+                new_coldkey.clone(),
+                execution_block,
+            ));
+        }
+        ColdkeyRecoveries::<T>::insert(&coldkey, &new_coldkey, recovery);
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic cancel_coldkey_recovery: Cancels all the
+    /// pending guardian recoveries of the calling coldkey.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// # Event:
+    /// * ColdkeyRecoveryCancelled;
+    ///     -  On successfully cancelling the recoveries.
+    ///
+    /// # Raises:
+    /// * 'ColdkeyRecoveryNotFound':
+    ///     -  Thrown if no recovery of the coldkey is pending.
+    ///
+    pub fn do_cancel_coldkey_recovery(origin: T::RuntimeOrigin) -> DispatchResultWithPostInfo {
+        let coldkey = ensure_signed(origin)?;
+        log::debug!("do_cancel_coldkey_recovery( origin:{:?} )", coldkey);
+
+        ensure!(
+            ColdkeyRecoveries::<T>::iter_prefix(&coldkey)
+                .next()
+                .is_some(),
+            Error::<T>::ColdkeyRecoveryNotFound
+        );
+        // At most MAX_PENDING_COLDKEY_RECOVERIES recoveries can be pending.
+        let removed = ColdkeyRecoveries::<T>::clear_prefix(
+            &coldkey,
+            MAX_PENDING_COLDKEY_RECOVERIES as u32,
+            None,
+        );
+
+        log::debug!("ColdkeyRecoveryCancelled( coldkey:{:?} )", coldkey);
+        Self::deposit_event(Event::ColdkeyRecoveryCancelled(coldkey));
+
+        // Ok and return, charging for the removed recoveries only.
+        let weight = Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(u64::from(removed.unique)));
+        Ok(Some(weight).into())
+    }
+
+    /// ---- The implementation for the extrinsic finalize_coldkey_recovery: Swaps a recovered
+    /// coldkey to the new coldkey approved by its guardians, without the key swap cost.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  Any signed account.
+    ///
+    /// * 'coldkey' (T::AccountId):
+    ///     -  The coldkey to recover.
+    ///
+    /// * 'new_coldkey' (T::AccountId):
+    ///     -  The coldkey approved by the guardians.
+    ///
+    /// # Event:
+    /// * ColdkeyRecovered;
+    ///     -  On successfully recovering the coldkey.
+    ///
+    /// # Raises:
+    /// * 'ColdkeyRecoveryNotFound':
+    ///     -  Thrown if no recovery of the coldkey to the new coldkey is pending.
+    ///
+    /// * 'ColdkeyRecoveryNotReady':
+    ///     -  Thrown if the threshold is not reached or the delay has not passed.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     -  Thrown if the coldkey is in arbitration, which governance resolves.
+    ///
+    pub fn do_finalize_coldkey_recovery(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        ensure_signed(origin)?;
+        log::debug!(
+            "do_finalize_coldkey_recovery( coldkey:{:?}, new_coldkey:{:?} )",
+            coldkey,
+            new_coldkey
+        );
+
+        let recovery = ColdkeyRecoveries::<T>::get(&coldkey, &new_coldkey)
+            .ok_or(Error::<T>::ColdkeyRecoveryNotFound)?;
+        ensure!(
+            recovery
+                .execution_block
+                .is_some_and(|execution_block| execution_block <= Self::get_current_block_as_u64()),
+            Error::<T>::ColdkeyRecoveryNotReady
+        );
+        ensure!(
+            !Self::coldkey_in_arbitration(&coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+
+        // The swap also drops the pending recoveries and the scheduled swap of the coldkey, whose
+        // scheduler task is cancelled.
+        Self::cancel_coldkey_swap_task(&coldkey);
+        let post_info = Self::do_swap_coldkey_with_cost(&coldkey, &new_coldkey, 0)?;
+
+        log::debug!(
+            "ColdkeyRecovered( coldkey:{:?}, new_coldkey:{:?} )",
+            coldkey,
+            new_coldkey
+        );
+        Self::deposit_event(Event::ColdkeyRecovered(coldkey, new_coldkey));
+
+        // Ok and return.
+        Ok(post_info)
+    }
+}
@@ -1,3 +1,5 @@
 use super::*;
+pub mod coldkey_arbitration;
+pub mod guardian_recovery;
 pub mod swap_coldkey;
 pub mod swap_hotkey;
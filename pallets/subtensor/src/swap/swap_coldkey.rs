@@ -32,6 +32,16 @@ impl<T: Config> Pallet<T> {
     pub fn do_swap_coldkey(
         old_coldkey: &T::AccountId,
         new_coldkey: &T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        Self::do_swap_coldkey_with_cost(old_coldkey, new_coldkey, Self::get_key_swap_cost())
+    }
+
+    /// Swaps the coldkey like `do_swap_coldkey`, burning `swap_cost` from the old coldkey
+    /// instead of the key swap cost. Guardian recoveries swap the coldkey without cost.
+    pub fn do_swap_coldkey_with_cost(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        swap_cost: u64,
     ) -> DispatchResultWithPostInfo {
         // 2. Initialize the weight for this operation
         let mut weight: Weight = T::DbWeight::get().reads(2);
@@ -49,13 +59,17 @@ impl<T: Config> Pallet<T> {
         );
         weight = weight.saturating_add(T::DbWeight::get().reads(1));
 
-        // 5. Swap the identity if the old coldkey has one
+        // 5. Swap the identity and its attestations if the old coldkey has one
         if let Some(identity) = Identities::<T>::take(old_coldkey) {
             Identities::<T>::insert(new_coldkey, identity);
+            let attestations: Vec<(IdentityField, IdentityAttestation<T::AccountId>)> =
+                IdentityAttestations::<T>::drain_prefix(old_coldkey).collect();
+            for (field, attestation) in attestations {
+                IdentityAttestations::<T>::insert(new_coldkey, field, attestation);
+            }
         }
 
-        // 6. Calculate the swap cost and ensure sufficient balance
-        let swap_cost = Self::get_key_swap_cost();
+        // 6. Ensure sufficient balance for the swap cost
         ensure!(
             Self::can_remove_balance_from_coldkey_account(old_coldkey, swap_cost),
             Error::<T>::NotEnoughBalanceToPaySwapColdKey
@@ -75,8 +89,11 @@ impl<T: Config> Pallet<T> {
         Self::set_last_tx_block(new_coldkey, Self::get_current_block_as_u64());
         weight.saturating_accrue(T::DbWeight::get().writes(1));
 
-        // 11. Remove the coldkey swap scheduled record
+        // 11. Remove the coldkey swap scheduled record, the scheduler task is the one executing
+        // or was cancelled by the caller
         ColdkeySwapScheduled::<T>::remove(old_coldkey);
+        ColdkeySwapDestinations::<T>::remove(old_coldkey);
+        ColdkeySwapTask::<T>::remove(old_coldkey);
 
         // 12. Emit the ColdkeySwapped event
         Self::deposit_event(Event::ColdkeySwapped {
@@ -124,6 +141,18 @@ impl<T: Config> Pallet<T> {
     /// 7. Transfer remaining balance:
     ///    - Transfer any remaining balance from the old coldkey to the new coldkey.
     ///
+    /// 8. Swap unbonding stake:
+    ///    - Transfer the unbonding queue and its scheduled releases from the old coldkey to the new coldkey.
+    ///
+    /// 9. Swap guardians:
+    ///    - Move the guardians of the old coldkey to the new coldkey and drop its pending recoveries.
+    ///
+    /// 10. Swap registration bids:
+    ///    - Make the new coldkey the bidder, and so the payer or refunded key, of the open registration bids of the old coldkey.
+    ///
+    /// 11. Swap nominator rewards:
+    ///    - Merge the reward ledger of the old coldkey into the ledger of the new coldkey.
+    ///
     /// Throughout the process, the function updates the transaction weight to reflect the operations performed.
     ///
     /// # Notes
@@ -239,6 +268,97 @@ impl<T: Config> Pallet<T> {
         }
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
+        // 9. Swap unbonding stake.
+        // UnbondingQueue: MAP ( coldkey ) --> Vec<unbonding_entry> | Stake waiting to be released.
+        // UnbondingReleases: MAP ( block ) --> Vec<coldkey> | Coldkeys released at a block.
+        // UnbondingColdkeys: DMAP ( hotkey, coldkey ) --> bool | Coldkeys unbonding from a hotkey.
+        let old_unbonding_queue = UnbondingQueue::<T>::take(old_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        if !old_unbonding_queue.is_empty() {
+            let mut release_blocks: Vec<u64> = old_unbonding_queue
+                .iter()
+                .map(|entry| entry.release_block)
+                .collect();
+            release_blocks.sort_unstable();
+            release_blocks.dedup();
+            for release_block in release_blocks {
+                UnbondingReleases::<T>::mutate(release_block, |coldkeys| {
+                    coldkeys.retain(|coldkey| coldkey != old_coldkey);
+                    if !coldkeys.contains(new_coldkey) {
+                        coldkeys.push(new_coldkey.clone());
+                    }
+                });
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            }
+            for entry in old_unbonding_queue.iter() {
+                if UnbondingColdkeys::<T>::take(&entry.hotkey, old_coldkey) {
+                    UnbondingColdkeys::<T>::insert(&entry.hotkey, new_coldkey, true);
+                }
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+            UnbondingQueue::<T>::mutate(new_coldkey, |queue| {
+                queue.extend(old_unbonding_queue);
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        // 10. Swap reward destinations.
+        // RewardDestinations: DMAP ( coldkey, hotkey ) --> destination | Where the rewards of a nomination are paid.
+        for (hotkey, destination) in RewardDestinations::<T>::drain_prefix(old_coldkey) {
+            if !RewardDestinations::<T>::contains_key(new_coldkey, &hotkey) {
+                RewardDestinations::<T>::insert(new_coldkey, &hotkey, destination);
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+
+        // 11. Swap guardians.
+        // ColdkeyGuardians: MAP ( coldkey ) --> guardians | The guardians able to recover a coldkey.
+        // ColdkeyRecoveries: DMAP ( coldkey, new_coldkey ) --> recovery | The pending guardian recoveries of a coldkey.
+        if let Some(guardian_set) = ColdkeyGuardians::<T>::take(old_coldkey) {
+            if !guardian_set.guardians.contains(new_coldkey)
+                && !ColdkeyGuardians::<T>::contains_key(new_coldkey)
+            {
+                ColdkeyGuardians::<T>::insert(new_coldkey, guardian_set);
+            }
+        }
+        let removed = ColdkeyRecoveries::<T>::clear_prefix(
+            old_coldkey,
+            MAX_PENDING_COLDKEY_RECOVERIES as u32,
+            None,
+        );
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        weight.saturating_accrue(T::DbWeight::get().writes(u64::from(removed.unique)));
+
+        // 12. Swap registration bids.
+        // RegistrationBids: DMAP ( netuid, hotkey ) --> bid | The open bids of the registration auction of a subnet.
+        let bids: Vec<(u16, T::AccountId)> = RegistrationBids::<T>::iter()
+            .filter(|(_, _, bid)| bid.coldkey == *old_coldkey)
+            .map(|(netuid, hotkey, _)| (netuid, hotkey))
+            .collect();
+        for (netuid, hotkey) in bids {
+            RegistrationBids::<T>::mutate(netuid, &hotkey, |bid| {
+                if let Some(bid) = bid {
+                    bid.coldkey = new_coldkey.clone();
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        // 13. Swap nominator rewards.
+        // NominatorRewards: MAP ( coldkey ) --> VecDeque<entry> | The latest rewards of a coldkey.
+        let old_nominator_rewards = NominatorRewards::<T>::take(old_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        if !old_nominator_rewards.is_empty() {
+            NominatorRewards::<T>::mutate(new_coldkey, |ledger| {
+                ledger.extend(old_nominator_rewards);
+                ledger.make_contiguous().sort_by_key(|entry| entry.block);
+                while ledger.len() > MAX_NOMINATOR_REWARD_ENTRIES {
+                    ledger.pop_front();
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
         // Return ok.
         Ok(())
     }
@@ -22,6 +22,7 @@ impl<T: Config> Pallet<T> {
     /// * `NewHotKeyIsSameWithOld` - If the new hotkey is the same as the old hotkey.
     /// * `HotKeyAlreadyRegisteredInSubNet` - If the new hotkey is already registered in the subnet.
     /// * `NotEnoughBalanceToPaySwapHotKey` - If there is not enough balance to pay for the swap.
+    /// * `ColdkeyIsInArbitration` - If the coldkey is frozen by the arbitration of its swap.
     pub fn do_swap_hotkey(
         origin: T::RuntimeOrigin,
         old_hotkey: &T::AccountId,
@@ -30,75 +31,270 @@ impl<T: Config> Pallet<T> {
         // 1. Ensure the origin is signed and get the coldkey
         let coldkey = ensure_signed(origin)?;
 
-        // 2. Initialize the weight for this operation
-        let mut weight = T::DbWeight::get().reads(2);
-
-        // 3. Ensure the new hotkey is different from the old one
-        ensure!(old_hotkey != new_hotkey, Error::<T>::NewHotKeyIsSameWithOld);
-
-        // 4. Ensure the new hotkey is not already registered on any network
-        ensure!(
-            !Self::is_hotkey_registered_on_any_network(new_hotkey),
-            Error::<T>::HotKeyAlreadyRegisteredInSubNet
-        );
+        // 2. Ensure the coldkey can swap the old hotkey for the new one
+        Self::swap_hotkey_checks(&mut DispatchChecks, &coldkey, old_hotkey, new_hotkey)?;
 
-        // 5. Update the weight for the checks above
+        // 3. Initialize the weight for this operation and the checks above
+        let mut weight = T::DbWeight::get().reads(2);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 0));
-
-        // 6. Ensure the coldkey owns the old hotkey
-        ensure!(
-            Self::coldkey_owns_hotkey(&coldkey, old_hotkey),
-            Error::<T>::NonAssociatedColdKey
-        );
-
-        // 7. Get the current block number
-        let block: u64 = Self::get_current_block_as_u64();
-
-        // 8. Ensure the transaction rate limit is not exceeded
-        ensure!(
-            !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&coldkey), block),
-            Error::<T>::HotKeySetTxRateLimitExceeded
-        );
-
-        // 9. Update the weight for reading the total networks
         weight.saturating_accrue(
             T::DbWeight::get().reads((TotalNetworks::<T>::get().saturating_add(1u16)) as u64),
         );
 
-        // 10. Get the cost for swapping the key
+        // 4. Get the current block number and the cost for swapping the key
+        let block: u64 = Self::get_current_block_as_u64();
         let swap_cost = Self::get_key_swap_cost();
         log::debug!("Swap cost: {:?}", swap_cost);
 
-        // 11. Ensure the coldkey has enough balance to pay for the swap
-        ensure!(
-            Self::can_remove_balance_from_coldkey_account(&coldkey, swap_cost),
-            Error::<T>::NotEnoughBalanceToPaySwapHotKey
-        );
-
-        // 12. Remove the swap cost from the coldkey's account
+        // 5. Remove the swap cost from the coldkey's account
         let actual_burn_amount = Self::remove_balance_from_coldkey_account(&coldkey, swap_cost)?;
 
-        // 13. Burn the tokens
+        // 6. Burn the tokens
         Self::burn_tokens(actual_burn_amount);
 
-        // 14. Perform the hotkey swap
+        // 7. Perform the hotkey swap
         let _ = Self::perform_hotkey_swap(old_hotkey, new_hotkey, &coldkey, &mut weight);
 
-        // 15. Update the last transaction block for the coldkey
+        // 8. Update the last transaction block for the coldkey
         Self::set_last_tx_block(&coldkey, block);
         weight.saturating_accrue(T::DbWeight::get().writes(1));
 
-        // 16. Emit an event for the hotkey swap
+        // 9. Emit an event for the hotkey swap
         Self::deposit_event(Event::HotkeySwapped {
             coldkey,
             old_hotkey: old_hotkey.clone(),
             new_hotkey: new_hotkey.clone(),
         });
 
-        // 17. Return the weight of the operation
+        // 10. Return the weight of the operation
         Ok(Some(weight).into())
     }
 
+    /// Swaps the hotkey of a coldkey account on a single subnet.
+    ///
+    /// Only the registration of the old hotkey on the subnet moves to the new hotkey: its UID,
+    /// axon, prometheus, neuron certificate, weight commits, childkey take and child relations.
+    /// Stake is not moved: it stays on the old hotkey, which remains registered on its other
+    /// subnets, and the neuron on the subnet is backed by the stake of the new hotkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the transaction, and also the coldkey account.
+    /// * `old_hotkey` - The old hotkey to be swapped.
+    /// * `new_hotkey` - The new hotkey to replace the old one on the subnet.
+    /// * `netuid` - The subnet the hotkey is swapped on.
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResultWithPostInfo` - The result of the dispatch.
+    ///
+    /// # Errors
+    ///
+    /// * `NewHotKeyIsSameWithOld` - If the new hotkey is the same as the old hotkey.
+    /// * `SubNetworkDoesNotExist` - If the subnet does not exist.
+    /// * `NonAssociatedColdKey` - If the coldkey does not own the old hotkey, or the new hotkey exists and is owned by another coldkey.
+    /// * `HotKeyNotRegisteredInSubNet` - If the old hotkey is not registered on the subnet.
+    /// * `HotKeyAlreadyRegisteredInSubNet` - If the new hotkey is already registered on the subnet.
+    /// * `NewHotkeyHasChildRelations` - If the new hotkey already has child or parent keys on the subnet.
+    /// * `HotKeySetTxRateLimitExceeded` - If the transaction rate limit is exceeded.
+    /// * `NotEnoughBalanceToPaySwapHotKey` - If there is not enough balance to pay for the swap.
+    /// * `ColdkeyIsInArbitration` - If the coldkey is frozen by the arbitration of its swap.
+    pub fn do_swap_hotkey_on_subnet(
+        origin: T::RuntimeOrigin,
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> DispatchResultWithPostInfo {
+        // 1. Ensure the origin is signed and get the coldkey
+        let coldkey = ensure_signed(origin)?;
+
+        // 2. Ensure the coldkey can swap the old hotkey for the new one on the subnet
+        Self::swap_hotkey_on_subnet_checks(
+            &mut DispatchChecks,
+            &coldkey,
+            old_hotkey,
+            new_hotkey,
+            netuid,
+        )?;
+        let mut weight = T::DbWeight::get().reads(9);
+
+        // 3. Remove and burn the swap cost from the coldkey's account
+        let swap_cost = Self::get_key_swap_cost();
+        let actual_burn_amount = Self::remove_balance_from_coldkey_account(&coldkey, swap_cost)?;
+        Self::burn_tokens(actual_burn_amount);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+        // 4. Perform the hotkey swap on the subnet
+        Self::create_account_if_non_existent(&coldkey, new_hotkey);
+        Self::perform_hotkey_swap_on_subnet(old_hotkey, new_hotkey, netuid, &mut weight);
+
+        // 5. Update the last transaction block for the coldkey
+        Self::set_last_tx_block(&coldkey, Self::get_current_block_as_u64());
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        // 6. Emit an event for the hotkey swap
+        Self::deposit_event(Event::HotkeySwappedOnSubnet(
+            coldkey,
+            old_hotkey.clone(),
+            new_hotkey.clone(),
+            netuid,
+        ));
+
+        // 7. Return the weight of the operation
+        Ok(Some(weight).into())
+    }
+
+    /// Checks `coldkey` swapping `old_hotkey` for `new_hotkey` before the swap.
+    /// Shared by `do_swap_hotkey` and the pre-dispatch check, see `CallChecks`.
+    pub fn swap_hotkey_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+    ) -> Result<(), Error<T>> {
+        // 1. Ensure the coldkey is not in arbitration
+        checks.ensure(
+            !Self::coldkey_in_arbitration(coldkey),
+            Error::<T>::ColdkeyIsInArbitration,
+        )?;
+
+        // 2. Ensure the new hotkey is different from the old one
+        checks.ensure(old_hotkey != new_hotkey, Error::<T>::NewHotKeyIsSameWithOld)?;
+
+        // 3. Ensure the new hotkey is not already registered on any network
+        checks.ensure(
+            !Self::is_hotkey_registered_on_any_network(new_hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet,
+        )?;
+
+        // 4. Ensure the coldkey owns the old hotkey
+        checks.ensure(
+            Self::coldkey_owns_hotkey(coldkey, old_hotkey),
+            Error::<T>::NonAssociatedColdKey,
+        )?;
+
+        // 5. Ensure the transaction rate limit is not exceeded
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_allowed(
+                &TransactionType::SwapHotkey,
+                coldkey,
+                GLOBAL_RATE_LIMIT_NETUID,
+            ),
+            Error::<T>::HotKeySetTxRateLimitExceeded,
+        )?;
+
+        // 6. Ensure the coldkey has enough balance to pay for the swap
+        checks.ensure(
+            Self::can_remove_balance_from_coldkey_account(coldkey, Self::get_key_swap_cost()),
+            Error::<T>::NotEnoughBalanceToPaySwapHotKey,
+        )
+    }
+
+    /// Checks `coldkey` swapping `old_hotkey` for `new_hotkey` on the subnet before the swap.
+    /// Shared by `do_swap_hotkey_on_subnet` and the pre-dispatch check, see `CallChecks`.
+    pub fn swap_hotkey_on_subnet_checks<C: CallChecks<T>>(
+        checks: &mut C,
+        coldkey: &T::AccountId,
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> Result<(), Error<T>> {
+        // 1. Ensure the coldkey is not in arbitration
+        checks.ensure(
+            !Self::coldkey_in_arbitration(coldkey),
+            Error::<T>::ColdkeyIsInArbitration,
+        )?;
+
+        // 2. Ensure the new hotkey is different from the old one and the subnet exists
+        checks.ensure(old_hotkey != new_hotkey, Error::<T>::NewHotKeyIsSameWithOld)?;
+        checks.require(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist,
+        )?;
+
+        // 3. Ensure the coldkey owns the old hotkey, and the new hotkey if it exists
+        checks.ensure(
+            Self::coldkey_owns_hotkey(coldkey, old_hotkey),
+            Error::<T>::NonAssociatedColdKey,
+        )?;
+        checks.ensure(
+            !Self::hotkey_account_exists(new_hotkey)
+                || Self::coldkey_owns_hotkey(coldkey, new_hotkey),
+            Error::<T>::NonAssociatedColdKey,
+        )?;
+
+        // 4. Ensure only the old hotkey is registered on the subnet
+        checks.ensure(
+            Self::is_hotkey_registered_on_network(netuid, old_hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet,
+        )?;
+        checks.ensure(
+            !Self::is_hotkey_registered_on_network(netuid, new_hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet,
+        )?;
+        checks.ensure(
+            ChildKeys::<T>::get(new_hotkey, netuid).is_empty()
+                && ParentKeys::<T>::get(new_hotkey, netuid).is_empty(),
+            Error::<T>::NewHotkeyHasChildRelations,
+        )?;
+
+        // 5. Ensure the transaction rate limit is not exceeded
+        checks.ensure_rate_limit(
+            Self::get_blocks_until_allowed(&TransactionType::SwapHotkey, coldkey, netuid),
+            Error::<T>::HotKeySetTxRateLimitExceeded,
+        )?;
+
+        // 6. Ensure the coldkey has enough balance to pay for the swap
+        checks.ensure(
+            Self::can_remove_balance_from_coldkey_account(coldkey, Self::get_key_swap_cost()),
+            Error::<T>::NotEnoughBalanceToPaySwapHotKey,
+        )
+    }
+
+    /// Performs the hotkey swap operation on a single subnet, transferring the subnet state of the
+    /// old hotkey to the new hotkey.
+    ///
+    /// 1. Swaps the registration: membership, UID and key, prometheus, axon, weight commits, loaded emission and neuron certificate.
+    /// 2. Rewrites the timelocked weight commits of the old hotkey.
+    /// 3. Swaps the childkey take.
+    /// 4. Swaps the child and parent keys.
+    /// 5. Moves the per subnet transaction rate limit blocks.
+    pub fn perform_hotkey_swap_on_subnet(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+        weight: &mut Weight,
+    ) {
+        // 1. Swap the subnet registration.
+        Self::swap_hotkey_subnet_registration(old_hotkey, new_hotkey, netuid, weight);
+
+        // 2. Swap TimelockedWeightCommits.
+        Self::swap_hotkey_timelocked_weight_commits(old_hotkey, new_hotkey, netuid, weight);
+
+        // 3. Swap ChildkeyTake.
+        // ChildkeyTake( hotkey, netuid ) --> take -- the childkey take of the hotkey on the subnet.
+        if let Ok(childkey_take) = ChildkeyTake::<T>::try_get(old_hotkey, netuid) {
+            ChildkeyTake::<T>::remove(old_hotkey, netuid);
+            ChildkeyTake::<T>::insert(new_hotkey, netuid, childkey_take);
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+        // 4. Swap ChildKeys and ParentKeys.
+        Self::swap_hotkey_subnet_child_relations(old_hotkey, new_hotkey, netuid);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+        // 5. Swap TransactionKeyLastBlock.
+        // TransactionKeyLastBlock( hotkey, netuid, tx_type ) --> block -- so the rate limits carry over.
+        let last_blocks: Vec<(u16, u64)> =
+            TransactionKeyLastBlock::<T>::iter_prefix((old_hotkey, netuid)).collect();
+        for (tx_type, block) in last_blocks {
+            TransactionKeyLastBlock::<T>::remove((old_hotkey, netuid, tx_type));
+            TransactionKeyLastBlock::<T>::insert((new_hotkey, netuid, tx_type), block);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+    }
+
     /// Performs the hotkey swap operation, transferring all associated data and state from the old hotkey to the new hotkey.
     ///
     /// This function executes a series of steps to ensure a complete transfer of all relevant information:
@@ -197,6 +393,9 @@ impl<T: Config> Pallet<T> {
             T::SenateMembers::swap_member(old_hotkey, new_hotkey).map_err(|e| e.error)?;
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
         }
+        // The senate term and term limit follow the hotkey.
+        Self::swap_senate_term(old_hotkey, new_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 4));
 
         // 8. Swap delegates.
         // Delegates( hotkey ) -> take value -- the hotkey delegate take value.
@@ -218,85 +417,8 @@ impl<T: Config> Pallet<T> {
         // 10. Swap all subnet specific info.
         let all_netuids: Vec<u16> = Self::get_all_subnet_netuids();
         for netuid in all_netuids {
-            // 10.1 Remove the previous hotkey and insert the new hotkey from membership.
-            // IsNetworkMember( hotkey, netuid ) -> bool -- is the hotkey a subnet member.
-            let is_network_member: bool = IsNetworkMember::<T>::get(old_hotkey, netuid);
-            IsNetworkMember::<T>::remove(old_hotkey, netuid);
-            IsNetworkMember::<T>::insert(new_hotkey, netuid, is_network_member);
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-
-            // 10.2 Swap Uids + Keys.
-            // Keys( netuid, hotkey ) -> uid -- the uid the hotkey has in the network if it is a member.
-            // Uids( netuid, hotkey ) -> uid -- the uids that the hotkey has.
-            if is_network_member {
-                // 10.2.1 Swap the UIDS
-                if let Ok(old_uid) = Uids::<T>::try_get(netuid, old_hotkey) {
-                    Uids::<T>::remove(netuid, old_hotkey);
-                    Uids::<T>::insert(netuid, new_hotkey, old_uid);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-
-                    // 10.2.2 Swap the keys.
-                    Keys::<T>::insert(netuid, old_uid, new_hotkey.clone());
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
-                }
-            }
-
-            // 10.3 Swap Prometheus.
-            // Prometheus( netuid, hotkey ) -> prometheus -- the prometheus data that a hotkey has in the network.
-            if is_network_member {
-                if let Ok(old_prometheus_info) = Prometheus::<T>::try_get(netuid, old_hotkey) {
-                    Prometheus::<T>::remove(netuid, old_hotkey);
-                    Prometheus::<T>::insert(netuid, new_hotkey, old_prometheus_info);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 10.4. Swap axons.
-            // Axons( netuid, hotkey ) -> axon -- the axon that the hotkey has.
-            if is_network_member {
-                if let Ok(old_axon_info) = Axons::<T>::try_get(netuid, old_hotkey) {
-                    Axons::<T>::remove(netuid, old_hotkey);
-                    Axons::<T>::insert(netuid, new_hotkey, old_axon_info);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 10.5 Swap WeightCommits
-            // WeightCommits( hotkey ) --> Vec<u64> -- the weight commits for the hotkey.
-            if is_network_member {
-                if let Ok(old_weight_commits) = WeightCommits::<T>::try_get(netuid, old_hotkey) {
-                    WeightCommits::<T>::remove(netuid, old_hotkey);
-                    WeightCommits::<T>::insert(netuid, new_hotkey, old_weight_commits);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 10.6. Swap the subnet loaded emission.
-            // LoadedEmission( netuid ) --> Vec<(hotkey, u64)> -- the loaded emission for the subnet.
-            if is_network_member {
-                if let Some(mut old_loaded_emission) = LoadedEmission::<T>::get(netuid) {
-                    for emission in old_loaded_emission.iter_mut() {
-                        if emission.0 == *old_hotkey {
-                            emission.0 = new_hotkey.clone();
-                        }
-                    }
-                    LoadedEmission::<T>::remove(netuid);
-                    LoadedEmission::<T>::insert(netuid, old_loaded_emission);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 10.7. Swap neuron TLS certificates.
-            // NeuronCertificates( netuid, hotkey ) -> Vec<u8> -- the neuron certificate for the hotkey.
-            if is_network_member {
-                if let Ok(old_neuron_certificates) =
-                    NeuronCertificates::<T>::try_get(netuid, old_hotkey)
-                {
-                    NeuronCertificates::<T>::remove(netuid, old_hotkey);
-                    NeuronCertificates::<T>::insert(netuid, new_hotkey, old_neuron_certificates);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
+            Self::swap_hotkey_subnet_registration(old_hotkey, new_hotkey, netuid, weight);
+            Self::swap_hotkey_timelocked_weight_commits(old_hotkey, new_hotkey, netuid, weight);
         }
 
         // 11. Swap Stake.
@@ -329,55 +451,12 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
 
-        // 12. Swap ChildKeys.
-        // ChildKeys( parent, netuid ) --> Vec<(proportion,child)> -- the child keys of the parent.
+        // 12. Swap ChildKeys and ParentKeys.
         for netuid in Self::get_all_subnet_netuids() {
-            // Get the children of the old hotkey for this subnet
-            let my_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(old_hotkey, netuid);
-            // Remove the old hotkey's child entries
-            ChildKeys::<T>::remove(old_hotkey, netuid);
-            // Insert the same child entries for the new hotkey
-            ChildKeys::<T>::insert(new_hotkey, netuid, my_children.clone());
-            for (_, child_key_i) in my_children {
-                // For each child, update their parent list
-                let mut child_parents: Vec<(u64, T::AccountId)> =
-                    ParentKeys::<T>::get(child_key_i.clone(), netuid);
-                for parent in child_parents.iter_mut() {
-                    // If the parent is the old hotkey, replace it with the new hotkey
-                    if parent.1 == *old_hotkey {
-                        parent.1 = new_hotkey.clone();
-                    }
-                }
-                // Update the child's parent list
-                ParentKeys::<T>::insert(child_key_i, netuid, child_parents);
-            }
-        }
-
-        // 13. Swap ParentKeys.
-        // ParentKeys( child, netuid ) --> Vec<(proportion,parent)> -- the parent keys of the child.
-        for netuid in Self::get_all_subnet_netuids() {
-            // Get the parents of the old hotkey for this subnet
-            let parents: Vec<(u64, T::AccountId)> = ParentKeys::<T>::get(old_hotkey, netuid);
-            // Remove the old hotkey's parent entries
-            ParentKeys::<T>::remove(old_hotkey, netuid);
-            // Insert the same parent entries for the new hotkey
-            ParentKeys::<T>::insert(new_hotkey, netuid, parents.clone());
-            for (_, parent_key_i) in parents {
-                // For each parent, update their children list
-                let mut parent_children: Vec<(u64, T::AccountId)> =
-                    ChildKeys::<T>::get(parent_key_i.clone(), netuid);
-                for child in parent_children.iter_mut() {
-                    // If the child is the old hotkey, replace it with the new hotkey
-                    if child.1 == *old_hotkey {
-                        child.1 = new_hotkey.clone();
-                    }
-                }
-                // Update the parent's children list
-                ChildKeys::<T>::insert(parent_key_i, netuid, parent_children);
-            }
+            Self::swap_hotkey_subnet_child_relations(old_hotkey, new_hotkey, netuid);
         }
 
-        // 14. Swap Stake Delta for all coldkeys.
+        // 13. Swap Stake Delta for all coldkeys.
         for (coldkey, stake_delta) in StakeDeltaSinceLastEmissionDrain::<T>::iter_prefix(old_hotkey)
         {
             let new_stake_delta = StakeDeltaSinceLastEmissionDrain::<T>::get(new_hotkey, &coldkey);
@@ -390,10 +469,233 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
         }
 
+        // 14. Swap reward destinations of the nominations.
+        // RewardDestinations( coldkey, hotkey ) --> destination -- where the rewards of a nomination are paid.
+        for (coldkey, _) in Stake::<T>::iter_prefix(new_hotkey) {
+            if let Ok(destination) = RewardDestinations::<T>::try_get(&coldkey, old_hotkey) {
+                RewardDestinations::<T>::remove(&coldkey, old_hotkey);
+                RewardDestinations::<T>::insert(&coldkey, new_hotkey, destination);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+        }
+
         // Return successful after swapping all the relevant terms.
         Ok(())
     }
 
+    /// Moves the timelocked weight commits of a hotkey on a subnet to the new hotkey. The commits
+    /// keep the hotkey which signed them, so they reveal against the identity in their payload.
+    pub fn swap_hotkey_timelocked_weight_commits(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+        weight: &mut Weight,
+    ) {
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        if TimelockedWeightCommitCount::<T>::get(netuid) == 0 {
+            return;
+        }
+
+        // TimelockedWeightCommits( netuid, reveal_epoch, hotkey ) --> Vec<(committer, ciphertext, reveal_round)>
+        // The keys of a subnet are bounded by MAX_TIMELOCKED_COMMITS_PER_SUBNET.
+        let keys: Vec<(u64, T::AccountId)> =
+            TimelockedWeightCommits::<T>::iter_key_prefix((netuid,)).collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(keys.len() as u64));
+        for (reveal_epoch, who) in keys {
+            if who != *old_hotkey {
+                continue;
+            }
+            let commits = TimelockedWeightCommits::<T>::take((netuid, reveal_epoch, who));
+            TimelockedWeightCommits::<T>::mutate(
+                (netuid, reveal_epoch, new_hotkey.clone()),
+                |new_commits| new_commits.extend(commits),
+            );
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+    }
+
+    /// Swaps the registration of a hotkey on a subnet: its membership, UID, prometheus, axon,
+    /// weight commits, loaded emission and neuron certificate.
+    pub fn swap_hotkey_subnet_registration(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+        weight: &mut Weight,
+    ) {
+        // 1. Remove the previous hotkey and insert the new hotkey from membership.
+        // IsNetworkMember( hotkey, netuid ) -> bool -- is the hotkey a subnet member.
+        let is_network_member: bool = IsNetworkMember::<T>::get(old_hotkey, netuid);
+        IsNetworkMember::<T>::remove(old_hotkey, netuid);
+        IsNetworkMember::<T>::insert(new_hotkey, netuid, is_network_member);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+        // 2. Swap Uids + Keys.
+        // Keys( netuid, hotkey ) -> uid -- the uid the hotkey has in the network if it is a member.
+        // Uids( netuid, hotkey ) -> uid -- the uids that the hotkey has.
+        if is_network_member {
+            // 2.1 Swap the UIDS
+            if let Ok(old_uid) = Uids::<T>::try_get(netuid, old_hotkey) {
+                Uids::<T>::remove(netuid, old_hotkey);
+                Uids::<T>::insert(netuid, new_hotkey, old_uid);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+                // 2.2 Swap the keys.
+                Keys::<T>::insert(netuid, old_uid, new_hotkey.clone());
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
+            }
+        }
+
+        // 3. Swap Prometheus.
+        // Prometheus( netuid, hotkey ) -> prometheus -- the prometheus data that a hotkey has in the network.
+        if is_network_member {
+            if let Ok(old_prometheus_info) = Prometheus::<T>::try_get(netuid, old_hotkey) {
+                Prometheus::<T>::remove(netuid, old_hotkey);
+                Prometheus::<T>::insert(netuid, new_hotkey, old_prometheus_info);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 4. Swap axons.
+        // Axons( netuid, hotkey ) -> axon -- the axon that the hotkey has.
+        if is_network_member {
+            if let Ok(old_axon_info) = Axons::<T>::try_get(netuid, old_hotkey) {
+                Axons::<T>::remove(netuid, old_hotkey);
+                Axons::<T>::insert(netuid, new_hotkey, old_axon_info);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 5. Swap WeightCommits
+        // WeightCommits( hotkey ) --> Vec<u64> -- the weight commits for the hotkey.
+        if is_network_member {
+            if let Ok(old_weight_commits) = WeightCommits::<T>::try_get(netuid, old_hotkey) {
+                WeightCommits::<T>::remove(netuid, old_hotkey);
+                WeightCommits::<T>::insert(netuid, new_hotkey, old_weight_commits);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 6. Swap the subnet loaded emission.
+        // LoadedEmission( netuid ) --> Vec<(hotkey, u64)> -- the loaded emission for the subnet.
+        if is_network_member {
+            if let Some(mut old_loaded_emission) = LoadedEmission::<T>::get(netuid) {
+                for emission in old_loaded_emission.iter_mut() {
+                    if emission.0 == *old_hotkey {
+                        emission.0 = new_hotkey.clone();
+                    }
+                }
+                LoadedEmission::<T>::remove(netuid);
+                LoadedEmission::<T>::insert(netuid, old_loaded_emission);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 7. Swap neuron TLS certificates.
+        // NeuronCertificates( netuid, hotkey ) -> Vec<u8> -- the neuron certificate for the hotkey.
+        if is_network_member {
+            if let Ok(old_neuron_certificates) =
+                NeuronCertificates::<T>::try_get(netuid, old_hotkey)
+            {
+                NeuronCertificates::<T>::remove(netuid, old_hotkey);
+                NeuronCertificates::<T>::insert(netuid, new_hotkey, old_neuron_certificates);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+    }
+
+    /// Swaps the child and parent keys of a hotkey on a subnet, updating the relations of its
+    /// children and parents, and the scheduled children and expiries referring to it.
+    pub fn swap_hotkey_subnet_child_relations(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+    ) {
+        // ChildKeys( parent, netuid ) --> Vec<(proportion,child)> -- the child keys of the parent.
+        // Get the children of the old hotkey for this subnet
+        let my_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(old_hotkey, netuid);
+        // Remove the old hotkey's child entries
+        ChildKeys::<T>::remove(old_hotkey, netuid);
+        // Insert the same child entries for the new hotkey
+        ChildKeys::<T>::insert(new_hotkey, netuid, my_children.clone());
+        for (_, child_key_i) in my_children {
+            // For each child, update their parent list
+            let mut child_parents: Vec<(u64, T::AccountId)> =
+                ParentKeys::<T>::get(child_key_i.clone(), netuid);
+            for parent in child_parents.iter_mut() {
+                // If the parent is the old hotkey, replace it with the new hotkey
+                if parent.1 == *old_hotkey {
+                    parent.1 = new_hotkey.clone();
+                }
+            }
+            // Update the child's parent list
+            ParentKeys::<T>::insert(child_key_i, netuid, child_parents);
+        }
+
+        // ParentKeys( child, netuid ) --> Vec<(proportion,parent)> -- the parent keys of the child.
+        // Get the parents of the old hotkey for this subnet
+        let parents: Vec<(u64, T::AccountId)> = ParentKeys::<T>::get(old_hotkey, netuid);
+        // Remove the old hotkey's parent entries
+        ParentKeys::<T>::remove(old_hotkey, netuid);
+        // Insert the same parent entries for the new hotkey
+        ParentKeys::<T>::insert(new_hotkey, netuid, parents.clone());
+        for (_, parent_key_i) in parents {
+            // For each parent, update their children list
+            let mut parent_children: Vec<(u64, T::AccountId)> =
+                ChildKeys::<T>::get(parent_key_i.clone(), netuid);
+            for child in parent_children.iter_mut() {
+                // If the child is the old hotkey, replace it with the new hotkey
+                if child.1 == *old_hotkey {
+                    child.1 = new_hotkey.clone();
+                }
+            }
+            // Update the parent's children list
+            ChildKeys::<T>::insert(parent_key_i, netuid, parent_children);
+        }
+
+        // PendingChildKeys( netuid, parent ) --> Vec<(proportion,child,expiry)> -- the scheduled children of the parent.
+        let pending_children: Vec<(T::AccountId, Vec<(u64, T::AccountId, Option<u64>)>)> =
+            PendingChildKeys::<T>::iter_prefix(netuid).collect();
+        for (parent, mut children) in pending_children {
+            if parent != *old_hotkey && !children.iter().any(|(_, child, _)| child == old_hotkey) {
+                continue;
+            }
+            for child in children.iter_mut() {
+                if child.1 == *old_hotkey {
+                    child.1 = new_hotkey.clone();
+                }
+            }
+            PendingChildKeys::<T>::remove(netuid, &parent);
+            let parent = if parent == *old_hotkey {
+                new_hotkey.clone()
+            } else {
+                parent
+            };
+            PendingChildKeys::<T>::insert(netuid, parent, children);
+        }
+
+        // ChildKeyExpiries( netuid, parent ) --> Vec<(child,expiry)> -- the expiries of the children of the parent.
+        let child_expiries: Vec<(T::AccountId, Vec<(T::AccountId, u64)>)> =
+            ChildKeyExpiries::<T>::iter_prefix(netuid).collect();
+        for (parent, mut expiries) in child_expiries {
+            if parent != *old_hotkey && !expiries.iter().any(|(child, _)| child == old_hotkey) {
+                continue;
+            }
+            for expiry in expiries.iter_mut() {
+                if expiry.0 == *old_hotkey {
+                    expiry.0 = new_hotkey.clone();
+                }
+            }
+            ChildKeyExpiries::<T>::remove(netuid, &parent);
+            let parent = if parent == *old_hotkey {
+                new_hotkey.clone()
+            } else {
+                parent
+            };
+            ChildKeyExpiries::<T>::insert(netuid, parent, expiries);
+        }
+    }
+
     pub fn swap_senate_member(
         old_hotkey: &T::AccountId,
         new_hotkey: &T::AccountId,
@@ -404,6 +706,9 @@ impl<T: Config> Pallet<T> {
             T::SenateMembers::swap_member(old_hotkey, new_hotkey).map_err(|e| e.error)?;
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
         }
+        // The senate term and term limit follow the hotkey.
+        Self::swap_senate_term(old_hotkey, new_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 4));
         Ok(())
     }
 }
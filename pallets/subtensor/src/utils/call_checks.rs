@@ -0,0 +1,47 @@
+use super::*;
+
+/// Runs the checks a call has to pass before it changes any state.
+///
+/// The checks of a call are written once against this trait and shared by the dispatchable,
+/// which fails on the first failed check with `DispatchChecks`, and by the pre-dispatch check,
+/// which collects every failed check.
+pub trait CallChecks<T: Config> {
+    /// Fails with `error` unless `passes` holds. The checks after it are skipped, as they depend
+    /// on it, e.g. on the subnet existing.
+    fn require(&mut self, passes: bool, error: Error<T>) -> Result<(), Error<T>>;
+
+    /// Fails with `error` unless `passes` holds. The checks after it do not depend on it.
+    fn ensure(&mut self, passes: bool, error: Error<T>) -> Result<(), Error<T>>;
+
+    /// Fails with `error` if the call is only allowed in `blocks_until_allowed` blocks.
+    fn ensure_rate_limit(
+        &mut self,
+        blocks_until_allowed: u64,
+        error: Error<T>,
+    ) -> Result<(), Error<T>>;
+}
+
+/// The checks of a dispatchable, failing on the first failed check.
+pub struct DispatchChecks;
+
+impl<T: Config> CallChecks<T> for DispatchChecks {
+    fn require(&mut self, passes: bool, error: Error<T>) -> Result<(), Error<T>> {
+        if passes {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    fn ensure(&mut self, passes: bool, error: Error<T>) -> Result<(), Error<T>> {
+        <Self as CallChecks<T>>::require(self, passes, error)
+    }
+
+    fn ensure_rate_limit(
+        &mut self,
+        blocks_until_allowed: u64,
+        error: Error<T>,
+    ) -> Result<(), Error<T>> {
+        <Self as CallChecks<T>>::require(self, blocks_until_allowed == 0, error)
+    }
+}
@@ -1,15 +1,95 @@
 use super::*;
 use frame_support::ensure;
 use frame_system::ensure_signed;
+use sp_core::H256;
 use sp_std::vec::Vec;
 
+/// The typed identity fields, in the order they are reported.
+pub const IDENTITY_FIELDS: [IdentityField; 5] = [
+    IdentityField::Url,
+    IdentityField::GithubRepo,
+    IdentityField::Discord,
+    IdentityField::Contact,
+    IdentityField::LogoHash,
+];
+
+/// The typed fields added to chain identities along with the typed calls. They are validated on
+/// every identity update, the other fields only by the typed calls.
+const CHAIN_IDENTITY_TYPED_ONLY_FIELDS: [IdentityField; 3] = [
+    IdentityField::GithubRepo,
+    IdentityField::Contact,
+    IdentityField::LogoHash,
+];
+
+/// The typed fields added to subnet identities along with the typed calls. They are validated on
+/// every identity update, the other fields only by the typed calls.
+const SUBNET_IDENTITY_TYPED_ONLY_FIELDS: [IdentityField; 3] = [
+    IdentityField::Url,
+    IdentityField::Discord,
+    IdentityField::LogoHash,
+];
+
 impl<T: Config> Pallet<T> {
+    /// Sets the identity for a coldkey, including its typed fields.
+    ///
+    /// This function allows a user to set or update their identity information associated with their coldkey.
+    /// It checks if the caller has at least one registered hotkey, validates the provided identity information,
+    /// and then stores it in the blockchain state.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, which should be a signed extrinsic.
+    /// * `name` - The name to be associated with the identity.
+    /// * `url` - A URL associated with the identity.
+    /// * `image` - An image URL or identifier for the identity.
+    /// * `discord` - Discord information for the identity.
+    /// * `description` - A description of the identity.
+    /// * `additional` - Any additional information for the identity.
+    /// * `github_repo` - The GitHub repository or organization of the identity.
+    /// * `contact` - Contact information for the identity.
+    /// * `logo_hash` - The hash of the logo of the identity.
+    ///
+    /// Every typed field must be well formed, see `is_valid_identity_field`. The attestations of
+    /// the typed fields whose value changes are revoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the identity is successfully set, otherwise returns an error.
+    pub fn do_set_typed_identity(
+        origin: T::RuntimeOrigin,
+        name: Vec<u8>,
+        url: Vec<u8>,
+        image: Vec<u8>,
+        discord: Vec<u8>,
+        description: Vec<u8>,
+        additional: Vec<u8>,
+        github_repo: Vec<u8>,
+        contact: Vec<u8>,
+        logo_hash: Option<H256>,
+    ) -> dispatch::DispatchResult {
+        let identity = ChainIdentityOf {
+            name,
+            url,
+            image,
+            discord,
+            description,
+            additional,
+            github_repo,
+            contact,
+            logo_hash,
+        };
+        Self::store_identity(origin, identity, true)
+    }
+
     /// Sets the identity for a coldkey.
     ///
     /// This function allows a user to set or update their identity information associated with their coldkey.
     /// It checks if the caller has at least one registered hotkey, validates the provided identity information,
     /// and then stores it in the blockchain state.
     ///
+    /// The typed fields the call does not carry keep the values of the current identity, so
+    /// clients built before the typed fields do not clear them.
+    ///
     /// # Arguments
     ///
     /// * `origin` - The origin of the call, which should be a signed extrinsic.
@@ -31,6 +111,30 @@ impl<T: Config> Pallet<T> {
         discord: Vec<u8>,
         description: Vec<u8>,
         additional: Vec<u8>,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin.clone())?;
+        let current: ChainIdentityOf = Identities::<T>::get(&coldkey).unwrap_or_default();
+        let identity = ChainIdentityOf {
+            name,
+            url,
+            image,
+            discord,
+            description,
+            additional,
+            github_repo: current.github_repo,
+            contact: current.contact,
+            logo_hash: current.logo_hash,
+        };
+        Self::store_identity(origin, identity, false)
+    }
+
+    /// Validates and stores the identity of the signing coldkey, revoking the attestations of
+    /// the typed fields whose value changes. All typed fields are validated if `typed`,
+    /// otherwise only the ones added along with the typed calls.
+    fn store_identity(
+        origin: T::RuntimeOrigin,
+        identity: ChainIdentityOf,
+        typed: bool,
     ) -> dispatch::DispatchResult {
         // Ensure the call is signed and get the signer's (coldkey) account
         let coldkey = ensure_signed(origin)?;
@@ -46,21 +150,24 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyNotRegisteredInNetwork
         );
 
-        // Create the identity struct with the provided information
-        let identity = ChainIdentityOf {
-            name,
-            url,
-            image,
-            discord,
-            description,
-            additional,
+        // Validate the identity
+        let is_valid: bool = if typed {
+            Self::is_valid_typed_identity(&identity)
+        } else {
+            Self::is_valid_identity(&identity)
         };
+        ensure!(is_valid, Error::<T>::InvalidIdentity);
 
-        // Validate the created identity
-        ensure!(
-            Self::is_valid_identity(&identity),
-            Error::<T>::InvalidIdentity
-        );
+        // Revoke the attestations of the changed fields
+        if let Some(old_identity) = Identities::<T>::get(&coldkey) {
+            for field in IDENTITY_FIELDS {
+                if Self::identity_field_value(&old_identity, field)
+                    != Self::identity_field_value(&identity, field)
+                {
+                    IdentityAttestations::<T>::remove(&coldkey, field);
+                }
+            }
+        }
 
         // Store the validated identity in the blockchain state
         Identities::<T>::insert(coldkey.clone(), identity.clone());
@@ -75,12 +182,59 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Sets the identity for a subnet, including its typed fields.
+    ///
+    /// This function allows the owner of a subnet to set or update the identity information associated with the subnet.
+    /// It verifies that the caller is the owner of the specified subnet, validates the provided identity information,
+    /// and then stores it in the blockchain state.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, which should be a signed extrinsic.
+    /// * `netuid` - The unique identifier for the subnet.
+    /// * `subnet_name` - The name of the subnet to be associated with the identity.
+    /// * `github_repo` - The GitHub repository URL associated with the subnet identity.
+    /// * `subnet_contact` - Contact information for the subnet.
+    /// * `subnet_url` - The URL of the subnet.
+    /// * `discord` - Discord information for the subnet.
+    /// * `logo_hash` - The hash of the logo of the subnet.
+    ///
+    /// Every typed field must be well formed, see `is_valid_identity_field`. The attestations of
+    /// the typed fields whose value changes are revoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the subnet identity is successfully set, otherwise returns an error.
+    pub fn do_set_typed_subnet_identity(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        subnet_name: Vec<u8>,
+        github_repo: Vec<u8>,
+        subnet_contact: Vec<u8>,
+        subnet_url: Vec<u8>,
+        discord: Vec<u8>,
+        logo_hash: Option<H256>,
+    ) -> dispatch::DispatchResult {
+        let identity: SubnetIdentityOf = SubnetIdentityOf {
+            subnet_name,
+            github_repo,
+            subnet_contact,
+            subnet_url,
+            discord,
+            logo_hash,
+        };
+        Self::store_subnet_identity(origin, netuid, identity, true)
+    }
+
     /// Sets the identity for a subnet.
     ///
     /// This function allows the owner of a subnet to set or update the identity information associated with the subnet.
     /// It verifies that the caller is the owner of the specified subnet, validates the provided identity information,
     /// and then stores it in the blockchain state.
     ///
+    /// The typed fields the call does not carry keep the values of the current identity, so
+    /// clients built before the typed fields do not clear them.
+    ///
     /// # Arguments
     ///
     /// * `origin` - The origin of the call, which should be a signed extrinsic.
@@ -98,6 +252,27 @@ impl<T: Config> Pallet<T> {
         subnet_name: Vec<u8>,
         github_repo: Vec<u8>,
         subnet_contact: Vec<u8>,
+    ) -> dispatch::DispatchResult {
+        let current: SubnetIdentityOf = SubnetIdentities::<T>::get(netuid).unwrap_or_default();
+        let identity: SubnetIdentityOf = SubnetIdentityOf {
+            subnet_name,
+            github_repo,
+            subnet_contact,
+            subnet_url: current.subnet_url,
+            discord: current.discord,
+            logo_hash: current.logo_hash,
+        };
+        Self::store_subnet_identity(origin, netuid, identity, false)
+    }
+
+    /// Validates and stores the identity of a subnet owned by the signing coldkey, revoking the
+    /// attestations of the typed fields whose value changes. All typed fields are validated if
+    /// `typed`, otherwise only the ones added along with the typed calls.
+    fn store_subnet_identity(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        identity: SubnetIdentityOf,
+        typed: bool,
     ) -> dispatch::DispatchResult {
         // Ensure the call is signed and get the signer's (coldkey) account
         let coldkey = ensure_signed(origin)?;
@@ -108,18 +283,24 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotSubnetOwner
         );
 
-        // Create the identity struct with the provided information
-        let identity: SubnetIdentityOf = SubnetIdentityOf {
-            subnet_name,
-            github_repo,
-            subnet_contact,
+        // Validate the identity
+        let is_valid: bool = if typed {
+            Self::is_valid_typed_subnet_identity(&identity)
+        } else {
+            Self::is_valid_subnet_identity(&identity)
         };
+        ensure!(is_valid, Error::<T>::InvalidIdentity);
 
-        // Validate the created identity
-        ensure!(
-            Self::is_valid_subnet_identity(&identity),
-            Error::<T>::InvalidIdentity
-        );
+        // Revoke the attestations of the changed fields
+        if let Some(old_identity) = SubnetIdentities::<T>::get(netuid) {
+            for field in IDENTITY_FIELDS {
+                if Self::subnet_identity_field_value(&old_identity, field)
+                    != Self::subnet_identity_field_value(&identity, field)
+                {
+                    SubnetIdentityAttestations::<T>::remove(netuid, field);
+                }
+            }
+        }
 
         // Store the validated identity in the blockchain state
         SubnetIdentities::<T>::insert(netuid, identity.clone());
@@ -134,11 +315,176 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Attests or revokes a typed field of the identity of a coldkey.
+    ///
+    /// This function allows an identity registrar to attest that a typed field of the identity of
+    /// a coldkey has been verified, or to revoke its attestation. The attestation is revoked when
+    /// the value of the field changes, or ignored once the registrar is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, which should be a signed extrinsic of a registrar.
+    /// * `coldkey` - The coldkey whose identity is attested.
+    /// * `field` - The typed field of the identity.
+    /// * `verified` - Whether the field is attested or its attestation revoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the attestation is successfully set, otherwise returns an error.
+    pub fn do_attest_identity_field(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        field: IdentityField,
+        verified: bool,
+    ) -> dispatch::DispatchResult {
+        // Ensure the call is signed by an identity registrar
+        let registrar = ensure_signed(origin)?;
+        ensure!(
+            Self::get_identity_registrars().contains(&registrar),
+            Error::<T>::NotIdentityRegistrar
+        );
+
+        // Ensure that the attested field is set
+        let identity = Identities::<T>::get(&coldkey).ok_or(Error::<T>::IdentityFieldNotSet)?;
+        ensure!(
+            !Self::identity_field_value(&identity, field).is_empty(),
+            Error::<T>::IdentityFieldNotSet
+        );
+
+        // Store or revoke the attestation
+        if verified {
+            IdentityAttestations::<T>::insert(
+                &coldkey,
+                field,
+                IdentityAttestation {
+                    registrar: registrar.clone(),
+                    block: Self::get_current_block_as_u64(),
+                },
+            );
+        } else {
+            IdentityAttestations::<T>::remove(&coldkey, field);
+        }
+
+        log::debug!(
+            "IdentityFieldAttested( registrar:{:?} coldkey:{:?} field:{:?} verified:{:?} ) ",
+            registrar,
+            coldkey,
+            field,
+            verified
+        );
+        Self::deposit_event(Event::IdentityFieldAttested(
+            registrar, coldkey, field, verified,
+        ));
+
+        Ok(())
+    }
+
+    /// Attests or revokes a typed field of the identity of a subnet.
+    ///
+    /// This function allows an identity registrar to attest that a typed field of the identity of
+    /// a subnet has been verified, or to revoke its attestation. The attestation is revoked when
+    /// the value of the field changes, or ignored once the registrar is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, which should be a signed extrinsic of a registrar.
+    /// * `netuid` - The unique identifier for the subnet.
+    /// * `field` - The typed field of the identity.
+    /// * `verified` - Whether the field is attested or its attestation revoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the attestation is successfully set, otherwise returns an error.
+    pub fn do_attest_subnet_identity_field(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        field: IdentityField,
+        verified: bool,
+    ) -> dispatch::DispatchResult {
+        // Ensure the call is signed by an identity registrar
+        let registrar = ensure_signed(origin)?;
+        ensure!(
+            Self::get_identity_registrars().contains(&registrar),
+            Error::<T>::NotIdentityRegistrar
+        );
+
+        // Ensure that the attested field is set
+        let identity = SubnetIdentities::<T>::get(netuid).ok_or(Error::<T>::IdentityFieldNotSet)?;
+        ensure!(
+            !Self::subnet_identity_field_value(&identity, field).is_empty(),
+            Error::<T>::IdentityFieldNotSet
+        );
+
+        // Store or revoke the attestation
+        if verified {
+            SubnetIdentityAttestations::<T>::insert(
+                netuid,
+                field,
+                IdentityAttestation {
+                    registrar: registrar.clone(),
+                    block: Self::get_current_block_as_u64(),
+                },
+            );
+        } else {
+            SubnetIdentityAttestations::<T>::remove(netuid, field);
+        }
+
+        log::debug!(
+            "SubnetIdentityFieldAttested( registrar:{:?} netuid:{:?} field:{:?} verified:{:?} ) ",
+            registrar,
+            netuid,
+            field,
+            verified
+        );
+        Self::deposit_event(Event::SubnetIdentityFieldAttested(
+            registrar, netuid, field, verified,
+        ));
+
+        Ok(())
+    }
+
+    /// Returns the typed fields of the identity of a coldkey attested by a current registrar.
+    pub fn get_verified_identity_fields(coldkey: &T::AccountId) -> Vec<IdentityField> {
+        let registrars: Vec<T::AccountId> = Self::get_identity_registrars();
+        IDENTITY_FIELDS
+            .into_iter()
+            .filter(
+                |field| match IdentityAttestations::<T>::get(coldkey, field) {
+                    Some(attestation) => registrars.contains(&attestation.registrar),
+                    None => false,
+                },
+            )
+            .collect()
+    }
+
+    /// Returns the typed fields of the identity of a subnet attested by a current registrar.
+    pub fn get_verified_subnet_identity_fields(netuid: u16) -> Vec<IdentityField> {
+        let registrars: Vec<T::AccountId> = Self::get_identity_registrars();
+        IDENTITY_FIELDS
+            .into_iter()
+            .filter(
+                |field| match SubnetIdentityAttestations::<T>::get(netuid, field) {
+                    Some(attestation) => registrars.contains(&attestation.registrar),
+                    None => false,
+                },
+            )
+            .collect()
+    }
+
+    pub fn get_identity_registrars() -> Vec<T::AccountId> {
+        IdentityRegistrars::<T>::get()
+    }
+    pub fn set_identity_registrars(registrars: Vec<T::AccountId>) {
+        IdentityRegistrars::<T>::put(registrars.clone());
+        Self::deposit_event(Event::IdentityRegistrarsSet(registrars));
+    }
+
     /// Validates the given ChainIdentityOf struct.
     ///
     /// This function checks if the total length of all fields in the ChainIdentityOf struct
-    /// is less than or equal to 512 bytes, and if each individual field is also
-    /// less than or equal to 512 bytes.
+    /// is within the sum of the field limits, if each individual field is also within its
+    /// respective maximum byte limit, and if the typed fields only set by the typed call are
+    /// well formed.
     ///
     /// # Arguments
     ///
@@ -155,22 +501,39 @@ impl<T: Config> Pallet<T> {
             .saturating_add(identity.image.len())
             .saturating_add(identity.discord.len())
             .saturating_add(identity.description.len())
-            .saturating_add(identity.additional.len());
+            .saturating_add(identity.additional.len())
+            .saturating_add(identity.github_repo.len())
+            .saturating_add(identity.contact.len());
 
-        total_length <= 256 + 256 + 1024 + 256 + 1024 + 1024
+        total_length <= 256 + 256 + 1024 + 256 + 1024 + 1024 + 1024 + 1024
             && identity.name.len() <= 256
             && identity.url.len() <= 256
             && identity.image.len() <= 1024
             && identity.discord.len() <= 256
             && identity.description.len() <= 1024
             && identity.additional.len() <= 1024
+            && identity.github_repo.len() <= 1024
+            && identity.contact.len() <= 1024
+            && CHAIN_IDENTITY_TYPED_ONLY_FIELDS.iter().all(|field| {
+                Self::is_valid_identity_field(*field, &Self::identity_field_value(identity, *field))
+            })
+    }
+
+    /// Validates the given ChainIdentityOf struct as set by the typed call: as
+    /// `is_valid_identity`, with every typed field well formed.
+    pub fn is_valid_typed_identity(identity: &ChainIdentityOf) -> bool {
+        Self::is_valid_identity(identity)
+            && IDENTITY_FIELDS.iter().all(|field| {
+                Self::is_valid_identity_field(*field, &Self::identity_field_value(identity, *field))
+            })
     }
 
     /// Validates the given SubnetIdentityOf struct.
     ///
     /// This function checks if the total length of all fields in the SubnetIdentityOf struct
-    /// is less than or equal to 2304 bytes, and if each individual field is also
-    /// within its respective maximum byte limit.
+    /// is within the sum of the field limits, if each individual field is also within its
+    /// respective maximum byte limit, and if the typed fields only set by the typed call are
+    /// well formed.
     ///
     /// # Arguments
     ///
@@ -184,11 +547,117 @@ impl<T: Config> Pallet<T> {
             .subnet_name
             .len()
             .saturating_add(identity.github_repo.len())
-            .saturating_add(identity.subnet_contact.len());
+            .saturating_add(identity.subnet_contact.len())
+            .saturating_add(identity.subnet_url.len())
+            .saturating_add(identity.discord.len());
 
-        total_length <= 256 + 1024 + 1024
+        total_length <= 256 + 1024 + 1024 + 256 + 256
             && identity.subnet_name.len() <= 256
             && identity.github_repo.len() <= 1024
             && identity.subnet_contact.len() <= 1024
+            && identity.subnet_url.len() <= 256
+            && identity.discord.len() <= 256
+            && SUBNET_IDENTITY_TYPED_ONLY_FIELDS.iter().all(|field| {
+                Self::is_valid_identity_field(
+                    *field,
+                    &Self::subnet_identity_field_value(identity, *field),
+                )
+            })
+    }
+
+    /// Validates the given SubnetIdentityOf struct as set by the typed call: as
+    /// `is_valid_subnet_identity`, with every typed field well formed.
+    pub fn is_valid_typed_subnet_identity(identity: &SubnetIdentityOf) -> bool {
+        Self::is_valid_subnet_identity(identity)
+            && IDENTITY_FIELDS.iter().all(|field| {
+                Self::is_valid_identity_field(
+                    *field,
+                    &Self::subnet_identity_field_value(identity, *field),
+                )
+            })
+    }
+
+    /// Validates the value of a typed identity field. Empty values leave the field unset.
+    ///
+    /// * `Url`: an http or https link without whitespace.
+    /// * `GithubRepo`: an `owner` or `owner/repo` path, optionally as a https://github.com link,
+    ///   of ASCII alphanumerics, '-', '_' and '.'.
+    /// * `Discord`: a handle or invite of printable ASCII without whitespace.
+    /// * `Contact`: UTF-8 text without control characters.
+    /// * `LogoHash`: a 32 byte hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The typed field.
+    /// * `value` - The value of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Returns true if the value is valid for the field, false otherwise.
+    pub fn is_valid_identity_field(field: IdentityField, value: &[u8]) -> bool {
+        if value.is_empty() {
+            return true;
+        }
+        let is_printable_ascii = |bytes: &[u8]| bytes.iter().all(|byte| byte.is_ascii_graphic());
+
+        match field {
+            IdentityField::Url => {
+                let link: Option<&[u8]> = value
+                    .strip_prefix(b"https://")
+                    .or_else(|| value.strip_prefix(b"http://"));
+                match link {
+                    Some(link) => !link.is_empty() && is_printable_ascii(link),
+                    None => false,
+                }
+            }
+            IdentityField::GithubRepo => {
+                let path: &[u8] = value.strip_prefix(b"https://github.com/").unwrap_or(value);
+                let segments: Vec<&[u8]> = path.split(|byte| *byte == b'/').collect();
+                (1..=2).contains(&segments.len())
+                    && segments.iter().all(|segment| {
+                        !segment.is_empty()
+                            && segment.iter().all(|byte| {
+                                byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.')
+                            })
+                    })
+            }
+            IdentityField::Discord => is_printable_ascii(value),
+            IdentityField::Contact => match core::str::from_utf8(value) {
+                Ok(contact) => !contact.chars().any(|c| c.is_control()),
+                Err(_) => false,
+            },
+            IdentityField::LogoHash => value.len() == 32,
+        }
+    }
+
+    /// Returns the value of a typed field of a chain identity, empty if the field is unset.
+    pub fn identity_field_value(identity: &ChainIdentityOf, field: IdentityField) -> Vec<u8> {
+        match field {
+            IdentityField::Url => identity.url.clone(),
+            IdentityField::GithubRepo => identity.github_repo.clone(),
+            IdentityField::Discord => identity.discord.clone(),
+            IdentityField::Contact => identity.contact.clone(),
+            IdentityField::LogoHash => identity
+                .logo_hash
+                .map(|hash| hash.as_bytes().to_vec())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the value of a typed field of a subnet identity, empty if the field is unset.
+    pub fn subnet_identity_field_value(
+        identity: &SubnetIdentityOf,
+        field: IdentityField,
+    ) -> Vec<u8> {
+        match field {
+            IdentityField::Url => identity.subnet_url.clone(),
+            IdentityField::GithubRepo => identity.github_repo.clone(),
+            IdentityField::Discord => identity.discord.clone(),
+            IdentityField::Contact => identity.subnet_contact.clone(),
+            IdentityField::LogoHash => identity
+                .logo_hash
+                .map(|hash| hash.as_bytes().to_vec())
+                .unwrap_or_default(),
+        }
     }
 }
@@ -149,7 +149,11 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::WeightsMinStake(min_stake));
     }
     pub fn set_target_stakes_per_interval(target_stakes_per_interval: u64) {
-        TargetStakesPerInterval::<T>::set(target_stakes_per_interval);
+        TxRateLimits::<T>::insert(
+            u16::from(TransactionType::Stake),
+            GLOBAL_RATE_LIMIT_NETUID,
+            target_stakes_per_interval,
+        );
         Self::deposit_event(Event::TargetStakesPerIntervalSet(
             target_stakes_per_interval,
         ));
@@ -306,17 +310,23 @@ impl<T: Config> Pallet<T> {
 
     // Configure tx rate limiting
     pub fn get_tx_rate_limit() -> u64 {
-        TxRateLimit::<T>::get()
+        Self::get_rate_limit(&TransactionType::BecomeDelegate)
     }
     pub fn set_tx_rate_limit(tx_rate_limit: u64) {
-        TxRateLimit::<T>::put(tx_rate_limit);
+        for tx_type in [TransactionType::BecomeDelegate, TransactionType::SwapHotkey] {
+            TxRateLimits::<T>::insert(u16::from(tx_type), GLOBAL_RATE_LIMIT_NETUID, tx_rate_limit);
+        }
         Self::deposit_event(Event::TxRateLimitSet(tx_rate_limit));
     }
     pub fn get_tx_delegate_take_rate_limit() -> u64 {
-        TxDelegateTakeRateLimit::<T>::get()
+        Self::get_rate_limit(&TransactionType::IncreaseDelegateTake)
     }
     pub fn set_tx_delegate_take_rate_limit(tx_rate_limit: u64) {
-        TxDelegateTakeRateLimit::<T>::put(tx_rate_limit);
+        TxRateLimits::<T>::insert(
+            u16::from(TransactionType::IncreaseDelegateTake),
+            GLOBAL_RATE_LIMIT_NETUID,
+            tx_rate_limit,
+        );
         Self::deposit_event(Event::TxDelegateTakeRateLimitSet(tx_rate_limit));
     }
     pub fn set_min_delegate_take(take: u16) {
@@ -343,10 +353,14 @@ impl<T: Config> Pallet<T> {
         MinChildkeyTake::<T>::get()
     }
     pub fn get_tx_childkey_take_rate_limit() -> u64 {
-        TxChildkeyTakeRateLimit::<T>::get()
+        Self::get_rate_limit(&TransactionType::SetChildkeyTake)
     }
     pub fn set_tx_childkey_take_rate_limit(tx_rate_limit: u64) {
-        TxChildkeyTakeRateLimit::<T>::put(tx_rate_limit);
+        TxRateLimits::<T>::insert(
+            u16::from(TransactionType::SetChildkeyTake),
+            GLOBAL_RATE_LIMIT_NETUID,
+            tx_rate_limit,
+        );
         Self::deposit_event(Event::TxChildKeyTakeRateLimitSet(tx_rate_limit));
     }
     pub fn set_min_childkey_take(take: u16) {
@@ -366,10 +380,12 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn get_serving_rate_limit(netuid: u16) -> u64 {
-        ServingRateLimit::<T>::get(netuid)
+        Self::get_rate_limit_on_subnet(&TransactionType::ServeAxon, netuid)
     }
     pub fn set_serving_rate_limit(netuid: u16, serving_rate_limit: u64) {
-        ServingRateLimit::<T>::insert(netuid, serving_rate_limit);
+        for tx_type in [TransactionType::ServeAxon, TransactionType::ServePrometheus] {
+            TxRateLimits::<T>::insert(u16::from(tx_type), netuid, serving_rate_limit);
+        }
         Self::deposit_event(Event::ServingRateLimitSet(netuid, serving_rate_limit));
     }
 
@@ -398,10 +414,14 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn get_weights_set_rate_limit(netuid: u16) -> u64 {
-        WeightsSetRateLimit::<T>::get(netuid)
+        Self::get_rate_limit_on_subnet(&TransactionType::SetWeights, netuid)
     }
     pub fn set_weights_set_rate_limit(netuid: u16, weights_set_rate_limit: u64) {
-        WeightsSetRateLimit::<T>::insert(netuid, weights_set_rate_limit);
+        TxRateLimits::<T>::insert(
+            u16::from(TransactionType::SetWeights),
+            netuid,
+            weights_set_rate_limit,
+        );
         Self::deposit_event(Event::WeightsSetRateLimitSet(
             netuid,
             weights_set_rate_limit,
@@ -424,6 +444,20 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::AdjustmentAlphaSet(netuid, adjustment_alpha));
     }
 
+    pub fn get_adjustment_controller(netuid: u16) -> AdjustmentController {
+        SubnetAdjustmentController::<T>::get(netuid)
+    }
+    pub fn set_adjustment_controller(netuid: u16, controller: AdjustmentController) {
+        SubnetAdjustmentController::<T>::insert(netuid, controller);
+        // The accumulated error of a previous controller does not carry over.
+        AdjustmentIntegral::<T>::remove(netuid);
+        Self::deposit_event(Event::AdjustmentControllerSet(netuid, controller));
+    }
+
+    pub fn get_adjustment_integral(netuid: u16) -> i64 {
+        AdjustmentIntegral::<T>::get(netuid)
+    }
+
     pub fn get_validator_prune_len(netuid: u16) -> u64 {
         ValidatorPruneLen::<T>::get(netuid)
     }
@@ -508,6 +542,14 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::ActivityCutoffSet(netuid, activity_cutoff));
     }
 
+    pub fn get_unbonding_period(netuid: u16) -> u64 {
+        UnbondingPeriod::<T>::get(netuid)
+    }
+    pub fn set_unbonding_period(netuid: u16, unbonding_period: u64) {
+        UnbondingPeriod::<T>::insert(netuid, unbonding_period);
+        Self::deposit_event(Event::UnbondingPeriodSet(netuid, unbonding_period));
+    }
+
     // Registration Toggle utils
     pub fn get_network_registration_allowed(netuid: u16) -> bool {
         NetworkRegistrationAllowed::<T>::get(netuid)
@@ -590,10 +632,18 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn get_max_registrations_per_block(netuid: u16) -> u16 {
-        MaxRegistrationsPerBlock::<T>::get(netuid)
+        u16::try_from(Self::get_rate_limit_on_subnet(
+            &TransactionType::Register,
+            netuid,
+        ))
+        .unwrap_or(u16::MAX)
     }
     pub fn set_max_registrations_per_block(netuid: u16, max_registrations_per_block: u16) {
-        MaxRegistrationsPerBlock::<T>::insert(netuid, max_registrations_per_block);
+        TxRateLimits::<T>::insert(
+            u16::from(TransactionType::Register),
+            netuid,
+            u64::from(max_registrations_per_block),
+        );
         Self::deposit_event(Event::MaxRegistrationsPerBlockSet(
             netuid,
             max_registrations_per_block,
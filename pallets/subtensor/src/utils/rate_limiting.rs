@@ -1,5 +1,6 @@
 use super::*;
-use sp_core::Get;
+use frame_support::pallet_prelude::Decode;
+use sp_core::{hexdisplay::AsBytesRef, Get};
 
 /// Enum representing different types of transactions
 #[derive(Copy, Clone)]
@@ -7,6 +8,15 @@ pub enum TransactionType {
     SetChildren,
     SetChildkeyTake,
     Unknown,
+    RegisterNetwork,
+    SetWeights,
+    ServeAxon,
+    ServePrometheus,
+    BecomeDelegate,
+    SwapHotkey,
+    IncreaseDelegateTake,
+    Stake,
+    Register,
 }
 
 /// Implement conversion from TransactionType to u16
@@ -16,6 +26,15 @@ impl From<TransactionType> for u16 {
             TransactionType::SetChildren => 0,
             TransactionType::SetChildkeyTake => 1,
             TransactionType::Unknown => 2,
+            TransactionType::RegisterNetwork => 3,
+            TransactionType::SetWeights => 4,
+            TransactionType::ServeAxon => 5,
+            TransactionType::ServePrometheus => 6,
+            TransactionType::BecomeDelegate => 7,
+            TransactionType::SwapHotkey => 8,
+            TransactionType::IncreaseDelegateTake => 9,
+            TransactionType::Stake => 10,
+            TransactionType::Register => 11,
         }
     }
 }
@@ -26,21 +45,228 @@ impl From<u16> for TransactionType {
         match value {
             0 => TransactionType::SetChildren,
             1 => TransactionType::SetChildkeyTake,
+            3 => TransactionType::RegisterNetwork,
+            4 => TransactionType::SetWeights,
+            5 => TransactionType::ServeAxon,
+            6 => TransactionType::ServePrometheus,
+            7 => TransactionType::BecomeDelegate,
+            8 => TransactionType::SwapHotkey,
+            9 => TransactionType::IncreaseDelegateTake,
+            10 => TransactionType::Stake,
+            11 => TransactionType::Register,
             _ => TransactionType::Unknown,
         }
     }
 }
+
+impl TransactionType {
+    /// Whether the first transaction of an account (no last block recorded) is exempt from the limit.
+    /// Network registrations are limited chain wide, so the exemption does not apply to them.
+    fn exempts_first_transaction(&self) -> bool {
+        !matches!(self, TransactionType::RegisterNetwork)
+    }
+
+    /// Whether the limit has to be strictly exceeded before the next transaction is allowed.
+    /// This is the case for the transactions limited by the tx and delegate take rate limits.
+    fn requires_strictly_exceeding_limit(&self) -> bool {
+        matches!(
+            self,
+            TransactionType::BecomeDelegate
+                | TransactionType::SwapHotkey
+                | TransactionType::IncreaseDelegateTake
+        )
+    }
+
+    /// Whether the limit is the number of transactions allowed within an interval rather than
+    /// the number of blocks between two transactions. Stakes are counted per coldkey and hotkey
+    /// within the stake interval, registrations per subnet within a block.
+    fn limits_transactions_per_interval(&self) -> bool {
+        matches!(self, TransactionType::Stake | TransactionType::Register)
+    }
+}
+
 impl<T: Config> Pallet<T> {
     // ========================
     // ==== Rate Limiting =====
     // ========================
     /// Get the rate limit for a specific transaction type
     pub fn get_rate_limit(tx_type: &TransactionType) -> u64 {
+        Self::get_rate_limit_on_subnet(tx_type, GLOBAL_RATE_LIMIT_NETUID)
+    }
+
+    /// Get the rate limit for a specific transaction type on a subnet. The limit set for the subnet
+    /// takes precedence over the global limit, which takes precedence over the legacy limit.
+    pub fn get_rate_limit_on_subnet(tx_type: &TransactionType, netuid: u16) -> u64 {
+        let tx_as_u16: u16 = (*tx_type).into();
+        TxRateLimits::<T>::get(tx_as_u16, netuid)
+            .or_else(|| TxRateLimits::<T>::get(tx_as_u16, GLOBAL_RATE_LIMIT_NETUID))
+            .unwrap_or_else(|| Self::get_legacy_rate_limit(tx_type, netuid))
+    }
+
+    /// Get the limit of a transaction type from the rate limit items predating `TxRateLimits`.
+    fn get_legacy_rate_limit(tx_type: &TransactionType, netuid: u16) -> u64 {
         match tx_type {
             TransactionType::SetChildren => (DefaultTempo::<T>::get().saturating_mul(2)).into(), // Cannot set children twice within the default tempo period.
             TransactionType::SetChildkeyTake => TxChildkeyTakeRateLimit::<T>::get(),
             TransactionType::Unknown => 0, // Default to no limit for unknown types (no limit)
+            TransactionType::RegisterNetwork => NetworkRateLimit::<T>::get(),
+            TransactionType::SetWeights => WeightsSetRateLimit::<T>::get(netuid),
+            TransactionType::ServeAxon | TransactionType::ServePrometheus => {
+                ServingRateLimit::<T>::get(netuid)
+            }
+            TransactionType::BecomeDelegate | TransactionType::SwapHotkey => {
+                TxRateLimit::<T>::get()
+            }
+            TransactionType::IncreaseDelegateTake => TxDelegateTakeRateLimit::<T>::get(),
+            TransactionType::Stake => TargetStakesPerInterval::<T>::get(),
+            TransactionType::Register => MaxRegistrationsPerBlock::<T>::get(netuid).into(),
+        }
+    }
+
+    /// Set the rate limit for a specific transaction type on a subnet, or on all subnets when
+    /// `netuid` is GLOBAL_RATE_LIMIT_NETUID.
+    pub fn set_rate_limit(tx_type: &TransactionType, netuid: u16, rate_limit: u64) {
+        let tx_as_u16: u16 = (*tx_type).into();
+        TxRateLimits::<T>::insert(tx_as_u16, netuid, rate_limit);
+        Self::deposit_event(Event::RateLimitSet(tx_as_u16, netuid, rate_limit));
+    }
+
+    /// Get the block of the last transaction of a specific type made by `account` on a subnet.
+    /// Depending on the transaction type the account is a hotkey or a coldkey, and the netuid is
+    /// ignored for transactions which are not limited per subnet.
+    pub fn get_last_block_for_rate_limit(
+        tx_type: &TransactionType,
+        account: &T::AccountId,
+        netuid: u16,
+    ) -> u64 {
+        match tx_type {
+            TransactionType::SetChildren
+            | TransactionType::SetChildkeyTake
+            | TransactionType::Unknown => {
+                Self::get_last_transaction_block(account, netuid, tx_type)
+            }
+            TransactionType::RegisterNetwork => Self::get_network_last_lock_block(),
+            TransactionType::SetWeights => Self::get_uid_for_net_and_hotkey(netuid, account)
+                .map(|uid| Self::get_last_update_for_uid(netuid, uid))
+                .unwrap_or(0),
+            TransactionType::ServeAxon => Self::get_axon_info(netuid, account).block,
+            TransactionType::ServePrometheus => Self::get_prometheus_info(netuid, account).block,
+            TransactionType::BecomeDelegate | TransactionType::SwapHotkey => {
+                Self::get_last_tx_block(account)
+            }
+            TransactionType::IncreaseDelegateTake => Self::get_last_tx_block_delegate_take(account),
+            // Limited per interval, see `get_blocks_until_allowed`.
+            TransactionType::Stake | TransactionType::Register => 0,
+        }
+    }
+
+    /// Get the number of blocks which have to pass at `current_block` before a transaction of a
+    /// specific type, last made at `last_block`, is allowed again on a subnet. Zero if it is allowed.
+    pub fn get_rate_limit_blocks_remaining(
+        tx_type: &TransactionType,
+        netuid: u16,
+        last_block: u64,
+        current_block: u64,
+    ) -> u64 {
+        let limit: u64 = Self::get_rate_limit_on_subnet(tx_type, netuid);
+        if limit == 0 || (last_block == 0 && tx_type.exempts_first_transaction()) {
+            return 0;
+        }
+
+        let required_blocks: u64 = if tx_type.requires_strictly_exceeding_limit() {
+            limit.saturating_add(1)
+        } else {
+            limit
+        };
+        required_blocks.saturating_sub(current_block.saturating_sub(last_block))
+    }
+
+    /// Get the number of blocks until `account` is allowed to make a transaction of a specific type
+    /// on a subnet. Zero if the transaction is allowed in the current block.
+    ///
+    /// For stakes the account is a coldkey, and the blocks are counted until it is allowed to
+    /// stake to each of the hotkeys it stakes to.
+    pub fn get_blocks_until_allowed(
+        tx_type: &TransactionType,
+        account: &T::AccountId,
+        netuid: u16,
+    ) -> u64 {
+        if tx_type.limits_transactions_per_interval() {
+            return match tx_type {
+                TransactionType::Stake => StakingHotkeys::<T>::get(account)
+                    .iter()
+                    .map(|hotkey| Self::get_blocks_until_stake_allowed(account, hotkey))
+                    .max()
+                    .unwrap_or(0),
+                _ => Self::get_blocks_until_registration_allowed(netuid),
+            };
+        }
+
+        let last_block: u64 = Self::get_last_block_for_rate_limit(tx_type, account, netuid);
+        Self::get_rate_limit_blocks_remaining(
+            tx_type,
+            netuid,
+            last_block,
+            Self::get_current_block_as_u64(),
+        )
+    }
+
+    /// Runtime api variant of `get_blocks_until_allowed` taking the encoded account id.
+    pub fn get_blocks_until_allowed_for_account(
+        account_vec: Vec<u8>,
+        tx_type: u16,
+        netuid: u16,
+    ) -> u64 {
+        let Ok(account) = T::AccountId::decode(&mut account_vec.as_bytes_ref()) else {
+            return 0;
+        };
+        Self::get_blocks_until_allowed(&TransactionType::from(tx_type), &account, netuid)
+    }
+
+    /// Get the number of blocks until the coldkey is allowed to stake to or unstake from the
+    /// hotkey. Zero if the stakes of the coldkey and hotkey within the current stake interval
+    /// are below the limit, otherwise the blocks until the interval is reset.
+    pub fn get_blocks_until_stake_allowed(coldkey: &T::AccountId, hotkey: &T::AccountId) -> u64 {
+        let (stakes, block_last_staked_at) =
+            TotalHotkeyColdkeyStakesThisInterval::<T>::get(coldkey, hotkey);
+        let block_to_reset_after = block_last_staked_at.saturating_add(StakeInterval::<T>::get());
+        let current_block = Self::get_current_block_as_u64();
+        if block_to_reset_after <= current_block
+            || stakes < Self::get_rate_limit(&TransactionType::Stake)
+        {
+            return 0;
         }
+        block_to_reset_after.saturating_sub(current_block)
+    }
+
+    /// Get the number of blocks until a neuron is allowed to register on a subnet. Zero if the
+    /// registrations of the subnet in the current block are below the limit, otherwise one.
+    pub fn get_blocks_until_registration_allowed(netuid: u16) -> u64 {
+        let registrations: u64 = Self::get_registrations_this_block(netuid).into();
+        if registrations < Self::get_rate_limit_on_subnet(&TransactionType::Register, netuid) {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Check if the coldkey passes the stake rate limit for the hotkey
+    pub fn passes_stake_rate_limit(coldkey: &T::AccountId, hotkey: &T::AccountId) -> bool {
+        Self::get_blocks_until_stake_allowed(coldkey, hotkey) == 0
+    }
+
+    /// Check if a neuron registration passes the rate limit of a subnet
+    pub fn passes_registration_rate_limit(netuid: u16) -> bool {
+        Self::get_blocks_until_registration_allowed(netuid) == 0
+    }
+
+    /// Check if a transaction passes the rate limit for `account` on a subnet
+    pub fn passes_rate_limit(
+        tx_type: &TransactionType,
+        account: &T::AccountId,
+        netuid: u16,
+    ) -> bool {
+        Self::get_blocks_until_allowed(tx_type, account, netuid) == 0
     }
 
     /// Check if a transaction should be rate limited on a specific subnet
@@ -49,17 +275,12 @@ impl<T: Config> Pallet<T> {
         hotkey: &T::AccountId,
         netuid: u16,
     ) -> bool {
-        let block: u64 = Self::get_current_block_as_u64();
-        let limit: u64 = Self::get_rate_limit(tx_type);
-        let last_block: u64 = Self::get_last_transaction_block(hotkey, netuid, tx_type);
-
-        // Allow the first transaction (when last_block is 0) or if the rate limit has passed
-        last_block == 0 || block.saturating_sub(last_block) >= limit
+        Self::passes_rate_limit(tx_type, hotkey, netuid)
     }
 
     /// Check if a transaction should be rate limited globally
     pub fn passes_rate_limit_globally(tx_type: &TransactionType, hotkey: &T::AccountId) -> bool {
-        let netuid: u16 = u16::MAX;
+        let netuid: u16 = GLOBAL_RATE_LIMIT_NETUID;
         let block: u64 = Self::get_current_block_as_u64();
         let limit: u64 = Self::get_rate_limit(tx_type);
         let last_block: u64 = Self::get_last_transaction_block(hotkey, netuid, tx_type);
@@ -107,19 +328,19 @@ impl<T: Config> Pallet<T> {
         LastTxBlockChildKeyTake::<T>::get(key)
     }
     pub fn exceeds_tx_rate_limit(prev_tx_block: u64, current_block: u64) -> bool {
-        let rate_limit: u64 = Self::get_tx_rate_limit();
-        if rate_limit == 0 || prev_tx_block == 0 {
-            return false;
-        }
-
-        current_block.saturating_sub(prev_tx_block) <= rate_limit
+        Self::get_rate_limit_blocks_remaining(
+            &TransactionType::BecomeDelegate,
+            GLOBAL_RATE_LIMIT_NETUID,
+            prev_tx_block,
+            current_block,
+        ) > 0
     }
     pub fn exceeds_tx_delegate_take_rate_limit(prev_tx_block: u64, current_block: u64) -> bool {
-        let rate_limit: u64 = Self::get_tx_delegate_take_rate_limit();
-        if rate_limit == 0 || prev_tx_block == 0 {
-            return false;
-        }
-
-        current_block.saturating_sub(prev_tx_block) <= rate_limit
+        Self::get_rate_limit_blocks_remaining(
+            &TransactionType::IncreaseDelegateTake,
+            GLOBAL_RATE_LIMIT_NETUID,
+            prev_tx_block,
+            current_block,
+        ) > 0
     }
 }
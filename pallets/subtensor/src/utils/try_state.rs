@@ -4,9 +4,10 @@ impl<T: Config> Pallet<T> {
     /// Checks if the accounting invariants for [`TotalStake`], [`TotalSubnetLocked`], and [`TotalIssuance`] are correct.
     ///
     /// This function verifies that:
-    /// 1. The sum of all stakes matches the [`TotalStake`].
+    /// 1. The sum of all stakes matches the [`TotalStake`], and the sum of the stakes of each hotkey its [`TotalHotkeyStake`].
     /// 2. The [`TotalSubnetLocked`] is correctly calculated.
-    /// 3. The [`TotalIssuance`] equals the sum of currency issuance, total stake, and total subnet locked.
+    /// 3. The sum of all unbonding queues matches the [`TotalUnbonding`].
+    /// 4. The [`TotalIssuance`] equals the sum of currency issuance, total stake, total unbonding stake and total subnet locked.
     ///
     /// # Returns
     ///
@@ -27,6 +28,52 @@ impl<T: Config> Pallet<T> {
             "TotalStake does not match total staked",
         );
 
+        // Verify that the stake of each hotkey matches the stored TotalHotkeyStake
+        for (hotkey, total_hotkey_stake) in TotalHotkeyStake::<T>::iter() {
+            let mut hotkey_staked: u64 = 0;
+            for (_coldkey, stake) in Stake::<T>::iter_prefix(&hotkey) {
+                hotkey_staked = hotkey_staked.saturating_add(stake);
+            }
+            ensure!(
+                hotkey_staked == total_hotkey_stake,
+                "TotalHotkeyStake does not match the stake of the hotkey",
+            );
+        }
+
+        // Verify that the unbonding queues match the stored TotalUnbonding and UnbondingColdkeys
+        let mut total_unbonding: u64 = 0;
+        for (coldkey, queue) in UnbondingQueue::<T>::iter() {
+            for entry in queue.iter() {
+                total_unbonding = total_unbonding.saturating_add(entry.amount);
+                ensure!(
+                    UnbondingColdkeys::<T>::get(&entry.hotkey, &coldkey),
+                    "UnbondingColdkeys is missing an unbonding coldkey",
+                );
+            }
+        }
+        ensure!(
+            total_unbonding == TotalUnbonding::<T>::get(),
+            "TotalUnbonding does not match total unbonding stake",
+        );
+        for (hotkey, coldkey, _) in UnbondingColdkeys::<T>::iter() {
+            ensure!(
+                UnbondingQueue::<T>::get(&coldkey)
+                    .iter()
+                    .any(|entry| entry.hotkey == hotkey),
+                "UnbondingColdkeys holds a coldkey without unbonding stake",
+            );
+        }
+
+        // Verify that the open registration bids match the stored TotalRegistrationBids
+        let mut total_registration_bids: u64 = 0;
+        for (_netuid, _hotkey, bid) in RegistrationBids::<T>::iter() {
+            total_registration_bids = total_registration_bids.saturating_add(bid.amount);
+        }
+        ensure!(
+            total_registration_bids == TotalRegistrationBids::<T>::get(),
+            "TotalRegistrationBids does not match the open registration bids",
+        );
+
         // Get the total subnet locked amount
         let total_subnet_locked: u64 = Self::get_total_subnet_locked();
 
@@ -36,6 +83,8 @@ impl<T: Config> Pallet<T> {
         // Calculate the expected total issuance
         let expected_total_issuance: u64 = currency_issuance
             .saturating_add(total_staked)
+            .saturating_add(total_unbonding)
+            .saturating_add(total_registration_bids)
             .saturating_add(total_subnet_locked);
 
         // Verify the diff between calculated TI and actual TI is less than delta
@@ -56,4 +105,41 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    /// Checks that the child key graph of every subnet is consistent.
+    ///
+    /// This function verifies that:
+    /// 1. Every child in [`ChildKeys`] lists its parent with the same proportion in [`ParentKeys`].
+    /// 2. Every parent in [`ParentKeys`] lists its child with the same proportion in [`ChildKeys`].
+    /// 3. The proportions of the children of a hotkey never exceed `u64::MAX` in aggregate.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if all invariants are correct, otherwise returns an error.
+    #[cfg(feature = "try-runtime")]
+    pub fn check_childkey_invariants() -> Result<(), sp_runtime::TryRuntimeError> {
+        for (parent, netuid, children) in ChildKeys::<T>::iter() {
+            let mut total_proportion: u64 = 0;
+            for (proportion, child) in children.iter() {
+                total_proportion = total_proportion
+                    .checked_add(*proportion)
+                    .ok_or("Proportions of the children of a hotkey exceed u64::MAX")?;
+                ensure!(
+                    ParentKeys::<T>::get(child, netuid).contains(&(*proportion, parent.clone())),
+                    "ChildKeys entry has no matching ParentKeys entry",
+                );
+            }
+        }
+
+        for (child, netuid, parents) in ParentKeys::<T>::iter() {
+            for (proportion, parent) in parents.iter() {
+                ensure!(
+                    ChildKeys::<T>::get(parent, netuid).contains(&(*proportion, child.clone())),
+                    "ParentKeys entry has no matching ChildKeys entry",
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
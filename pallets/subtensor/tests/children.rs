@@ -1,9 +1,11 @@
 #![allow(clippy::indexing_slicing)]
 use crate::mock::*;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+    assert_err, assert_noop, assert_ok, dispatch::GetDispatchInfo, weights::Weight,
+};
 mod mock;
 use pallet_subtensor::{utils::rate_limiting::TransactionType, *};
-use sp_core::U256;
+use sp_core::{Get, U256};
 
 // 1: Successful setting of a single child
 // SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_do_set_child_singular_success --exact --nocapture
@@ -3702,3 +3704,397 @@ fn test_childkey_take_drain_validator_take() {
         ));
     });
 }
+
+// 55: Test scheduled children are set at the next epoch of the subnet
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_schedule_children_applied_at_next_epoch --exact --nocapture
+#[test]
+fn test_schedule_children_applied_at_next_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let child = U256::from(3);
+        let netuid: u16 = 1;
+        let proportion: u64 = 1000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+
+        assert_ok!(SubtensorModule::schedule_children(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            vec![(proportion, child, None)]
+        ));
+        let apply_block =
+            SubtensorModule::get_next_epoch_block(netuid, System::block_number()).unwrap();
+        System::assert_last_event(
+            Event::ChildrenScheduled(hotkey, netuid, vec![(proportion, child, None)], apply_block)
+                .into(),
+        );
+
+        // The children are not set before the epoch
+        run_to_block(apply_block - 1);
+        assert!(SubtensorModule::get_children(&hotkey, netuid).is_empty());
+
+        run_to_block(apply_block);
+        assert_eq!(
+            SubtensorModule::get_children(&hotkey, netuid),
+            vec![(proportion, child)]
+        );
+        assert_eq!(
+            SubtensorModule::get_parents(&child, netuid),
+            vec![(proportion, hotkey)]
+        );
+        assert!(PendingChildKeys::<Test>::get(netuid, hotkey).is_none());
+    });
+}
+
+// 56: Test a scheduled child is removed at the first epoch from its expiry block
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_schedule_children_expiry --exact --nocapture
+#[test]
+fn test_schedule_children_expiry() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let child1 = U256::from(3);
+        let child2 = U256::from(4);
+        let netuid: u16 = 1;
+        let proportion: u64 = 1000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        let apply_block =
+            SubtensorModule::get_next_epoch_block(netuid, System::block_number()).unwrap();
+
+        // The expiry must be after the epoch setting the children
+        assert_noop!(
+            SubtensorModule::schedule_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                vec![(proportion, child1, Some(apply_block))]
+            ),
+            Error::<Test>::InvalidChildkeyExpiry
+        );
+
+        let expiry_block = apply_block + 1;
+        assert_ok!(SubtensorModule::schedule_children(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            vec![
+                (proportion, child1, Some(expiry_block)),
+                (proportion, child2, None)
+            ]
+        ));
+        run_to_block(apply_block);
+        assert_eq!(
+            SubtensorModule::get_children(&hotkey, netuid),
+            vec![(proportion, child1), (proportion, child2)]
+        );
+        assert_eq!(
+            ChildKeyExpiries::<Test>::get(netuid, hotkey),
+            vec![(child1, expiry_block)]
+        );
+
+        // The child stays until the epoch following its expiry block
+        let next_epoch_block = SubtensorModule::get_next_epoch_block(netuid, apply_block).unwrap();
+        run_to_block(next_epoch_block - 1);
+        assert_eq!(SubtensorModule::get_children(&hotkey, netuid).len(), 2);
+
+        run_to_block(next_epoch_block);
+        System::assert_has_event(Event::ChildKeyExpired(hotkey, netuid, child1).into());
+        assert_eq!(
+            SubtensorModule::get_children(&hotkey, netuid),
+            vec![(proportion, child2)]
+        );
+        assert!(SubtensorModule::get_parents(&child1, netuid).is_empty());
+        assert!(ChildKeyExpiries::<Test>::get(netuid, hotkey).is_empty());
+    });
+}
+
+// 57: Test the stake graph reports the stake the epoch sees for each hotkey
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_get_subnet_stake_graph --exact --nocapture
+#[test]
+fn test_get_subnet_stake_graph() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child = U256::from(3);
+        let unregistered_child = U256::from(4);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        register_ok_neuron(netuid, child, coldkey, 1);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &parent, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &child, 500);
+
+        // Half of the parent stake to the child, a quarter to the unregistered child
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX / 2, child), (u64::MAX / 4, unregistered_child)]
+        ));
+
+        let graph = SubtensorModule::get_subnet_stake_graph(netuid).unwrap();
+        assert_eq!(graph.netuid.0, netuid);
+        let edge_stakes: Vec<(U256, U256, u64)> = graph
+            .edges
+            .iter()
+            .map(|edge| (edge.parent, edge.child, edge.stake.0))
+            .collect();
+        assert_eq!(
+            edge_stakes,
+            vec![(parent, child, 499), (parent, unregistered_child, 249)]
+        );
+
+        // Registered hotkeys come first, in uid order
+        let hotkeys: Vec<(U256, Option<u16>)> = graph
+            .hotkeys
+            .iter()
+            .map(|info| (info.hotkey, info.uid.map(|uid| uid.0)))
+            .collect();
+        assert_eq!(
+            hotkeys,
+            vec![
+                (parent, Some(0)),
+                (child, Some(1)),
+                (unregistered_child, None)
+            ]
+        );
+        for info in graph.hotkeys.iter() {
+            assert_eq!(
+                info.effective_stake.0,
+                info.own_stake
+                    .0
+                    .saturating_sub(info.delegated_stake.0)
+                    .saturating_add(info.inherited_stake.0)
+            );
+            assert_eq!(
+                info.effective_stake.0,
+                SubtensorModule::get_stake_for_hotkey_on_subnet(&info.hotkey, netuid)
+            );
+        }
+        assert_eq!(graph.hotkeys[0].delegated_stake.0, 748);
+        assert_eq!(graph.hotkeys[1].inherited_stake.0, 499);
+
+        // Unknown subnets have no graph
+        assert!(SubtensorModule::get_subnet_stake_graph(2).is_none());
+    });
+}
+
+// 58: Test children forming a cycle or too long a chain are rejected
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_do_set_children_graph_validation --exact --nocapture
+#[test]
+fn test_do_set_children_graph_validation() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let netuid: u16 = 1;
+        let proportion: u64 = 1000;
+        let num_keys: usize = MAX_CHILDKEY_DEPTH as usize + 2;
+        let hotkeys: Vec<U256> = (0..num_keys).map(|i| U256::from(i as u64 + 2)).collect();
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 1000);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1000);
+        for hotkey in &hotkeys {
+            register_ok_neuron(netuid, *hotkey, coldkey, 0);
+        }
+
+        // A chain of MAX_CHILDKEY_DEPTH links is allowed
+        for i in 0..num_keys - 2 {
+            assert_ok!(SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkeys[i],
+                netuid,
+                vec![(proportion, hotkeys[i + 1])]
+            ));
+        }
+
+        // One more link at either end is too deep
+        assert_err!(
+            SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkeys[num_keys - 2],
+                netuid,
+                vec![(proportion, hotkeys[num_keys - 1])]
+            ),
+            Error::<Test>::ChildkeyDepthExceeded
+        );
+        assert_err!(
+            SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkeys[num_keys - 1],
+                netuid,
+                vec![(proportion, hotkeys[0])]
+            ),
+            Error::<Test>::ChildkeyDepthExceeded
+        );
+
+        // An ancestor cannot become a child
+        assert_err!(
+            SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkeys[2],
+                netuid,
+                vec![(proportion, hotkeys[3]), (proportion, hotkeys[0])]
+            ),
+            Error::<Test>::ChildkeyCycle
+        );
+        assert_err!(
+            SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkeys[1],
+                netuid,
+                vec![(proportion, hotkeys[0])]
+            ),
+            Error::<Test>::ChildkeyCycle
+        );
+    });
+}
+
+// 59: Test scheduled children forming a cycle with other scheduled children are dropped
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_schedule_children_cycle_dropped --exact --nocapture
+#[test]
+fn test_schedule_children_cycle_dropped() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey1 = U256::from(2);
+        let hotkey2 = U256::from(3);
+        let netuid: u16 = 1;
+        let proportion: u64 = 1000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey1, coldkey, 0);
+        register_ok_neuron(netuid, hotkey2, coldkey, 1);
+
+        // Each change is valid on its own
+        for (hotkey, child) in [(hotkey1, hotkey2), (hotkey2, hotkey1)] {
+            assert_ok!(SubtensorModule::schedule_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                vec![(proportion, child, None)]
+            ));
+        }
+
+        let apply_block =
+            SubtensorModule::get_next_epoch_block(netuid, System::block_number()).unwrap();
+        run_to_block(apply_block);
+
+        // Only one of the two changes is applied
+        let set = [hotkey1, hotkey2]
+            .iter()
+            .filter(|hotkey| !SubtensorModule::get_children(hotkey, netuid).is_empty())
+            .count();
+        assert_eq!(set, 1);
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::SubtensorModule(Event::ScheduledChildrenDropped(_, _, _))
+        )));
+        assert!(PendingChildKeys::<Test>::iter_prefix(netuid)
+            .next()
+            .is_none());
+    });
+}
+
+// 60: Test the child key graph walk is bounded and charged by the hotkeys it reads
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_do_set_children_graph_walk_bounded --exact --nocapture
+#[test]
+fn test_do_set_children_graph_walk_bounded() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let child = U256::from(3);
+        let netuid: u16 = 1;
+        let proportion: u64 = 1000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, child, coldkey, 1);
+
+        // Only the hotkey and the child are read.
+        let actual_weight = SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            vec![(proportion, child)],
+        )
+        .expect("children are set")
+        .actual_weight
+        .expect("actual weight is returned");
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::set_children {
+            hotkey,
+            netuid,
+            children: vec![(proportion, child)],
+        });
+        assert_eq!(
+            call.get_dispatch_info()
+                .weight
+                .saturating_sub(actual_weight),
+            <Test as frame_system::Config>::DbWeight::get()
+                .reads(u64::from(MAX_CHILDKEY_GRAPH_VISITS) - 2)
+        );
+
+        // Parents shared by many chains are read once each, up to MAX_CHILDKEY_GRAPH_VISITS.
+        let parents: Vec<(u64, U256)> = (0..MAX_CHILDKEY_GRAPH_VISITS)
+            .map(|i| (proportion, U256::from(1000 + i)))
+            .collect();
+        let other_hotkey = U256::from(4);
+        register_ok_neuron(netuid, other_hotkey, coldkey, 2);
+        ParentKeys::<Test>::insert(other_hotkey, netuid, parents);
+        assert_err!(
+            SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                other_hotkey,
+                netuid,
+                vec![(proportion, child)]
+            ),
+            Error::<Test>::ChildkeyGraphTooLarge
+        );
+    });
+}
+
+// 61: Test applying the child key expiries is charged for the keys it reads and writes
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_apply_scheduled_children_expiry_weight --exact --nocapture
+#[test]
+fn test_apply_scheduled_children_expiry_weight() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let child = U256::from(3);
+        let netuid: u16 = 1;
+        let proportion: u64 = 1000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            vec![(proportion, child)]
+        ));
+        ChildKeyExpiries::<Test>::insert(netuid, hotkey, vec![(child, 1)]);
+
+        // The expiry is read, the child keys are read and replaced, the parents of the expired
+        // child are read and written, and the expiries are removed.
+        let mut weight = Weight::zero();
+        SubtensorModule::apply_scheduled_children(netuid, 1, &mut weight);
+        assert!(SubtensorModule::get_children(&hotkey, netuid).is_empty());
+        assert_eq!(
+            weight,
+            <Test as frame_system::Config>::DbWeight::get().reads_writes(4, 3)
+        );
+
+        // Expiries which are not due are only read.
+        ChildKeyExpiries::<Test>::insert(netuid, hotkey, vec![(child, 10)]);
+        let mut weight = Weight::zero();
+        SubtensorModule::apply_scheduled_children(netuid, 1, &mut weight);
+        assert_eq!(
+            weight,
+            <Test as frame_system::Config>::DbWeight::get().reads(1)
+        );
+    });
+}
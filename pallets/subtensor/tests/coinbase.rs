@@ -1,11 +1,16 @@
 #![allow(unused, clippy::indexing_slicing, clippy::panic, clippy::unwrap_used)]
 use crate::mock::*;
 mod mock;
-use frame_support::assert_ok;
+use codec::Encode;
+use frame_support::{assert_ok, weights::Weight};
 use sp_core::U256;
 use substrate_fixed::types::I64F64;
 
-use pallet_subtensor::TargetStakesPerInterval;
+use pallet_subtensor::{
+    Event, NominatorRewardEntry, NominatorRewards, PendingdHotkeyEmission,
+    PendingdHotkeyEmissionUntouchable, RewardDestination, StakeDeltaSinceLastEmissionDrain,
+    TargetStakesPerInterval, MAX_NOMINATOR_REWARD_ENTRIES,
+};
 
 // Test the ability to hash all sorts of hotkeys.
 #[test]
@@ -46,6 +51,366 @@ fn test_hotkey_drain_time() {
     });
 }
 
+// Test that draining a hotkey emits the drained emission and the number of rewarded nominators.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_hotkey_emission_event -- --nocapture
+#[test]
+fn test_drain_hotkey_emission_event() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        let nominator = U256::from(1);
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            u16::MAX / 10
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 100);
+
+        let owner_stake_before =
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+        let nominator_stake_before =
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey);
+
+        let total_new_tao =
+            SubtensorModule::drain_hotkey_emission(&hotkey, 100, 1, &mut Weight::zero());
+
+        let owner_increase = SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey)
+            - owner_stake_before;
+        let nominator_increase =
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey)
+                - nominator_stake_before;
+        assert!(owner_increase > 0);
+        assert!(nominator_increase > 0);
+        assert_eq!(owner_increase + nominator_increase, total_new_tao);
+
+        System::assert_last_event(
+            Event::HotkeyEmissionDrained(hotkey, total_new_tao, owner_increase, 1).into(),
+        );
+    });
+}
+
+// Test that draining a hotkey records the reward of each nominator in its ledger.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_hotkey_emission_nominator_rewards -- --nocapture
+#[test]
+fn test_drain_hotkey_emission_nominator_rewards() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        let nominator = U256::from(1);
+        let new_nominator = U256::from(2);
+        let take = u16::MAX / 10;
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            take
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 100);
+        // The stake of the new nominator was added since the last drain.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&new_nominator, &hotkey, 50);
+        StakeDeltaSinceLastEmissionDrain::<Test>::insert(hotkey, new_nominator, 50);
+
+        SubtensorModule::drain_hotkey_emission(&hotkey, 100, 1, &mut Weight::zero());
+        let reward = SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey) - 100;
+        assert!(reward > 0);
+        assert_eq!(
+            NominatorRewards::<Test>::get(nominator),
+            vec![NominatorRewardEntry {
+                block: 1,
+                hotkey,
+                take,
+                reward,
+                nonviable_stake: 0,
+            }]
+        );
+        assert_eq!(
+            NominatorRewards::<Test>::get(new_nominator),
+            vec![NominatorRewardEntry {
+                block: 1,
+                hotkey,
+                take,
+                reward: 0,
+                nonviable_stake: 50,
+            }]
+        );
+
+        // All stake is viable at the next drain.
+        SubtensorModule::drain_hotkey_emission(&hotkey, 100, 5, &mut Weight::zero());
+        let rewards = SubtensorModule::get_nominator_rewards(new_nominator.encode(), 2);
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(rewards[0].block, 5);
+        assert!(rewards[0].reward > 0);
+        assert_eq!(rewards[0].nonviable_stake, 0);
+        assert_eq!(
+            SubtensorModule::get_nominator_rewards(nominator.encode(), 0).len(),
+            2
+        );
+    });
+}
+
+// Test that a drain records the reward of every nominator in the ledgers.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_hotkey_emission_records_every_nominator -- --nocapture
+#[test]
+fn test_drain_hotkey_emission_records_every_nominator() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(1);
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            u16::MAX / 10
+        ));
+        let nominators: Vec<U256> = (0..100).map(|i| U256::from(100 + i)).collect();
+        for (i, nominator) in nominators.iter().enumerate() {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                nominator,
+                &hotkey,
+                1_000_000 * (i as u64 + 1),
+            );
+        }
+
+        let mut weight = Weight::zero();
+        SubtensorModule::drain_hotkey_emission(&hotkey, 100_000_000, 1, &mut weight);
+        assert!(weight.ref_time() > 0);
+
+        for nominator in nominators.iter() {
+            assert_eq!(NominatorRewards::<Test>::get(nominator).len(), 1);
+        }
+    });
+}
+
+// Test that the next drain block is the first later block on which the hotkey is drained.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_get_next_drain_block -- --nocapture
+#[test]
+fn test_get_next_drain_block() {
+    new_test_ext(1).execute_with(|| {
+        for emit_tempo in [0, 1, 7, 100] {
+            for i in 0..20 {
+                let hotkey = U256::from(i);
+                for block in [0, 1, 5, 99, 1000] {
+                    let next_drain_block =
+                        SubtensorModule::get_next_drain_block(&hotkey, block, emit_tempo);
+                    assert!(next_drain_block > block);
+                    assert!(SubtensorModule::should_drain_hotkey(
+                        &hotkey,
+                        next_drain_block,
+                        emit_tempo
+                    ));
+                    assert!(((block + 1)..next_drain_block).all(|b| {
+                        !SubtensorModule::should_drain_hotkey(&hotkey, b, emit_tempo)
+                    }));
+                }
+            }
+        }
+    });
+}
+
+// Test the drain info and epoch schedule reported to clients.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_emission_schedule_info -- --nocapture
+#[test]
+fn test_emission_schedule_info() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        add_network(1, 10, 0);
+        add_network(2, 0, 0);
+        run_to_block(5);
+        PendingdHotkeyEmission::<Test>::insert(hotkey, 300);
+        PendingdHotkeyEmissionUntouchable::<Test>::insert(hotkey, 100);
+
+        let info = SubtensorModule::get_hotkey_drain_info(hotkey.encode()).unwrap();
+        assert_eq!(info.hotkey, hotkey);
+        assert_eq!(
+            info.next_drain_block.0,
+            SubtensorModule::get_next_drain_block(
+                &hotkey,
+                5,
+                SubtensorModule::get_hotkey_emission_tempo()
+            )
+        );
+        assert_eq!(info.pending_emission.0, 300);
+        assert_eq!(info.pending_emission_untouchable.0, 100);
+        assert!(SubtensorModule::get_hotkey_drain_info(vec![0; 3]).is_none());
+
+        let schedule = SubtensorModule::get_subnets_epoch_schedule();
+        let next_epoch_block = |netuid: u16| {
+            schedule
+                .iter()
+                .find(|subnet| subnet.netuid.0 == netuid)
+                .and_then(|subnet| subnet.next_epoch_block.map(|block| block.0))
+        };
+        // (block + netuid + 1) % (tempo + 1) == tempo runs the epoch of subnet 1 on block 8.
+        assert_eq!(next_epoch_block(1), Some(8));
+        assert!(SubtensorModule::should_run_epoch(1, 8));
+        // A tempo of zero never runs.
+        assert_eq!(next_epoch_block(2), None);
+    });
+}
+
+// Test that nominator rewards are paid to the reward destination of the nomination.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_hotkey_emission_reward_destination -- --nocapture
+#[test]
+fn test_drain_hotkey_emission_reward_destination() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let other_hotkey = U256::from(10);
+        let coldkey = U256::from(3);
+        let free_nominator = U256::from(1);
+        let redirecting_nominator = U256::from(2);
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        register_ok_neuron(netuid, other_hotkey, coldkey, 200000);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            u16::MAX / 10
+        ));
+        assert_ok!(SubtensorModule::do_become_delegate(
+            RuntimeOrigin::signed(coldkey),
+            other_hotkey,
+            u16::MAX / 10
+        ));
+        for nominator in [free_nominator, redirecting_nominator] {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1000);
+        }
+        SubtensorModule::add_balance_to_coldkey_account(&free_nominator, 1000);
+        assert_ok!(SubtensorModule::set_reward_destination(
+            RuntimeOrigin::signed(free_nominator),
+            hotkey,
+            RewardDestination::Free
+        ));
+        assert_ok!(SubtensorModule::set_reward_destination(
+            RuntimeOrigin::signed(redirecting_nominator),
+            hotkey,
+            RewardDestination::Hotkey(other_hotkey)
+        ));
+
+        let total_new_tao =
+            SubtensorModule::drain_hotkey_emission(&hotkey, 1000, 1, &mut Weight::zero());
+
+        // The free nominator is paid to its balance, the stake is unchanged.
+        let free_reward = SubtensorModule::get_coldkey_balance(&free_nominator) - 1000;
+        assert!(free_reward > 0);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&free_nominator, &hotkey),
+            1000
+        );
+        // The redirecting nominator is staked on the other hotkey.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&redirecting_nominator, &hotkey),
+            1000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(
+                &redirecting_nominator,
+                &other_hotkey
+            ),
+            free_reward
+        );
+        // Both nominators are counted as rewarded.
+        System::assert_has_event(
+            Event::HotkeyEmissionDrained(
+                hotkey,
+                total_new_tao,
+                SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+                2,
+            )
+            .into(),
+        );
+
+        // A destination hotkey which no longer exists falls back to restaking.
+        RewardDestinations::<Test>::insert(
+            redirecting_nominator,
+            hotkey,
+            RewardDestination::Hotkey(U256::from(99)),
+        );
+        SubtensorModule::drain_hotkey_emission(&hotkey, 1000, 5, &mut Weight::zero());
+        let restaked_reward =
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&redirecting_nominator, &hotkey)
+                - 1000;
+        assert!(restaked_reward > 0);
+        System::assert_has_event(
+            Event::NominatorRewardRestaked(redirecting_nominator, hotkey, restaked_reward).into(),
+        );
+    });
+}
+
+// Test that the nominator reward ledger keeps the latest entries only.
+#[test]
+fn test_nominator_rewards_ledger_is_bounded() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(0);
+        let nominator = U256::from(1);
+
+        for block in 0..(MAX_NOMINATOR_REWARD_ENTRIES as u64 + 2) {
+            SubtensorModule::record_nominator_reward(&nominator, &hotkey, 0, 1, 0, block);
+        }
+        // Entries without reward or non-viable stake are not recorded.
+        SubtensorModule::record_nominator_reward(&nominator, &hotkey, 0, 0, 0, 1000);
+
+        let ledger = NominatorRewards::<Test>::get(nominator);
+        assert_eq!(ledger.len(), MAX_NOMINATOR_REWARD_ENTRIES);
+        assert_eq!(ledger.front().map(|entry| entry.block), Some(2));
+        assert_eq!(
+            ledger.back().map(|entry| entry.block),
+            Some(MAX_NOMINATOR_REWARD_ENTRIES as u64 + 1)
+        );
+    });
+}
+
+// Test that the nominator reward ledger is dropped once the nominator removes all of its stake.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_nominator_rewards_cleared_on_full_unstake -- --nocapture
+#[test]
+fn test_nominator_rewards_cleared_on_full_unstake() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        let nominator = U256::from(1);
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            u16::MAX / 10
+        ));
+        SubtensorModule::set_target_stakes_per_interval(10);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1000);
+        SubtensorModule::record_nominator_reward(&nominator, &hotkey, 0, 1, 0, 1);
+
+        // The ledger is kept while the nominator has stake left.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey,
+            400
+        ));
+        assert_eq!(NominatorRewards::<Test>::get(nominator).len(), 1);
+
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey,
+            600
+        ));
+        assert!(!NominatorRewards::<Test>::contains_key(nominator));
+    });
+}
+
 // To run this test specifically, use the following command:
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_coinbase_basic -- --nocapture
 #[test]
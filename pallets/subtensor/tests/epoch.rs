@@ -2750,6 +2750,94 @@ fn test_blocks_since_last_step() {
         assert_eq!(SubtensorModule::get_blocks_since_last_step(netuid), 27);
     });
 }
+
+// Test that the epoch dry run returns the same results as the epoch without writing to storage.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --test epoch -- test_epoch_simulation_matches_epoch --exact --nocapture
+#[test]
+fn test_epoch_simulation_matches_epoch() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        let n: u16 = 4;
+        let netuid: u16 = 1;
+        let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
+        let block_number: u64 = System::block_number();
+        let stake: u64 = 1;
+        let rao_emission: u64 = 1_000_000_000;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        SubtensorModule::set_max_allowed_validators(netuid, n);
+
+        // Unknown subnets have nothing to simulate.
+        assert!(SubtensorModule::get_epoch_simulation(netuid + 1, rao_emission).is_none());
+
+        // === Register [validator1, validator2, server1, server2]
+        for key in 0..n as u64 {
+            SubtensorModule::add_balance_to_coldkey_account(&U256::from(key), stake);
+            let (nonce, work): (u64, Vec<u8>) = SubtensorModule::create_work_for_block_number(
+                netuid,
+                block_number,
+                key * 1_000_000,
+                &U256::from(key),
+            );
+            assert_ok!(SubtensorModule::register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(key)),
+                netuid,
+                block_number,
+                nonce,
+                work,
+                U256::from(key),
+                U256::from(key)
+            ));
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(key),
+                &U256::from(key),
+                stake,
+            );
+        }
+        SubtensorModule::epoch(netuid, rao_emission); // run first epoch to set allowed validators
+        next_block();
+
+        // === Set weights [val1->srv1: 0.5, val1->srv2: 0.5, val2->srv1: 0.5, val2->srv2: 0.5]
+        for uid in 0..(n / 2) as u64 {
+            assert_ok!(SubtensorModule::set_weights(
+                RuntimeOrigin::signed(U256::from(uid)),
+                netuid,
+                ((n / 2)..n).collect(),
+                vec![u16::MAX / (n / 2); (n / 2) as usize],
+                0
+            ));
+        }
+
+        // === Dry run leaves storage untouched.
+        let emission_before: Vec<u64> = Emission::<Test>::get(netuid);
+        let bonds_before: Vec<Vec<I32F32>> = SubtensorModule::get_bonds(netuid);
+        let simulation = SubtensorModule::get_epoch_simulation(netuid, rao_emission).unwrap();
+        assert_eq!(Emission::<Test>::get(netuid), emission_before);
+        assert_eq!(SubtensorModule::get_bonds(netuid), bonds_before);
+        assert_eq!(simulation.n.0, n);
+        assert_eq!(simulation.hotkeys.len(), n as usize);
+        assert_eq!(simulation.weights.len(), n as usize);
+
+        // === The real epoch produces what the simulation reported.
+        let emission_tuples = SubtensorModule::epoch(netuid, rao_emission);
+        for (hotkey, server_emission, validator_emission) in emission_tuples {
+            let uid: u16 = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+            assert_eq!(simulation.server_emission[uid as usize].0, server_emission);
+            assert_eq!(
+                simulation.validator_emission[uid as usize].0,
+                validator_emission
+            );
+            assert_eq!(
+                simulation.combined_emission[uid as usize].0,
+                SubtensorModule::get_emission_for_uid(netuid, uid)
+            );
+        }
+    });
+}
 // // Map the retention graph for consensus guarantees with an single epoch on a graph with 512 nodes, of which the first 64 are validators, the graph is split into a major and minor set, each setting specific weight on itself and the complement on the other.
 // //
 // // ```import torch
@@ -2892,3 +2980,119 @@ fn assert_approx_eq_vec_of_vec(
         }
     }
 }
+
+// Test the off-chain epoch simulator replays an epoch of a fixture subnet exactly as the pallet
+// runs it, from a snapshot of the subnet state the epoch reads.
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test epoch -- test_epoch_simulator_parity --exact --nocapture
+#[test]
+fn test_epoch_simulator_parity() {
+    use subtensor_epoch_simulator::{simulate, Hyperparameters, NeuronSnapshot, Snapshot};
+
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let rao_emission: u64 = 1_000_000_000;
+        let validators: Vec<u16> = vec![0, 1];
+        let servers: Vec<u16> = vec![2, 3];
+        let stakes: Vec<u64> = vec![3_000, 1_000, 0, 0];
+
+        // Two validators with different stake weighting two servers differently.
+        add_network(netuid, u16::MAX - 1, 0); // set higher tempo to avoid built-in epoch, then manual epoch instead
+        SubtensorModule::set_max_allowed_uids(netuid, 4);
+        SubtensorModule::set_max_allowed_validators(netuid, 2);
+        for (uid, stake) in stakes.iter().enumerate() {
+            let key = U256::from(uid);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&key, &key, *stake);
+            SubtensorModule::append_neuron(netuid, &key, 0);
+        }
+        run_to_block(1); // run to next block to ensure weights are set on nodes after their registration block
+        for (i, uid) in validators.iter().enumerate() {
+            assert_ok!(SubtensorModule::set_weights(
+                RuntimeOrigin::signed(U256::from(*uid)),
+                netuid,
+                servers.clone(),
+                vec![u16::MAX / (i as u16 + 1), u16::MAX],
+                0
+            ));
+        }
+
+        // A first epoch sets the validator permits and bonds the second epoch reads.
+        SubtensorModule::epoch(netuid, rao_emission);
+        run_to_block(2);
+
+        let (alpha_low, alpha_high) = SubtensorModule::get_alpha_values(netuid);
+        let last_update = SubtensorModule::get_last_update(netuid);
+        let block_at_registration = SubtensorModule::get_block_at_registration(netuid);
+        let validator_permit = SubtensorModule::get_validator_permit(netuid);
+        let snapshot = Snapshot {
+            netuid,
+            current_block: SubtensorModule::get_current_block_as_u64(),
+            rao_emission,
+            hyperparameters: Hyperparameters {
+                activity_cutoff: SubtensorModule::get_activity_cutoff(netuid),
+                max_allowed_validators: SubtensorModule::get_max_allowed_validators(netuid),
+                kappa: SubtensorModule::get_kappa(netuid),
+                bonds_moving_average: SubtensorModule::get_bonds_moving_average(netuid),
+                liquid_alpha_enabled: SubtensorModule::get_liquid_alpha_enabled(netuid),
+                alpha_low,
+                alpha_high,
+            },
+            neurons: (0..SubtensorModule::get_subnetwork_n(netuid))
+                .map(|uid| {
+                    let hotkey = Keys::<Test>::get(netuid, uid);
+                    NeuronSnapshot {
+                        uid,
+                        hotkey: hotkey.to_string(),
+                        stake: SubtensorModule::get_stake_for_hotkey_on_subnet(&hotkey, netuid),
+                        last_update: last_update[uid as usize],
+                        block_at_registration: block_at_registration[uid as usize],
+                        validator_permit: validator_permit[uid as usize],
+                        weights: Weights::<Test>::get(netuid, uid),
+                        bonds: Bonds::<Test>::get(netuid, uid),
+                    }
+                })
+                .collect(),
+        };
+        let simulated = simulate(&snapshot).unwrap();
+
+        // The emission and every value the epoch stores match.
+        let mut emissions: Vec<(String, u64, u64)> = SubtensorModule::epoch(netuid, rao_emission)
+            .into_iter()
+            .map(|(hotkey, server, validator)| (hotkey.to_string(), server, validator))
+            .collect();
+        emissions.sort();
+        let mut simulated_emissions: Vec<(String, u64, u64)> = simulated
+            .emissions
+            .iter()
+            .map(|e| (e.hotkey.clone(), e.server_emission, e.validator_emission))
+            .collect();
+        simulated_emissions.sort();
+        assert_eq!(emissions, simulated_emissions);
+        assert!(simulated.storage.dividends.iter().any(|d| *d > 0));
+
+        let storage = simulated.storage;
+        assert_eq!(SubtensorModule::get_emission(netuid), storage.emission);
+        assert_eq!(SubtensorModule::get_active(netuid), storage.active);
+        assert_eq!(SubtensorModule::get_rank(netuid), storage.rank);
+        assert_eq!(SubtensorModule::get_trust(netuid), storage.trust);
+        assert_eq!(SubtensorModule::get_consensus(netuid), storage.consensus);
+        assert_eq!(SubtensorModule::get_incentive(netuid), storage.incentive);
+        assert_eq!(SubtensorModule::get_dividends(netuid), storage.dividends);
+        assert_eq!(
+            SubtensorModule::get_pruning_score(netuid),
+            storage.pruning_scores
+        );
+        assert_eq!(
+            SubtensorModule::get_validator_trust(netuid),
+            storage.validator_trust
+        );
+        assert_eq!(
+            SubtensorModule::get_validator_permit(netuid),
+            storage.validator_permit
+        );
+        for (uid, bonds_row) in storage.bonds.into_iter().enumerate() {
+            if let Some(bonds_row) = bonds_row {
+                assert_eq!(Bonds::<Test>::get(netuid, uid as u16), bonds_row);
+            }
+        }
+    });
+}
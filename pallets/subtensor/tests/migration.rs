@@ -10,7 +10,7 @@ use frame_support::{
 };
 use frame_system::Config;
 use mock::*;
-use pallet_subtensor::*;
+use pallet_subtensor::{utils::rate_limiting::TransactionType, *};
 use sp_core::{crypto::Ss58Codec, H256, U256};
 use sp_io::hashing::twox_128;
 use sp_runtime::traits::Zero;
@@ -39,6 +39,40 @@ fn test_initialise_ti() {
     });
 }
 
+#[test]
+fn test_initialise_ti_with_open_registration_bids() {
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let balance: u64 = 10_000;
+        let bid: u64 = 1_000;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, bid);
+        SubtensorModule::set_registration_auction_enabled(netuid, true);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, balance);
+        assert_ok!(SubtensorModule::bid_for_registration(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(2),
+            bid
+        ));
+        assert_eq!(pallet_subtensor::TotalRegistrationBids::<Test>::get(), bid);
+        let total_subnet_locked: u64 = pallet_subtensor::SubnetLocked::<Test>::iter()
+            .fold(0, |acc, (_, locked)| acc.saturating_add(locked));
+
+        pallet_subtensor::TotalIssuance::<Test>::put(0);
+        pallet_subtensor::migrations::migrate_init_total_issuance::initialise_total_issuance::Migration::<Test>::on_runtime_upgrade();
+
+        // The balance held by the open bid is still part of the issuance.
+        assert_eq!(
+            pallet_subtensor::TotalIssuance::<Test>::get(),
+            balance.saturating_add(total_subnet_locked)
+        );
+    });
+}
+
 #[test]
 fn test_migration_fix_total_stake_maps() {
     new_test_ext(1).execute_with(|| {
@@ -684,3 +718,194 @@ fn test_migrate_fix_pending_emissions() {
         assert!(StakingHotkeys::<Test>::get(migration_account).contains(taostats_old_hk_account));
     })
 }
+
+#[test]
+fn test_migrate_rate_limits() {
+    use frame_support::traits::Get;
+
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_rate_limits";
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+
+        // Simulate limits stored before `TxRateLimits`.
+        NetworkRateLimit::<Test>::put(11);
+        TxRateLimit::<Test>::put(12);
+        TxChildkeyTakeRateLimit::<Test>::put(13);
+        ServingRateLimit::<Test>::insert(netuid, 14);
+        WeightsSetRateLimit::<Test>::insert(netuid, 15);
+
+        let weight =
+            pallet_subtensor::migrations::migrate_rate_limits::migrate_rate_limits::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert!(!weight.is_zero());
+
+        // The legacy items are cleared.
+        assert!(!NetworkRateLimit::<Test>::exists());
+        assert!(!TxRateLimit::<Test>::exists());
+        assert!(!TxChildkeyTakeRateLimit::<Test>::exists());
+        assert!(!ServingRateLimit::<Test>::contains_key(netuid));
+        assert!(!WeightsSetRateLimit::<Test>::contains_key(netuid));
+
+        // The limits are kept.
+        let global = GLOBAL_RATE_LIMIT_NETUID;
+        let tx_rate_limit = |tx_type: TransactionType, netuid: u16| {
+            TxRateLimits::<Test>::get(u16::from(tx_type), netuid)
+        };
+        assert_eq!(
+            tx_rate_limit(TransactionType::RegisterNetwork, global),
+            Some(11)
+        );
+        assert_eq!(
+            tx_rate_limit(TransactionType::BecomeDelegate, global),
+            Some(12)
+        );
+        assert_eq!(tx_rate_limit(TransactionType::SwapHotkey, global), Some(12));
+        assert_eq!(
+            tx_rate_limit(TransactionType::SetChildkeyTake, global),
+            Some(13)
+        );
+        assert_eq!(tx_rate_limit(TransactionType::ServeAxon, netuid), Some(14));
+        assert_eq!(
+            tx_rate_limit(TransactionType::ServePrometheus, netuid),
+            Some(14)
+        );
+        assert_eq!(tx_rate_limit(TransactionType::SetWeights, netuid), Some(15));
+        // Limits which were not stored keep their defaults.
+        assert_eq!(
+            tx_rate_limit(TransactionType::IncreaseDelegateTake, global),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_tx_delegate_take_rate_limit(),
+            InitialTxDelegateTakeRateLimit::get()
+        );
+        assert_eq!(
+            SubtensorModule::get_serving_rate_limit(other_netuid),
+            InitialServingRateLimit::get()
+        );
+
+        assert_eq!(SubtensorModule::get_tx_rate_limit(), 12);
+        assert_eq!(SubtensorModule::get_serving_rate_limit(netuid), 14);
+        assert_eq!(SubtensorModule::get_weights_set_rate_limit(netuid), 15);
+    });
+}
+
+#[test]
+fn test_migrate_stake_and_registration_rate_limits() {
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_stake_and_registration_rate_limits";
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+
+        // Simulate limits stored before `TxRateLimits`.
+        TargetStakesPerInterval::<Test>::put(7);
+        MaxRegistrationsPerBlock::<Test>::insert(netuid, 9);
+
+        let weight = pallet_subtensor::migrations::migrate_stake_and_registration_rate_limits::migrate_stake_and_registration_rate_limits::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert!(!weight.is_zero());
+
+        // The legacy items are cleared.
+        assert!(!TargetStakesPerInterval::<Test>::exists());
+        assert!(!MaxRegistrationsPerBlock::<Test>::contains_key(netuid));
+
+        // The limits are kept.
+        assert_eq!(
+            TxRateLimits::<Test>::get(
+                u16::from(TransactionType::Stake),
+                GLOBAL_RATE_LIMIT_NETUID
+            ),
+            Some(7)
+        );
+        assert_eq!(
+            TxRateLimits::<Test>::get(u16::from(TransactionType::Register), netuid),
+            Some(9)
+        );
+        assert_eq!(SubtensorModule::get_target_stakes_per_interval(), 7);
+        assert_eq!(SubtensorModule::get_max_registrations_per_block(netuid), 9);
+        // Limits which were not stored keep their defaults.
+        assert_eq!(
+            SubtensorModule::get_max_registrations_per_block(other_netuid),
+            InitialMaxRegistrationsPerBlock::get()
+        );
+    });
+}
+
+#[test]
+fn test_migrate_identity_fields() {
+    use pallet_subtensor::migrations::migrate_identity_fields::{
+        OldChainIdentity, OldSubnetIdentity,
+    };
+
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_identity_fields";
+        let coldkey = U256::from(1);
+        let netuid: u16 = 1;
+
+        // Simulate identities stored before the typed fields.
+        let old_identity = OldChainIdentity {
+            name: b"name".to_vec(),
+            url: b"https://example.com".to_vec(),
+            image: b"image".to_vec(),
+            discord: b"discord".to_vec(),
+            description: b"description".to_vec(),
+            additional: b"additional".to_vec(),
+        };
+        put_raw(
+            &Identities::<Test>::hashed_key_for(coldkey),
+            &old_identity.encode(),
+        );
+        let old_subnet_identity = OldSubnetIdentity {
+            subnet_name: b"subnet".to_vec(),
+            github_repo: b"opentensor/subtensor".to_vec(),
+            subnet_contact: b"contact".to_vec(),
+        };
+        put_raw(
+            &SubnetIdentities::<Test>::hashed_key_for(netuid),
+            &old_subnet_identity.encode(),
+        );
+
+        let weight =
+            pallet_subtensor::migrations::migrate_identity_fields::migrate_identity_fields::<Test>(
+            );
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert!(!weight.is_zero());
+
+        // The previous fields are kept and the typed fields are unset.
+        assert_eq!(
+            Identities::<Test>::get(coldkey),
+            Some(ChainIdentity {
+                name: b"name".to_vec(),
+                url: b"https://example.com".to_vec(),
+                image: b"image".to_vec(),
+                discord: b"discord".to_vec(),
+                description: b"description".to_vec(),
+                additional: b"additional".to_vec(),
+                github_repo: vec![],
+                contact: vec![],
+                logo_hash: None,
+            })
+        );
+        assert_eq!(
+            SubnetIdentities::<Test>::get(netuid),
+            Some(SubnetIdentity {
+                subnet_name: b"subnet".to_vec(),
+                github_repo: b"opentensor/subtensor".to_vec(),
+                subnet_contact: b"contact".to_vec(),
+                subnet_url: vec![],
+                discord: vec![],
+                logo_hash: None,
+            })
+        );
+    });
+}
@@ -10,12 +10,13 @@ use frame_support::{
 use frame_system as system;
 use frame_system::{limits, EnsureNever, EnsureRoot, RawOrigin};
 use pallet_collective::MemberCount;
-use sp_core::{Get, H256, U256};
+use sp_core::{blake2_256, Get, H256, U256};
 use sp_runtime::Perbill;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
+use sp_std::cell::RefCell;
 use sp_std::cmp::Ordering;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -401,6 +402,72 @@ impl pallet_subtensor::Config for Test {
     type Preimages = Preimage;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type TimelockKeySource = MockTimelockKeySource;
+    type NestedCalls = MockNestedCalls;
+}
+
+thread_local! {
+    static TIMELOCK_LATEST_ROUND: RefCell<Option<u64>> = const { RefCell::new(Some(0)) };
+}
+
+/// Stand-in for a randomness beacon. The key of a round is derived from the round number and
+/// only usable for decryption once the round is published with `publish_round`.
+pub struct MockTimelockKeySource;
+
+#[allow(dead_code)]
+impl MockTimelockKeySource {
+    /// Publishes the keys of all rounds up to and including `round`.
+    pub fn publish_round(round: u64) {
+        TIMELOCK_LATEST_ROUND.with(|latest| *latest.borrow_mut() = Some(round));
+    }
+
+    /// Makes the key source unavailable.
+    pub fn disable() {
+        TIMELOCK_LATEST_ROUND.with(|latest| *latest.borrow_mut() = None);
+    }
+
+    /// Encrypts `plaintext` to `round`.
+    pub fn encrypt(round: u64, plaintext: &[u8]) -> Vec<u8> {
+        Self::apply_keystream(round, plaintext)
+    }
+
+    fn apply_keystream(round: u64, data: &[u8]) -> Vec<u8> {
+        let key = blake2_256(&round.to_le_bytes());
+        data.iter()
+            .zip(key.iter().cycle())
+            .map(|(byte, key_byte)| byte ^ key_byte)
+            .collect()
+    }
+}
+
+impl pallet_subtensor::TimelockKeySource for MockTimelockKeySource {
+    fn latest_round() -> Option<u64> {
+        TIMELOCK_LATEST_ROUND.with(|latest| *latest.borrow())
+    }
+
+    fn decrypt(round: u64, ciphertext: &[u8]) -> Option<Vec<u8>> {
+        let latest_round = Self::latest_round()?;
+        if round > latest_round {
+            return None;
+        }
+        Some(Self::apply_keystream(round, ciphertext))
+    }
+}
+
+/// Calls nested in utility batches, dispatched for the signer of the batch.
+pub struct MockNestedCalls;
+
+impl pallet_subtensor::NestedCalls<U256, RuntimeCall> for MockNestedCalls {
+    fn nested_calls<'a>(who: &U256, call: &'a RuntimeCall) -> Vec<(U256, &'a RuntimeCall)> {
+        match call {
+            RuntimeCall::Utility(
+                pallet_utility::Call::batch { calls }
+                | pallet_utility::Call::batch_all { calls }
+                | pallet_utility::Call::force_batch { calls },
+            ) => calls.iter().map(|call| (*who, call)).collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 pub struct OriginPrivilegeCmp;
@@ -7,7 +7,12 @@ use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays
 use frame_support::sp_runtime::{transaction_validity::InvalidTransaction, DispatchError};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
-use pallet_subtensor::{AxonInfoOf, Error, SubtensorSignedExtension};
+use pallet_subtensor::{
+    utils::rate_limiting::TransactionType, AdjustmentController, AxonInfoOf, Emission, Error,
+    Event, Incentive, PruningEmissionHistory, PruningPolicy, PruningReason, RegistrationBids,
+    SubtensorSignedExtension, TotalRegistrationBids, MAX_PROJECTED_ADJUSTMENT_INTERVALS,
+    MAX_REGISTRATION_BIDS,
+};
 use sp_core::U256;
 use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
 
@@ -535,6 +540,124 @@ fn test_burn_adjustment() {
     });
 }
 
+#[test]
+fn test_burn_adjustment_pid_controller() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_min_burn(netuid, 100);
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1);
+        SubtensorModule::set_adjustment_controller(
+            netuid,
+            AdjustmentController::Pid {
+                proportional_gain: u16::MAX,
+                integral_gain: u16::MAX,
+            },
+        );
+
+        for i in 0..2 {
+            let key = U256::from(i);
+            SubtensorModule::add_balance_to_coldkey_account(&key, 10000);
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(key),
+                netuid,
+                key
+            ));
+        }
+
+        // One registration above target, both this interval and accumulated: 1 + 1 + 1.
+        step_block(1);
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 3000);
+        assert_eq!(SubtensorModule::get_adjustment_integral(netuid), 1);
+
+        // One registration below target cancels the accumulated error: 1 - 1 + 0.
+        step_block(1);
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 100);
+        assert_eq!(SubtensorModule::get_adjustment_integral(netuid), 0);
+    });
+}
+
+#[test]
+fn test_burn_adjustment_idle_decay_controller() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_min_burn(netuid, 200);
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1);
+        SubtensorModule::set_adjustment_controller(
+            netuid,
+            AdjustmentController::IdleDecay(u16::MAX / 2),
+        );
+
+        // Idle intervals halve the distance to the min burn.
+        step_block(1);
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 600);
+        step_block(1);
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 400);
+
+        // Registrations follow the EMA controller.
+        for i in 0..2 {
+            let key = U256::from(i);
+            SubtensorModule::add_balance_to_coldkey_account(&key, 10000);
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(key),
+                netuid,
+                key
+            ));
+        }
+        step_block(1);
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 600);
+    });
+}
+
+#[test]
+fn test_project_registration_terms() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1);
+        let difficulty: u64 = SubtensorModule::get_difficulty_as_u64(netuid);
+
+        assert!(SubtensorModule::project_registration_terms(netuid + 1, 0, 2, 3).is_none());
+
+        // Two burn registrations per interval raise the burn by half each interval.
+        let projection = SubtensorModule::project_registration_terms(netuid, 0, 2, 3).unwrap();
+        let burns: Vec<u64> = projection.iter().map(|terms| terms.burn.0).collect();
+        assert_eq!(burns, vec![1500, 2250, 3375]);
+        assert!(projection
+            .iter()
+            .all(|terms| terms.difficulty.0 == difficulty));
+        assert_eq!(
+            SubtensorModule::project_registration_terms(netuid, 0, 2, u16::MAX)
+                .unwrap()
+                .len(),
+            MAX_PROJECTED_ADJUSTMENT_INTERVALS as usize
+        );
+
+        // The projection matches the adjustment.
+        for i in 0..2 {
+            let key = U256::from(i);
+            SubtensorModule::add_balance_to_coldkey_account(&key, 10000);
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(key),
+                netuid,
+                key
+            ));
+        }
+        step_block(1);
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), burns[0]);
+    });
+}
+
 #[test]
 fn test_burn_registration_pruning_scenarios() {
     new_test_ext(1).execute_with(|| {
@@ -651,6 +774,323 @@ fn test_burn_registration_pruning_scenarios() {
     });
 }
 
+#[test]
+fn test_pruning_policies() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        for i in 0..3 {
+            register_ok_neuron(netuid, U256::from(i), coldkey, i * 100_000);
+            step_block(1);
+        }
+        let prune = || SubtensorModule::get_neuron_to_prune_with_reason(netuid);
+
+        // The oldest neuron is pruned
+        SubtensorModule::set_pruning_policy(netuid, PruningPolicy::OldestFirst);
+        assert_eq!(
+            prune(),
+            (0, PruningReason::OutOfImmunity(PruningPolicy::OldestFirst))
+        );
+
+        // The neuron with the lowest incentive is pruned
+        SubtensorModule::set_pruning_policy(netuid, PruningPolicy::LowestIncentive);
+        Incentive::<Test>::insert(netuid, vec![30, 10, 20]);
+        assert_eq!(prune().0, 1);
+
+        // The neuron with the lowest stake is pruned
+        SubtensorModule::set_pruning_policy(netuid, PruningPolicy::LowestStake);
+        for (i, stake) in [5, 50, 1].into_iter().enumerate() {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &coldkey,
+                &U256::from(i),
+                stake,
+            );
+        }
+        assert_eq!(prune().0, 2);
+
+        // The neuron with the lowest emission over the last two epochs is pruned
+        let policy = PruningPolicy::LowestEmission(2);
+        SubtensorModule::set_pruning_policy(netuid, policy);
+        for emission in [vec![10, 0, 0], vec![0, 5, 5], vec![0, 5, 6]] {
+            Emission::<Test>::insert(netuid, emission);
+            SubtensorModule::record_pruning_emission(netuid);
+        }
+        assert_eq!(PruningEmissionHistory::<Test>::get(netuid).len(), 2);
+        assert_eq!(prune(), (0, PruningReason::OutOfImmunity(policy)));
+
+        // Protected neurons are only pruned when all neurons are protected
+        SubtensorModule::set_pruning_protected_uids(netuid, vec![0]);
+        assert_eq!(prune(), (1, PruningReason::OutOfImmunity(policy)));
+        SubtensorModule::set_pruning_protected_uids(netuid, vec![0, 1, 2]);
+        assert_eq!(prune(), (0, PruningReason::AllProtected(policy)));
+
+        // Other policies drop the emission history
+        SubtensorModule::set_pruning_policy(netuid, PruningPolicy::LowestPruningScore);
+        assert!(PruningEmissionHistory::<Test>::get(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_registration_emits_neuron_pruned() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let burn_cost = 1000;
+        let coldkey = U256::from(667);
+        let policy = PruningPolicy::LowestEmission(3);
+
+        SubtensorModule::set_burn(netuid, burn_cost);
+        SubtensorModule::set_max_allowed_uids(netuid, 2);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 3);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_pruning_policy(netuid, policy);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+
+        for i in 0..2 {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(i)
+            ));
+            step_block(1);
+        }
+        Emission::<Test>::insert(netuid, vec![5, 10]);
+        SubtensorModule::record_pruning_emission(netuid);
+
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(2)
+        ));
+        System::assert_has_event(
+            Event::NeuronPruned(
+                netuid,
+                0,
+                U256::from(0),
+                PruningReason::OutOfImmunity(policy),
+            )
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_hotkey_for_net_and_uid(netuid, 0),
+            Ok(U256::from(2))
+        );
+        // The emission of the pruned neuron is not held against the new one
+        assert_eq!(
+            PruningEmissionHistory::<Test>::get(netuid),
+            vec![vec![0, 10]]
+        );
+    });
+}
+
+#[test]
+fn test_registration_auction_settles_at_adjustment_interval() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let burn_cost = 1000;
+        let balance = 1_000_000;
+        let coldkeys = [U256::from(101), U256::from(102), U256::from(103)];
+        let hotkeys = [U256::from(1), U256::from(2), U256::from(3)];
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, burn_cost);
+        SubtensorModule::set_adjustment_interval(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 2);
+        SubtensorModule::set_registration_auction_enabled(netuid, true);
+        for coldkey in coldkeys {
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, balance);
+        }
+
+        // First-come burn registration is closed.
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkeys[0]),
+                netuid,
+                hotkeys[0]
+            ),
+            Error::<Test>::RegistrationByAuctionOnly
+        );
+
+        // New bids start at the burn.
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkeys[0]),
+                netuid,
+                hotkeys[0],
+                burn_cost - 1
+            ),
+            Error::<Test>::RegistrationBidTooLow
+        );
+        for (i, amount) in [1000, 2000, 1500].into_iter().enumerate() {
+            assert_ok!(SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkeys[i]),
+                netuid,
+                hotkeys[i],
+                amount
+            ));
+        }
+
+        // Only the bidding coldkey raises a bid, and only upwards.
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkeys[1]),
+                netuid,
+                hotkeys[0],
+                5000
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkeys[0]),
+                netuid,
+                hotkeys[0],
+                1000
+            ),
+            Error::<Test>::RegistrationBidTooLow
+        );
+        assert_ok!(SubtensorModule::bid_for_registration(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkeys[0]),
+            netuid,
+            hotkeys[0],
+            3000
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkeys[0]),
+            balance - 3000
+        );
+        assert_eq!(TotalRegistrationBids::<Test>::get(), 6500);
+
+        // The two highest bids are registered at the lowest of them and the other is refunded.
+        step_block(10);
+        System::assert_has_event(
+            Event::RegistrationAuctionSettled(netuid, vec![hotkeys[0], hotkeys[1]], Some(2000))
+                .into(),
+        );
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &hotkeys[0]
+        ));
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &hotkeys[1]
+        ));
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &hotkeys[2]
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkeys[0]),
+            balance - 2000
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkeys[1]),
+            balance - 2000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkeys[2]), balance);
+        assert_eq!(TotalRegistrationBids::<Test>::get(), 0);
+        assert_eq!(RegistrationBids::<Test>::iter_prefix(netuid).count(), 0);
+
+        // Three bids for two slots raise the burn from the clearing price.
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 2500);
+    });
+}
+
+#[test]
+fn test_registration_auction_outbid_and_disable() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let burn_cost = 1000;
+        let coldkey = U256::from(667);
+        let balance = 1_000_000;
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, burn_cost);
+        SubtensorModule::set_registration_auction_enabled(netuid, true);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, balance);
+
+        for i in 0..MAX_REGISTRATION_BIDS {
+            assert_ok!(SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(i),
+                burn_cost
+            ));
+            step_block(1);
+        }
+        let outbid_hotkey = U256::from(MAX_REGISTRATION_BIDS - 1);
+
+        // A full auction only takes bids above its lowest bid, evicting the latest lowest bid.
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(1000),
+                burn_cost
+            ),
+            Error::<Test>::RegistrationBidTooLow
+        );
+        assert_ok!(SubtensorModule::bid_for_registration(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(1000),
+            burn_cost + 1
+        ));
+        System::assert_has_event(
+            Event::RegistrationBidRefunded(coldkey, netuid, outbid_hotkey, burn_cost).into(),
+        );
+        assert!(RegistrationBids::<Test>::get(netuid, outbid_hotkey).is_none());
+        assert_eq!(
+            RegistrationBids::<Test>::iter_prefix(netuid).count(),
+            MAX_REGISTRATION_BIDS
+        );
+
+        // Disabling the auction refunds the open bids.
+        SubtensorModule::set_registration_auction_enabled(netuid, false);
+        assert_eq!(RegistrationBids::<Test>::iter_prefix(netuid).count(), 0);
+        assert_eq!(TotalRegistrationBids::<Test>::get(), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), balance);
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(1000),
+                burn_cost + 1
+            ),
+            Error::<Test>::RegistrationAuctionDisabled
+        );
+    });
+}
+
+#[test]
+fn test_blocks_until_allowed_registration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_rate_limit(&TransactionType::Register, netuid, 1);
+        assert_eq!(SubtensorModule::get_max_registrations_per_block(netuid), 1);
+
+        // The limit is per subnet, so any account reports the same blocks.
+        let blocks_until_allowed = || {
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::Register,
+                &U256::from(0),
+                netuid,
+            )
+        };
+        assert_eq!(blocks_until_allowed(), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 0);
+        assert_eq!(blocks_until_allowed(), 1);
+
+        step_block(1);
+        assert_eq!(blocks_until_allowed(), 0);
+    });
+}
+
 #[test]
 fn test_registration_too_many_registrations_per_block() {
     new_test_ext(1).execute_with(|| {
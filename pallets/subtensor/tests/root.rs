@@ -1,7 +1,7 @@
 #![allow(clippy::indexing_slicing, clippy::unwrap_used)]
 
 use crate::mock::*;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, weights::Weight};
 use frame_system::Config;
 use frame_system::{EventRecord, Phase};
 use pallet_subtensor::Error;
@@ -295,7 +295,10 @@ fn test_root_set_weights() {
         // Run the root epoch
         log::debug!("Running Root epoch");
         SubtensorModule::set_tempo(root_netuid, 1);
-        assert_ok!(SubtensorModule::root_epoch(1_000_000_000));
+        assert_ok!(SubtensorModule::root_epoch(
+            1_000_000_000,
+            &mut Weight::zero()
+        ));
         // Check that the emission values have been set.
         for netuid in 1..n {
             log::debug!("check emission for netuid: {}", netuid);
@@ -414,7 +417,10 @@ fn test_root_set_weights_out_of_order_netuids() {
         // Run the root epoch
         log::debug!("Running Root epoch");
         SubtensorModule::set_tempo(root_netuid, 1);
-        assert_ok!(SubtensorModule::root_epoch(1_000_000_000));
+        assert_ok!(SubtensorModule::root_epoch(
+            1_000_000_000,
+            &mut Weight::zero()
+        ));
         // Check that the emission values have been set.
         for netuid in subnets.iter() {
             log::debug!("check emission for netuid: {}", netuid);
@@ -615,7 +621,10 @@ fn test_network_pruning() {
         // 9 : 1_000
 
         step_block(1);
-        assert_ok!(SubtensorModule::root_epoch(1_000_000_000));
+        assert_ok!(SubtensorModule::root_epoch(
+            1_000_000_000,
+            &mut Weight::zero()
+        ));
         assert_eq!(SubtensorModule::get_subnet_emission_value(0), 385_861_815);
         assert_eq!(SubtensorModule::get_subnet_emission_value(1), 249_435_914);
         assert_eq!(SubtensorModule::get_subnet_emission_value(2), 180_819_837);
@@ -998,6 +1007,9 @@ fn test_user_add_network_with_identity_fields_ok() {
             subnet_name: subnet_name_1.clone(),
             github_repo: github_repo_1.clone(),
             subnet_contact: subnet_contact_1.clone(),
+            subnet_url: vec![],
+            discord: vec![],
+            logo_hash: None,
         };
 
         let subnet_name_2: Vec<u8> = b"DistinctSubnet2".to_vec();
@@ -1008,6 +1020,9 @@ fn test_user_add_network_with_identity_fields_ok() {
             subnet_name: subnet_name_2.clone(),
             github_repo: github_repo_2.clone(),
             subnet_contact: subnet_contact_2.clone(),
+            subnet_url: vec![],
+            discord: vec![],
+            logo_hash: None,
         };
 
         SubtensorModule::add_balance_to_coldkey_account(&coldkey_1, balance_1);
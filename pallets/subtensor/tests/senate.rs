@@ -6,7 +6,7 @@ use mock::*;
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use frame_system::{EventRecord, Phase};
-use sp_core::{bounded_vec, H256, U256};
+use sp_core::{bounded_vec, Get, H256, U256};
 use sp_runtime::{
     traits::{BlakeTwo256, Hash},
     BuildStorage,
@@ -16,7 +16,7 @@ use frame_system::pallet_prelude::*;
 use frame_system::Config;
 use pallet_collective::Event as CollectiveEvent;
 use pallet_subtensor::migrations;
-use pallet_subtensor::Error;
+use pallet_subtensor::{Error, SenateTermEnd, SenateTermStart};
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
     sp_tracing::try_init_simple();
@@ -808,3 +808,183 @@ fn test_adjust_senate_events() {
         );
     });
 }
+
+#[test]
+fn test_senate_rebalanced_at_root_epoch() {
+    // Test that a root validator outstaking a member joins the full senate at the root epoch
+    new_test_ext().execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let burn_cost = 1000;
+        let coldkey_account_id = U256::from(667);
+        let root_netuid = SubtensorModule::get_root_netuid();
+        let genesis_member = U256::from(1);
+        let challenger_hotkey_account_id = U256::from(200);
+
+        let max_senate_size: u16 = SenateMaxMembers::get() as u16;
+        // Seats left after the five genesis members
+        let validators: u16 = max_senate_size - Senate::members().len() as u16;
+
+        //add network
+        SubtensorModule::set_burn(netuid, burn_cost);
+        add_network(netuid, tempo, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, 1_000_000);
+
+        // Allow all registrations in netuid in same block. Same for root network.
+        SubtensorModule::set_max_registrations_per_block(netuid, max_senate_size + 1);
+        SubtensorModule::set_target_registrations_per_interval(netuid, max_senate_size + 1);
+        SubtensorModule::set_max_registrations_per_block(root_netuid, max_senate_size + 1);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, max_senate_size + 1);
+
+        // Fill the senate with staked root validators
+        for i in 0..validators {
+            let hotkey_account_id = U256::from(100 + i);
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                netuid,
+                hotkey_account_id
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+                1000
+            ));
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id
+            ));
+            assert!(Senate::is_member(&hotkey_account_id));
+        }
+        assert_eq!(Senate::members().len(), max_senate_size as usize);
+
+        // The challenger has no stake when registering, so does not join the full senate
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            netuid,
+            challenger_hotkey_account_id
+        ));
+        assert_ok!(SubtensorModule::root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            challenger_hotkey_account_id
+        ));
+        assert!(!Senate::is_member(&challenger_hotkey_account_id));
+
+        // Outstaking the genesis members does not seat the challenger before the root epoch
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            challenger_hotkey_account_id,
+            500
+        ));
+        assert!(!Senate::is_member(&challenger_hotkey_account_id));
+
+        System::reset_events();
+
+        // The root epoch at block 99 rebalances the senate
+        step_block(100);
+
+        assert!(Senate::is_member(&challenger_hotkey_account_id));
+        assert!(!Senate::is_member(&genesis_member));
+        assert_eq!(Senate::members().len(), max_senate_size as usize);
+        assert!(
+            System::events().contains(&record(RuntimeEvent::SubtensorModule(
+                SubtensorEvent::SenateRebalanced {
+                    added: vec![challenger_hotkey_account_id],
+                    removed: vec![genesis_member]
+                }
+            )))
+        );
+    });
+}
+
+#[test]
+fn test_senate_term_limit() {
+    // Test that members leave the senate at the term limit and sit out a term
+    new_test_ext().execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let hotkey_account_id = U256::from(6);
+        let burn_cost = 1000;
+        let coldkey_account_id = U256::from(667);
+        let term_limit: u64 = 100;
+
+        //add network
+        SubtensorModule::set_burn(netuid, burn_cost);
+        add_network(netuid, tempo, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, 10000);
+        SubtensorModule::set_senate_term_limit(term_limit);
+
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            netuid,
+            hotkey_account_id
+        ));
+        assert_ok!(SubtensorModule::root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id
+        ));
+        assert!(Senate::is_member(&hotkey_account_id));
+
+        // The terms of all members start at block 1
+        SubtensorModule::rebalance_senate();
+        let members = Senate::members();
+        assert_eq!(members.len(), 6);
+
+        // All members have served the term limit
+        System::set_block_number(1 + term_limit);
+        System::reset_events();
+        SubtensorModule::rebalance_senate();
+        assert!(Senate::members().is_empty());
+        assert!(
+            System::events().contains(&record(RuntimeEvent::SubtensorModule(
+                SubtensorEvent::SenateRebalanced {
+                    added: vec![],
+                    removed: members
+                }
+            )))
+        );
+
+        // The hotkey sits out a term
+        assert!(!SubtensorModule::is_eligible_for_senate(&hotkey_account_id));
+        assert_noop!(
+            SubtensorModule::adjust_senate(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id
+            ),
+            Error::<Test>::SenateTermLimitReached
+        );
+
+        // And is eligible again after its term
+        System::set_block_number(1 + 2 * term_limit);
+        SubtensorModule::rebalance_senate();
+        assert_eq!(Senate::members(), vec![hotkey_account_id]);
+    });
+}
+
+#[test]
+fn test_rebalance_senate_weight_counts_terms() {
+    // Test that the rebalance weight counts the senate terms it iterates and removes
+    new_test_ext().execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        let former_member = U256::from(900);
+        let rested_hotkey = U256::from(901);
+
+        // The terms of all members start at block 1
+        SubtensorModule::rebalance_senate();
+        let weight = SubtensorModule::rebalance_senate();
+
+        // The term of a former member and a sat out term are read and removed
+        SenateTermStart::<Test>::insert(former_member, 0);
+        SenateTermEnd::<Test>::insert(rested_hotkey, 0);
+        let db_weight = <Test as Config>::DbWeight::get();
+        assert_eq!(
+            SubtensorModule::rebalance_senate(),
+            weight.saturating_add(db_weight.reads_writes(4, 2))
+        );
+        assert!(!SenateTermStart::<Test>::contains_key(former_member));
+        assert!(!SenateTermEnd::<Test>::contains_key(rested_hotkey));
+    });
+}
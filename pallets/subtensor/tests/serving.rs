@@ -1,5 +1,6 @@
 use crate::mock::*;
 mod mock;
+use codec::Encode;
 use frame_support::assert_noop;
 use frame_support::pallet_prelude::Weight;
 use frame_support::{
@@ -7,9 +8,11 @@ use frame_support::{
     dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays},
 };
 use frame_system::Config;
+use pallet_subtensor::rpc_info::pre_dispatch_info::PreDispatchFailure;
+use pallet_subtensor::utils::rate_limiting::TransactionType;
 use pallet_subtensor::Error;
 use pallet_subtensor::*;
-use sp_core::U256;
+use sp_core::{H256, U256};
 
 mod test {
     use std::net::{Ipv4Addr, Ipv6Addr};
@@ -303,6 +306,175 @@ fn test_axon_serving_rate_limit_exceeded() {
     });
 }
 
+#[test]
+fn test_axon_serving_blocks_until_allowed() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+        add_network(netuid, 13, 0);
+        add_network(other_netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        register_ok_neuron(other_netuid, hotkey_account_id, U256::from(66), 0);
+
+        // A global limit applies to both subnets, the subnet limit takes precedence on its subnet.
+        SubtensorModule::set_rate_limit(&TransactionType::ServeAxon, GLOBAL_RATE_LIMIT_NETUID, 5);
+        SubtensorModule::set_serving_rate_limit(netuid, 2);
+        assert_eq!(SubtensorModule::get_serving_rate_limit(netuid), 2);
+        assert_eq!(SubtensorModule::get_serving_rate_limit(other_netuid), 5);
+
+        // Nothing served yet.
+        assert_eq!(
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::ServeAxon,
+                &hotkey_account_id,
+                netuid
+            ),
+            0
+        );
+
+        run_to_block(1);
+        for serve_netuid in [netuid, other_netuid] {
+            assert_ok!(SubtensorModule::serve_axon(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                serve_netuid,
+                2,
+                1676056785,
+                128,
+                4,
+                0,
+                0,
+                0
+            ));
+        }
+        assert_eq!(
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::ServeAxon,
+                &hotkey_account_id,
+                netuid
+            ),
+            2
+        );
+        assert_eq!(
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::ServeAxon,
+                &hotkey_account_id,
+                other_netuid
+            ),
+            5
+        );
+        // Prometheus is limited separately.
+        assert_eq!(
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::ServePrometheus,
+                &hotkey_account_id,
+                netuid
+            ),
+            0
+        );
+
+        run_to_block(3);
+        assert_eq!(
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::ServeAxon,
+                &hotkey_account_id,
+                netuid
+            ),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::ServeAxon,
+                &hotkey_account_id,
+                other_netuid
+            ),
+            3
+        );
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        assert_noop!(
+            SubtensorModule::serve_axon(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                other_netuid,
+                2,
+                1676056785,
+                128,
+                4,
+                0,
+                0,
+                0
+            ),
+            Error::<Test>::ServingRateLimitExceeded
+        );
+    });
+}
+
+#[test]
+fn test_pre_dispatch_check_serve_axon_rate_limited() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_serving_rate_limit(netuid, 2);
+        let call = RuntimeCall::SubtensorModule(pallet_subtensor::Call::serve_axon {
+            netuid,
+            version: 2,
+            ip: 1676056785,
+            port: 128,
+            ip_type: 4,
+            protocol: 0,
+            placeholder1: 0,
+            placeholder2: 0,
+        });
+
+        // Not registered.
+        assert_eq!(
+            SubtensorModule::pre_dispatch_check(&call, hotkey_account_id.encode()),
+            Some(vec![PreDispatchFailure {
+                error: b"HotKeyNotRegisteredInNetwork".to_vec(),
+                blocks_until_allowed: None,
+            }])
+        );
+
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        assert_eq!(
+            SubtensorModule::pre_dispatch_check(&call, hotkey_account_id.encode()),
+            Some(vec![])
+        );
+
+        run_to_block(1);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+
+        // Rate limited, with the blocks until the call is allowed.
+        assert_eq!(
+            SubtensorModule::pre_dispatch_check(&call, hotkey_account_id.encode()),
+            Some(vec![PreDispatchFailure {
+                error: b"ServingRateLimitExceeded".to_vec(),
+                blocks_until_allowed: Some(2.into()),
+            }])
+        );
+    });
+}
+
 #[test]
 fn test_axon_invalid_port() {
     new_test_ext(1).execute_with(|| {
@@ -704,6 +876,10 @@ fn test_do_set_identity() {
 #[test]
 fn test_is_valid_identity() {
     new_test_ext(1).execute_with(|| {
+        // Well formed values of the maximum lengths of the typed fields only set by the typed call
+        let github_repo: Vec<u8> = vec![b'a'; 1024];
+        let contact: Vec<u8> = vec![b'a'; 1024];
+
         // Test valid identity
         let valid_identity = ChainIdentity {
             name: vec![0; 256],
@@ -712,6 +888,9 @@ fn test_is_valid_identity() {
             discord: vec![0; 256],
             description: vec![0; 1024],
             additional: vec![0; 1024],
+            github_repo: vec![],
+            contact: vec![],
+            logo_hash: None,
         };
         assert!(SubtensorModule::is_valid_identity(&valid_identity));
 
@@ -723,6 +902,9 @@ fn test_is_valid_identity() {
             discord: vec![0; 256],
             description: vec![0; 1024],
             additional: vec![0; 1024],
+            github_repo: github_repo.clone(),
+            contact: contact.clone(),
+            logo_hash: Some(H256::repeat_byte(1)),
         };
         assert!(SubtensorModule::is_valid_identity(&max_length_identity));
 
@@ -734,6 +916,9 @@ fn test_is_valid_identity() {
             discord: vec![0; 256],
             description: vec![0; 1024],
             additional: vec![0; 1024],
+            github_repo: github_repo.clone(),
+            contact: contact.clone(),
+            logo_hash: Some(H256::repeat_byte(1)),
         };
         assert!(!SubtensorModule::is_valid_identity(
             &invalid_length_identity
@@ -747,6 +932,9 @@ fn test_is_valid_identity() {
             discord: vec![0; 256],
             description: vec![0; 1024],
             additional: vec![0; 1024],
+            github_repo: vec![],
+            contact: vec![],
+            logo_hash: None,
         };
         assert!(!SubtensorModule::is_valid_identity(&invalid_field_identity));
 
@@ -758,6 +946,9 @@ fn test_is_valid_identity() {
             discord: vec![],
             description: vec![],
             additional: vec![],
+            github_repo: vec![],
+            contact: vec![],
+            logo_hash: None,
         };
         assert!(SubtensorModule::is_valid_identity(&empty_identity));
 
@@ -769,6 +960,9 @@ fn test_is_valid_identity() {
             discord: b"alice#1234".to_vec(),
             description: vec![],
             additional: b"Additional info".to_vec(),
+            github_repo: vec![],
+            contact: vec![],
+            logo_hash: None,
         };
         assert!(SubtensorModule::is_valid_identity(&mixed_identity));
 
@@ -780,6 +974,9 @@ fn test_is_valid_identity() {
             discord: vec![0; 256],
             description: vec![0; 1024],
             additional: vec![0; 1024],
+            github_repo: github_repo.clone(),
+            contact: contact.clone(),
+            logo_hash: Some(H256::repeat_byte(1)),
         };
         assert!(SubtensorModule::is_valid_identity(&max_field_identity));
     });
@@ -970,11 +1167,18 @@ fn test_do_set_subnet_identity() {
 #[test]
 fn test_is_valid_subnet_identity() {
     new_test_ext(1).execute_with(|| {
+        // Well formed values of the maximum lengths of the typed fields only set by the typed call
+        let subnet_url: Vec<u8> = [b"https://".as_slice(), &[b'a'; 248]].concat();
+        let discord: Vec<u8> = vec![b'a'; 256];
+
         // Test valid subnet identity
         let valid_identity = SubnetIdentity {
             subnet_name: vec![0; 256],
             github_repo: vec![0; 1024],
             subnet_contact: vec![0; 1024],
+            subnet_url: vec![],
+            discord: vec![],
+            logo_hash: None,
         };
         assert!(SubtensorModule::is_valid_subnet_identity(&valid_identity));
 
@@ -983,6 +1187,9 @@ fn test_is_valid_subnet_identity() {
             subnet_name: vec![0; 256],
             github_repo: vec![0; 1024],
             subnet_contact: vec![0; 1024],
+            subnet_url: subnet_url.clone(),
+            discord: discord.clone(),
+            logo_hash: Some(H256::repeat_byte(1)),
         };
         assert!(SubtensorModule::is_valid_subnet_identity(
             &max_length_identity
@@ -993,6 +1200,9 @@ fn test_is_valid_subnet_identity() {
             subnet_name: vec![0; 257],
             github_repo: vec![0; 1024],
             subnet_contact: vec![0; 1024],
+            subnet_url: subnet_url.clone(),
+            discord: discord.clone(),
+            logo_hash: Some(H256::repeat_byte(1)),
         };
         assert!(!SubtensorModule::is_valid_subnet_identity(
             &invalid_length_identity
@@ -1003,6 +1213,9 @@ fn test_is_valid_subnet_identity() {
             subnet_name: vec![0; 257],
             github_repo: vec![0; 1024],
             subnet_contact: vec![0; 1024],
+            subnet_url: vec![],
+            discord: vec![],
+            logo_hash: None,
         };
         assert!(!SubtensorModule::is_valid_subnet_identity(
             &invalid_field_identity
@@ -1013,6 +1226,9 @@ fn test_is_valid_subnet_identity() {
             subnet_name: vec![],
             github_repo: vec![],
             subnet_contact: vec![],
+            subnet_url: vec![],
+            discord: vec![],
+            logo_hash: None,
         };
         assert!(SubtensorModule::is_valid_subnet_identity(&empty_identity));
 
@@ -1021,6 +1237,9 @@ fn test_is_valid_subnet_identity() {
             subnet_name: b"Test Subnet".to_vec(),
             github_repo: vec![],
             subnet_contact: b"contact@testsubnet.com".to_vec(),
+            subnet_url: vec![],
+            discord: vec![],
+            logo_hash: None,
         };
         assert!(SubtensorModule::is_valid_subnet_identity(&mixed_identity));
     });
@@ -1072,3 +1291,379 @@ fn test_set_subnet_identity_dispatch_info_ok() {
         assert_eq!(dispatch_info.pays_fee, Pays::Yes);
     });
 }
+
+#[test]
+fn test_set_typed_subnet_identity_dispatch_info_ok() {
+    new_test_ext(1).execute_with(|| {
+        let call: RuntimeCall =
+            RuntimeCall::SubtensorModule(SubtensorCall::set_typed_subnet_identity {
+                netuid: 1,
+                subnet_name: b"JesusSubnet".to_vec(),
+                github_repo: b"bible.com".to_vec(),
+                subnet_contact: b"https://www.vatican.va".to_vec(),
+                subnet_url: vec![],
+                discord: vec![],
+                logo_hash: None,
+            });
+
+        let dispatch_info: DispatchInfo = call.get_dispatch_info();
+
+        assert_eq!(dispatch_info.class, DispatchClass::Normal);
+        assert_eq!(dispatch_info.pays_fee, Pays::Yes);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test serving -- test_untyped_identity_calls_keep_typed_fields --exact --nocapture
+#[test]
+fn test_untyped_identity_calls_keep_typed_fields() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let netuid = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubnetOwner::<Test>::insert(netuid, coldkey);
+
+        assert_ok!(SubtensorModule::set_typed_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            b"Alice".to_vec(),
+            b"https://alice.com".to_vec(),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            b"alice/repo".to_vec(),
+            b"alice@example.com".to_vec(),
+            Some(H256::repeat_byte(1)),
+        ));
+        assert_ok!(SubtensorModule::set_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            b"Alice Renamed".to_vec(),
+            b"https://alice.com".to_vec(),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        ));
+        let identity = Identities::<Test>::get(coldkey).expect("identity is set");
+        assert_eq!(identity.name, b"Alice Renamed".to_vec());
+        assert_eq!(identity.github_repo, b"alice/repo".to_vec());
+        assert_eq!(identity.contact, b"alice@example.com".to_vec());
+        assert_eq!(identity.logo_hash, Some(H256::repeat_byte(1)));
+
+        assert_ok!(SubtensorModule::set_typed_subnet_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            b"Test Subnet".to_vec(),
+            b"https://github.com/test/subnet".to_vec(),
+            vec![],
+            b"https://testsubnet.com".to_vec(),
+            b"discord.gg/subnet".to_vec(),
+            Some(H256::repeat_byte(2)),
+        ));
+        assert_ok!(SubtensorModule::set_subnet_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            b"Renamed Subnet".to_vec(),
+            b"https://github.com/test/subnet".to_vec(),
+            vec![],
+        ));
+        let identity = SubnetIdentities::<Test>::get(netuid).expect("subnet identity is set");
+        assert_eq!(identity.subnet_name, b"Renamed Subnet".to_vec());
+        assert_eq!(identity.subnet_url, b"https://testsubnet.com".to_vec());
+        assert_eq!(identity.discord, b"discord.gg/subnet".to_vec());
+        assert_eq!(identity.logo_hash, Some(H256::repeat_byte(2)));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test serving -- test_is_valid_identity_field --exact --nocapture
+#[test]
+fn test_is_valid_identity_field() {
+    new_test_ext(1).execute_with(|| {
+        let is_valid = |field: IdentityField, value: &[u8]| {
+            SubtensorModule::is_valid_identity_field(field, value)
+        };
+
+        // Empty values leave the field unset.
+        for field in [
+            IdentityField::Url,
+            IdentityField::GithubRepo,
+            IdentityField::Discord,
+            IdentityField::Contact,
+            IdentityField::LogoHash,
+        ] {
+            assert!(is_valid(field, b""));
+        }
+
+        assert!(is_valid(IdentityField::Url, b"https://alice.com"));
+        assert!(is_valid(IdentityField::Url, b"http://alice.com/path?q=1"));
+        assert!(!is_valid(IdentityField::Url, b"alice.com"));
+        assert!(!is_valid(IdentityField::Url, b"https://"));
+        assert!(!is_valid(IdentityField::Url, b"https://alice .com"));
+        assert!(!is_valid(IdentityField::Url, b"ftp://alice.com"));
+
+        assert!(is_valid(IdentityField::GithubRepo, b"opentensor"));
+        assert!(is_valid(IdentityField::GithubRepo, b"opentensor/subtensor"));
+        assert!(is_valid(
+            IdentityField::GithubRepo,
+            b"https://github.com/opentensor/bit_tensor.py"
+        ));
+        assert!(!is_valid(
+            IdentityField::GithubRepo,
+            b"https://gitlab.com/opentensor/subtensor"
+        ));
+        assert!(!is_valid(IdentityField::GithubRepo, b"opentensor/"));
+        assert!(!is_valid(IdentityField::GithubRepo, b"a/b/c"));
+        assert!(!is_valid(IdentityField::GithubRepo, b"open tensor"));
+
+        assert!(is_valid(IdentityField::Discord, b"alice#1234"));
+        assert!(is_valid(IdentityField::Discord, b"discord.gg/invite"));
+        assert!(!is_valid(IdentityField::Discord, b"alice 1234"));
+        assert!(!is_valid(IdentityField::Discord, &[0xff]));
+
+        assert!(is_valid(IdentityField::Contact, b"alice@example.com"));
+        assert!(is_valid(IdentityField::Contact, "Alice Müller".as_bytes()));
+        assert!(!is_valid(IdentityField::Contact, b"alice\nexample"));
+        assert!(!is_valid(IdentityField::Contact, &[0xff, 0xfe]));
+
+        assert!(is_valid(IdentityField::LogoHash, &[1; 32]));
+        assert!(!is_valid(IdentityField::LogoHash, &[1; 31]));
+
+        // A malformed typed field only set by the typed call invalidates the identity.
+        let identity = ChainIdentity {
+            name: b"Alice".to_vec(),
+            url: vec![],
+            image: vec![],
+            discord: vec![],
+            description: vec![],
+            additional: vec![],
+            github_repo: b"not a repo".to_vec(),
+            contact: vec![],
+            logo_hash: None,
+        };
+        assert!(!SubtensorModule::is_valid_identity(&identity));
+        assert!(!SubtensorModule::is_valid_typed_identity(&identity));
+
+        // The fields set by the untyped calls are only checked to be well formed by the typed calls.
+        let identity = ChainIdentity {
+            name: b"Alice".to_vec(),
+            url: b"alice.com".to_vec(),
+            image: vec![],
+            discord: b"alice 1234".to_vec(),
+            description: vec![],
+            additional: vec![],
+            github_repo: vec![],
+            contact: vec![],
+            logo_hash: None,
+        };
+        assert!(SubtensorModule::is_valid_identity(&identity));
+        assert!(!SubtensorModule::is_valid_typed_identity(&identity));
+
+        let subnet_identity = SubnetIdentity {
+            subnet_name: b"Test Subnet".to_vec(),
+            github_repo: b"bible.com/repo/path".to_vec(),
+            subnet_contact: vec![],
+            subnet_url: vec![],
+            discord: vec![],
+            logo_hash: None,
+        };
+        assert!(SubtensorModule::is_valid_subnet_identity(&subnet_identity));
+        assert!(!SubtensorModule::is_valid_typed_subnet_identity(
+            &subnet_identity
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test serving -- test_attest_identity_field --exact --nocapture
+#[test]
+fn test_attest_identity_field() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let registrar = U256::from(3);
+        let other_registrar = U256::from(4);
+        let netuid = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::set_identity_registrars(vec![registrar, other_registrar]);
+
+        let set_identity = |github_repo: &[u8]| {
+            SubtensorModule::do_set_typed_identity(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                b"Alice".to_vec(),
+                b"https://alice.com".to_vec(),
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                github_repo.to_vec(),
+                b"alice@example.com".to_vec(),
+                None,
+            )
+        };
+        assert_ok!(set_identity(b"alice/repo"));
+
+        // Only registrars attest fields.
+        assert_noop!(
+            SubtensorModule::attest_identity_field(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                coldkey,
+                IdentityField::Url,
+                true
+            ),
+            Error::<Test>::NotIdentityRegistrar
+        );
+
+        // Unset fields cannot be attested.
+        assert_noop!(
+            SubtensorModule::attest_identity_field(
+                <<Test as Config>::RuntimeOrigin>::signed(registrar),
+                coldkey,
+                IdentityField::Discord,
+                true
+            ),
+            Error::<Test>::IdentityFieldNotSet
+        );
+        assert_noop!(
+            SubtensorModule::attest_identity_field(
+                <<Test as Config>::RuntimeOrigin>::signed(registrar),
+                U256::from(99),
+                IdentityField::Url,
+                true
+            ),
+            Error::<Test>::IdentityFieldNotSet
+        );
+
+        assert_ok!(SubtensorModule::attest_identity_field(
+            <<Test as Config>::RuntimeOrigin>::signed(registrar),
+            coldkey,
+            IdentityField::Url,
+            true
+        ));
+        assert_ok!(SubtensorModule::attest_identity_field(
+            <<Test as Config>::RuntimeOrigin>::signed(other_registrar),
+            coldkey,
+            IdentityField::GithubRepo,
+            true
+        ));
+        System::assert_last_event(
+            Event::IdentityFieldAttested(other_registrar, coldkey, IdentityField::GithubRepo, true)
+                .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_verified_identity_fields(&coldkey),
+            vec![IdentityField::Url, IdentityField::GithubRepo]
+        );
+
+        // Changing a field revokes its attestation only.
+        assert_ok!(set_identity(b"alice/other-repo"));
+        assert_eq!(
+            SubtensorModule::get_verified_identity_fields(&coldkey),
+            vec![IdentityField::Url]
+        );
+
+        // Attestations of removed registrars are ignored.
+        SubtensorModule::set_identity_registrars(vec![other_registrar]);
+        assert!(SubtensorModule::get_verified_identity_fields(&coldkey).is_empty());
+
+        // Revoking removes the attestation.
+        assert_ok!(SubtensorModule::attest_identity_field(
+            <<Test as Config>::RuntimeOrigin>::signed(other_registrar),
+            coldkey,
+            IdentityField::Contact,
+            true
+        ));
+        assert_ok!(SubtensorModule::attest_identity_field(
+            <<Test as Config>::RuntimeOrigin>::signed(other_registrar),
+            coldkey,
+            IdentityField::Contact,
+            false
+        ));
+        assert!(IdentityAttestations::<Test>::get(coldkey, IdentityField::Contact).is_none());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test serving -- test_attest_subnet_identity_field --exact --nocapture
+#[test]
+fn test_attest_subnet_identity_field() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let registrar = U256::from(3);
+        let netuid = 1;
+
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, coldkey);
+        SubtensorModule::set_identity_registrars(vec![registrar]);
+
+        let set_subnet_identity = |subnet_url: &[u8]| {
+            SubtensorModule::do_set_typed_subnet_identity(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                b"Test Subnet".to_vec(),
+                b"https://github.com/test/subnet".to_vec(),
+                vec![],
+                subnet_url.to_vec(),
+                b"discord.gg/subnet".to_vec(),
+                Some(H256::repeat_byte(1)),
+            )
+        };
+
+        // Malformed typed fields are rejected.
+        assert_noop!(
+            set_subnet_identity(b"testsubnet.com"),
+            Error::<Test>::InvalidIdentity
+        );
+        assert_ok!(set_subnet_identity(b"https://testsubnet.com"));
+
+        assert_noop!(
+            SubtensorModule::attest_subnet_identity_field(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                IdentityField::Url,
+                true
+            ),
+            Error::<Test>::NotIdentityRegistrar
+        );
+        assert_noop!(
+            SubtensorModule::attest_subnet_identity_field(
+                <<Test as Config>::RuntimeOrigin>::signed(registrar),
+                netuid,
+                IdentityField::Contact,
+                true
+            ),
+            Error::<Test>::IdentityFieldNotSet
+        );
+
+        for field in [
+            IdentityField::Url,
+            IdentityField::Discord,
+            IdentityField::LogoHash,
+        ] {
+            assert_ok!(SubtensorModule::attest_subnet_identity_field(
+                <<Test as Config>::RuntimeOrigin>::signed(registrar),
+                netuid,
+                field,
+                true
+            ));
+        }
+        System::assert_last_event(
+            Event::SubnetIdentityFieldAttested(registrar, netuid, IdentityField::LogoHash, true)
+                .into(),
+        );
+
+        // Changing the URL revokes its attestation only.
+        assert_ok!(set_subnet_identity(b"https://testsubnet.org"));
+        assert_eq!(
+            SubtensorModule::get_verified_subnet_identity_fields(netuid),
+            vec![IdentityField::Discord, IdentityField::LogoHash]
+        );
+
+        // Removing the network clears its attestations.
+        SubtensorModule::remove_network(netuid);
+        assert!(SubnetIdentityAttestations::<Test>::iter_prefix(netuid)
+            .next()
+            .is_none());
+    });
+}
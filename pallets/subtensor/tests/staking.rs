@@ -1,13 +1,14 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::arithmetic_side_effects)]
 
+use codec::Encode;
 use frame_support::{assert_err, assert_noop, assert_ok, traits::Currency};
 use frame_system::Config;
 mod mock;
 use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays};
 use frame_support::sp_runtime::DispatchError;
 use mock::*;
-use pallet_subtensor::*;
+use pallet_subtensor::{utils::rate_limiting::TransactionType, *};
 use sp_core::{H256, U256};
 
 /***********************************************************
@@ -441,6 +442,53 @@ fn test_add_stake_rate_limit_exceeded() {
     });
 }
 
+#[test]
+fn test_blocks_until_allowed_stake() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(561337);
+        let coldkey = U256::from(61337);
+        let netuid: u16 = 1;
+        let stake_interval: u64 = 10;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 60000);
+        SubtensorModule::set_rate_limit(&TransactionType::Stake, GLOBAL_RATE_LIMIT_NETUID, 1);
+        SubtensorModule::set_stake_interval(stake_interval);
+        assert_eq!(SubtensorModule::get_target_stakes_per_interval(), 1);
+
+        let blocks_until_allowed = || {
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::Stake,
+                &coldkey,
+                GLOBAL_RATE_LIMIT_NETUID,
+            )
+        };
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            1,
+        ));
+        assert_eq!(blocks_until_allowed(), stake_interval);
+        assert_err!(
+            SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                1,
+            ),
+            Error::<Test>::StakeRateLimitExceeded
+        );
+
+        step_block(stake_interval as u16);
+        assert_eq!(blocks_until_allowed(), 0);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            1,
+        ));
+    });
+}
+
 // /***********************************************************
 // 	staking::remove_stake() tests
 // ************************************************************/
@@ -2051,6 +2099,63 @@ fn test_rate_limits_enforced_on_increase_take() {
     });
 }
 
+#[test]
+fn test_blocks_until_allowed_increase_take() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            SubtensorModule::get_min_delegate_take()
+        ));
+
+        // The delegate take rate limit has to be exceeded before the take can be increased.
+        let blocks_until_allowed = || {
+            SubtensorModule::get_blocks_until_allowed(
+                &TransactionType::IncreaseDelegateTake,
+                &coldkey0,
+                GLOBAL_RATE_LIMIT_NETUID,
+            )
+        };
+        assert_eq!(
+            blocks_until_allowed(),
+            InitialTxDelegateTakeRateLimit::get() + 1
+        );
+        assert_eq!(
+            SubtensorModule::get_blocks_until_allowed_for_account(
+                coldkey0.encode(),
+                TransactionType::IncreaseDelegateTake.into(),
+                GLOBAL_RATE_LIMIT_NETUID
+            ),
+            blocks_until_allowed()
+        );
+
+        step_block(InitialTxDelegateTakeRateLimit::get() as u16);
+        assert_eq!(blocks_until_allowed(), 1);
+        assert_eq!(
+            SubtensorModule::do_increase_take(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0,
+                u16::MAX / 8
+            ),
+            Err(Error::<Test>::DelegateTxRateLimitExceeded.into())
+        );
+
+        step_block(1);
+        assert_eq!(blocks_until_allowed(), 0);
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 8
+        ));
+    });
+}
+
 #[test]
 fn test_get_total_delegated_stake_after_unstaking() {
     new_test_ext(1).execute_with(|| {
@@ -2796,3 +2901,874 @@ fn test_mining_emission_drain_validator_valiminer_miner() {
         assert_eq!(miner_emission, total_emission / 4);
     });
 }
+
+/************************************************************
+    staking::move_stake() and staking::rebalance_stake() tests
+************************************************************/
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_move_stake_ok --exact --nocapture
+#[test]
+fn test_move_stake_ok() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let from_hotkey = U256::from(2);
+        let to_hotkey = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, from_hotkey, coldkey, 0);
+        register_ok_neuron(netuid, to_hotkey, coldkey, 100);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &from_hotkey, 1000);
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+
+        assert_ok!(SubtensorModule::move_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            from_hotkey,
+            to_hotkey,
+            400
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &from_hotkey),
+            600
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &to_hotkey),
+            400
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_coldkey(&coldkey), 1000);
+        assert_eq!(SubtensorModule::get_total_stake(), 1000);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before
+        );
+        assert_eq!(
+            StakeDeltaSinceLastEmissionDrain::<Test>::get(from_hotkey, coldkey),
+            -400
+        );
+        assert_eq!(
+            StakeDeltaSinceLastEmissionDrain::<Test>::get(to_hotkey, coldkey),
+            400
+        );
+        System::assert_last_event(Event::StakeMoved(coldkey, from_hotkey, to_hotkey, 400).into());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_move_stake_errors --exact --nocapture
+#[test]
+fn test_move_stake_errors() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let from_hotkey = U256::from(2);
+        let to_hotkey = U256::from(3);
+        let other_hotkey = U256::from(4);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, from_hotkey, coldkey, 0);
+        register_ok_neuron(netuid, to_hotkey, coldkey, 100);
+        register_ok_neuron(netuid, other_hotkey, U256::from(5), 200);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &from_hotkey, 1000);
+
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                from_hotkey,
+                from_hotkey,
+                100
+            ),
+            Error::<Test>::CannotMoveStakeToSameHotkey
+        );
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                from_hotkey,
+                to_hotkey,
+                1001
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                from_hotkey,
+                other_hotkey,
+                100
+            ),
+            Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // Each move counts once against the rate limit of both hotkeys.
+        SubtensorModule::set_target_stakes_per_interval(1);
+        assert_ok!(SubtensorModule::move_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            from_hotkey,
+            to_hotkey,
+            100
+        ));
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                from_hotkey,
+                to_hotkey,
+                100
+            ),
+            Error::<Test>::UnstakeRateLimitExceeded
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_rebalance_stake_ok --exact --nocapture
+#[test]
+fn test_rebalance_stake_ok() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey1 = U256::from(2);
+        let hotkey2 = U256::from(3);
+        let hotkey3 = U256::from(4);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey1, coldkey, 0);
+        register_ok_neuron(netuid, hotkey2, coldkey, 100);
+        register_ok_neuron(netuid, hotkey3, coldkey, 200);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey1, 700);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey3, 301);
+
+        // Split the stake 50/50 over hotkey1 and hotkey2, emptying hotkey3.
+        SubtensorModule::set_target_stakes_per_interval(1);
+        assert_ok!(SubtensorModule::rebalance_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            vec![(hotkey1, u16::MAX / 2 + 1), (hotkey2, u16::MAX / 2)]
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey1),
+            501
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey2),
+            500
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey3),
+            0
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_coldkey(&coldkey), 1001);
+        assert_eq!(SubtensorModule::get_total_stake(), 1001);
+        System::assert_last_event(
+            Event::StakeRebalanced(
+                coldkey,
+                vec![(hotkey1, 700, 501), (hotkey3, 301, 0), (hotkey2, 0, 500)],
+            )
+            .into(),
+        );
+
+        // The rebalance counted as a single operation on each changed hotkey.
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey1,
+                hotkey2,
+                1
+            ),
+            Error::<Test>::UnstakeRateLimitExceeded
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_rebalance_stake_invalid_proportions --exact --nocapture
+#[test]
+fn test_rebalance_stake_invalid_proportions() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey1 = U256::from(2);
+        let hotkey2 = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey1, coldkey, 0);
+        register_ok_neuron(netuid, hotkey2, coldkey, 100);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey1, 1000);
+
+        for targets in [
+            vec![],
+            vec![(hotkey1, u16::MAX / 2), (hotkey2, u16::MAX / 2)],
+            vec![(hotkey1, u16::MAX / 2 + 1), (hotkey1, u16::MAX / 2)],
+        ] {
+            assert_noop!(
+                SubtensorModule::rebalance_stake(
+                    <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                    targets
+                ),
+                Error::<Test>::InvalidStakeProportions
+            );
+        }
+
+        // The targets are bounded.
+        let targets: Vec<(U256, u16)> = (0..=MAX_REBALANCE_HOTKEYS as u64)
+            .map(|i| (U256::from(100 + i), 1))
+            .collect();
+        assert_noop!(
+            SubtensorModule::rebalance_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                targets
+            ),
+            Error::<Test>::TooManyRebalanceHotkeys
+        );
+
+        // The call is paid.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::rebalance_stake {
+            targets: vec![(hotkey1, u16::MAX)],
+        });
+        assert_eq!(call.get_dispatch_info().pays_fee, Pays::Yes);
+    });
+}
+
+/************************************************************
+    staking::unbonding tests
+************************************************************/
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_remove_stake_unbonding_queue --exact --nocapture
+#[test]
+fn test_remove_stake_unbonding_queue() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::set_unbonding_period(netuid, 10);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            400
+        ));
+
+        // The stake leaves the hotkey but is not credited yet.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            600
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), 400);
+        assert_eq!(
+            UnbondingQueue::<Test>::get(coldkey),
+            vec![UnbondingEntry {
+                hotkey,
+                amount: 400,
+                release_block: 11,
+            }]
+        );
+        assert_eq!(UnbondingReleases::<Test>::get(11), vec![coldkey]);
+
+        // Nothing is released before the release block.
+        step_block(9);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before
+        );
+
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before + 400
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+        assert!(!UnbondingQueue::<Test>::contains_key(coldkey));
+        assert!(!UnbondingReleases::<Test>::contains_key(11));
+        System::assert_last_event(Event::UnbondedStakeReleased(coldkey, hotkey, 400).into());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_remove_stake_without_unbonding_period --exact --nocapture
+#[test]
+fn test_remove_stake_without_unbonding_period() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            400
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before + 400
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+        assert!(!UnbondingQueue::<Test>::contains_key(coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_cancel_unbond_ok --exact --nocapture
+#[test]
+fn test_cancel_unbond_ok() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, other_hotkey, coldkey, 100);
+        SubtensorModule::set_unbonding_period(netuid, 10);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &other_hotkey, 1000);
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            300
+        ));
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            other_hotkey,
+            200
+        ));
+        assert_eq!(TotalUnbonding::<Test>::get(), 500);
+
+        assert_noop!(
+            SubtensorModule::cancel_unbond(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(9)),
+                hotkey
+            ),
+            Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        assert_ok!(SubtensorModule::cancel_unbond(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey
+        ));
+        System::assert_last_event(Event::UnbondCancelled(coldkey, hotkey, 300).into());
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            1000
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), 200);
+
+        // Nothing is left to cancel on the hotkey.
+        assert_noop!(
+            SubtensorModule::cancel_unbond(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey
+            ),
+            Error::<Test>::NoUnbondingStake
+        );
+
+        // Only the stake still unbonding is released.
+        step_block(10);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before + 200
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+        assert!(!UnbondingQueue::<Test>::contains_key(coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_remove_stake_too_many_unbonding_entries --exact --nocapture
+#[test]
+fn test_remove_stake_too_many_unbonding_entries() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, other_hotkey, coldkey, 100);
+        SubtensorModule::set_unbonding_period(netuid, 10);
+        SubtensorModule::set_target_stakes_per_interval(u64::MAX);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &other_hotkey, 1000);
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            other_hotkey,
+            1
+        ));
+        for _ in 1..MAX_UNBONDING_ENTRIES {
+            assert_ok!(SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                1
+            ));
+        }
+        assert_eq!(
+            UnbondingQueue::<Test>::get(coldkey).len(),
+            MAX_UNBONDING_ENTRIES
+        );
+
+        // Once the queue is full, stake joins the last entry of its own hotkey.
+        step_block(1);
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            other_hotkey,
+            1
+        ));
+        let queue = UnbondingQueue::<Test>::get(coldkey);
+        assert_eq!(queue.len(), MAX_UNBONDING_ENTRIES);
+        let other_entry = queue.first().unwrap();
+        assert_eq!(other_entry.hotkey, other_hotkey);
+        assert_eq!(other_entry.amount, 2);
+        assert_eq!(
+            other_entry.release_block,
+            SubtensorModule::get_current_block_as_u64() + 10
+        );
+        assert_eq!(
+            SubtensorModule::get_unbonding_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            (MAX_UNBONDING_ENTRIES - 1) as u64
+        );
+
+        // Stake of a hotkey without an entry cannot enter the full queue.
+        let new_hotkey = U256::from(4);
+        register_ok_neuron(netuid, new_hotkey, coldkey, 200);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &new_hotkey, 1000);
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                new_hotkey,
+                1
+            ),
+            Error::<Test>::TooManyUnbondingEntries
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_move_stake_keeps_unbonding_period --exact --nocapture
+#[test]
+fn test_move_stake_keeps_unbonding_period() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let bonded_hotkey = U256::from(2);
+        let free_hotkey = U256::from(3);
+        add_network(1, 13, 0);
+        add_network(2, 13, 0);
+        register_ok_neuron(1, bonded_hotkey, coldkey, 0);
+        register_ok_neuron(2, free_hotkey, coldkey, 100);
+        SubtensorModule::set_unbonding_period(1, 10);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &bonded_hotkey, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &free_hotkey, 1000);
+
+        // Stake cannot leave the unbonding period by moving to a hotkey without one.
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                bonded_hotkey,
+                free_hotkey,
+                500
+            ),
+            Error::<Test>::UnbondingPeriodTooShort
+        );
+        assert_noop!(
+            SubtensorModule::rebalance_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                vec![(free_hotkey, u16::MAX)]
+            ),
+            Error::<Test>::UnbondingPeriodTooShort
+        );
+
+        // Stake can move to a hotkey with a longer unbonding period.
+        assert_ok!(SubtensorModule::move_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            free_hotkey,
+            bonded_hotkey,
+            500
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &bonded_hotkey),
+            1500
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test staking -- test_release_unbonded_stake_per_block_cap --exact --nocapture
+#[test]
+fn test_release_unbonded_stake_per_block_cap() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkeys: Vec<U256> = (0..=MAX_UNBONDING_RELEASES_PER_BLOCK as u64)
+            .map(|i| U256::from(100 + i))
+            .collect();
+        for coldkey in coldkeys.iter() {
+            assert_ok!(SubtensorModule::queue_unbonding_stake(
+                coldkey, &hotkey, 100, 10
+            ));
+        }
+        assert_eq!(UnbondingReleases::<Test>::get(11).len(), coldkeys.len());
+
+        // The coldkeys above the cap are released in the next block.
+        let last_coldkey = *coldkeys.last().unwrap();
+        let weight = SubtensorModule::release_unbonded_stake(11);
+        assert!(weight.ref_time() > 0);
+        assert_eq!(UnbondingReleases::<Test>::get(12), vec![last_coldkey]);
+        assert_eq!(TotalUnbonding::<Test>::get(), 100);
+
+        SubtensorModule::release_unbonded_stake(12);
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&last_coldkey), 100);
+    });
+}
+
+/************************************************************
+    staking::set_reward_destination() tests
+************************************************************/
+
+#[test]
+fn test_set_reward_destination() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        let foreign_hotkey = U256::from(4);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, other_hotkey, coldkey, 100);
+        register_ok_neuron(netuid, foreign_hotkey, U256::from(5), 200);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+
+        assert_noop!(
+            SubtensorModule::set_reward_destination(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                U256::from(99),
+                RewardDestination::Free
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        assert_noop!(
+            SubtensorModule::set_reward_destination(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                RewardDestination::Hotkey(hotkey)
+            ),
+            Error::<Test>::InvalidRewardDestination
+        );
+        assert_noop!(
+            SubtensorModule::set_reward_destination(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                RewardDestination::Hotkey(foreign_hotkey)
+            ),
+            Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        assert_ok!(SubtensorModule::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            RewardDestination::Hotkey(other_hotkey)
+        ));
+        assert_eq!(
+            SubtensorModule::get_reward_destination(&coldkey, &hotkey),
+            RewardDestination::Hotkey(other_hotkey)
+        );
+        System::assert_last_event(
+            Event::RewardDestinationSet(coldkey, hotkey, RewardDestination::Hotkey(other_hotkey))
+                .into(),
+        );
+        let stake_info = SubtensorModule::get_stake_info_for_coldkey(coldkey.encode());
+        assert_eq!(
+            stake_info
+                .iter()
+                .find(|info| info.hotkey == hotkey)
+                .map(|info| info.reward_destination.clone()),
+            Some(RewardDestination::Hotkey(other_hotkey))
+        );
+
+        // Restaking is the default and clears the setting.
+        assert_ok!(SubtensorModule::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            RewardDestination::Restake
+        ));
+        assert!(!RewardDestinations::<Test>::contains_key(coldkey, hotkey));
+    });
+}
+
+/************************************************************
+    staking::slash_hotkey() tests
+************************************************************/
+
+#[test]
+fn test_slash_hotkey_burn_owner_stake() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1000);
+        TotalIssuance::<Test>::put(2000);
+
+        assert_noop!(
+            SubtensorModule::slash_hotkey(
+                RuntimeOrigin::none(),
+                hotkey,
+                u16::MAX / 2,
+                false,
+                SlashDestination::Burn
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::slash_hotkey(
+                RuntimeOrigin::root(),
+                U256::from(99),
+                u16::MAX / 2,
+                false,
+                SlashDestination::Burn
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        assert_noop!(
+            SubtensorModule::slash_hotkey(
+                RuntimeOrigin::root(),
+                hotkey,
+                0,
+                false,
+                SlashDestination::Burn
+            ),
+            Error::<Test>::InvalidSlashProportion
+        );
+
+        // Only the owner's stake is slashed.
+        assert_ok!(SubtensorModule::slash_hotkey(
+            RuntimeOrigin::root(),
+            hotkey,
+            u16::MAX / 2,
+            false,
+            SlashDestination::Burn
+        ));
+        let slashed = SubtensorModule::get_slash_amount(1000, u16::MAX / 2);
+        assert_eq!(slashed, 499);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&owner, &hotkey),
+            1000 - slashed
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            1000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey),
+            2000 - slashed
+        );
+        assert_eq!(SubtensorModule::get_total_stake(), 2000 - slashed);
+        assert_eq!(TotalIssuance::<Test>::get(), 2000 - slashed);
+        System::assert_last_event(
+            Event::Slashed(hotkey, vec![(owner, slashed)], SlashDestination::Burn).into(),
+        );
+    });
+}
+
+#[test]
+fn test_slash_hotkey_to_account_with_nominators() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+        let treasury = U256::from(4);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 2000);
+        TotalIssuance::<Test>::put(3000);
+
+        // The council origin of the mock is any signed origin.
+        assert_ok!(SubtensorModule::slash_hotkey(
+            RuntimeOrigin::signed(U256::from(5)),
+            hotkey,
+            u16::MAX,
+            true,
+            SlashDestination::Account(treasury)
+        ));
+
+        // The whole stake is slashed and paid to the treasury, the issuance is unchanged.
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 0);
+        assert_eq!(SubtensorModule::get_total_stake_for_coldkey(&nominator), 0);
+        assert_eq!(SubtensorModule::get_total_stake(), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&treasury), 3000);
+        assert_eq!(TotalIssuance::<Test>::get(), 3000);
+    });
+}
+
+#[test]
+fn test_slash_hotkey_during_unbonding() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        SubtensorModule::set_unbonding_period(netuid, 10);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            SubtensorModule::get_min_delegate_take()
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1000);
+        TotalIssuance::<Test>::put(1000);
+
+        // The nominator removes stake before the slash lands.
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            600
+        ));
+        assert_eq!(TotalUnbonding::<Test>::get(), 600);
+        assert!(UnbondingColdkeys::<Test>::get(hotkey, nominator));
+        let balance_before = SubtensorModule::get_coldkey_balance(&nominator);
+
+        assert_ok!(SubtensorModule::slash_hotkey(
+            RuntimeOrigin::root(),
+            hotkey,
+            u16::MAX / 2,
+            true,
+            SlashDestination::Burn
+        ));
+
+        // The unbonding stake and the remaining stake are slashed alike.
+        let unbonding_slashed = SubtensorModule::get_slash_amount(600, u16::MAX / 2);
+        let stake_slashed = SubtensorModule::get_slash_amount(400, u16::MAX / 2);
+        assert_eq!(
+            UnbondingQueue::<Test>::get(nominator),
+            vec![UnbondingEntry {
+                hotkey,
+                amount: 600 - unbonding_slashed,
+                release_block: 11,
+            }]
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), 600 - unbonding_slashed);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            400 - stake_slashed
+        );
+        assert_eq!(
+            TotalIssuance::<Test>::get(),
+            1000 - unbonding_slashed - stake_slashed
+        );
+        System::assert_last_event(
+            Event::Slashed(
+                hotkey,
+                vec![(nominator, unbonding_slashed + stake_slashed)],
+                SlashDestination::Burn,
+            )
+            .into(),
+        );
+
+        // The slash is part of the stake delta since the last emission drain.
+        assert_eq!(
+            StakeDeltaSinceLastEmissionDrain::<Test>::get(hotkey, nominator),
+            -(600 + stake_slashed as i128)
+        );
+
+        // Only the slashed unbonding stake is released.
+        step_block(10);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&nominator),
+            balance_before + 600 - unbonding_slashed
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+        assert!(!UnbondingColdkeys::<Test>::contains_key(hotkey, nominator));
+    });
+}
+
+#[test]
+fn test_slash_hotkey_too_many_stakers() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let hotkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1000);
+        for i in 0..MAX_SLASHED_STAKERS as u64 {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(100 + i),
+                &hotkey,
+                1000,
+            );
+        }
+
+        // The nominators cannot all be slashed within the weight of the call.
+        assert_noop!(
+            SubtensorModule::slash_hotkey(
+                RuntimeOrigin::root(),
+                hotkey,
+                u16::MAX / 2,
+                true,
+                SlashDestination::Burn
+            ),
+            Error::<Test>::TooManySlashedStakers
+        );
+
+        // The owner alone can still be slashed, at the weight of a single stake.
+        let post_info = SubtensorModule::slash_hotkey(
+            RuntimeOrigin::root(),
+            hotkey,
+            u16::MAX / 2,
+            false,
+            SlashDestination::Burn,
+        )
+        .unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(SubtensorModule::get_slash_hotkey_weight(0, 1))
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&owner, &hotkey),
+            1000 - SubtensorModule::get_slash_amount(1000, u16::MAX / 2)
+        );
+    });
+}
+
+#[test]
+fn test_slash_hotkey_clears_small_nominations() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        SubtensorModule::set_nominator_min_required_stake(500);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 800);
+        let balance_before = SubtensorModule::get_coldkey_balance(&nominator);
+
+        assert_ok!(SubtensorModule::slash_hotkey(
+            RuntimeOrigin::root(),
+            hotkey,
+            u16::MAX / 2,
+            true,
+            SlashDestination::Burn
+        ));
+
+        // The nomination left below the minimum is cleared, the owner's stake is kept.
+        let slashed = SubtensorModule::get_slash_amount(800, u16::MAX / 2);
+        assert!(!Stake::<Test>::contains_key(hotkey, nominator));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&nominator),
+            balance_before + 800 - slashed
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&owner, &hotkey),
+            1000 - SubtensorModule::get_slash_amount(1000, u16::MAX / 2)
+        );
+    });
+}
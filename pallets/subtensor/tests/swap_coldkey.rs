@@ -1,5 +1,6 @@
 #![allow(unused, clippy::indexing_slicing, clippy::panic, clippy::unwrap_used)]
 use codec::Encode;
+use frame_support::dispatch::DispatchInfo;
 use frame_support::weights::Weight;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::{Config, RawOrigin};
@@ -13,6 +14,8 @@ use pallet_subtensor::*;
 use pallet_subtensor::{Call, ColdkeySwapScheduleDuration, Error};
 use sp_core::H256;
 use sp_core::U256;
+use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
+use sp_runtime::transaction_validity::InvalidTransaction;
 use sp_runtime::DispatchError;
 
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_total_hotkey_coldkey_stakes_this_interval --exact --nocapture
@@ -113,6 +116,70 @@ fn test_swap_total_coldkey_stake() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_unbonding_queue --exact --nocapture
+#[test]
+fn test_swap_unbonding_queue() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let entry = UnbondingEntry {
+            hotkey,
+            amount: 100,
+            release_block: 10,
+        };
+
+        UnbondingQueue::<Test>::insert(old_coldkey, vec![entry.clone()]);
+        UnbondingReleases::<Test>::insert(10, vec![old_coldkey]);
+        UnbondingColdkeys::<Test>::insert(hotkey, old_coldkey, true);
+
+        let mut weight = Weight::zero();
+        assert_ok!(SubtensorModule::perform_swap_coldkey(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        assert!(!UnbondingQueue::<Test>::contains_key(old_coldkey));
+        assert_eq!(UnbondingQueue::<Test>::get(new_coldkey), vec![entry]);
+        assert_eq!(UnbondingReleases::<Test>::get(10), vec![new_coldkey]);
+        assert!(!UnbondingColdkeys::<Test>::contains_key(
+            hotkey,
+            old_coldkey
+        ));
+        assert!(UnbondingColdkeys::<Test>::get(hotkey, new_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_nominator_rewards --exact --nocapture
+#[test]
+fn test_swap_nominator_rewards() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+
+        SubtensorModule::record_nominator_reward(&old_coldkey, &hotkey, 0, 10, 0, 1);
+        SubtensorModule::record_nominator_reward(&new_coldkey, &hotkey, 0, 20, 0, 2);
+        SubtensorModule::record_nominator_reward(&old_coldkey, &hotkey, 0, 30, 0, 3);
+
+        let mut weight = Weight::zero();
+        assert_ok!(SubtensorModule::perform_swap_coldkey(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        // The ledgers are merged in block order.
+        assert!(!NominatorRewards::<Test>::contains_key(old_coldkey));
+        let rewards: Vec<u64> = NominatorRewards::<Test>::get(new_coldkey)
+            .iter()
+            .map(|entry| entry.reward)
+            .collect();
+        assert_eq!(rewards, vec![10, 20, 30]);
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_staking_hotkeys --exact --nocapture
 #[test]
 fn test_swap_staking_hotkeys() {
@@ -538,6 +605,9 @@ fn test_do_swap_coldkey_success() {
             discord: vec![],
             description: vec![],
             additional: vec![],
+            github_repo: vec![],
+            contact: vec![],
+            logo_hash: None,
         };
 
         Identities::<Test>::insert(old_coldkey, identity.clone());
@@ -1494,7 +1564,17 @@ fn test_schedule_swap_coldkey_with_pending_swap() {
             new_coldkey1
         ));
 
-        // Attempt to schedule another swap before the first one executes
+        // Scheduling another swap before the first one executes puts the coldkey in arbitration
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey2
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapDisputed(old_coldkey, vec![new_coldkey1, new_coldkey2]).into(),
+        );
+        assert!(SubtensorModule::coldkey_in_arbitration(&old_coldkey));
+
+        // Scheduling a destination again is rejected
         assert_noop!(
             SubtensorModule::schedule_swap_coldkey(
                 <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
@@ -1505,6 +1585,331 @@ fn test_schedule_swap_coldkey_with_pending_swap() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_coldkey_in_arbitration_is_frozen --exact --nocapture
+#[test]
+fn test_coldkey_in_arbitration_is_frozen() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey1 = U256::from(2);
+        let new_coldkey2 = U256::from(3);
+        let hotkey = U256::from(4);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            hotkey,
+            100
+        ));
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey1
+        ));
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey2
+        ));
+
+        // Stake removal, every other call moving stake away and transfers are rejected
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorSignedExtension::<Test>::new();
+        let remove_stake: RuntimeCall = Call::remove_stake {
+            hotkey,
+            amount_unstaked: 100,
+        }
+        .into();
+        assert_err!(
+            extension.validate(&old_coldkey, &remove_stake, &info, 10),
+            InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+        );
+        let value_moving_calls: Vec<RuntimeCall> = vec![
+            Call::move_stake {
+                from_hotkey: hotkey,
+                to_hotkey: U256::from(5),
+                amount: 100,
+            }
+            .into(),
+            Call::rebalance_stake {
+                targets: vec![(U256::from(5), u16::MAX)],
+            }
+            .into(),
+            Call::cancel_unbond { hotkey }.into(),
+            Call::set_reward_destination {
+                hotkey,
+                destination: RewardDestination::Free,
+            }
+            .into(),
+            Call::swap_hotkey {
+                hotkey,
+                new_hotkey: U256::from(5),
+            }
+            .into(),
+        ];
+        for value_moving_call in value_moving_calls.iter() {
+            assert_err!(
+                extension.validate(&old_coldkey, value_moving_call, &info, 10),
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+            );
+        }
+
+        // The pre dispatch check reports the rejection of the signed extension
+        let failures = SubtensorModule::pre_dispatch_check(
+            &Call::remove_stake {
+                hotkey,
+                amount_unstaked: 100,
+            }
+            .into(),
+            old_coldkey.encode(),
+        )
+        .expect("Signer is valid");
+        assert_eq!(failures[0].error, b"ColdkeyIsInArbitration".to_vec());
+        let transfer = RuntimeCall::Balances(BalanceCall::transfer_allow_death {
+            dest: new_coldkey1,
+            value: 1_000,
+        });
+        assert_err!(
+            extension.validate(&old_coldkey, &transfer, &info, 10),
+            InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+        );
+
+        // The calls of other pallets are checked as well
+        let failures = SubtensorModule::pre_dispatch_check(&transfer, old_coldkey.encode())
+            .expect("Signer is valid");
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].error, b"ColdkeyIsInArbitration".to_vec());
+
+        // The scheduled swap does not execute
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert_eq!(Stake::<Test>::get(hotkey, old_coldkey), 100);
+        assert!(SubtensorModule::coldkey_in_arbitration(&old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_coldkey_in_arbitration_is_frozen_in_batches --exact --nocapture
+#[test]
+fn test_coldkey_in_arbitration_is_frozen_in_batches() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey1 = U256::from(2);
+        let new_coldkey2 = U256::from(3);
+        let hotkey = U256::from(4);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            hotkey,
+            100
+        ));
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey1
+        ));
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey2
+        ));
+
+        // The stake removal is rejected when it dispatches, directly or batched
+        let remove_stake: RuntimeCall = Call::remove_stake {
+            hotkey,
+            amount_unstaked: 100,
+        }
+        .into();
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                hotkey,
+                100
+            ),
+            Error::<Test>::ColdkeyIsInArbitration
+        );
+        assert_eq!(
+            Utility::batch_all(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                vec![remove_stake.clone()]
+            )
+            .map_err(|e| e.error),
+            Err(Error::<Test>::ColdkeyIsInArbitration.into())
+        );
+        assert_ok!(Utility::batch(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            vec![remove_stake.clone()]
+        ));
+        assert_eq!(Stake::<Test>::get(hotkey, old_coldkey), 100);
+
+        // The signed extension rejects batched stake removals and transfers
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorSignedExtension::<Test>::new();
+        let transfer = RuntimeCall::Balances(BalanceCall::transfer_allow_death {
+            dest: new_coldkey1,
+            value: 1_000,
+        });
+        for batched_call in [remove_stake, transfer] {
+            let batch = RuntimeCall::Utility(pallet_utility::Call::batch {
+                calls: vec![batched_call],
+            });
+            assert_err!(
+                extension.validate(&old_coldkey, &batch, &info, 10),
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+            );
+            assert_err!(
+                SubtensorSignedExtension::<Test>::new().pre_dispatch(
+                    &old_coldkey,
+                    &batch,
+                    &info,
+                    10
+                ),
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+            );
+        }
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_resolve_coldkey_swap_arbitration --exact --nocapture
+#[test]
+fn test_resolve_coldkey_swap_arbitration() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey1 = U256::from(2);
+        let new_coldkey2 = U256::from(3);
+        let hotkey = U256::from(4);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_noop!(
+            SubtensorModule::resolve_coldkey_swap_arbitration(
+                RawOrigin::Root.into(),
+                old_coldkey,
+                Some(new_coldkey1)
+            ),
+            Error::<Test>::ColdkeyNotInArbitration
+        );
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey1
+        ));
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey2
+        ));
+
+        // Governance can only pick one of the scheduled destinations
+        assert_noop!(
+            SubtensorModule::resolve_coldkey_swap_arbitration(
+                RawOrigin::Root.into(),
+                old_coldkey,
+                Some(U256::from(5))
+            ),
+            Error::<Test>::InvalidColdkeySwapDestination
+        );
+
+        assert_ok!(SubtensorModule::resolve_coldkey_swap_arbitration(
+            RawOrigin::Root.into(),
+            old_coldkey,
+            Some(new_coldkey2)
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapArbitrationResolved(old_coldkey, Some(new_coldkey2)).into(),
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey2);
+        assert!(!SubtensorModule::coldkey_in_arbitration(&old_coldkey));
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+
+        // The scheduler task of the swap is cancelled and no longer executes
+        assert!(!ColdkeySwapTask::<Test>::contains_key(old_coldkey));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey2);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_swap_coldkey_root_without_schedule --exact --nocapture
+#[test]
+fn test_swap_coldkey_root_without_schedule() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+
+        // Root can swap a coldkey which never scheduled a swap
+        assert_ok!(SubtensorModule::swap_coldkey(
+            RawOrigin::Root.into(),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert_eq!(OwnedHotkeys::<Test>::get(new_coldkey), vec![hotkey]);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_resolve_coldkey_swap_arbitration_cancel --exact --nocapture
+#[test]
+fn test_resolve_coldkey_swap_arbitration_cancel() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey1 = U256::from(2);
+        let new_coldkey2 = U256::from(3);
+        let hotkey = U256::from(4);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey1
+        ));
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey2
+        ));
+
+        assert_ok!(SubtensorModule::resolve_coldkey_swap_arbitration(
+            RawOrigin::Root.into(),
+            old_coldkey,
+            None
+        ));
+        System::assert_last_event(Event::ColdkeySwapArbitrationResolved(old_coldkey, None).into());
+        assert!(!SubtensorModule::coldkey_in_arbitration(&old_coldkey));
+        assert!(ColdkeySwapDestinations::<Test>::get(old_coldkey).is_empty());
+
+        // The scheduled swap does not execute and the coldkey keeps its hotkey
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+    });
+}
+
 #[test]
 fn test_coldkey_swap_delegate_identity_updated() {
     new_test_ext(1).execute_with(|| {
@@ -1534,9 +1939,21 @@ fn test_coldkey_swap_delegate_identity_updated() {
             discord: vec![],
             description: vec![],
             additional: vec![],
+            github_repo: b"opentensor".to_vec(),
+            contact: vec![],
+            logo_hash: None,
+        };
+        let attestation = IdentityAttestation {
+            registrar: U256::from(3),
+            block: 1,
         };
 
         Identities::<Test>::insert(old_coldkey, identity.clone());
+        IdentityAttestations::<Test>::insert(
+            old_coldkey,
+            IdentityField::GithubRepo,
+            attestation.clone(),
+        );
 
         assert!(Identities::<Test>::get(old_coldkey).is_some());
         assert!(Identities::<Test>::get(new_coldkey).is_none());
@@ -1549,6 +1966,14 @@ fn test_coldkey_swap_delegate_identity_updated() {
             Identities::<Test>::get(new_coldkey).expect("Expected an Identity"),
             identity
         );
+        // The attestations follow the identity.
+        assert!(
+            IdentityAttestations::<Test>::get(old_coldkey, IdentityField::GithubRepo).is_none()
+        );
+        assert_eq!(
+            IdentityAttestations::<Test>::get(new_coldkey, IdentityField::GithubRepo),
+            Some(attestation)
+        );
     });
 }
 
@@ -1613,6 +2038,9 @@ fn test_coldkey_swap_no_identity_no_changes_newcoldkey_exists() {
             discord: vec![],
             description: vec![],
             additional: vec![],
+            github_repo: vec![],
+            contact: vec![],
+            logo_hash: None,
         };
 
         Identities::<Test>::insert(new_coldkey, identity.clone());
@@ -1666,3 +2094,231 @@ fn test_coldkey_swap_stake_delta() {
         );
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_set_guardians --exact --nocapture
+#[test]
+fn test_set_guardians() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let guardians = vec![U256::from(10), U256::from(11), U256::from(12)];
+
+        // Zero threshold, threshold above the number of guardians, duplicates and self
+        for (invalid_guardians, threshold) in [
+            (guardians.clone(), 0),
+            (guardians.clone(), 4),
+            (vec![U256::from(10), U256::from(10)], 1),
+            (vec![coldkey, U256::from(10)], 1),
+        ] {
+            assert_noop!(
+                SubtensorModule::set_guardians(
+                    <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                    invalid_guardians,
+                    threshold
+                ),
+                Error::<Test>::InvalidGuardians
+            );
+        }
+
+        assert_ok!(SubtensorModule::set_guardians(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            guardians.clone(),
+            2
+        ));
+        System::assert_last_event(Event::GuardiansSet(coldkey, guardians.clone(), 2).into());
+        assert_eq!(
+            ColdkeyGuardians::<Test>::get(coldkey),
+            Some(GuardianSet {
+                guardians: guardians.clone(),
+                threshold: 2
+            })
+        );
+
+        // The guardians cannot change under a pending recovery
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(10)),
+            coldkey,
+            U256::from(2)
+        ));
+        assert_noop!(
+            SubtensorModule::set_guardians(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                vec![],
+                0
+            ),
+            Error::<Test>::ColdkeyRecoveryInProgress
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_guardian_recovery --exact --nocapture
+#[test]
+fn test_guardian_recovery() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let guardians = vec![U256::from(10), U256::from(11), U256::from(12)];
+        let netuid = 1u16;
+        let balance = 1_000_000_000_000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, balance);
+        assert_ok!(SubtensorModule::set_guardians(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            guardians.clone(),
+            2
+        ));
+
+        // Only guardians can approve, once per new coldkey
+        assert_noop!(
+            SubtensorModule::approve_coldkey_recovery(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(13)),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::NotGuardian
+        );
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            <<Test as Config>::RuntimeOrigin>::signed(guardians[0]),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert_noop!(
+            SubtensorModule::approve_coldkey_recovery(
+                <<Test as Config>::RuntimeOrigin>::signed(guardians[0]),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::ColdkeyRecoveryAlreadyApproved
+        );
+        assert_noop!(
+            SubtensorModule::finalize_coldkey_recovery(
+                <<Test as Config>::RuntimeOrigin>::signed(guardians[0]),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::ColdkeyRecoveryNotReady
+        );
+
+        // The threshold starts the delay
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            <<Test as Config>::RuntimeOrigin>::signed(guardians[1]),
+            old_coldkey,
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        System::assert_last_event(
+            Event::ColdkeyRecoveryScheduled(old_coldkey, new_coldkey, execution_block).into(),
+        );
+        assert_noop!(
+            SubtensorModule::finalize_coldkey_recovery(
+                <<Test as Config>::RuntimeOrigin>::signed(guardians[0]),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::ColdkeyRecoveryNotReady
+        );
+
+        System::set_block_number(execution_block);
+        assert_ok!(SubtensorModule::finalize_coldkey_recovery(
+            <<Test as Config>::RuntimeOrigin>::signed(guardians[0]),
+            old_coldkey,
+            new_coldkey
+        ));
+        System::assert_last_event(Event::ColdkeyRecovered(old_coldkey, new_coldkey).into());
+
+        // The coldkey is swapped without burning the key swap cost, and keeps its guardians
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&old_coldkey), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), balance);
+        assert!(ColdkeyGuardians::<Test>::get(old_coldkey).is_none());
+        assert!(ColdkeyGuardians::<Test>::get(new_coldkey).is_some());
+        assert!(ColdkeyRecoveries::<Test>::get(old_coldkey, new_coldkey).is_none());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_cancel_coldkey_recovery --exact --nocapture
+#[test]
+fn test_cancel_coldkey_recovery() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let guardian = U256::from(10);
+
+        assert_ok!(SubtensorModule::set_guardians(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            vec![guardian],
+            1
+        ));
+        assert_noop!(
+            SubtensorModule::cancel_coldkey_recovery(<<Test as Config>::RuntimeOrigin>::signed(
+                coldkey
+            )),
+            Error::<Test>::ColdkeyRecoveryNotFound
+        );
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            <<Test as Config>::RuntimeOrigin>::signed(guardian),
+            coldkey,
+            new_coldkey
+        ));
+
+        // The coldkey cancels the recovery during the delay
+        assert_ok!(SubtensorModule::cancel_coldkey_recovery(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+        System::assert_last_event(Event::ColdkeyRecoveryCancelled(coldkey).into());
+
+        System::set_block_number(
+            System::block_number() + ColdkeySwapScheduleDuration::<Test>::get(),
+        );
+        assert_noop!(
+            SubtensorModule::finalize_coldkey_recovery(
+                <<Test as Config>::RuntimeOrigin>::signed(guardian),
+                coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::ColdkeyRecoveryNotFound
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_pending_coldkey_recoveries_bounded --exact --nocapture
+#[test]
+fn test_pending_coldkey_recoveries_bounded() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let guardian = U256::from(10);
+
+        assert_ok!(SubtensorModule::set_guardians(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            vec![guardian],
+            1
+        ));
+        for i in 0..MAX_PENDING_COLDKEY_RECOVERIES as u64 {
+            assert_ok!(SubtensorModule::approve_coldkey_recovery(
+                <<Test as Config>::RuntimeOrigin>::signed(guardian),
+                coldkey,
+                U256::from(100 + i)
+            ));
+        }
+
+        // No more recoveries of the coldkey can be opened
+        assert_noop!(
+            SubtensorModule::approve_coldkey_recovery(
+                <<Test as Config>::RuntimeOrigin>::signed(guardian),
+                coldkey,
+                U256::from(1000)
+            ),
+            Error::<Test>::TooManyColdkeyRecoveries
+        );
+
+        // Cancelling removes all of them
+        assert_ok!(SubtensorModule::cancel_coldkey_recovery(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+        assert!(ColdkeyRecoveries::<Test>::iter_prefix(coldkey)
+            .next()
+            .is_none());
+    });
+}
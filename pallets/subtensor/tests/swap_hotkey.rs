@@ -1334,3 +1334,121 @@ fn test_swap_child_hotkey_childkey_maps() {
         );
     })
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_on_subnet --exact --nocapture
+#[test]
+fn test_swap_hotkey_on_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1: u16 = 1;
+        let netuid2: u16 = 2;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let child = U256::from(4);
+        let stake = 1_000;
+
+        add_network(netuid1, 13, 0);
+        add_network(netuid2, 13, 0);
+        register_ok_neuron(netuid1, old_hotkey, coldkey, 0);
+        register_ok_neuron(netuid2, old_hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000_000_000);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            old_hotkey,
+            stake
+        ));
+        ChildKeys::<Test>::insert(old_hotkey, netuid1, vec![(u64::MAX, child)]);
+        ParentKeys::<Test>::insert(child, netuid1, vec![(u64::MAX, old_hotkey)]);
+        ChildkeyTake::<Test>::insert(old_hotkey, netuid1, 100);
+        let uid1 = SubtensorModule::get_uid_for_net_and_hotkey(netuid1, &old_hotkey).unwrap();
+        let uid2 = SubtensorModule::get_uid_for_net_and_hotkey(netuid2, &old_hotkey).unwrap();
+
+        assert_ok!(SubtensorModule::swap_hotkey_on_subnet(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            old_hotkey,
+            new_hotkey,
+            netuid1
+        ));
+        System::assert_last_event(
+            Event::HotkeySwappedOnSubnet(coldkey, old_hotkey, new_hotkey, netuid1).into(),
+        );
+
+        // The registration on the subnet moved to the new hotkey
+        assert_eq!(Owner::<Test>::get(new_hotkey), coldkey);
+        assert_eq!(Uids::<Test>::get(netuid1, new_hotkey), Some(uid1));
+        assert_eq!(Keys::<Test>::get(netuid1, uid1), new_hotkey);
+        assert!(IsNetworkMember::<Test>::get(new_hotkey, netuid1));
+        assert!(!IsNetworkMember::<Test>::get(old_hotkey, netuid1));
+        assert_eq!(
+            ChildKeys::<Test>::get(new_hotkey, netuid1),
+            vec![(u64::MAX, child)]
+        );
+        assert_eq!(
+            ParentKeys::<Test>::get(child, netuid1),
+            vec![(u64::MAX, new_hotkey)]
+        );
+        assert!(ChildKeys::<Test>::get(old_hotkey, netuid1).is_empty());
+        assert_eq!(ChildkeyTake::<Test>::get(new_hotkey, netuid1), 100);
+
+        // The other subnet and the stake are untouched
+        assert_eq!(Uids::<Test>::get(netuid2, old_hotkey), Some(uid2));
+        assert!(!IsNetworkMember::<Test>::get(new_hotkey, netuid2));
+        assert_eq!(Stake::<Test>::get(old_hotkey, coldkey), stake);
+        assert_eq!(TotalHotkeyStake::<Test>::get(old_hotkey), stake);
+        assert_eq!(TotalHotkeyStake::<Test>::get(new_hotkey), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_on_subnet_errors --exact --nocapture
+#[test]
+fn test_swap_hotkey_on_subnet_errors() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1: u16 = 1;
+        let netuid2: u16 = 2;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let other_coldkey = U256::from(4);
+        let other_hotkey = U256::from(5);
+
+        add_network(netuid1, 13, 0);
+        add_network(netuid2, 13, 0);
+        register_ok_neuron(netuid1, old_hotkey, coldkey, 0);
+        register_ok_neuron(netuid1, new_hotkey, coldkey, 0);
+        register_ok_neuron(netuid2, other_hotkey, other_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000_000_000);
+
+        // The old hotkey is not registered on the subnet
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                old_hotkey,
+                U256::from(6),
+                netuid2
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        // The new hotkey is already registered on the subnet
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                old_hotkey,
+                new_hotkey,
+                netuid1
+            ),
+            Error::<Test>::HotKeyAlreadyRegisteredInSubNet
+        );
+
+        // The new hotkey is owned by another coldkey
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                old_hotkey,
+                other_hotkey,
+                netuid1
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+    });
+}
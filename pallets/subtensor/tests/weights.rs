@@ -1,13 +1,20 @@
 #![allow(clippy::indexing_slicing)]
 
 mod mock;
+use codec::Encode;
 use frame_support::{
     assert_err, assert_ok,
     dispatch::{DispatchClass, DispatchInfo, DispatchResult, GetDispatchInfo, Pays},
-    pallet_prelude::{InvalidTransaction, TransactionValidityError},
+    pallet_prelude::{ConstU32, InvalidTransaction, TransactionValidityError},
+    weights::Weight,
+    BoundedVec,
 };
 use mock::*;
-use pallet_subtensor::{Error, Owner};
+use pallet_subtensor::{
+    Error, Owner, TimelockedWeightCommitCount, TimelockedWeightCommits, Weights,
+    WeightsTlockPayload, MAX_TIMELOCKED_COMMITS_PER_SUBNET, MAX_TIMELOCKED_REVEALS_PER_BLOCK,
+    MAX_TIMELOCK_COMMIT_SIZE_BYTES,
+};
 use scale_info::prelude::collections::HashMap;
 use sp_core::{H256, U256};
 use sp_runtime::{
@@ -420,6 +427,64 @@ fn test_set_weights_is_root_error() {
     });
 }
 
+// Test the pre dispatch check reports the reasons set_weights would be rejected.
+#[test]
+fn test_pre_dispatch_check_set_weights() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let unregistered_hotkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(3), 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_weights_version_key(netuid, 10);
+
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::set_weights {
+            netuid,
+            dests: vec![0, 0],
+            weights: vec![1],
+            version_key: 10,
+        });
+        let failures: Vec<Vec<u8>> =
+            SubtensorModule::pre_dispatch_check(&call, unregistered_hotkey.encode())
+                .expect("Signer is valid")
+                .into_iter()
+                .map(|failure| failure.error)
+                .collect();
+        let expected: Vec<Vec<u8>> = ["WeightVecNotEqualSize", "HotKeyNotRegisteredInSubNet"]
+            .iter()
+            .map(|error| error.as_bytes().to_vec())
+            .collect();
+        assert_eq!(failures, expected);
+
+        // A registered hotkey with an outdated version key.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::set_weights {
+            netuid,
+            dests: vec![0],
+            weights: vec![1],
+            version_key: 0,
+        });
+        let failures =
+            SubtensorModule::pre_dispatch_check(&call, hotkey.encode()).expect("Signer is valid");
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].error, b"IncorrectWeightVersionKey".to_vec());
+        assert_eq!(failures[0].blocks_until_allowed, None);
+
+        // A valid call has no failures, an invalid signer is not checked.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::set_weights {
+            netuid,
+            dests: vec![0],
+            weights: vec![1],
+            version_key: 10,
+        });
+        assert_eq!(
+            SubtensorModule::pre_dispatch_check(&call, hotkey.encode()),
+            Some(vec![])
+        );
+        assert_eq!(SubtensorModule::pre_dispatch_check(&call, vec![0; 3]), None);
+    });
+}
+
 // Test ensures that uid has validator permit to set non-self weights.
 #[test]
 fn test_weights_err_no_validator_permit() {
@@ -4188,3 +4253,470 @@ fn test_commit_weights_rate_limit() {
         ));
     });
 }
+
+#[test]
+fn test_commit_weights_max_unrevealed_commits_and_expiry_event() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        System::set_block_number(0);
+        add_network(netuid, 5, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300_000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        SubtensorModule::set_max_unrevealed_commits(netuid, 2);
+
+        let commit_hashes: Vec<H256> = (0..3u64).map(H256::from_low_u64_be).collect();
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hashes[0]
+        ));
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hashes[1]
+        ));
+        assert_err!(
+            SubtensorModule::commit_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                commit_hashes[2]
+            ),
+            Error::<Test>::TooManyUnrevealedCommits
+        );
+
+        // Once the first commits expire they are pruned and reported.
+        step_epochs(2, netuid);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hashes[2]
+        ));
+        for commit_hash in commit_hashes.iter().take(2) {
+            assert!(System::events().iter().any(|record| record.event
+                == SubtensorEvent::WeightCommitExpired(hotkey, netuid, *commit_hash).into()));
+        }
+        assert_eq!(
+            pallet_subtensor::WeightCommits::<Test>::get(netuid, hotkey)
+                .expect("Expected a commit")
+                .len(),
+            1
+        );
+    });
+}
+
+#[test]
+fn test_weight_commits_expire_in_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        System::set_block_number(0);
+        add_network(netuid, 5, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300_000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        let commit_hash: H256 = H256::from_low_u64_be(1);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+
+        // The commit is still revealable after one epoch.
+        step_epochs(1, netuid);
+        assert!(pallet_subtensor::WeightCommits::<Test>::contains_key(
+            netuid, hotkey
+        ));
+
+        // The epoch after the reveal period removes the commit without any call from the hotkey.
+        step_epochs(1, netuid);
+        assert!(!pallet_subtensor::WeightCommits::<Test>::contains_key(
+            netuid, hotkey
+        ));
+        System::assert_has_event(
+            SubtensorEvent::WeightCommitExpired(hotkey, netuid, commit_hash).into(),
+        );
+    });
+}
+
+/***************************
+  pub fn commit_timelocked_weights() tests
+*****************************/
+
+fn setup_timelocked_weights(netuid: u16) {
+    System::set_block_number(0);
+
+    let tempo: u16 = 5;
+    add_network(netuid, tempo, 0);
+
+    register_ok_neuron(netuid, U256::from(3), U256::from(4), 300_000);
+    register_ok_neuron(netuid, U256::from(1), U256::from(2), 100_000);
+    SubtensorModule::set_weights_set_rate_limit(netuid, 5);
+    SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+    SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+    SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+}
+
+fn timelocked_commit(
+    hotkey: U256,
+    uids: Vec<u16>,
+    values: Vec<u16>,
+    reveal_round: u64,
+) -> BoundedVec<u8, ConstU32<MAX_TIMELOCK_COMMIT_SIZE_BYTES>> {
+    let payload = WeightsTlockPayload {
+        hotkey,
+        uids,
+        values,
+        version_key: 0,
+    };
+    MockTimelockKeySource::encrypt(reveal_round, &payload.encode())
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_commit_timelocked_weights_revealed_at_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        setup_timelocked_weights(netuid);
+
+        let commit = timelocked_commit(hotkey, vec![0, 1], vec![10, 10], 5);
+        let commit_hash: H256 = BlakeTwo256::hash_of(&commit);
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit,
+            5
+        ));
+        System::assert_last_event(
+            SubtensorEvent::TimelockedWeightsCommitted(hotkey, netuid, commit_hash, 5).into(),
+        );
+        assert_eq!(
+            TimelockedWeightCommits::<Test>::get((netuid, 1, hotkey)).len(),
+            1
+        );
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 1);
+
+        // The commit is not revealed before the end of the reveal epoch.
+        MockTimelockKeySource::publish_round(5);
+        step_epochs(1, netuid);
+        assert!(Weights::<Test>::get(netuid, 1).is_empty());
+
+        // The chain decrypts and sets the weights without a reveal.
+        step_epochs(1, netuid);
+        assert_eq!(
+            Weights::<Test>::get(netuid, 1),
+            vec![(0, u16::MAX), (1, u16::MAX)]
+        );
+        assert!(!TimelockedWeightCommits::<Test>::contains_key((
+            netuid, 1, hotkey
+        )));
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 0);
+        assert!(System::events().iter().any(|record| record.event
+            == SubtensorEvent::TimelockedWeightsRevealed(hotkey, netuid, commit_hash).into()));
+    });
+}
+
+#[test]
+fn test_commit_timelocked_weights_errors() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        setup_timelocked_weights(netuid);
+        let commit = timelocked_commit(hotkey, vec![0, 1], vec![10, 10], 5);
+
+        // The key of the reveal round must not be published yet.
+        MockTimelockKeySource::publish_round(5);
+        assert_err!(
+            SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                commit.clone(),
+                5
+            ),
+            Error::<Test>::InvalidRevealRound
+        );
+
+        MockTimelockKeySource::disable();
+        assert_err!(
+            SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                commit.clone(),
+                5
+            ),
+            Error::<Test>::TimelockKeySourceUnavailable
+        );
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::commit_timelocked_weights {
+            netuid,
+            commit: commit.clone(),
+            reveal_round: 5,
+        });
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        assert_eq!(
+            pallet_subtensor::SubtensorSignedExtension::<Test>::new()
+                .validate(&hotkey, &call, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(8)
+            ))
+        );
+
+        MockTimelockKeySource::publish_round(0);
+        assert_err!(
+            SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(U256::from(9)),
+                netuid,
+                commit.clone(),
+                5
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        assert_err!(
+            SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                commit,
+                5
+            ),
+            Error::<Test>::CommitRevealDisabled
+        );
+    });
+}
+
+#[test]
+fn test_timelocked_weights_unpublished_commits_are_retried() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        let other_hotkey: U256 = U256::from(3);
+        setup_timelocked_weights(netuid);
+
+        // A commit whose key is not published by the end of the reveal epoch.
+        let unpublished = timelocked_commit(hotkey, vec![0, 1], vec![10, 10], 100);
+        let unpublished_hash: H256 = BlakeTwo256::hash_of(&unpublished);
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            unpublished,
+            100
+        ));
+
+        // A commit copied from another hotkey.
+        let copied = timelocked_commit(hotkey, vec![0, 1], vec![10, 10], 5);
+        let copied_hash: H256 = BlakeTwo256::hash_of(&copied);
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(other_hotkey),
+            netuid,
+            copied,
+            5
+        ));
+
+        // The copied commit is dropped, the unpublished one is kept.
+        MockTimelockKeySource::publish_round(5);
+        step_epochs(2, netuid);
+        assert!(Weights::<Test>::get(netuid, 0).is_empty());
+        assert!(Weights::<Test>::get(netuid, 1).is_empty());
+        assert!(System::events().iter().any(|record| record.event
+            == SubtensorEvent::TimelockedWeightsRevealFailed(other_hotkey, netuid, copied_hash)
+                .into()));
+        assert!(TimelockedWeightCommits::<Test>::contains_key((
+            netuid, 1, hotkey
+        )));
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 1);
+
+        // The kept commit is revealed once its key is published.
+        MockTimelockKeySource::publish_round(100);
+        step_epochs(1, netuid);
+        assert_eq!(
+            Weights::<Test>::get(netuid, 1),
+            vec![(0, u16::MAX), (1, u16::MAX)]
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == SubtensorEvent::TimelockedWeightsRevealed(hotkey, netuid, unpublished_hash).into()));
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 0);
+    });
+}
+
+#[test]
+fn test_timelocked_weights_unpublished_commits_expire() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        setup_timelocked_weights(netuid);
+
+        let unpublished = timelocked_commit(hotkey, vec![0, 1], vec![10, 10], 100);
+        let unpublished_hash: H256 = BlakeTwo256::hash_of(&unpublished);
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            unpublished,
+            100
+        ));
+
+        // The commit is dropped a reveal period after its reveal epoch.
+        MockTimelockKeySource::publish_round(5);
+        step_epochs(2, netuid);
+        assert!(TimelockedWeightCommits::<Test>::contains_key((
+            netuid, 1, hotkey
+        )));
+        step_epochs(1, netuid);
+        assert!(!TimelockedWeightCommits::<Test>::contains_key((
+            netuid, 1, hotkey
+        )));
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 0);
+        assert!(Weights::<Test>::get(netuid, 1).is_empty());
+        assert!(System::events().iter().any(|record| record.event
+            == SubtensorEvent::TimelockedWeightsRevealFailed(hotkey, netuid, unpublished_hash)
+                .into()));
+    });
+}
+
+#[test]
+fn test_timelocked_weights_reveals_bounded_per_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        let other_hotkey: U256 = U256::from(3);
+        setup_timelocked_weights(netuid);
+
+        for (who, uids, values) in [
+            (hotkey, vec![0, 1], vec![10, 10]),
+            (other_hotkey, vec![1], vec![10]),
+        ] {
+            let commit = timelocked_commit(who, uids, values, 5);
+            assert_ok!(SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(who),
+                netuid,
+                commit,
+                5
+            ));
+        }
+        MockTimelockKeySource::publish_round(5);
+        // A block of an epoch after the reveal epoch of the commits.
+        let reveal_block: u64 = 100;
+
+        // Without budget left in the block the commits are kept.
+        let mut reveals_left: u32 = 0;
+        SubtensorModule::reveal_timelocked_weight_commits(netuid, reveal_block, &mut reveals_left);
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 2);
+
+        // A single reveal left reveals a single commit and keeps the other.
+        reveals_left = 1;
+        SubtensorModule::reveal_timelocked_weight_commits(netuid, reveal_block, &mut reveals_left);
+        assert_eq!(reveals_left, 0);
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 1);
+
+        reveals_left = MAX_TIMELOCKED_REVEALS_PER_BLOCK;
+        SubtensorModule::reveal_timelocked_weight_commits(netuid, reveal_block, &mut reveals_left);
+        assert_eq!(
+            reveals_left,
+            MAX_TIMELOCKED_REVEALS_PER_BLOCK.saturating_sub(1)
+        );
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 0);
+    });
+}
+
+#[test]
+fn test_timelocked_weight_commits_bounded_per_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        setup_timelocked_weights(netuid);
+
+        TimelockedWeightCommitCount::<Test>::insert(netuid, MAX_TIMELOCKED_COMMITS_PER_SUBNET);
+        assert_err!(
+            SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                timelocked_commit(hotkey, vec![0, 1], vec![10, 10], 5),
+                5
+            ),
+            Error::<Test>::TooManyTimelockedCommits
+        );
+    });
+}
+
+#[test]
+fn test_timelocked_weights_revealed_after_hotkey_swap() {
+    for swap_on_subnet in [false, true] {
+        new_test_ext(1).execute_with(|| {
+            let netuid: u16 = 1;
+            let hotkey: U256 = U256::from(1);
+            let coldkey: U256 = U256::from(2);
+            let new_hotkey: U256 = U256::from(5);
+            setup_timelocked_weights(netuid);
+
+            let commit = timelocked_commit(hotkey, vec![0, 1], vec![10, 10], 5);
+            let commit_hash: H256 = BlakeTwo256::hash_of(&commit);
+            assert_ok!(SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                commit,
+                5
+            ));
+
+            // The commit follows the hotkey on both swap paths.
+            let mut weight = Weight::zero();
+            if swap_on_subnet {
+                SubtensorModule::perform_hotkey_swap_on_subnet(
+                    &hotkey,
+                    &new_hotkey,
+                    netuid,
+                    &mut weight,
+                );
+            } else {
+                assert_ok!(SubtensorModule::perform_hotkey_swap(
+                    &hotkey,
+                    &new_hotkey,
+                    &coldkey,
+                    &mut weight
+                ));
+            }
+
+            // The payload keeps the identity of the hotkey which signed the commit.
+            MockTimelockKeySource::publish_round(5);
+            step_epochs(2, netuid);
+            assert_eq!(
+                Weights::<Test>::get(netuid, 1),
+                vec![(0, u16::MAX), (1, u16::MAX)]
+            );
+            assert!(System::events().iter().any(|record| record.event
+                == SubtensorEvent::TimelockedWeightsRevealed(new_hotkey, netuid, commit_hash)
+                    .into()));
+        });
+    }
+}
+
+#[test]
+fn test_timelocked_weight_commits_cleared_on_network_removal() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        setup_timelocked_weights(netuid);
+
+        let commit = timelocked_commit(hotkey, vec![0, 1], vec![10, 10], 5);
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit,
+            5
+        ));
+        assert!(TimelockedWeightCommits::<Test>::contains_key((
+            netuid, 1, hotkey
+        )));
+
+        SubtensorModule::remove_network(netuid);
+        assert!(!TimelockedWeightCommits::<Test>::contains_key((
+            netuid, 1, hotkey
+        )));
+        assert_eq!(TimelockedWeightCommitCount::<Test>::get(netuid), 0);
+    });
+}
@@ -42,7 +42,8 @@ use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{
         AccountIdLookup, BlakeTwo256, Block as BlockT, DispatchInfoOf, Dispatchable,
-        IdentifyAccount, NumberFor, One, PostDispatchInfoOf, UniqueSaturatedInto, Verify,
+        IdentifyAccount, NumberFor, One, PostDispatchInfoOf, StaticLookup, UniqueSaturatedInto,
+        Verify,
     },
     transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
     AccountId32, ApplyExtrinsicResult, ConsensusEngineId, MultiSignature,
@@ -160,7 +161,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 210,
+    spec_version: 211,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -653,8 +654,25 @@ impl Default for ProxyType {
         Self::Any
     }
 } // allow all Calls; required to be most permissive
+
+// Guardian and recovery calls can hand the coldkey to another account
+fn is_coldkey_recovery_call(c: &RuntimeCall) -> bool {
+    matches!(
+        c,
+        RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_guardians { .. })
+            | RuntimeCall::SubtensorModule(pallet_subtensor::Call::approve_coldkey_recovery { .. })
+            | RuntimeCall::SubtensorModule(pallet_subtensor::Call::cancel_coldkey_recovery { .. })
+            | RuntimeCall::SubtensorModule(
+                pallet_subtensor::Call::finalize_coldkey_recovery { .. }
+            )
+    )
+}
 impl InstanceFilter<RuntimeCall> for ProxyType {
     fn filter(&self, c: &RuntimeCall) -> bool {
+        // Only `Any` proxies may make guardian and recovery calls.
+        if *self != ProxyType::Any && is_coldkey_recovery_call(c) {
+            return false;
+        }
         match self {
             ProxyType::Any => true,
             ProxyType::NonTransfer => !matches!(c, RuntimeCall::Balances(..)),
@@ -663,12 +681,21 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 RuntimeCall::Balances(..)
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::rebalance_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::cancel_unbond { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::bid_for_registration { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_hotkey { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::swap_hotkey_on_subnet { .. }
+                    )
             ),
             ProxyType::Transfer => matches!(
                 c,
@@ -711,6 +738,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::rebalance_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::cancel_unbond { .. })
             ),
             ProxyType::Registration => matches!(
                 c,
@@ -1050,6 +1080,63 @@ impl pallet_subtensor::Config for Runtime {
     type Preimages = Preimage;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    // No randomness beacon is connected yet: timelocked weight commits are rejected by the
+    // transaction pool and the epoch reveals nothing.
+    type TimelockKeySource = ();
+    type NestedCalls = SubtensorNestedCalls;
+}
+
+/// Calls nested in batches, proxies and multisigs, with the account each is dispatched for.
+pub struct SubtensorNestedCalls;
+impl pallet_subtensor::NestedCalls<AccountId, RuntimeCall> for SubtensorNestedCalls {
+    fn nested_calls<'a>(
+        who: &AccountId,
+        call: &'a RuntimeCall,
+    ) -> Vec<(AccountId, &'a RuntimeCall)> {
+        match call {
+            RuntimeCall::Utility(
+                pallet_utility::Call::batch { calls }
+                | pallet_utility::Call::batch_all { calls }
+                | pallet_utility::Call::force_batch { calls },
+            ) => calls.iter().map(|call| (who.clone(), call)).collect(),
+            RuntimeCall::Utility(pallet_utility::Call::as_derivative { index, call }) => {
+                vec![(
+                    Utility::derivative_account_id(who.clone(), *index),
+                    call.as_ref(),
+                )]
+            }
+            RuntimeCall::Proxy(
+                pallet_proxy::Call::proxy { real, call, .. }
+                | pallet_proxy::Call::proxy_announced { real, call, .. },
+            ) => <Runtime as frame_system::Config>::Lookup::lookup(real.clone())
+                .map(|real| vec![(real, call.as_ref())])
+                .unwrap_or_default(),
+            RuntimeCall::Multisig(pallet_multisig::Call::as_multi {
+                threshold,
+                other_signatories,
+                call,
+                ..
+            }) => {
+                let mut signatories = other_signatories.clone();
+                signatories.push(who.clone());
+                signatories.sort();
+                vec![(
+                    Multisig::multi_account_id(&signatories, *threshold),
+                    call.as_ref(),
+                )]
+            }
+            RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 {
+                other_signatories,
+                call,
+            }) => {
+                let mut signatories = other_signatories.clone();
+                signatories.push(who.clone());
+                signatories.sort();
+                vec![(Multisig::multi_account_id(&signatories, 1), call.as_ref())]
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 use sp_runtime::BoundedVec;
@@ -2010,6 +2097,11 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_stake_info_for_coldkeys( coldkey_account_vecs );
             result.encode()
         }
+
+        fn get_nominator_rewards( coldkey_account_vec: Vec<u8>, from_block: u64 ) -> Vec<u8> {
+            let result = SubtensorModule::get_nominator_rewards( coldkey_account_vec, from_block );
+            result.encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
@@ -2017,6 +2109,80 @@ impl_runtime_apis! {
             SubtensorModule::get_network_lock_cost()
         }
     }
+
+    impl subtensor_custom_rpc_runtime_api::RateLimitInfoRuntimeApi<Block> for Runtime {
+        fn get_blocks_until_allowed( account_vec: Vec<u8>, tx_type: u16, netuid: u16 ) -> u64 {
+            SubtensorModule::get_blocks_until_allowed_for_account( account_vec, tx_type, netuid )
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::PreDispatchCheckRuntimeApi<Block> for Runtime {
+        fn pre_dispatch_check( call_vec: Vec<u8>, signer_account_vec: Vec<u8> ) -> Vec<u8> {
+            let Ok(call) = RuntimeCall::decode(&mut call_vec.as_slice()) else {
+                return vec![]; // Invalid call
+            };
+            let _result = SubtensorModule::pre_dispatch_check( &call, signer_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get PreDispatchFailures");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::EmissionScheduleRuntimeApi<Block> for Runtime {
+        fn get_hotkey_drain_info( hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_hotkey_drain_info( hotkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeyDrainInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_subnets_epoch_schedule() -> Vec<u8> {
+            let result = SubtensorModule::get_subnets_epoch_schedule();
+            result.encode()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::StakeGraphRuntimeApi<Block> for Runtime {
+        fn get_subnet_stake_graph(netuid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_subnet_stake_graph(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get SubnetStakeGraph");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::RegistrationTermsRuntimeApi<Block> for Runtime {
+        fn project_registration_terms(netuid: u16, pow_registrations: u16, burn_registrations: u16, intervals: u16) -> Vec<u8> {
+            let _result = SubtensorModule::project_registration_terms(netuid, pow_registrations, burn_registrations, intervals);
+            if _result.is_some() {
+                let result = _result.expect("Could not get RegistrationTermsProjection");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::EpochInfoRuntimeApi<Block> for Runtime {
+        fn epoch_simulate(netuid: u16, rao_emission: u64) -> Vec<u8> {
+            let _result = SubtensorModule::get_epoch_simulation(netuid, rao_emission);
+            if _result.is_some() {
+                let result = _result.expect("Could not get EpochSimulation");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
 }
 
 #[test]
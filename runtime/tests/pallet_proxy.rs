@@ -227,3 +227,38 @@ fn test_non_transfer_cannot_transfer() {
         );
     });
 }
+
+#[test]
+fn test_only_any_proxy_can_set_guardians() {
+    let proxy_types = [
+        ProxyType::Owner,
+        ProxyType::NonCritical,
+        ProxyType::NonTransfer,
+        ProxyType::Senate,
+        ProxyType::NonFungibile,
+        ProxyType::Triumvirate,
+        ProxyType::Governance,
+        ProxyType::Staking,
+        ProxyType::Registration,
+    ];
+
+    let call = RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_guardians {
+        guardians: vec![AccountId::from(DELEGATE)],
+        threshold: 1,
+    });
+    assert!(ProxyType::Any.filter(&call));
+
+    for proxy_type in proxy_types.iter() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Proxy::add_proxy(
+                RuntimeOrigin::signed(AccountId::from(ACCOUNT)),
+                AccountId::from(DELEGATE).into(),
+                *proxy_type,
+                0
+            ));
+
+            assert!(!proxy_type.filter(&call));
+            verify_call_with_proxy_type(proxy_type, &call);
+        });
+    }
+}
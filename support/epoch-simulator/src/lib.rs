@@ -0,0 +1,409 @@
+//! Off-chain Yuma consensus simulator.
+//!
+//! Runs the same epoch pipeline as the pallet (`pallet_subtensor::epoch::pipeline`) on a JSON
+//! snapshot of a subnet, so historical epochs can be replayed and hyperparameter changes can be
+//! evaluated before they are proposed on-chain.
+//!
+//! `rho` is not part of the snapshot since the current epoch does not read it.
+
+use pallet_subtensor::epoch::pipeline::{self, EpochInputs, EpochParams, EpochTerms};
+use serde::{Deserialize, Serialize};
+use substrate_fixed::types::{I32F32, I64F64};
+
+/// Subnet hyperparameters in their on-chain representation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Hyperparameters {
+    pub activity_cutoff: u16,
+    pub max_allowed_validators: u16,
+    /// Consensus majority ratio normalized over u16::MAX.
+    pub kappa: u16,
+    /// Bonds moving average normalized over 1_000_000.
+    pub bonds_moving_average: u64,
+    #[serde(default)]
+    pub liquid_alpha_enabled: bool,
+    /// Liquid alpha lower bound normalized over u16::MAX.
+    #[serde(default)]
+    pub alpha_low: u16,
+    /// Liquid alpha upper bound normalized over u16::MAX.
+    #[serde(default)]
+    pub alpha_high: u16,
+}
+
+impl From<&Hyperparameters> for EpochParams {
+    fn from(params: &Hyperparameters) -> Self {
+        EpochParams {
+            activity_cutoff: params.activity_cutoff,
+            max_allowed_validators: params.max_allowed_validators,
+            kappa: params.kappa,
+            bonds_moving_average: params.bonds_moving_average,
+            liquid_alpha_enabled: params.liquid_alpha_enabled,
+            alpha_low: params.alpha_low,
+            alpha_high: params.alpha_high,
+        }
+    }
+}
+
+/// State of a single neuron as read from chain storage.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NeuronSnapshot {
+    pub uid: u16,
+    pub hotkey: String,
+    /// Stake of the hotkey on the subnet in rao, after parent and child adjustments.
+    pub stake: u64,
+    pub last_update: u64,
+    pub block_at_registration: u64,
+    pub validator_permit: bool,
+    /// Stored `(uid, weight)` pairs, row max-upscaled in u16.
+    #[serde(default)]
+    pub weights: Vec<(u16, u16)>,
+    /// Stored `(uid, bond)` pairs, column max-upscaled in u16.
+    #[serde(default)]
+    pub bonds: Vec<(u16, u16)>,
+}
+
+/// Snapshot of a subnet at the block an epoch runs.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub netuid: u16,
+    pub current_block: u64,
+    /// Emission distributed by the epoch in rao.
+    pub rao_emission: u64,
+    pub hyperparameters: Hyperparameters,
+    pub neurons: Vec<NeuronSnapshot>,
+}
+
+impl Snapshot {
+    /// Converts the snapshot into the pipeline inputs, applying the same filtering as the pallet
+    /// does when reading weights and bonds from storage.
+    pub fn to_inputs(&self) -> anyhow::Result<EpochInputs<String>> {
+        let n: u16 = self
+            .neurons
+            .iter()
+            .map(|neuron| neuron.uid.saturating_add(1))
+            .max()
+            .unwrap_or_default();
+        if usize::from(n) != self.neurons.len() {
+            anyhow::bail!(
+                "snapshot uids must be unique and contiguous from 0, got {} neurons for {} uids",
+                self.neurons.len(),
+                n
+            );
+        }
+
+        let mut neurons: Vec<&NeuronSnapshot> = self.neurons.iter().collect();
+        neurons.sort_by_key(|neuron| neuron.uid);
+
+        Ok(EpochInputs {
+            n,
+            current_block: self.current_block,
+            hotkeys: neurons
+                .iter()
+                .map(|neuron| (neuron.uid, neuron.hotkey.clone()))
+                .collect(),
+            stake: neurons
+                .iter()
+                .map(|neuron| I64F64::from_num(neuron.stake))
+                .collect(),
+            last_update: neurons.iter().map(|neuron| neuron.last_update).collect(),
+            block_at_registration: neurons
+                .iter()
+                .map(|neuron| neuron.block_at_registration)
+                .collect(),
+            validator_permits: neurons
+                .iter()
+                .map(|neuron| neuron.validator_permit)
+                .collect(),
+            weights: neurons
+                .iter()
+                .map(|neuron| {
+                    neuron
+                        .weights
+                        .iter()
+                        .filter(|(uid_j, _)| *uid_j < n)
+                        .map(|(uid_j, weight_ij)| (*uid_j, I32F32::from_num(*weight_ij)))
+                        .collect()
+                })
+                .collect(),
+            bonds: neurons
+                .iter()
+                .map(|neuron| {
+                    neuron
+                        .bonds
+                        .iter()
+                        .map(|(uid_j, bond_ij)| (*uid_j, I32F32::from_num(*bond_ij)))
+                        .collect()
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Hyperparameter overrides applied on top of a snapshot.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Overrides {
+    pub kappa: Option<u16>,
+    pub bonds_moving_average: Option<u64>,
+    pub liquid_alpha_enabled: Option<bool>,
+    pub alpha_low: Option<u16>,
+    pub alpha_high: Option<u16>,
+    pub activity_cutoff: Option<u16>,
+    pub max_allowed_validators: Option<u16>,
+    pub rao_emission: Option<u64>,
+}
+
+impl Snapshot {
+    /// Returns a copy of the snapshot with `overrides` applied.
+    pub fn with_overrides(&self, overrides: &Overrides) -> Snapshot {
+        let mut snapshot = self.clone();
+        let params = &mut snapshot.hyperparameters;
+        if let Some(kappa) = overrides.kappa {
+            params.kappa = kappa;
+        }
+        if let Some(bonds_moving_average) = overrides.bonds_moving_average {
+            params.bonds_moving_average = bonds_moving_average;
+        }
+        if let Some(liquid_alpha_enabled) = overrides.liquid_alpha_enabled {
+            params.liquid_alpha_enabled = liquid_alpha_enabled;
+        }
+        if let Some(alpha_low) = overrides.alpha_low {
+            params.alpha_low = alpha_low;
+        }
+        if let Some(alpha_high) = overrides.alpha_high {
+            params.alpha_high = alpha_high;
+        }
+        if let Some(activity_cutoff) = overrides.activity_cutoff {
+            params.activity_cutoff = activity_cutoff;
+        }
+        if let Some(max_allowed_validators) = overrides.max_allowed_validators {
+            params.max_allowed_validators = max_allowed_validators;
+        }
+        if let Some(rao_emission) = overrides.rao_emission {
+            snapshot.rao_emission = rao_emission;
+        }
+        snapshot
+    }
+}
+
+/// Values the epoch writes to chain storage, comparable bit-for-bit with on-chain state.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StorageResult {
+    pub stake_weight: Vec<u16>,
+    pub active: Vec<bool>,
+    pub emission: Vec<u64>,
+    pub rank: Vec<u16>,
+    pub trust: Vec<u16>,
+    pub consensus: Vec<u16>,
+    pub incentive: Vec<u16>,
+    pub dividends: Vec<u16>,
+    pub pruning_scores: Vec<u16>,
+    pub validator_trust: Vec<u16>,
+    pub validator_permit: Vec<bool>,
+    /// New bonds row per uid, `None` when the stored row is left untouched.
+    pub bonds: Vec<Option<Vec<(u16, u16)>>>,
+}
+
+/// Intermediate terms of the epoch as floating point values, for inspection only.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TermsResult {
+    pub stake: Vec<f64>,
+    pub active_stake: Vec<f64>,
+    pub weights: Vec<Vec<(u16, f64)>>,
+    pub preranks: Vec<f64>,
+    pub consensus: Vec<f64>,
+    pub clipped_weights: Vec<Vec<(u16, f64)>>,
+    pub validator_trust: Vec<f64>,
+    pub ranks: Vec<f64>,
+    pub trust: Vec<f64>,
+    pub incentive: Vec<f64>,
+    pub bonds: Vec<Vec<(u16, f64)>>,
+    pub bonds_delta: Vec<Vec<(u16, f64)>>,
+    pub ema_bonds: Vec<Vec<(u16, f64)>>,
+    pub dividends: Vec<f64>,
+    pub normalized_server_emission: Vec<f64>,
+    pub normalized_validator_emission: Vec<f64>,
+    pub normalized_combined_emission: Vec<f64>,
+    pub pruning_scores: Vec<f64>,
+}
+
+fn to_f64(v: &[I32F32]) -> Vec<f64> {
+    v.iter().map(|x| x.to_num::<f64>()).collect()
+}
+
+fn sparse_to_f64(m: &[Vec<(u16, I32F32)>]) -> Vec<Vec<(u16, f64)>> {
+    m.iter()
+        .map(|row| row.iter().map(|(j, x)| (*j, x.to_num::<f64>())).collect())
+        .collect()
+}
+
+impl<AccountId> From<&EpochTerms<AccountId>> for TermsResult {
+    fn from(terms: &EpochTerms<AccountId>) -> Self {
+        TermsResult {
+            stake: to_f64(&terms.stake),
+            active_stake: to_f64(&terms.active_stake),
+            weights: sparse_to_f64(&terms.weights),
+            preranks: to_f64(&terms.preranks),
+            consensus: to_f64(&terms.consensus),
+            clipped_weights: sparse_to_f64(&terms.clipped_weights),
+            validator_trust: to_f64(&terms.validator_trust),
+            ranks: to_f64(&terms.ranks),
+            trust: to_f64(&terms.trust),
+            incentive: to_f64(&terms.incentive),
+            bonds: sparse_to_f64(&terms.bonds),
+            bonds_delta: sparse_to_f64(&terms.bonds_delta),
+            ema_bonds: sparse_to_f64(&terms.ema_bonds),
+            dividends: to_f64(&terms.dividends),
+            normalized_server_emission: to_f64(&terms.normalized_server_emission),
+            normalized_validator_emission: to_f64(&terms.normalized_validator_emission),
+            normalized_combined_emission: to_f64(&terms.normalized_combined_emission),
+            pruning_scores: to_f64(&terms.pruning_scores),
+        }
+    }
+}
+
+/// Emission of a single hotkey in rao.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HotkeyEmission {
+    pub hotkey: String,
+    pub server_emission: u64,
+    pub validator_emission: u64,
+}
+
+/// Result of simulating one epoch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SimulationResult {
+    pub netuid: u16,
+    pub current_block: u64,
+    pub rao_emission: u64,
+    pub emissions: Vec<HotkeyEmission>,
+    pub storage: StorageResult,
+    pub terms: TermsResult,
+}
+
+/// Runs the epoch pipeline on `snapshot`.
+pub fn simulate(snapshot: &Snapshot) -> anyhow::Result<SimulationResult> {
+    let params: EpochParams = (&snapshot.hyperparameters).into();
+    let terms: EpochTerms<String> =
+        pipeline::epoch_terms(snapshot.to_inputs()?, &params, snapshot.rao_emission);
+
+    let emissions: Vec<HotkeyEmission> = terms
+        .emission_tuples()
+        .into_iter()
+        .map(
+            |(hotkey, server_emission, validator_emission)| HotkeyEmission {
+                hotkey,
+                server_emission,
+                validator_emission,
+            },
+        )
+        .collect();
+    let terms_result: TermsResult = (&terms).into();
+    let storage = terms.into_storage();
+
+    Ok(SimulationResult {
+        netuid: snapshot.netuid,
+        current_block: snapshot.current_block,
+        rao_emission: snapshot.rao_emission,
+        emissions,
+        storage: StorageResult {
+            stake_weight: storage.stake_weight,
+            active: storage.active,
+            emission: storage.emission,
+            rank: storage.rank,
+            trust: storage.trust,
+            consensus: storage.consensus,
+            incentive: storage.incentive,
+            dividends: storage.dividends,
+            pruning_scores: storage.pruning_scores,
+            validator_trust: storage.validator_trust,
+            validator_permit: storage.validator_permit,
+            bonds: storage.bonds,
+        },
+        terms: terms_result,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing, clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn neuron(uid: u16, stake: u64, weights: Vec<(u16, u16)>) -> NeuronSnapshot {
+        NeuronSnapshot {
+            uid,
+            hotkey: format!("hotkey{}", uid),
+            stake,
+            last_update: 10,
+            block_at_registration: 0,
+            validator_permit: true,
+            weights,
+            bonds: vec![],
+        }
+    }
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            netuid: 1,
+            current_block: 11,
+            rao_emission: 1_000_000_000,
+            hyperparameters: Hyperparameters {
+                activity_cutoff: 5000,
+                max_allowed_validators: 4,
+                kappa: 32_767,
+                bonds_moving_average: 900_000,
+                liquid_alpha_enabled: false,
+                alpha_low: 45_875,
+                alpha_high: 58_982,
+            },
+            neurons: vec![
+                neuron(0, 1_000, vec![(2, u16::MAX), (3, u16::MAX)]),
+                neuron(1, 1_000, vec![(2, u16::MAX), (3, u16::MAX)]),
+                neuron(2, 0, vec![]),
+                neuron(3, 0, vec![]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_simulate_splits_emission_between_servers_and_validators() {
+        let result = simulate(&snapshot()).unwrap();
+        assert_eq!(result.emissions.len(), 4);
+        assert_eq!(result.storage.emission.len(), 4);
+
+        let total: u64 = result
+            .emissions
+            .iter()
+            .map(|e| e.server_emission.saturating_add(e.validator_emission))
+            .sum();
+        assert!(total <= 1_000_000_000 && total >= 999_999_990);
+
+        // Symmetric validators and servers receive identical emission.
+        assert_eq!(result.storage.emission[0], result.storage.emission[1]);
+        assert_eq!(result.storage.emission[2], result.storage.emission[3]);
+        assert_eq!(result.storage.incentive[0], 0);
+        assert_eq!(result.storage.dividends[2], 0);
+    }
+
+    #[test]
+    fn test_overrides_change_only_selected_hyperparameters() {
+        let base = snapshot();
+        let overridden = base.with_overrides(&Overrides {
+            kappa: Some(0),
+            rao_emission: Some(10),
+            ..Default::default()
+        });
+        assert_eq!(overridden.hyperparameters.kappa, 0);
+        assert_eq!(overridden.rao_emission, 10);
+        assert_eq!(
+            overridden.hyperparameters.bonds_moving_average,
+            base.hyperparameters.bonds_moving_average
+        );
+        assert_eq!(overridden.neurons, base.neurons);
+    }
+
+    #[test]
+    fn test_snapshot_rejects_non_contiguous_uids() {
+        let mut snapshot = snapshot();
+        snapshot.neurons[3].uid = 7;
+        assert!(simulate(&snapshot).is_err());
+    }
+}
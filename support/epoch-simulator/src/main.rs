@@ -0,0 +1,64 @@
+use clap::Parser;
+use std::path::PathBuf;
+use subtensor_epoch_simulator::{simulate, Overrides, Snapshot};
+
+/// Replays a subnet epoch from a JSON snapshot using the on-chain epoch pipeline.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Cli {
+    /// Path to the JSON snapshot of the subnet.
+    #[arg(long)]
+    snapshot: PathBuf,
+    /// Override kappa (normalized over u16::MAX).
+    #[arg(long)]
+    kappa: Option<u16>,
+    /// Override bonds moving average (normalized over 1_000_000).
+    #[arg(long)]
+    bonds_moving_average: Option<u64>,
+    /// Override whether liquid alpha is enabled.
+    #[arg(long)]
+    liquid_alpha_enabled: Option<bool>,
+    /// Override the liquid alpha lower bound (normalized over u16::MAX).
+    #[arg(long)]
+    alpha_low: Option<u16>,
+    /// Override the liquid alpha upper bound (normalized over u16::MAX).
+    #[arg(long)]
+    alpha_high: Option<u16>,
+    /// Override the activity cutoff in blocks.
+    #[arg(long)]
+    activity_cutoff: Option<u16>,
+    /// Override the maximum number of validators.
+    #[arg(long)]
+    max_allowed_validators: Option<u16>,
+    /// Override the emission distributed by the epoch in rao.
+    #[arg(long)]
+    rao_emission: Option<u64>,
+    /// Pretty print the JSON output.
+    #[arg(long)]
+    pretty: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    let snapshot: Snapshot = serde_json::from_slice(&std::fs::read(&cli.snapshot)?)?;
+    let overrides = Overrides {
+        kappa: cli.kappa,
+        bonds_moving_average: cli.bonds_moving_average,
+        liquid_alpha_enabled: cli.liquid_alpha_enabled,
+        alpha_low: cli.alpha_low,
+        alpha_high: cli.alpha_high,
+        activity_cutoff: cli.activity_cutoff,
+        max_allowed_validators: cli.max_allowed_validators,
+        rao_emission: cli.rao_emission,
+    };
+    let result = simulate(&snapshot.with_overrides(&overrides))?;
+
+    let output = if cli.pretty {
+        serde_json::to_string_pretty(&result)?
+    } else {
+        serde_json::to_string(&result)?
+    };
+    println!("{}", output);
+    Ok(())
+}